@@ -183,7 +183,21 @@ fn main() {
 			let ident = node.ident();
 			if matches!(
 				ident.to_string().as_str(),
-				"FontFaceRuleStyleValue" | "PropertyRuleStyleValue" | "CounterStyleRuleStyleValue"
+				"FontFaceRuleStyleValue"
+					| "PropertyRuleStyleValue"
+					| "CounterStyleRuleStyleValue"
+					| "FontPaletteValuesRuleStyleValue"
+					| "FontPaletteFontFamilyStyleValue"
+					| "BasePaletteStyleValue"
+					| "OverrideColorsStyleValue"
+					| "ColorProfileRuleStyleValue"
+					| "ColorProfileSrcStyleValue"
+					| "RenderingIntentStyleValue"
+					| "ComponentsStyleValue"
+					| "PositionTryRuleStyleValue"
+					| "ViewTransitionRuleStyleValue"
+					| "NavigationStyleValue"
+					| "TypesStyleValue"
 			) {
 				return None;
 			}
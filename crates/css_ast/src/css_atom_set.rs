@@ -97,6 +97,7 @@ pub enum CssAtomSet {
 	Accentcolor,
 	Accentcolortext,
 	Accumulate,
+	Acos,
 	Acronym,
 	Active,
 	Activate,
@@ -125,6 +126,8 @@ pub enum CssAtomSet {
 	Always,
 	AnchorName,
 	AnchorScope,
+	AnchorsValid,
+	AnchorsVisible,
 	And,
 	Animate,
 	Animatemotion,
@@ -182,6 +185,9 @@ pub enum CssAtomSet {
 	Attr,
 	Audio,
 	Auto,
+	AutoFill,
+	AutoFit,
+	AutoFlow,
 	AutoPhrase,
 	Autofill,
 	Avoid,
@@ -256,6 +262,7 @@ pub enum CssAtomSet {
 	Blockquote,
 	Blue,
 	Blueviolet,
+	Blur,
 	Body,
 	Bold,
 	Bolder,
@@ -379,6 +386,7 @@ pub enum CssAtomSet {
 	BreakInside,
 	BreakSpaces,
 	BreakWord,
+	Brightness,
 	Brown,
 	Browser,
 	Buffering,
@@ -396,6 +404,7 @@ pub enum CssAtomSet {
 	Cambodian,
 	Canvas,
 	Canvastext,
+	CapHeight,
 	Caption,
 	CaptionSide,
 	Card,
@@ -410,6 +419,8 @@ pub enum CssAtomSet {
 	Center,
 	Central,
 	Cerror,
+	ChWidth,
+	CharacterVariant,
 	Charset,
 	Chartreuse,
 	Checkbox,
@@ -478,6 +489,7 @@ pub enum CssAtomSet {
 	ColumnWidth,
 	ColumnWrap,
 	Columns,
+	CommonLigatures,
 	Compact,
 	Components,
 	Compose,
@@ -499,7 +511,9 @@ pub enum CssAtomSet {
 	ContentVisibility,
 	Contents,
 	ContextMenu,
+	Contextual,
 	Continue,
+	Contrast,
 	Copy,
 	CopyInto,
 	Coral,
@@ -596,6 +610,7 @@ pub enum CssAtomSet {
 	Decimal,
 	DecimalLeadingZero,
 	Declare,
+	Decreasing,
 	Deeppink,
 	Deepskyblue,
 	Default,
@@ -603,6 +618,7 @@ pub enum CssAtomSet {
 	Defs,
 	Degree,
 	Del,
+	Dense,
 	Desc,
 	DescentOverride,
 	Details,
@@ -610,9 +626,11 @@ pub enum CssAtomSet {
 	Determinant,
 	Devanagari,
 	DeviceAspectRatio,
+	DeviceCmyk,
 	DeviceHeight,
 	DeviceWidth,
 	Dfn,
+	DiagonalFractions,
 	Dialog,
 	Diff,
 	Difference,
@@ -627,9 +645,11 @@ pub enum CssAtomSet {
 	DisclousureClosed,
 	DisclousureOpen,
 	Discrete,
+	DiscretionaryLigatures,
 	Display,
 	DisplayMode,
 	DisplayP3,
+	DisplayP3Linear,
 	Div,
 	Divergence,
 	Divide,
@@ -642,10 +662,12 @@ pub enum CssAtomSet {
 	Dotted,
 	Double,
 	Down,
+	DropShadow,
 	Dt,
 	DynamicRange,
 	DynamicRangeLimit,
 	DynamicRangeLimitMix,
+	E,
 	EResize,
 	Ease,
 	EaseIn,
@@ -660,6 +682,7 @@ pub enum CssAtomSet {
 	Emptyset,
 	Enabled,
 	End,
+	Env,
 	EnvironmentBlending,
 	Eq,
 	Equivalent,
@@ -669,6 +692,7 @@ pub enum CssAtomSet {
 	EventTriggerName,
 	EventTriggerSource,
 	EwResize,
+	ExHeight,
 	Exact,
 	Exclusion,
 	Exists,
@@ -755,6 +779,11 @@ pub enum CssAtomSet {
 	FlexStart,
 	FlexVisual,
 	FlexWrap,
+	FlipBlock,
+	FlipInline,
+	FlipStart,
+	FlipX,
+	FlipY,
 	Float,
 	FloatDefer,
 	FloatOffset,
@@ -792,6 +821,7 @@ pub enum CssAtomSet {
 	FontPaletteValues,
 	FontSize,
 	FontSizeAdjust,
+	FontStretch,
 	FontStyle,
 	FontSynthesis,
 	FontSynthesisPosition,
@@ -826,6 +856,7 @@ pub enum CssAtomSet {
 	From,
 	FromFont,
 	Fuchsia,
+	FullWidth,
 	Fullscreen,
 	FullscreenLandscape,
 	FullscreenPortait,
@@ -845,6 +876,7 @@ pub enum CssAtomSet {
 	Grabbing,
 	GrammarError,
 	Gray,
+	Grayscale,
 	Graytext,
 	Green,
 	Greenyellow,
@@ -897,6 +929,8 @@ pub enum CssAtomSet {
 	Highlighttext,
 	Hiragana,
 	HiraganaIroha,
+	HistoricalForms,
+	HistoricalLigatures,
 	Honeydew,
 	Horizontal,
 	HorizontalTb,
@@ -910,6 +944,7 @@ pub enum CssAtomSet {
 	Hsla,
 	Html,
 	Hue,
+	HueRotate,
 	Hwb,
 	HyphenateCharacter,
 	HyphenateLimitChars,
@@ -919,6 +954,9 @@ pub enum CssAtomSet {
 	Hyphens,
 	Hypot,
 	I,
+	IcHeight,
+	IcWidth,
+	Icon,
 	Ident,
 	Ideographic,
 	Iframe,
@@ -933,6 +971,7 @@ pub enum CssAtomSet {
 	Implies,
 	Import,
 	InRange,
+	Increasing,
 	Indeterminate,
 	Indianred,
 	Indigo,
@@ -979,6 +1018,7 @@ pub enum CssAtomSet {
 	Interval,
 	Invalid,
 	Inverse,
+	Invert,
 	Inverted,
 	InvertedColors,
 	Is,
@@ -994,6 +1034,10 @@ pub enum CssAtomSet {
 	ItemTrack,
 	ItemWrap,
 	Ivory,
+	Jis04,
+	Jis78,
+	Jis83,
+	Jis90,
 	JumpBoth,
 	JumpEnd,
 	JumpNone,
@@ -1086,6 +1130,7 @@ pub enum CssAtomSet {
 	LinearGradient,
 	Lineargradient,
 	Linen,
+	LiningNums,
 	Link,
 	LinkParameters,
 	Linktext,
@@ -1102,6 +1147,7 @@ pub enum CssAtomSet {
 	LocalLink,
 	Log,
 	Logbase,
+	Longer,
 	Loose,
 	LowerAlpha,
 	LowerArmenian,
@@ -1111,6 +1157,7 @@ pub enum CssAtomSet {
 	Lt,
 	Ltr,
 	Luminance,
+	MessageBox,
 	Modifications,
 	Maction,
 	Magenta,
@@ -1158,6 +1205,7 @@ pub enum CssAtomSet {
 	MaskRepeat,
 	MaskSize,
 	MaskType,
+	Masonry,
 	MatchParent,
 	MatchSelf,
 	Math,
@@ -1235,6 +1283,7 @@ pub enum CssAtomSet {
 	MinWidth,
 	MinimalUi,
 	Minimum,
+	Minmax,
 	Mintcream,
 	Minus,
 	MissingGlyph,
@@ -1293,6 +1342,7 @@ pub enum CssAtomSet {
 	Myanmar,
 	NResize,
 	Namespace,
+	Nan,
 	Narrow,
 	Nastaliq,
 	Nav,
@@ -1313,11 +1363,16 @@ pub enum CssAtomSet {
 	Never,
 	Nextid,
 	NoCloseQuote,
+	NoCommonLigatures,
+	NoContextual,
+	NoDiscretionaryLigatures,
 	NoDrop,
 	NoClip,
 	NoEllipsis,
+	NoHistoricalLigatures,
 	NoLimit,
 	NoOpenQuote,
+	NoOverflow,
 	NoPreference,
 	NoRepeat,
 	Nobr,
@@ -1364,6 +1419,7 @@ pub enum CssAtomSet {
 	Oklch,
 	Ol,
 	Oldlace,
+	OldstyleNums,
 	Olive,
 	Olivedrab,
 	On,
@@ -1383,8 +1439,10 @@ pub enum CssAtomSet {
 	Orangered,
 	Orchid,
 	Order,
+	Ordinal,
 	Orientation,
 	Oriya,
+	Ornaments,
 	Orphans,
 	Otherwise,
 	OutOfRange,
@@ -1423,6 +1481,7 @@ pub enum CssAtomSet {
 	OverscrollBehaviorInline,
 	OverscrollBehaviorX,
 	OverscrollBehaviorY,
+	PaletteMix,
 	PlusLighter,
 	PointerTimelineAxis,
 	P,
@@ -1468,6 +1527,7 @@ pub enum CssAtomSet {
 	PerspectiveOrigin,
 	Peru,
 	PetiteCaps,
+	Pi,
 	Picker,
 	PickerIcon,
 	Picture,
@@ -1528,6 +1588,8 @@ pub enum CssAtomSet {
 	Progressive,
 	Property,
 	ProphotoRgb,
+	ProportionalNums,
+	ProportionalWidth,
 	Prsubset,
 	Punctuation,
 	Purple,
@@ -1657,6 +1719,7 @@ pub enum CssAtomSet {
 	Samp,
 	Sandybrown,
 	SansSerif,
+	Saturate,
 	Saturation,
 	Scalarproduct,
 	Scale,
@@ -1686,7 +1749,14 @@ pub enum CssAtomSet {
 	ScrollMarginInlineStart,
 	ScrollMarginLeft,
 	ScrollMarginRight,
+	Sepia,
+	Shorter,
+	Simplified,
+	SlashedZero,
+	SmallCaption,
 	SoftLift,
+	StackedFractions,
+	StatusBar,
 	StrokeAlign,
 	StrokeDashJustify,
 	StrokeImage,
@@ -1740,6 +1810,8 @@ pub enum CssAtomSet {
 	SelfInlineEnd,
 	SelfInlineStart,
 	SelfStart,
+	#[atom("self")]
+	Self_,
 	Semantics,
 	SemiCondensed,
 	SemiExpanded,
@@ -1874,7 +1946,10 @@ pub enum CssAtomSet {
 	Strong,
 	Stuck,
 	Style,
+	Styleset,
+	Stylistic,
 	Sub,
+	Subgrid,
 	Subset,
 	Subtractive,
 	Suffix,
@@ -1887,6 +1962,7 @@ pub enum CssAtomSet {
 	Svg,
 	SwResize,
 	Swap,
+	Swash,
 	Switch,
 	Symbol,
 	Symbolic,
@@ -1905,6 +1981,7 @@ pub enum CssAtomSet {
 	TableLayout,
 	TableRow,
 	TableRowGroup,
+	TabularNums,
 	Tamil,
 	Tan,
 	Target,
@@ -1987,6 +2064,7 @@ pub enum CssAtomSet {
 	Title,
 	TitlingCaps,
 	To,
+	ToZero,
 	Tomato,
 	Top,
 	TopCenter,
@@ -1998,6 +2076,7 @@ pub enum CssAtomSet {
 	Touch,
 	Tr,
 	Track,
+	Traditional,
 	Transform,
 	TransformBox,
 	TransformOrigin,
@@ -2105,6 +2184,7 @@ pub enum CssAtomSet {
 	Wavy,
 	Wbr,
 	Weak,
+	Weight,
 	Wheat,
 	Where,
 	White,
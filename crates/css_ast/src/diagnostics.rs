@@ -13,6 +13,9 @@ pub trait CssDiagnostic {
 	fn reserved_keyframe_name(diagnostic: &Diagnostic, source: &str) -> DiagnosticMeta;
 	fn non_negative(diagnostic: &Diagnostic, source: &str) -> DiagnosticMeta;
 	fn positive(diagnostic: &Diagnostic, source: &str) -> DiagnosticMeta;
+	fn invalid_grid_area_string(diagnostic: &Diagnostic, source: &str) -> DiagnosticMeta;
+	fn grid_area_column_mismatch(diagnostic: &Diagnostic, source: &str) -> DiagnosticMeta;
+	fn non_rectangular_grid_area(diagnostic: &Diagnostic, source: &str) -> DiagnosticMeta;
 }
 
 impl CssDiagnostic for Diagnostic {
@@ -139,4 +142,32 @@ impl CssDiagnostic for Diagnostic {
 			labels: vec![],
 		}
 	}
+
+	fn invalid_grid_area_string(_diagnostic: &Diagnostic, _source: &str) -> DiagnosticMeta {
+		DiagnosticMeta {
+			code: "InvalidGridAreaString",
+			message: "Grid area strings may only contain area names, `.` for empty cells, and whitespace.".into(),
+			help: "Each string needs at least one cell. Remove any other characters.".into(),
+			labels: vec![],
+		}
+	}
+
+	fn grid_area_column_mismatch(_diagnostic: &Diagnostic, _source: &str) -> DiagnosticMeta {
+		DiagnosticMeta {
+			code: "GridAreaColumnMismatch",
+			message: "This row has a different number of columns to the rows before it.".into(),
+			help: "Every string in a grid template must have the same number of columns. Use `.` to fill empty cells."
+				.into(),
+			labels: vec![],
+		}
+	}
+
+	fn non_rectangular_grid_area(_diagnostic: &Diagnostic, _source: &str) -> DiagnosticMeta {
+		DiagnosticMeta {
+			code: "NonRectangularGridArea",
+			message: "This grid area doesn't form a single filled rectangle.".into(),
+			help: "Each named grid area must cover a rectangle of adjacent cells, with no gaps.".into(),
+			labels: vec![],
+		}
+	}
 }
@@ -24,6 +24,8 @@ pub enum ColorSpace {
 	XyzD50(T![Ident]),
 	#[atom(CssAtomSet::XyzD65)]
 	XyzD65(T![Ident]),
+	/// A color space defined by an `@color-profile` rule of the same name.
+	Custom(T![DashedIdent]),
 }
 
 #[derive(IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -58,8 +60,8 @@ impl<'a> Parse<'a> for CommaOrSlash {
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 #[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
 #[derive(csskit_derives::NodeWithMetadata)]
-pub enum ColorFunction {
-	Color(ColorFunctionColor),
+pub enum ColorFunction<'a> {
+	Color(ColorFunctionColor<'a>),
 	Rgb(RgbFunction),
 	Rgba(RgbaFunction),
 	Hsl(HslFunction),
@@ -72,7 +74,7 @@ pub enum ColorFunction {
 }
 
 #[cfg(feature = "chromashift")]
-impl crate::ToChromashift for ColorFunction {
+impl<'a> crate::ToChromashift for ColorFunction<'a> {
 	fn to_chromashift(&self) -> Option<chromashift::Color> {
 		match self {
 			Self::Color(c) => c.to_chromashift(),
@@ -89,11 +91,12 @@ impl crate::ToChromashift for ColorFunction {
 	}
 }
 
-/// <https://drafts.csswg.org/css-color/#funcdef-color>
+/// <https://drafts.csswg.org/css-color-5/#color-function>
 ///
 /// ```text,ignore
 /// color() = color( <colorspace-params> [ / [ <alpha-value> | none ] ]? )
-/// <colorspace-params> = [ <predefined-rgb-params> | <xyz-params>]
+/// <colorspace-params> = [ <custom-params> | <predefined-rgb-params> | <xyz-params>]
+/// <custom-params> = <dashed-ident> [ <number> | <percentage> | none ]+
 /// <predefined-rgb-params> = <predefined-rgb> [ <number> | <percentage> | none ]{3}
 /// <predefined-rgb> = srgb | srgb-linear | display-p3 | a98-rgb | prophoto-rgb | rec2020
 /// <xyz-params> = <xyz-space> [ <number> | <percentage> | none ]{3}
@@ -103,33 +106,107 @@ impl crate::ToChromashift for ColorFunction {
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 #[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
 #[derive(csskit_derives::NodeWithMetadata)]
-pub struct ColorFunctionColor {
+pub struct ColorFunctionColor<'a> {
 	#[atom(CssAtomSet::Color)]
 	pub name: T![Function],
-	pub params: ColorFunctionColorParams,
+	pub params: ColorFunctionColorParams<'a>,
 	pub close: T![')'],
 }
 
+impl<'a> ColorFunctionColor<'a> {
+	/// The name of the `@color-profile` this color is in, when it uses a custom color space such as
+	/// `color(--swop5c 0% 70% 20% 0%)`. This can be compared with [ColorProfileRule::prelude][crate::ColorProfileRule]
+	/// to find the profile's definition.
+	pub fn profile(&self) -> Option<Cursor> {
+		match self.params.0 {
+			ColorSpace::Custom(name) => Some(name.into()),
+			_ => None,
+		}
+	}
+}
+
 #[cfg(feature = "chromashift")]
-impl crate::ToChromashift for ColorFunctionColor {
+impl<'a> crate::ToChromashift for ColorFunctionColor<'a> {
 	fn to_chromashift(&self) -> Option<chromashift::Color> {
-		todo!();
+		self.params.to_chromashift()
 	}
 }
 
-#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The color space and components of `color()`. The predefined color spaces always have three components, while a
+/// custom profile has as many as its `@color-profile` defines.
+#[derive(Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 #[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
 #[derive(csskit_derives::NodeWithMetadata)]
-pub struct ColorFunctionColorParams(
+pub struct ColorFunctionColorParams<'a>(
 	pub ColorSpace,
-	pub NoneOr<NumberOrPercentage>,
-	pub NoneOr<NumberOrPercentage>,
-	pub NoneOr<NumberOrPercentage>,
+	pub Vec<'a, NoneOr<NumberOrPercentage>>,
 	pub Option<T![/]>,
 	pub Option<NoneOr<NumberOrPercentage>>,
 );
 
+impl<'a> Parse<'a> for ColorFunctionColorParams<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let space = p.parse::<ColorSpace>()?;
+		let mut components = Vec::new_in(p.bump());
+		components.push(p.parse::<NoneOr<NumberOrPercentage>>()?);
+		if matches!(space, ColorSpace::Custom(_)) {
+			while p.peek::<NoneOr<NumberOrPercentage>>() {
+				components.push(p.parse::<NoneOr<NumberOrPercentage>>()?);
+			}
+		} else {
+			components.push(p.parse::<NoneOr<NumberOrPercentage>>()?);
+			components.push(p.parse::<NoneOr<NumberOrPercentage>>()?);
+		}
+		let slash = p.parse_if_peek::<T![/]>()?;
+		let alpha = if slash.is_some() { Some(p.parse::<NoneOr<NumberOrPercentage>>()?) } else { None };
+		Ok(Self(space, components, slash, alpha))
+	}
+}
+
+#[cfg(feature = "chromashift")]
+impl<'a> crate::ToChromashift for ColorFunctionColorParams<'a> {
+	fn to_chromashift(&self) -> Option<chromashift::Color> {
+		use chromashift::{A98Rgb, Color, DisplayP3, LinearRgb, ProphotoRgb, Rec2020, Srgb, XyzD50, XyzD65};
+		let Self(space, components, _, alpha) = &self;
+		let alpha = match alpha {
+			Some(NoneOr::None(_)) => 0.0,
+			Some(NoneOr::Some(NumberOrPercentage::Number(t))) => t.value() * 100.0,
+			Some(NoneOr::Some(NumberOrPercentage::Percentage(t))) => t.value(),
+			None => 100.0,
+		};
+		let channel = |channel: &NoneOr<NumberOrPercentage>| match channel {
+			NoneOr::None(_) => None,
+			NoneOr::Some(NumberOrPercentage::Number(t)) => Some(t.value() as f64),
+			NoneOr::Some(NumberOrPercentage::Percentage(t)) => Some(t.value() as f64 / 100.0),
+		};
+		let [first, second, third] = components.as_slice() else {
+			return None;
+		};
+		let (first, second, third) = (channel(first)?, channel(second)?, channel(third)?);
+		// Srgb only holds whole channels, so out of gamut values are clamped.
+		let byte = |value: f64| (value * 255.0).round() as u8;
+		Some(match space {
+			ColorSpace::Srgb(_) => Color::Srgb(Srgb::new(byte(first), byte(second), byte(third), alpha)),
+			ColorSpace::SrgbLinear(_) => Color::LinearRgb(LinearRgb::new(first, second, third, alpha)),
+			ColorSpace::DisplayP3(_) => Color::DisplayP3(DisplayP3::new(first, second, third, alpha)),
+			ColorSpace::A98Rgb(_) => Color::A98Rgb(A98Rgb::new(first, second, third, alpha)),
+			ColorSpace::ProphotoRgb(_) => Color::ProphotoRgb(ProphotoRgb::new(first, second, third, alpha)),
+			ColorSpace::Rec2020(_) => Color::Rec2020(Rec2020::new(first, second, third, alpha)),
+			// chromashift's XYZ spaces range from 0 to 100, rather than 0 to 1.
+			ColorSpace::Xyz(_) | ColorSpace::XyzD65(_) => {
+				Color::XyzD65(XyzD65::new(first * 100.0, second * 100.0, third * 100.0, alpha))
+			}
+			ColorSpace::XyzD50(_) => Color::XyzD50(XyzD50::new(first * 100.0, second * 100.0, third * 100.0, alpha)),
+			// Converting a custom profile needs the ICC profile it was defined with, so its colors cannot be known here.
+			ColorSpace::Custom(_) => return None,
+		})
+	}
+}
+
 /// <https://drafts.csswg.org/css-color/#funcdef-rgb>
 ///
 /// ```text,ignore
@@ -651,8 +728,8 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_eq!(std::mem::size_of::<ColorFunction>(), 140);
-		assert_eq!(std::mem::size_of::<ColorFunctionColor>(), 120);
+		assert_eq!(std::mem::size_of::<ColorFunction>(), 144);
+		assert_eq!(std::mem::size_of::<ColorFunctionColor>(), 104);
 		assert_eq!(std::mem::size_of::<RgbFunction>(), 136);
 		assert_eq!(std::mem::size_of::<RgbaFunction>(), 136);
 		assert_eq!(std::mem::size_of::<HslFunction>(), 136);
@@ -0,0 +1,349 @@
+use super::prelude::*;
+use crate::{AngleOrZero, Length, NonNegative, NumberPercentage, Url};
+use css_lexer::ToSpan;
+use css_parse::{CursorSink, ToCursors, ToNumberValue};
+
+/// <https://drafts.csswg.org/filter-effects-1/#typedef-filter-function>
+///
+/// ```text,ignore
+/// <filter-function> = <blur()> | <brightness()> | <contrast()> | <drop-shadow()> | <grayscale()> | <hue-rotate()> |
+///                     <invert()> | <opacity()> | <saturate()> | <sepia()>
+/// ```
+///
+/// A `<filter-value-list>` may also reference an SVG filter by `<url>`, which is included here as [FilterFunction::Url].
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum FilterFunction<'a> {
+	Blur(BlurFunction),
+	Brightness(BrightnessFunction),
+	Contrast(ContrastFunction),
+	DropShadow(DropShadowFunction<'a>),
+	Grayscale(GrayscaleFunction),
+	HueRotate(HueRotateFunction),
+	Invert(InvertFunction),
+	Opacity(OpacityFunction),
+	Saturate(SaturateFunction),
+	Sepia(SepiaFunction),
+	Url(Url),
+}
+
+impl<'a> FilterFunction<'a> {
+	/// The `<number> | <percentage>` argument of the functions which take an amount: `brightness()`, `contrast()`,
+	/// `grayscale()`, `invert()`, `opacity()`, `saturate()` and `sepia()`.
+	pub fn amount(&self) -> Option<NumberPercentage> {
+		match self {
+			Self::Brightness(f) => f.params.map(|p| p.0),
+			Self::Contrast(f) => f.params.map(|p| p.0),
+			Self::Grayscale(f) => f.params.map(|p| p.0),
+			Self::Invert(f) => f.params.map(|p| p.0),
+			Self::Opacity(f) => f.params.map(|p| p.0),
+			Self::Saturate(f) => f.params.map(|p| p.0),
+			Self::Sepia(f) => f.params.map(|p| p.0),
+			Self::Blur(_) | Self::DropShadow(_) | Self::HueRotate(_) | Self::Url(_) => None,
+		}
+	}
+
+	/// Whether this function leaves the image unchanged, for example `blur(0)`, `opacity(1)` or `sepia(0%)`.
+	///
+	/// An omitted argument takes the function's default, which is the identity for `blur()`, `brightness()`,
+	/// `contrast()`, `hue-rotate()`, `opacity()` and `saturate()`, but not for `grayscale()`, `invert()` or `sepia()`.
+	/// `drop-shadow()` and `<url>` references are never considered an identity.
+	pub fn is_identity(&self) -> bool {
+		fn amount_is(amount: Option<NonNegative<NumberPercentage>>, identity: f32) -> bool {
+			amount.is_none_or(|amount| match amount.0 {
+				NumberPercentage::Number(n) => n.value() == identity,
+				NumberPercentage::Percentage(p) => p.value() == identity * 100.0,
+			})
+		}
+		match self {
+			Self::Blur(f) => f.params.is_none_or(|length| length.0.to_number_value() == Some(0.0)),
+			Self::HueRotate(f) => f.params.is_none_or(|angle| f32::from(angle) == 0.0),
+			Self::Brightness(f) => amount_is(f.params, 1.0),
+			Self::Contrast(f) => amount_is(f.params, 1.0),
+			Self::Opacity(f) => amount_is(f.params, 1.0),
+			Self::Saturate(f) => amount_is(f.params, 1.0),
+			Self::Grayscale(f) => f.params.is_some() && amount_is(f.params, 0.0),
+			Self::Invert(f) => f.params.is_some() && amount_is(f.params, 0.0),
+			Self::Sepia(f) => f.params.is_some() && amount_is(f.params, 0.0),
+			Self::DropShadow(_) | Self::Url(_) => false,
+		}
+	}
+}
+
+/// <https://drafts.csswg.org/filter-effects-1/#funcdef-filter-blur>
+///
+/// ```text,ignore
+/// blur() = blur( <length>? )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct BlurFunction {
+	#[atom(CssAtomSet::Blur)]
+	pub name: T![Function],
+	pub params: Option<NonNegative<Length>>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/filter-effects-1/#funcdef-filter-brightness>
+///
+/// ```text,ignore
+/// brightness() = brightness( [ <number> | <percentage> ]? )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct BrightnessFunction {
+	#[atom(CssAtomSet::Brightness)]
+	pub name: T![Function],
+	pub params: Option<NonNegative<NumberPercentage>>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/filter-effects-1/#funcdef-filter-contrast>
+///
+/// ```text,ignore
+/// contrast() = contrast( [ <number> | <percentage> ]? )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct ContrastFunction {
+	#[atom(CssAtomSet::Contrast)]
+	pub name: T![Function],
+	pub params: Option<NonNegative<NumberPercentage>>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/filter-effects-1/#funcdef-filter-drop-shadow>
+///
+/// ```text,ignore
+/// drop-shadow() = drop-shadow( [ <color>? && <length>{2,3} ] )
+/// ```
+///
+/// The color may come before or after the lengths, and is written back in the position it was parsed from.
+#[derive(Peek, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct DropShadowFunction<'a> {
+	#[atom(CssAtomSet::DropShadow)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub color: Option<Color<'a>>,
+	pub offset: (Length, Length),
+	pub blur_radius: Option<NonNegative<Length>>,
+	pub close: T![')'],
+}
+
+impl<'a> DropShadowFunction<'a> {
+	fn color_is_trailing(&self) -> bool {
+		self.color.as_ref().is_some_and(|color| color.to_span().start() > self.offset.0.to_span().start())
+	}
+}
+
+impl<'a> Parse<'a> for DropShadowFunction<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let name = p.parse::<T![Function]>()?;
+		if !p.equals_atom(name.into(), &CssAtomSet::DropShadow) {
+			Err(Diagnostic::new(name.into(), Diagnostic::unexpected))?
+		}
+		let mut color = p.parse_if_peek::<Color>()?;
+		let x = p.parse::<Length>()?;
+		let y = p.parse::<Length>()?;
+		let blur_radius = p.parse_if_peek::<NonNegative<Length>>()?;
+		if color.is_none() {
+			color = p.parse_if_peek::<Color>()?;
+		}
+		let close = p.parse::<T![')']>()?;
+		Ok(Self { name, color, offset: (x, y), blur_radius, close })
+	}
+}
+
+impl<'a> ToCursors for DropShadowFunction<'a> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		ToCursors::to_cursors(&self.name, s);
+		if !self.color_is_trailing() {
+			ToCursors::to_cursors(&self.color, s);
+		}
+		ToCursors::to_cursors(&self.offset.0, s);
+		ToCursors::to_cursors(&self.offset.1, s);
+		ToCursors::to_cursors(&self.blur_radius, s);
+		if self.color_is_trailing() {
+			ToCursors::to_cursors(&self.color, s);
+		}
+		ToCursors::to_cursors(&self.close, s);
+	}
+}
+
+/// <https://drafts.csswg.org/filter-effects-1/#funcdef-filter-grayscale>
+///
+/// ```text,ignore
+/// grayscale() = grayscale( [ <number> | <percentage> ]? )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct GrayscaleFunction {
+	#[atom(CssAtomSet::Grayscale)]
+	pub name: T![Function],
+	pub params: Option<NonNegative<NumberPercentage>>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/filter-effects-1/#funcdef-filter-hue-rotate>
+///
+/// ```text,ignore
+/// hue-rotate() = hue-rotate( [ <angle> | <zero> ]? )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct HueRotateFunction {
+	#[atom(CssAtomSet::HueRotate)]
+	pub name: T![Function],
+	pub params: Option<AngleOrZero>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/filter-effects-1/#funcdef-filter-invert>
+///
+/// ```text,ignore
+/// invert() = invert( [ <number> | <percentage> ]? )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct InvertFunction {
+	#[atom(CssAtomSet::Invert)]
+	pub name: T![Function],
+	pub params: Option<NonNegative<NumberPercentage>>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/filter-effects-1/#funcdef-filter-opacity>
+///
+/// ```text,ignore
+/// opacity() = opacity( [ <number> | <percentage> ]? )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct OpacityFunction {
+	#[atom(CssAtomSet::Opacity)]
+	pub name: T![Function],
+	pub params: Option<NonNegative<NumberPercentage>>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/filter-effects-1/#funcdef-filter-saturate>
+///
+/// ```text,ignore
+/// saturate() = saturate( [ <number> | <percentage> ]? )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct SaturateFunction {
+	#[atom(CssAtomSet::Saturate)]
+	pub name: T![Function],
+	pub params: Option<NonNegative<NumberPercentage>>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/filter-effects-1/#funcdef-filter-sepia>
+///
+/// ```text,ignore
+/// sepia() = sepia( [ <number> | <percentage> ]? )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct SepiaFunction {
+	#[atom(CssAtomSet::Sepia)]
+	pub name: T![Function],
+	pub params: Option<NonNegative<NumberPercentage>>,
+	pub close: T![')'],
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<FilterFunction>(), 216);
+		assert_eq!(std::mem::size_of::<DropShadowFunction>(), 216);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, FilterFunction, "blur()");
+		assert_parse!(CssAtomSet::ATOMS, FilterFunction, "blur(4px)");
+		assert_parse!(CssAtomSet::ATOMS, FilterFunction, "blur(0)");
+		assert_parse!(CssAtomSet::ATOMS, FilterFunction, "brightness(1.5)");
+		assert_parse!(CssAtomSet::ATOMS, FilterFunction, "contrast(200%)");
+		assert_parse!(CssAtomSet::ATOMS, FilterFunction, "drop-shadow(2px 4px)");
+		assert_parse!(CssAtomSet::ATOMS, FilterFunction, "drop-shadow(red 2px 4px 6px)");
+		assert_parse!(CssAtomSet::ATOMS, FilterFunction, "drop-shadow(2px 4px 6px #000)");
+		assert_parse!(CssAtomSet::ATOMS, FilterFunction, "grayscale(100%)");
+		assert_parse!(CssAtomSet::ATOMS, FilterFunction, "hue-rotate(90deg)");
+		assert_parse!(CssAtomSet::ATOMS, FilterFunction, "hue-rotate(0)");
+		assert_parse!(CssAtomSet::ATOMS, FilterFunction, "invert(.8)");
+		assert_parse!(CssAtomSet::ATOMS, FilterFunction, "opacity(50%)");
+		assert_parse!(CssAtomSet::ATOMS, FilterFunction, "saturate()");
+		assert_parse!(CssAtomSet::ATOMS, FilterFunction, "sepia(1)");
+		assert_parse!(CssAtomSet::ATOMS, FilterFunction, "url(#filter)");
+		assert_parse!(CssAtomSet::ATOMS, FilterFunction, "url(\"filters.svg#blur\")");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, FilterFunction, "blur(-1px)");
+		assert_parse_error!(CssAtomSet::ATOMS, FilterFunction, "blur(10%)");
+		assert_parse_error!(CssAtomSet::ATOMS, FilterFunction, "brightness(-1)");
+		assert_parse_error!(CssAtomSet::ATOMS, FilterFunction, "opacity(1,2)");
+		assert_parse_error!(CssAtomSet::ATOMS, FilterFunction, "hue-rotate(90)");
+		assert_parse_error!(CssAtomSet::ATOMS, FilterFunction, "drop-shadow(2px)");
+		assert_parse_error!(CssAtomSet::ATOMS, FilterFunction, "drop-shadow(red 2px 4px blue)");
+		assert_parse_error!(CssAtomSet::ATOMS, FilterFunction, "drop-shadow(2px 4px -6px)");
+		assert_parse_error!(CssAtomSet::ATOMS, FilterFunction, "rotate(45deg)");
+	}
+
+	#[test]
+	fn test_is_identity() {
+		fn is_identity(source_text: &str) -> bool {
+			let bump = bumpalo::Bump::default();
+			let lexer = css_lexer::Lexer::new(&CssAtomSet::ATOMS, source_text);
+			let mut p = Parser::new(&bump, source_text, lexer);
+			p.parse_entirely::<FilterFunction>().output.unwrap().is_identity()
+		}
+		assert!(is_identity("blur()"));
+		assert!(is_identity("blur(0px)"));
+		assert!(is_identity("brightness(100%)"));
+		assert!(is_identity("opacity(1)"));
+		assert!(is_identity("hue-rotate(0deg)"));
+		assert!(is_identity("sepia(0%)"));
+		assert!(!is_identity("sepia()"));
+		assert!(!is_identity("grayscale(1)"));
+		assert!(!is_identity("opacity(.5)"));
+		assert!(!is_identity("drop-shadow(0 0)"));
+		assert!(!is_identity("url(#f)"));
+	}
+}
@@ -0,0 +1,142 @@
+use super::prelude::*;
+
+/// <https://drafts.csswg.org/css-fonts-4/#font-variant-alternates-prop>
+///
+/// A name defined by a `@font-feature-values` rule, referenced by the `font-variant-alternates` functions.
+///
+/// ```text,ignore
+/// <feature-value-name> = <custom-ident>
+/// ```
+#[derive(IntoCursor, Parse, Peek, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct FeatureValueName(T![Ident]);
+
+/// <https://drafts.csswg.org/css-fonts-4/#stylistic>
+///
+/// ```text,ignore
+/// stylistic() = stylistic( <feature-value-name> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct StylisticFunction {
+	#[atom(CssAtomSet::Stylistic)]
+	pub name: T![Function],
+	pub params: FeatureValueName,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#styleset>
+///
+/// ```text,ignore
+/// styleset() = styleset( <feature-value-name># )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct StylesetFunction<'a> {
+	#[atom(CssAtomSet::Styleset)]
+	pub name: T![Function],
+	pub params: CommaSeparated<'a, FeatureValueName>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#character-variant>
+///
+/// ```text,ignore
+/// character-variant() = character-variant( <feature-value-name># )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct CharacterVariantFunction<'a> {
+	#[atom(CssAtomSet::CharacterVariant)]
+	pub name: T![Function],
+	pub params: CommaSeparated<'a, FeatureValueName>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#swash>
+///
+/// ```text,ignore
+/// swash() = swash( <feature-value-name> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct SwashFunction {
+	#[atom(CssAtomSet::Swash)]
+	pub name: T![Function],
+	pub params: FeatureValueName,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#ornaments>
+///
+/// ```text,ignore
+/// ornaments() = ornaments( <feature-value-name> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct OrnamentsFunction {
+	#[atom(CssAtomSet::Ornaments)]
+	pub name: T![Function],
+	pub params: FeatureValueName,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#annotation>
+///
+/// ```text,ignore
+/// annotation() = annotation( <feature-value-name> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct AnnotationFunction {
+	#[atom(CssAtomSet::Annotation)]
+	pub name: T![Function],
+	pub params: FeatureValueName,
+	pub close: T![')'],
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<StylisticFunction>(), 36);
+		assert_eq!(std::mem::size_of::<StylesetFunction>(), 56);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, StylisticFunction, "stylistic(fancy)");
+		assert_parse!(CssAtomSet::ATOMS, StylesetFunction, "styleset(alt-g)");
+		assert_parse!(CssAtomSet::ATOMS, StylesetFunction, "styleset(alt-g,alt-a)");
+		assert_parse!(CssAtomSet::ATOMS, CharacterVariantFunction, "character-variant(a,b,c)");
+		assert_parse!(CssAtomSet::ATOMS, SwashFunction, "swash(flowing)");
+		assert_parse!(CssAtomSet::ATOMS, OrnamentsFunction, "ornaments(leaves)");
+		assert_parse!(CssAtomSet::ATOMS, AnnotationFunction, "annotation(circled)");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, StylisticFunction, "stylistic()");
+		assert_parse_error!(CssAtomSet::ATOMS, StylisticFunction, "stylistic(a,b)");
+		assert_parse_error!(CssAtomSet::ATOMS, StylesetFunction, "styleset()");
+		assert_parse_error!(CssAtomSet::ATOMS, SwashFunction, "swash(10)");
+	}
+}
@@ -0,0 +1,934 @@
+use super::prelude::*;
+use crate::{Angle, Flex, Frequency, Length, NoneOr, Percentage, Resolution, Time, VarFunction};
+use css_parse::{AssociatedWhitespaceRules, FunctionBlock};
+
+/// <https://drafts.csswg.org/css-values-4/#math-function>
+///
+/// ```text,ignore
+/// <math-function> = <calc()> | <min()> | <max()> | <clamp()> | <round()> | <mod()> | <rem()> | <sin()> | <cos()>
+///                 | <tan()> | <asin()> | <acos()> | <atan()> | <atan2()> | <pow()> | <sqrt()> | <hypot()> | <log()>
+///                 | <exp()> | <abs()> | <sign()>
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+#[metadata(node_kinds = Function)]
+pub enum MathFunction<'a> {
+	Calc(CalcFunction<'a>),
+	Min(MinFunction<'a>),
+	Max(MaxFunction<'a>),
+	Clamp(ClampFunction<'a>),
+	Round(RoundFunction<'a>),
+	Mod(ModFunction<'a>),
+	Rem(RemFunction<'a>),
+	Sin(SinFunction<'a>),
+	Cos(CosFunction<'a>),
+	Tan(TanFunction<'a>),
+	Asin(AsinFunction<'a>),
+	Acos(AcosFunction<'a>),
+	Atan(AtanFunction<'a>),
+	Atan2(Atan2Function<'a>),
+	Pow(PowFunction<'a>),
+	Sqrt(SqrtFunction<'a>),
+	Hypot(HypotFunction<'a>),
+	Log(LogFunction<'a>),
+	Exp(ExpFunction<'a>),
+	Abs(AbsFunction<'a>),
+	Sign(SignFunction<'a>),
+}
+
+impl<'a> MathFunction<'a> {
+	/// Resolves the [CalcType] of this function, following the type checking rules of each math function. Returns
+	/// [None] if the arguments are not of a consistent type, or if the type cannot be known until a substitution
+	/// function (such as `var()`) has been resolved.
+	pub fn resolve_type(&self) -> Option<CalcType> {
+		match self {
+			Self::Calc(f) => f.params.resolve_type(),
+			Self::Min(f) => CalcType::add_all((&f.params).into_iter().map(|(sum, _)| sum)),
+			Self::Max(f) => CalcType::add_all((&f.params).into_iter().map(|(sum, _)| sum)),
+			Self::Clamp(f) => {
+				let value = f.value.resolve_type()?;
+				[&f.min, &f.max].into_iter().try_fold(value, |ty, bound| match bound {
+					NoneOr::None(_) => Some(ty),
+					NoneOr::Some(sum) => ty.try_add(sum.resolve_type()?),
+				})
+			}
+			Self::Round(f) => match &f.interval {
+				Some((_, interval)) => f.value.resolve_type()?.try_add(interval.resolve_type()?),
+				None => f.value.resolve_type(),
+			},
+			Self::Mod(f) => f.dividend.resolve_type()?.try_add(f.divisor.resolve_type()?),
+			Self::Rem(f) => f.dividend.resolve_type()?.try_add(f.divisor.resolve_type()?),
+			Self::Sin(f) => f.params.resolve_type().filter(CalcType::is_angle_or_number).map(|_| CalcType::NUMBER),
+			Self::Cos(f) => f.params.resolve_type().filter(CalcType::is_angle_or_number).map(|_| CalcType::NUMBER),
+			Self::Tan(f) => f.params.resolve_type().filter(CalcType::is_angle_or_number).map(|_| CalcType::NUMBER),
+			Self::Asin(f) => f.params.resolve_type().filter(CalcType::is_number).map(|_| CalcType::ANGLE),
+			Self::Acos(f) => f.params.resolve_type().filter(CalcType::is_number).map(|_| CalcType::ANGLE),
+			Self::Atan(f) => f.params.resolve_type().filter(CalcType::is_number).map(|_| CalcType::ANGLE),
+			Self::Atan2(f) => f.y.resolve_type()?.try_add(f.x.resolve_type()?).map(|_| CalcType::ANGLE),
+			Self::Pow(f) => f.base.resolve_type().filter(CalcType::is_number)?.try_add(f.exponent.resolve_type()?),
+			Self::Sqrt(f) => f.params.resolve_type().filter(CalcType::is_number),
+			Self::Hypot(f) => CalcType::add_all((&f.params).into_iter().map(|(sum, _)| sum)),
+			Self::Log(f) => match &f.base {
+				Some((_, base)) => f.value.resolve_type().filter(CalcType::is_number)?.try_add(base.resolve_type()?),
+				None => f.value.resolve_type().filter(CalcType::is_number),
+			},
+			Self::Exp(f) => f.params.resolve_type().filter(CalcType::is_number),
+			Self::Abs(f) => f.params.resolve_type(),
+			Self::Sign(f) => f.params.resolve_type().map(|_| CalcType::NUMBER),
+		}
+	}
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-calc>
+///
+/// ```text,ignore
+/// calc() = calc( <calc-sum> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct CalcFunction<'a> {
+	#[atom(CssAtomSet::Calc)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub params: CalcSum<'a>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-min>
+///
+/// ```text,ignore
+/// min() = min( <calc-sum># )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct MinFunction<'a> {
+	#[atom(CssAtomSet::Min)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub params: CommaSeparated<'a, CalcSum<'a>>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-max>
+///
+/// ```text,ignore
+/// max() = max( <calc-sum># )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct MaxFunction<'a> {
+	#[atom(CssAtomSet::Max)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub params: CommaSeparated<'a, CalcSum<'a>>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-clamp>
+///
+/// ```text,ignore
+/// clamp() = clamp( [ <calc-sum> | none ], <calc-sum>, [ <calc-sum> | none ] )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct ClampFunction<'a> {
+	#[atom(CssAtomSet::Clamp)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub min: NoneOr<CalcSum<'a>>,
+	pub min_comma: T![,],
+	pub value: CalcSum<'a>,
+	pub max_comma: T![,],
+	pub max: NoneOr<CalcSum<'a>>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-round>
+///
+/// ```text,ignore
+/// round() = round( <rounding-strategy>?, <calc-sum>, <calc-sum>? )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct RoundFunction<'a> {
+	#[atom(CssAtomSet::Round)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub strategy: Option<(RoundingStrategy, T![,])>,
+	pub value: CalcSum<'a>,
+	pub interval: Option<(T![,], CalcSum<'a>)>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#typedef-rounding-strategy>
+///
+/// ```text,ignore
+/// <rounding-strategy> = nearest | up | down | to-zero
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum RoundingStrategy {
+	#[atom(CssAtomSet::Nearest)]
+	Nearest(T![Ident]),
+	#[atom(CssAtomSet::Up)]
+	Up(T![Ident]),
+	#[atom(CssAtomSet::Down)]
+	Down(T![Ident]),
+	#[atom(CssAtomSet::ToZero)]
+	ToZero(T![Ident]),
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-mod>
+///
+/// ```text,ignore
+/// mod() = mod( <calc-sum>, <calc-sum> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct ModFunction<'a> {
+	#[atom(CssAtomSet::Mod)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub dividend: CalcSum<'a>,
+	pub comma: T![,],
+	pub divisor: CalcSum<'a>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-rem>
+///
+/// ```text,ignore
+/// rem() = rem( <calc-sum>, <calc-sum> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct RemFunction<'a> {
+	#[atom(CssAtomSet::Rem)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub dividend: CalcSum<'a>,
+	pub comma: T![,],
+	pub divisor: CalcSum<'a>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-atan2>
+///
+/// ```text,ignore
+/// atan2() = atan2( <calc-sum>, <calc-sum> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct Atan2Function<'a> {
+	#[atom(CssAtomSet::Atan2)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub y: CalcSum<'a>,
+	pub comma: T![,],
+	pub x: CalcSum<'a>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-pow>
+///
+/// ```text,ignore
+/// pow() = pow( <calc-sum>, <calc-sum> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct PowFunction<'a> {
+	#[atom(CssAtomSet::Pow)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub base: CalcSum<'a>,
+	pub comma: T![,],
+	pub exponent: CalcSum<'a>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-sin>
+///
+/// ```text,ignore
+/// sin() = sin( <calc-sum> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct SinFunction<'a> {
+	#[atom(CssAtomSet::Sin)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub params: CalcSum<'a>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-cos>
+///
+/// ```text,ignore
+/// cos() = cos( <calc-sum> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct CosFunction<'a> {
+	#[atom(CssAtomSet::Cos)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub params: CalcSum<'a>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-tan>
+///
+/// ```text,ignore
+/// tan() = tan( <calc-sum> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct TanFunction<'a> {
+	#[atom(CssAtomSet::Tan)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub params: CalcSum<'a>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-asin>
+///
+/// ```text,ignore
+/// asin() = asin( <calc-sum> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct AsinFunction<'a> {
+	#[atom(CssAtomSet::Asin)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub params: CalcSum<'a>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-acos>
+///
+/// ```text,ignore
+/// acos() = acos( <calc-sum> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct AcosFunction<'a> {
+	#[atom(CssAtomSet::Acos)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub params: CalcSum<'a>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-atan>
+///
+/// ```text,ignore
+/// atan() = atan( <calc-sum> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct AtanFunction<'a> {
+	#[atom(CssAtomSet::Atan)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub params: CalcSum<'a>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-sqrt>
+///
+/// ```text,ignore
+/// sqrt() = sqrt( <calc-sum> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct SqrtFunction<'a> {
+	#[atom(CssAtomSet::Sqrt)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub params: CalcSum<'a>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-exp>
+///
+/// ```text,ignore
+/// exp() = exp( <calc-sum> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct ExpFunction<'a> {
+	#[atom(CssAtomSet::Exp)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub params: CalcSum<'a>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-abs>
+///
+/// ```text,ignore
+/// abs() = abs( <calc-sum> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct AbsFunction<'a> {
+	#[atom(CssAtomSet::Abs)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub params: CalcSum<'a>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-sign>
+///
+/// ```text,ignore
+/// sign() = sign( <calc-sum> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct SignFunction<'a> {
+	#[atom(CssAtomSet::Sign)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub params: CalcSum<'a>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-hypot>
+///
+/// ```text,ignore
+/// hypot() = hypot( <calc-sum># )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct HypotFunction<'a> {
+	#[atom(CssAtomSet::Hypot)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub params: CommaSeparated<'a, CalcSum<'a>>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#funcdef-log>
+///
+/// ```text,ignore
+/// log() = log( <calc-sum>, <calc-sum>? )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct LogFunction<'a> {
+	#[atom(CssAtomSet::Log)]
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub value: CalcSum<'a>,
+	pub base: Option<(T![,], CalcSum<'a>)>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/css-values-4/#typedef-calc-sum>
+///
+/// ```text,ignore
+/// <calc-sum> = <calc-product> [ [ '+' | '-' ] <calc-product> ]*
+/// ```
+///
+/// The first term never has an operator.
+#[derive(ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable))]
+pub struct CalcSum<'a> {
+	pub terms: Vec<'a, (Option<CalcSumOperator>, CalcProduct<'a>)>,
+}
+
+impl<'a> Peek<'a> for CalcSum<'a> {
+	fn peek<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		CalcValue::peek(p, c)
+	}
+}
+
+impl<'a> Parse<'a> for CalcSum<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let mut terms = Vec::new_in(p.bump());
+		terms.push((None, p.parse::<CalcProduct>()?));
+		while let Some(operator) = p.parse_if_peek::<CalcSumOperator>()? {
+			terms.push((Some(operator), p.parse::<CalcProduct>()?));
+		}
+		Ok(Self { terms })
+	}
+}
+
+impl<'a> CalcSum<'a> {
+	pub fn resolve_type(&self) -> Option<CalcType> {
+		CalcType::add_all(self.terms.iter().map(|(_, product)| product))
+	}
+}
+
+/// The `'+'` or `'-'` between terms of a [CalcSum]. Whitespace is required on both sides of these operators, so they
+/// always retain it when written.
+#[derive(ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+pub enum CalcSumOperator {
+	Plus(T![+]),
+	Minus(T![-]),
+}
+
+impl<'a> Peek<'a> for CalcSumOperator {
+	fn peek<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		<T![+]>::peek(p, c) || <T![-]>::peek(p, c)
+	}
+}
+
+impl<'a> Parse<'a> for CalcSumOperator {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let rules = AssociatedWhitespaceRules::EnforceBefore | AssociatedWhitespaceRules::EnforceAfter;
+		if let Some(plus) = p.parse_if_peek::<T![+]>()? {
+			Ok(Self::Plus(plus.with_associated_whitespace(rules)))
+		} else {
+			Ok(Self::Minus(p.parse::<T![-]>()?.with_associated_whitespace(rules)))
+		}
+	}
+}
+
+/// <https://drafts.csswg.org/css-values-4/#typedef-calc-product>
+///
+/// ```text,ignore
+/// <calc-product> = <calc-value> [ [ '*' | '/' ] <calc-value> ]*
+/// ```
+///
+/// The first factor never has an operator.
+#[derive(ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable))]
+pub struct CalcProduct<'a> {
+	pub factors: Vec<'a, (Option<CalcProductOperator>, CalcValue<'a>)>,
+}
+
+impl<'a> Peek<'a> for CalcProduct<'a> {
+	fn peek<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		CalcValue::peek(p, c)
+	}
+}
+
+impl<'a> Parse<'a> for CalcProduct<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let mut factors = Vec::new_in(p.bump());
+		factors.push((None, p.parse::<CalcValue>()?));
+		while let Some(operator) = p.parse_if_peek::<CalcProductOperator>()? {
+			factors.push((Some(operator), p.parse::<CalcValue>()?));
+		}
+		Ok(Self { factors })
+	}
+}
+
+impl<'a> CalcProduct<'a> {
+	pub fn resolve_type(&self) -> Option<CalcType> {
+		self.factors.iter().try_fold(CalcType::NUMBER, |ty, (operator, value)| {
+			let value = value.resolve_type()?;
+			match operator {
+				Some(CalcProductOperator::Divide(_)) => ty.multiply(value.invert()),
+				_ => ty.multiply(value),
+			}
+		})
+	}
+}
+
+/// The `'*'` or `'/'` between factors of a [CalcProduct].
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+pub enum CalcProductOperator {
+	Multiply(T![*]),
+	Divide(T![/]),
+}
+
+/// <https://drafts.csswg.org/css-values-4/#typedef-calc-value>
+///
+/// ```text,ignore
+/// <calc-value> = <number> | <dimension> | <percentage> | <calc-keyword> | ( <calc-sum> )
+/// ```
+///
+/// Nested math functions are parsed as [CalcValue::Function], and `var()` as [CalcValue::Var]. The other substitution
+/// functions (`env()` and `attr()`) are kept as an opaque [CalcValue::Substitution]. The type of a substitution is only
+/// known once it has been substituted.
+///
+/// Only nested functions are visited; the numeric leaves are not, as transforms which apply to a specified value (such
+/// as dropping the unit of a zero length) may be invalid within a calculation.
+#[derive(ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable))]
+pub enum CalcValue<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	Number(T![Number]),
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	Percentage(Percentage),
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	Length(Length),
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	Angle(Angle),
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	Time(Time),
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	Frequency(Frequency),
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	Resolution(Resolution),
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	Flex(Flex),
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	Keyword(CalcKeyword),
+	Nested(T!['('], CalcSum<'a>, T![')']),
+	Function(MathFunction<'a>),
+	Var(VarFunction<'a>),
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	Substitution(FunctionBlock<'a>),
+}
+
+impl<'a> CalcValue<'a> {
+	fn peek_substitution<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		<T![Function]>::peek(p, c) && matches!(p.to_atom::<CssAtomSet>(c), CssAtomSet::Env | CssAtomSet::Attr)
+	}
+
+	pub fn resolve_type(&self) -> Option<CalcType> {
+		match self {
+			Self::Number(_) | Self::Keyword(_) => Some(CalcType::NUMBER),
+			Self::Percentage(_) => Some(CalcType::new(CalcBaseType::Percent)),
+			Self::Length(_) => Some(CalcType::new(CalcBaseType::Length)),
+			Self::Angle(_) => Some(CalcType::ANGLE),
+			Self::Time(_) => Some(CalcType::new(CalcBaseType::Time)),
+			Self::Frequency(_) => Some(CalcType::new(CalcBaseType::Frequency)),
+			Self::Resolution(_) => Some(CalcType::new(CalcBaseType::Resolution)),
+			Self::Flex(_) => Some(CalcType::new(CalcBaseType::Flex)),
+			Self::Nested(_, sum, _) => sum.resolve_type(),
+			Self::Function(function) => function.resolve_type(),
+			Self::Var(_) | Self::Substitution(_) => None,
+		}
+	}
+}
+
+impl<'a> Peek<'a> for CalcValue<'a> {
+	fn peek<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		<T![Number]>::peek(p, c)
+			|| <T![Dimension]>::peek(p, c)
+			|| CalcKeyword::peek(p, c)
+			|| <T!['(']>::peek(p, c)
+			|| MathFunction::peek(p, c)
+			|| VarFunction::peek(p, c)
+			|| Self::peek_substitution(p, c)
+	}
+}
+
+impl<'a> Parse<'a> for CalcValue<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let c = p.peek_n(1);
+		if <T![Number]>::peek(p, c) {
+			p.parse::<T![Number]>().map(Self::Number)
+		} else if Percentage::peek(p, c) {
+			p.parse::<Percentage>().map(Self::Percentage)
+		} else if Length::peek(p, c) {
+			p.parse::<Length>().map(Self::Length)
+		} else if Angle::peek(p, c) {
+			p.parse::<Angle>().map(Self::Angle)
+		} else if Time::peek(p, c) {
+			p.parse::<Time>().map(Self::Time)
+		} else if Frequency::peek(p, c) {
+			p.parse::<Frequency>().map(Self::Frequency)
+		} else if Resolution::peek(p, c) {
+			p.parse::<Resolution>().map(Self::Resolution)
+		} else if Flex::peek(p, c) {
+			p.parse::<Flex>().map(Self::Flex)
+		} else if CalcKeyword::peek(p, c) {
+			p.parse::<CalcKeyword>().map(Self::Keyword)
+		} else if <T!['(']>::peek(p, c) {
+			Ok(Self::Nested(p.parse::<T!['(']>()?, p.parse::<CalcSum>()?, p.parse::<T![')']>()?))
+		} else if MathFunction::peek(p, c) {
+			p.parse::<MathFunction>().map(Self::Function)
+		} else if VarFunction::peek(p, c) {
+			p.parse::<VarFunction>().map(Self::Var)
+		} else if Self::peek_substitution(p, c) {
+			p.parse::<FunctionBlock>().map(Self::Substitution)
+		} else {
+			Err(Diagnostic::new(c, Diagnostic::unexpected))?
+		}
+	}
+}
+
+/// <https://drafts.csswg.org/css-values-4/#typedef-calc-keyword>
+///
+/// ```text,ignore
+/// <calc-keyword> = e | pi | infinity | -infinity | NaN
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum CalcKeyword {
+	#[atom(CssAtomSet::E)]
+	E(T![Ident]),
+	#[atom(CssAtomSet::Pi)]
+	Pi(T![Ident]),
+	#[atom(CssAtomSet::Infinity)]
+	Infinity(T![Ident]),
+	#[atom(CssAtomSet::_NegInfinity)]
+	NegInfinity(T![Ident]),
+	#[atom(CssAtomSet::Nan)]
+	NaN(T![Ident]),
+}
+
+/// <https://drafts.csswg.org/css-values-4/#css-base-type>
+///
+/// The base types which can be combined into a [CalcType].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CalcBaseType {
+	Length,
+	Angle,
+	Time,
+	Frequency,
+	Resolution,
+	Flex,
+	Percent,
+}
+
+impl CalcBaseType {
+	const ALL: [CalcBaseType; 7] =
+		[Self::Length, Self::Angle, Self::Time, Self::Frequency, Self::Resolution, Self::Flex, Self::Percent];
+}
+
+/// <https://drafts.csswg.org/css-values-4/#determine-the-type-of-a-calculation>
+///
+/// The type of a math expression: the exponent of each [CalcBaseType], along with an optional percent hint recording
+/// which base type any percentages have been resolved against. A type with all exponents at zero is a `<number>`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CalcType {
+	exponents: [i8; 7],
+	percent_hint: Option<CalcBaseType>,
+}
+
+impl CalcType {
+	pub const NUMBER: Self = Self { exponents: [0; 7], percent_hint: None };
+	pub const ANGLE: Self = Self::new(CalcBaseType::Angle);
+
+	pub const fn new(base: CalcBaseType) -> Self {
+		let mut exponents = [0; 7];
+		exponents[base as usize] = 1;
+		Self { exponents, percent_hint: None }
+	}
+
+	pub fn exponent(&self, base: CalcBaseType) -> i8 {
+		self.exponents[base as usize]
+	}
+
+	pub fn percent_hint(&self) -> Option<CalcBaseType> {
+		self.percent_hint
+	}
+
+	/// Returns true if this type is a `<number>`, that is every exponent is zero and there is no percent hint.
+	pub fn is_number(&self) -> bool {
+		self.exponents == [0; 7] && self.percent_hint.is_none()
+	}
+
+	/// <https://drafts.csswg.org/css-values-4/#css-match>
+	///
+	/// Returns true if this type matches the given base type, allowing percentages to have been resolved against it.
+	pub fn matches(&self, base: CalcBaseType) -> bool {
+		CalcBaseType::ALL.iter().all(|other| self.exponent(*other) == (*other == base) as i8)
+			&& self.percent_hint.is_none_or(|hint| hint == base)
+	}
+
+	fn is_angle_or_number(&self) -> bool {
+		self.is_number() || self.matches(CalcBaseType::Angle)
+	}
+
+	/// <https://drafts.css-houdini.org/css-typed-om-1/#apply-the-percent-hint>
+	fn apply_percent_hint(mut self, hint: CalcBaseType) -> Self {
+		if hint != CalcBaseType::Percent {
+			self.exponents[hint as usize] += self.exponents[CalcBaseType::Percent as usize];
+			self.exponents[CalcBaseType::Percent as usize] = 0;
+		}
+		self.percent_hint = Some(hint);
+		self
+	}
+
+	/// <https://drafts.css-houdini.org/css-typed-om-1/#cssnumericvalue-add-two-types>
+	pub fn try_add(self, other: Self) -> Option<Self> {
+		let (a, b) = match (self.percent_hint, other.percent_hint) {
+			(Some(a), Some(b)) if a != b => return None,
+			(Some(hint), None) => (self, other.apply_percent_hint(hint)),
+			(None, Some(hint)) => (self.apply_percent_hint(hint), other),
+			_ => (self, other),
+		};
+		if a.exponents == b.exponents {
+			return Some(Self { exponents: a.exponents, percent_hint: a.percent_hint.or(b.percent_hint) });
+		}
+		let has_percent = a.exponent(CalcBaseType::Percent) != 0 || b.exponent(CalcBaseType::Percent) != 0;
+		let has_other = CalcBaseType::ALL
+			.iter()
+			.any(|base| *base != CalcBaseType::Percent && (a.exponent(*base) != 0 || b.exponent(*base) != 0));
+		if has_percent && has_other {
+			for hint in CalcBaseType::ALL.into_iter().filter(|base| *base != CalcBaseType::Percent) {
+				let (a, b) = (a.apply_percent_hint(hint), b.apply_percent_hint(hint));
+				if a.exponents == b.exponents {
+					return Some(a);
+				}
+			}
+		}
+		None
+	}
+
+	/// <https://drafts.css-houdini.org/css-typed-om-1/#cssnumericvalue-multiply-two-types>
+	pub fn multiply(self, other: Self) -> Option<Self> {
+		let (mut a, b) = match (self.percent_hint, other.percent_hint) {
+			(Some(a), Some(b)) if a != b => return None,
+			(Some(hint), None) => (self, other.apply_percent_hint(hint)),
+			(None, Some(hint)) => (self.apply_percent_hint(hint), other),
+			_ => (self, other),
+		};
+		for (exponent, other) in a.exponents.iter_mut().zip(b.exponents) {
+			*exponent += other;
+		}
+		a.percent_hint = a.percent_hint.or(b.percent_hint);
+		Some(a)
+	}
+
+	/// <https://drafts.css-houdini.org/css-typed-om-1/#cssmathinvert-type>
+	pub fn invert(mut self) -> Self {
+		for exponent in self.exponents.iter_mut() {
+			*exponent = -*exponent;
+		}
+		self
+	}
+
+	fn add_all<'b, T: ResolveCalcType + 'b>(mut items: impl Iterator<Item = &'b T>) -> Option<Self> {
+		let first = items.next()?.resolve_type()?;
+		items.try_fold(first, |ty, item| ty.try_add(item.resolve_type()?))
+	}
+}
+
+trait ResolveCalcType {
+	fn resolve_type(&self) -> Option<CalcType>;
+}
+
+impl<'a> ResolveCalcType for CalcSum<'a> {
+	fn resolve_type(&self) -> Option<CalcType> {
+		CalcSum::resolve_type(self)
+	}
+}
+
+impl<'a> ResolveCalcType for CalcProduct<'a> {
+	fn resolve_type(&self) -> Option<CalcType> {
+		CalcProduct::resolve_type(self)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<MathFunction>(), 152);
+		assert_eq!(std::mem::size_of::<CalcSum>(), 32);
+		assert_eq!(std::mem::size_of::<CalcValue>(), 152);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, MathFunction, "calc(1px)");
+		assert_parse!(CssAtomSet::ATOMS, MathFunction, "calc(10px + 5px)");
+		assert_parse!(CssAtomSet::ATOMS, MathFunction, "calc(100% - 2 * 1em)");
+		assert_parse!(CssAtomSet::ATOMS, MathFunction, "calc((1px + 2px) / 3)");
+		assert_parse!(CssAtomSet::ATOMS, MathFunction, "calc(pi * 1rad)");
+		assert_parse!(CssAtomSet::ATOMS, MathFunction, "calc(1px + var(--gap))");
+		assert_parse!(CssAtomSet::ATOMS, MathFunction, "min(1px, 2px, 3%)");
+		assert_parse!(CssAtomSet::ATOMS, MathFunction, "max(1px, calc(2px * 2))");
+		assert_parse!(CssAtomSet::ATOMS, MathFunction, "clamp(none, 1vw, 20px)");
+		assert_parse!(CssAtomSet::ATOMS, MathFunction, "round(up, 13px, 5px)");
+		assert_parse!(CssAtomSet::ATOMS, MathFunction, "round(2.5)");
+		assert_parse!(CssAtomSet::ATOMS, MathFunction, "mod(18px, 5px)");
+		assert_parse!(CssAtomSet::ATOMS, MathFunction, "atan2(1px, -1px)");
+		assert_parse!(CssAtomSet::ATOMS, MathFunction, "log(8, 2)");
+		assert_parse!(CssAtomSet::ATOMS, MathFunction, "hypot(3px, 4px)");
+		assert_parse!(CssAtomSet::ATOMS, MathFunction, "sign(-infinity)");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, MathFunction, "calc()");
+		assert_parse_error!(CssAtomSet::ATOMS, MathFunction, "calc(1px +)");
+		assert_parse_error!(CssAtomSet::ATOMS, MathFunction, "calc(1px 2px)");
+		assert_parse_error!(CssAtomSet::ATOMS, MathFunction, "calc(foo)");
+		assert_parse_error!(CssAtomSet::ATOMS, MathFunction, "mod(1px)");
+	}
+}
@@ -0,0 +1,673 @@
+use crate::{
+	CalcKeyword, CalcProduct, CalcProductOperator, CalcSum, CalcSumOperator, CalcValue, CssAtomSet, MathFunction,
+	NoneOr, RoundFunction, RoundingStrategy,
+};
+use css_parse::{AtomSet, Cursor, CursorCompactWriteSink, ToCursors};
+use std::f64::consts::{E, PI};
+
+/// The unit of a resolved value within a simplified math expression.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum CalcUnit {
+	Number,
+	Percentage,
+	Dimension(CssAtomSet),
+}
+
+impl CalcUnit {
+	fn from_cursor(c: Cursor) -> Self {
+		match CssAtomSet::from_bits(c.atom_bits()) {
+			CssAtomSet::Percentage => Self::Percentage,
+			atom => Self::Dimension(atom),
+		}
+	}
+
+	/// Returns the canonical unit of this unit's type and the factor to convert into it, for units that have a fixed
+	/// ratio to one another (absolute lengths, angles, times, frequencies & resolutions).
+	fn canonical(&self) -> Option<(CssAtomSet, f64)> {
+		let Self::Dimension(atom) = self else { return None };
+		Some(match atom {
+			CssAtomSet::Px => (CssAtomSet::Px, 1.0),
+			CssAtomSet::In => (CssAtomSet::Px, 96.0),
+			CssAtomSet::Cm => (CssAtomSet::Px, 96.0 / 2.54),
+			CssAtomSet::Mm => (CssAtomSet::Px, 96.0 / 25.4),
+			CssAtomSet::Q => (CssAtomSet::Px, 96.0 / 101.6),
+			CssAtomSet::Pt => (CssAtomSet::Px, 96.0 / 72.0),
+			CssAtomSet::Pc => (CssAtomSet::Px, 16.0),
+			CssAtomSet::Deg => (CssAtomSet::Deg, 1.0),
+			CssAtomSet::Grad => (CssAtomSet::Deg, 0.9),
+			CssAtomSet::Rad => (CssAtomSet::Deg, 180.0 / PI),
+			CssAtomSet::Turn => (CssAtomSet::Deg, 360.0),
+			CssAtomSet::S => (CssAtomSet::S, 1.0),
+			CssAtomSet::Ms => (CssAtomSet::S, 0.001),
+			CssAtomSet::Hz => (CssAtomSet::Hz, 1.0),
+			CssAtomSet::Khz => (CssAtomSet::Hz, 1000.0),
+			CssAtomSet::Dppx | CssAtomSet::X => (CssAtomSet::Dppx, 1.0),
+			CssAtomSet::Dpi => (CssAtomSet::Dppx, 1.0 / 96.0),
+			CssAtomSet::Dpcm => (CssAtomSet::Dppx, 2.54 / 96.0),
+			_ => return None,
+		})
+	}
+
+	fn is_angle(&self) -> bool {
+		self.canonical().is_some_and(|(unit, _)| unit == CssAtomSet::Deg)
+	}
+}
+
+/// Converts two values into a common unit so they can be compared or combined, returning [None] if their units are
+/// not compatible.
+fn unify(a: (f64, CalcUnit), b: (f64, CalcUnit)) -> Option<(f64, f64, CalcUnit)> {
+	if a.1 == b.1 {
+		return Some((a.0, b.0, a.1));
+	}
+	let (a_unit, a_factor) = a.1.canonical()?;
+	let (b_unit, b_factor) = b.1.canonical()?;
+	(a_unit == b_unit).then_some((a.0 * a_factor, b.0 * b_factor, CalcUnit::Dimension(a_unit)))
+}
+
+/// Converts all values into a common unit, returning [None] if any node is not a resolved value, or their units are
+/// not compatible.
+fn unify_all(nodes: &[CalcNode]) -> Option<(Vec<f64>, CalcUnit)> {
+	let mut values = Vec::with_capacity(nodes.len());
+	let mut unit = None;
+	for node in nodes {
+		let CalcNode::Value(value, node_unit) = node else { return None };
+		values.push((*value, *node_unit));
+		unit = match unit {
+			None => Some(*node_unit),
+			Some(unit) if unit == *node_unit => Some(unit),
+			Some(unit) => Some(unify((0.0, unit), (0.0, *node_unit))?.2),
+		};
+	}
+	let unit = unit?;
+	let values = values
+		.into_iter()
+		.map(
+			|(value, value_unit)| {
+				if value_unit == unit { Some(value) } else { unify((value, value_unit), (0.0, unit)).map(|v| v.0) }
+			},
+		)
+		.collect::<Option<_>>()?;
+	Some((values, unit))
+}
+
+/// <https://drafts.csswg.org/css-values-4/#calc-simplification>
+///
+/// An owned representation of a math expression, used while simplifying it.
+#[derive(Debug, Clone, PartialEq)]
+enum CalcNode {
+	Value(f64, CalcUnit),
+	Sum(Vec<CalcNode>),
+	Product(Vec<CalcNode>),
+	Negate(Box<CalcNode>),
+	Invert(Box<CalcNode>),
+	Function(&'static str, Vec<CalcNode>),
+	/// A value which cannot be simplified, written as-is: substitution functions, and keywords such as `infinity`.
+	Opaque(String),
+}
+
+impl CalcNode {
+	fn sum(terms: Vec<CalcNode>) -> Self {
+		let mut values: Vec<(f64, CalcUnit)> = vec![];
+		let mut others = vec![];
+		for term in terms {
+			match term {
+				Self::Sum(inner) => {
+					for term in inner {
+						Self::push_term(&mut values, &mut others, term);
+					}
+				}
+				term => Self::push_term(&mut values, &mut others, term),
+			}
+		}
+		values.sort_by_key(|(_, unit)| match unit {
+			CalcUnit::Number => (0, ""),
+			CalcUnit::Percentage => (1, ""),
+			CalcUnit::Dimension(atom) => (2, atom.to_str()),
+		});
+		let mut terms: Vec<_> = values.into_iter().map(|(value, unit)| Self::Value(value, unit)).collect();
+		terms.extend(others);
+		if terms.len() == 1 { terms.pop().unwrap() } else { Self::Sum(terms) }
+	}
+
+	fn push_term(values: &mut Vec<(f64, CalcUnit)>, others: &mut Vec<CalcNode>, term: CalcNode) {
+		let Self::Value(value, unit) = term else {
+			others.push(term);
+			return;
+		};
+		for existing in values.iter_mut() {
+			if let Some((a, b, unit)) = unify(*existing, (value, unit)) {
+				*existing = (a + b, unit);
+				return;
+			}
+		}
+		values.push((value, unit));
+	}
+
+	fn product(factors: Vec<CalcNode>) -> Self {
+		let mut coefficient = 1.0;
+		let mut dimension: Option<(f64, CalcUnit)> = None;
+		let mut others = vec![];
+		let mut stack = factors;
+		stack.reverse();
+		while let Some(factor) = stack.pop() {
+			match factor {
+				Self::Product(inner) => stack.extend(inner.into_iter().rev()),
+				Self::Negate(inner) => {
+					coefficient = -coefficient;
+					stack.push(*inner);
+				}
+				Self::Value(value, CalcUnit::Number) => coefficient *= value,
+				Self::Value(value, unit) if dimension.is_none() => dimension = Some((value, unit)),
+				factor => others.push(factor),
+			}
+		}
+		if others.is_empty() {
+			return match dimension {
+				Some((value, unit)) => Self::Value(value * coefficient, unit),
+				None => Self::Value(coefficient, CalcUnit::Number),
+			};
+		}
+		if dimension.is_none() && others.len() == 1 && matches!(others[0], Self::Sum(_)) {
+			let Some(Self::Sum(terms)) = others.pop() else { unreachable!() };
+			return Self::sum(terms.into_iter().map(|term| term.scale(coefficient)).collect());
+		}
+		let mut factors = vec![];
+		match dimension {
+			Some((value, unit)) => factors.push(Self::Value(value * coefficient, unit)),
+			None if coefficient != 1.0 => factors.push(Self::Value(coefficient, CalcUnit::Number)),
+			None => {}
+		}
+		factors.extend(others);
+		if factors.len() == 1 { factors.pop().unwrap() } else { Self::Product(factors) }
+	}
+
+	fn scale(self, coefficient: f64) -> Self {
+		match self {
+			Self::Value(value, unit) => Self::Value(value * coefficient, unit),
+			node if coefficient == 1.0 => node,
+			node => Self::product(vec![Self::Value(coefficient, CalcUnit::Number), node]),
+		}
+	}
+
+	fn negate(self) -> Self {
+		match self {
+			Self::Value(value, unit) => Self::Value(-value, unit),
+			Self::Negate(inner) => *inner,
+			Self::Sum(terms) => Self::Sum(terms.into_iter().map(Self::negate).collect()),
+			Self::Product(factors) if matches!(factors.first(), Some(Self::Value(..))) => Self::product(
+				factors.into_iter().enumerate().map(|(i, f)| if i == 0 { f.negate() } else { f }).collect(),
+			),
+			node => Self::Negate(Box::new(node)),
+		}
+	}
+
+	fn invert(self) -> Self {
+		match self {
+			Self::Value(value, CalcUnit::Number) => Self::Value(1.0 / value, CalcUnit::Number),
+			Self::Invert(inner) => *inner,
+			node => Self::Invert(Box::new(node)),
+		}
+	}
+
+	/// Returns true if every value within this node is finite.
+	fn is_finite(&self) -> bool {
+		match self {
+			Self::Value(value, _) => value.is_finite(),
+			Self::Sum(nodes) | Self::Product(nodes) | Self::Function(_, nodes) => nodes.iter().all(Self::is_finite),
+			Self::Negate(node) | Self::Invert(node) => node.is_finite(),
+			Self::Opaque(_) => true,
+		}
+	}
+}
+
+/// Resolves the given math function with its simplified arguments, if they are all resolved values of compatible
+/// units. Returns the function unchanged otherwise.
+fn resolve_function(name: &'static str, args: Vec<CalcNode>) -> CalcNode {
+	let resolved = match name {
+		"min" => unify_all(&args).map(|(values, unit)| (values.into_iter().fold(f64::INFINITY, f64::min), unit)),
+		"max" => unify_all(&args).map(|(values, unit)| (values.into_iter().fold(f64::NEG_INFINITY, f64::max), unit)),
+		"clamp" => {
+			let is_none = |arg: &CalcNode| matches!(arg, CalcNode::Opaque(s) if s == "none");
+			let bounds: Vec<_> = args.iter().filter(|arg| !is_none(arg)).cloned().collect();
+			unify_all(&bounds).map(|(values, unit)| {
+				let (has_min, has_max) = (!is_none(&args[0]), !is_none(&args[2]));
+				let mut iter = values.into_iter();
+				let min = if has_min { iter.next().unwrap() } else { f64::NEG_INFINITY };
+				let value = iter.next().unwrap();
+				let max = if has_max { iter.next().unwrap() } else { f64::INFINITY };
+				(value.min(max).max(min), unit)
+			})
+		}
+		"round" => {
+			let (strategy, rest) = match args.first() {
+				Some(CalcNode::Opaque(strategy)) => (strategy.as_str(), &args[1..]),
+				_ => ("nearest", &args[..]),
+			};
+			let rest = if rest.len() == 1 {
+				match rest[0] {
+					CalcNode::Value(_, CalcUnit::Number) => {
+						vec![rest[0].clone(), CalcNode::Value(1.0, CalcUnit::Number)]
+					}
+					_ => vec![],
+				}
+			} else {
+				rest.to_vec()
+			};
+			unify_all(&rest).and_then(|(values, unit)| {
+				let (value, interval) = (values[0], values[1]);
+				if interval == 0.0 {
+					return None;
+				}
+				let rounded = match strategy {
+					"up" => (value / interval).ceil(),
+					"down" => (value / interval).floor(),
+					"to-zero" => (value / interval).trunc(),
+					_ => (value / interval).round(),
+				};
+				Some((rounded * interval, unit))
+			})
+		}
+		"mod" | "rem" => unify_all(&args).and_then(|(values, unit)| {
+			let (dividend, divisor) = (values[0], values[1]);
+			if divisor == 0.0 {
+				return None;
+			}
+			let quotient = if name == "mod" { (dividend / divisor).floor() } else { (dividend / divisor).trunc() };
+			Some((dividend - divisor * quotient, unit))
+		}),
+		"sin" | "cos" | "tan" => match args[..] {
+			[CalcNode::Value(value, unit)] if unit == CalcUnit::Number || unit.is_angle() => {
+				let radians = match unify((value, unit), (0.0, CalcUnit::Dimension(CssAtomSet::Deg))) {
+					Some((degrees, _, _)) if unit != CalcUnit::Number => degrees.to_radians(),
+					_ => value,
+				};
+				let result = match name {
+					"sin" => radians.sin(),
+					"cos" => radians.cos(),
+					_ => radians.tan(),
+				};
+				Some((result, CalcUnit::Number))
+			}
+			_ => None,
+		},
+		"asin" | "acos" | "atan" => match args[..] {
+			[CalcNode::Value(value, CalcUnit::Number)] => {
+				let radians = match name {
+					"asin" => value.asin(),
+					"acos" => value.acos(),
+					_ => value.atan(),
+				};
+				Some((radians.to_degrees(), CalcUnit::Dimension(CssAtomSet::Deg)))
+			}
+			_ => None,
+		},
+		"atan2" => unify_all(&args)
+			.map(|(values, _)| (values[0].atan2(values[1]).to_degrees(), CalcUnit::Dimension(CssAtomSet::Deg))),
+		"pow" => match args[..] {
+			[CalcNode::Value(base, CalcUnit::Number), CalcNode::Value(exponent, CalcUnit::Number)] => {
+				Some((base.powf(exponent), CalcUnit::Number))
+			}
+			_ => None,
+		},
+		"sqrt" => match args[..] {
+			[CalcNode::Value(value, CalcUnit::Number)] => Some((value.sqrt(), CalcUnit::Number)),
+			_ => None,
+		},
+		"exp" => match args[..] {
+			[CalcNode::Value(value, CalcUnit::Number)] => Some((value.exp(), CalcUnit::Number)),
+			_ => None,
+		},
+		"log" => match args[..] {
+			[CalcNode::Value(value, CalcUnit::Number)] => Some((value.ln(), CalcUnit::Number)),
+			[CalcNode::Value(value, CalcUnit::Number), CalcNode::Value(base, CalcUnit::Number)] => {
+				Some((value.log(base), CalcUnit::Number))
+			}
+			_ => None,
+		},
+		"hypot" => unify_all(&args).map(|(values, unit)| (values.into_iter().map(|v| v * v).sum::<f64>().sqrt(), unit)),
+		"abs" => match args[..] {
+			[CalcNode::Value(value, unit)] => Some((value.abs(), unit)),
+			_ => None,
+		},
+		"sign" => match args[..] {
+			[CalcNode::Value(value, _)] => Some((if value == 0.0 { value } else { value.signum() }, CalcUnit::Number)),
+			_ => None,
+		},
+		_ => None,
+	};
+	match resolved {
+		Some((value, unit)) if value.is_finite() => CalcNode::Value(value, unit),
+		_ => CalcNode::Function(name, args),
+	}
+}
+
+/// Formats a number as compactly as possible, rounding to 6 decimal places and dropping any leading zero.
+fn format_number(value: f64) -> String {
+	let rounded = (value * 1_000_000.0).round() / 1_000_000.0;
+	let formatted = if rounded == 0.0 { "0".to_string() } else { format!("{rounded}") };
+	if let Some(rest) = formatted.strip_prefix("0.") {
+		format!(".{rest}")
+	} else if let Some(rest) = formatted.strip_prefix("-0.") {
+		format!("-.{rest}")
+	} else {
+		formatted
+	}
+}
+
+fn format_value(value: f64, unit: CalcUnit) -> String {
+	let number = format_number(value);
+	match unit {
+		CalcUnit::Number => number,
+		CalcUnit::Percentage => format!("{number}%"),
+		CalcUnit::Dimension(atom) => format!("{number}{}", atom.to_str()),
+	}
+}
+
+/// Writes a node as a `<calc-sum>`, for use as the contents of `calc()` or another math function's argument.
+fn write_sum(node: &CalcNode, out: &mut String) {
+	match node {
+		CalcNode::Sum(terms) => {
+			for (i, term) in terms.iter().enumerate() {
+				match term {
+					CalcNode::Value(value, unit) if i > 0 && *value < 0.0 => {
+						out.push_str(" - ");
+						out.push_str(&format_value(-value, *unit));
+					}
+					CalcNode::Negate(inner) if i > 0 => {
+						out.push_str(" - ");
+						write_product(inner, out);
+					}
+					term => {
+						if i > 0 {
+							out.push_str(" + ");
+						}
+						write_product(term, out);
+					}
+				}
+			}
+		}
+		node => write_product(node, out),
+	}
+}
+
+/// Writes a node as a `<calc-product>`, wrapping it in parentheses if it is a sum.
+fn write_product(node: &CalcNode, out: &mut String) {
+	match node {
+		CalcNode::Product(factors) => {
+			for (i, factor) in factors.iter().enumerate() {
+				match factor {
+					CalcNode::Invert(inner) => {
+						out.push_str(if i == 0 { "1/" } else { "/" });
+						write_value(inner, out);
+					}
+					factor => {
+						if i > 0 {
+							out.push('*');
+						}
+						write_value(factor, out);
+					}
+				}
+			}
+		}
+		CalcNode::Negate(inner) => {
+			out.push_str("-1*");
+			write_value(inner, out);
+		}
+		CalcNode::Invert(inner) => {
+			out.push_str("1/");
+			write_value(inner, out);
+		}
+		node => write_value(node, out),
+	}
+}
+
+/// Writes a node as a `<calc-value>`, wrapping it in parentheses if it is a sum or product.
+fn write_value(node: &CalcNode, out: &mut String) {
+	match node {
+		CalcNode::Value(value, unit) => out.push_str(&format_value(*value, *unit)),
+		CalcNode::Opaque(text) => out.push_str(text),
+		CalcNode::Function(name, args) => write_function(name, args, out),
+		node => {
+			out.push('(');
+			write_sum(node, out);
+			out.push(')');
+		}
+	}
+}
+
+fn write_function(name: &str, args: &[CalcNode], out: &mut String) {
+	out.push_str(name);
+	out.push('(');
+	for (i, arg) in args.iter().enumerate() {
+		if i > 0 {
+			out.push(',');
+		}
+		write_sum(arg, out);
+	}
+	out.push(')');
+}
+
+struct Simplifier<'s> {
+	source_text: &'s str,
+	/// Set if a substitution function was encountered, meaning the type of the expression cannot be checked.
+	has_substitution: bool,
+}
+
+impl<'s> Simplifier<'s> {
+	fn sum(&mut self, sum: &CalcSum) -> CalcNode {
+		let terms = sum
+			.terms
+			.iter()
+			.map(|(operator, product)| {
+				let product = self.product(product);
+				if matches!(operator, Some(CalcSumOperator::Minus(_))) { product.negate() } else { product }
+			})
+			.collect();
+		CalcNode::sum(terms)
+	}
+
+	fn product(&mut self, product: &CalcProduct) -> CalcNode {
+		let factors = product
+			.factors
+			.iter()
+			.map(|(operator, value)| {
+				let value = self.value(value);
+				if matches!(operator, Some(CalcProductOperator::Divide(_))) { value.invert() } else { value }
+			})
+			.collect();
+		CalcNode::product(factors)
+	}
+
+	fn value(&mut self, value: &CalcValue) -> CalcNode {
+		let dimension = |c: Cursor| CalcNode::Value(c.token().value() as f64, CalcUnit::from_cursor(c));
+		match value {
+			CalcValue::Number(n) => CalcNode::Value(n.value() as f64, CalcUnit::Number),
+			CalcValue::Percentage(p) => CalcNode::Value(p.value() as f64, CalcUnit::Percentage),
+			CalcValue::Length(l) => dimension((*l).into()),
+			CalcValue::Angle(a) => dimension((*a).into()),
+			CalcValue::Time(t) => dimension((*t).into()),
+			CalcValue::Frequency(f) => dimension((*f).into()),
+			CalcValue::Resolution(r) => dimension((*r).into()),
+			CalcValue::Flex(f) => dimension((*f).into()),
+			CalcValue::Keyword(CalcKeyword::E(_)) => CalcNode::Value(E, CalcUnit::Number),
+			CalcValue::Keyword(CalcKeyword::Pi(_)) => CalcNode::Value(PI, CalcUnit::Number),
+			CalcValue::Keyword(CalcKeyword::Infinity(_)) => CalcNode::Opaque("infinity".into()),
+			CalcValue::Keyword(CalcKeyword::NegInfinity(_)) => CalcNode::Opaque("-infinity".into()),
+			CalcValue::Keyword(CalcKeyword::NaN(_)) => CalcNode::Opaque("NaN".into()),
+			CalcValue::Nested(_, sum, _) => self.sum(sum),
+			CalcValue::Function(function) => self.function(function),
+			CalcValue::Var(function) => self.substitution(function),
+			CalcValue::Substitution(function) => self.substitution(function),
+		}
+	}
+
+	fn substitution(&mut self, function: &impl ToCursors) -> CalcNode {
+		self.has_substitution = true;
+		let mut out = String::new();
+		function.to_cursors(&mut CursorCompactWriteSink::new(self.source_text, &mut out));
+		CalcNode::Opaque(out)
+	}
+
+	fn function(&mut self, function: &MathFunction) -> CalcNode {
+		let (name, args): (&'static str, Vec<CalcNode>) = match function {
+			MathFunction::Calc(f) => return self.sum(&f.params),
+			MathFunction::Min(f) => ("min", (&f.params).into_iter().map(|(sum, _)| self.sum(sum)).collect()),
+			MathFunction::Max(f) => ("max", (&f.params).into_iter().map(|(sum, _)| self.sum(sum)).collect()),
+			MathFunction::Clamp(f) => {
+				let mut bound = |bound: &NoneOr<CalcSum>| match bound {
+					NoneOr::None(_) => CalcNode::Opaque("none".into()),
+					NoneOr::Some(sum) => self.sum(sum),
+				};
+				let min = bound(&f.min);
+				let max = bound(&f.max);
+				("clamp", vec![min, self.sum(&f.value), max])
+			}
+			MathFunction::Round(f) => ("round", self.round_args(f)),
+			MathFunction::Mod(f) => ("mod", vec![self.sum(&f.dividend), self.sum(&f.divisor)]),
+			MathFunction::Rem(f) => ("rem", vec![self.sum(&f.dividend), self.sum(&f.divisor)]),
+			MathFunction::Sin(f) => ("sin", vec![self.sum(&f.params)]),
+			MathFunction::Cos(f) => ("cos", vec![self.sum(&f.params)]),
+			MathFunction::Tan(f) => ("tan", vec![self.sum(&f.params)]),
+			MathFunction::Asin(f) => ("asin", vec![self.sum(&f.params)]),
+			MathFunction::Acos(f) => ("acos", vec![self.sum(&f.params)]),
+			MathFunction::Atan(f) => ("atan", vec![self.sum(&f.params)]),
+			MathFunction::Atan2(f) => ("atan2", vec![self.sum(&f.y), self.sum(&f.x)]),
+			MathFunction::Pow(f) => ("pow", vec![self.sum(&f.base), self.sum(&f.exponent)]),
+			MathFunction::Sqrt(f) => ("sqrt", vec![self.sum(&f.params)]),
+			MathFunction::Hypot(f) => ("hypot", (&f.params).into_iter().map(|(sum, _)| self.sum(sum)).collect()),
+			MathFunction::Log(f) => {
+				let mut args = vec![self.sum(&f.value)];
+				args.extend(f.base.as_ref().map(|(_, base)| self.sum(base)));
+				("log", args)
+			}
+			MathFunction::Exp(f) => ("exp", vec![self.sum(&f.params)]),
+			MathFunction::Abs(f) => ("abs", vec![self.sum(&f.params)]),
+			MathFunction::Sign(f) => ("sign", vec![self.sum(&f.params)]),
+		};
+		resolve_function(name, args)
+	}
+
+	fn round_args(&mut self, f: &RoundFunction) -> Vec<CalcNode> {
+		let mut args = vec![];
+		match f.strategy {
+			// `nearest` is the default, so it can be dropped.
+			None | Some((RoundingStrategy::Nearest(_), _)) => {}
+			Some((RoundingStrategy::Up(_), _)) => args.push(CalcNode::Opaque("up".into())),
+			Some((RoundingStrategy::Down(_), _)) => args.push(CalcNode::Opaque("down".into())),
+			Some((RoundingStrategy::ToZero(_), _)) => args.push(CalcNode::Opaque("to-zero".into())),
+		}
+		args.push(self.sum(&f.value));
+		args.extend(f.interval.as_ref().map(|(_, interval)| self.sum(interval)));
+		args
+	}
+}
+
+impl<'a> MathFunction<'a> {
+	/// <https://drafts.csswg.org/css-values-4/#calc-simplification>
+	///
+	/// Simplifies this math function, folding constants (including `pi` and `e`), combining terms with compatible
+	/// units, and resolving any functions whose arguments are all known. The result is serialized as compactly as
+	/// possible: a lone value is written without a function where that is guaranteed to be equivalent, otherwise it is
+	/// wrapped in `calc()`.
+	///
+	/// Returns [None] if the expression does not type check, or could not be simplified to finite values.
+	pub fn simplify(&self, source_text: &str) -> Option<String> {
+		let mut simplifier = Simplifier { source_text, has_substitution: false };
+		let node = simplifier.function(self);
+		if !simplifier.has_substitution && self.resolve_type().is_none() {
+			return None;
+		}
+		if !node.is_finite() {
+			return None;
+		}
+		let mut out = String::new();
+		match &node {
+			// Out of range values are clamped when they come from a math function, but would be invalid if written
+			// bare, so only non-negative values can drop the function. Non-integer numbers also keep it as they may be
+			// rounded in an `<integer>` context.
+			CalcNode::Value(value, unit) if *value >= 0.0 && (*unit != CalcUnit::Number || value.fract() == 0.0) => {
+				out.push_str(&format_value(*value, *unit));
+			}
+			CalcNode::Function(name, args) => write_function(name, args, &mut out),
+			node => {
+				out.push_str("calc(");
+				write_sum(node, &mut out);
+				out.push(')');
+			}
+		}
+		Some(out)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bumpalo::Bump;
+	use css_lexer::Lexer;
+	use css_parse::Parser;
+
+	fn simplify(source_text: &str) -> Option<String> {
+		let bump = Bump::default();
+		let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+		let mut parser = Parser::new(&bump, source_text, lexer);
+		let result = parser.parse_entirely::<MathFunction>();
+		result.output.as_ref().expect("failed to parse").simplify(source_text)
+	}
+
+	#[test]
+	fn test_simplify() {
+		assert_eq!(simplify("calc(10px + 5px)").as_deref(), Some("15px"));
+		assert_eq!(simplify("calc(1in + 4px)").as_deref(), Some("100px"));
+		assert_eq!(simplify("calc(1s + 500ms)").as_deref(), Some("1.5s"));
+		assert_eq!(simplify("calc(2 * (3px + 4px))").as_deref(), Some("14px"));
+		assert_eq!(simplify("calc(10px / 4)").as_deref(), Some("2.5px"));
+		assert_eq!(simplify("calc(10px - 15px)").as_deref(), Some("calc(-5px)"));
+		assert_eq!(simplify("calc(100% - 10px - 10px)").as_deref(), Some("calc(100% - 20px)"));
+		assert_eq!(simplify("calc(1px + var(--a) + 2px)").as_deref(), Some("calc(3px + var(--a))"));
+		assert_eq!(simplify("calc(2 * var(--a) * 3)").as_deref(), Some("calc(6*var(--a))"));
+		assert_eq!(simplify("calc(1px - var(--a))").as_deref(), Some("calc(1px - var(--a))"));
+		assert_eq!(simplify("calc(1 / 3)").as_deref(), Some("calc(.333333)"));
+		assert_eq!(simplify("calc(calc(1px + 2px) * 2)").as_deref(), Some("6px"));
+		assert_eq!(simplify("calc(min(1px, 2px))").as_deref(), Some("1px"));
+		assert_eq!(simplify("calc(min(1px, 2%))").as_deref(), Some("min(1px,2%)"));
+	}
+
+	#[test]
+	fn test_simplify_constants() {
+		assert_eq!(simplify("calc(pi * 1rad)").as_deref(), Some("3.141593rad"));
+		assert_eq!(simplify("calc(1turn - 90deg)").as_deref(), Some("270deg"));
+		assert_eq!(simplify("calc(e * 1)").as_deref(), Some("calc(2.718282)"));
+		assert_eq!(simplify("calc(infinity * 1px)").as_deref(), Some("calc(1px*infinity)"));
+	}
+
+	#[test]
+	fn test_simplify_functions() {
+		assert_eq!(simplify("min(10px, 5px, 20px)").as_deref(), Some("5px"));
+		assert_eq!(simplify("max(1in, 90px)").as_deref(), Some("96px"));
+		assert_eq!(simplify("clamp(10px, 5px, 20px)").as_deref(), Some("10px"));
+		assert_eq!(simplify("clamp(10px, 50px, 20px)").as_deref(), Some("20px"));
+		assert_eq!(simplify("clamp(none, 50px, 20px)").as_deref(), Some("20px"));
+		assert_eq!(simplify("clamp(10px, 5vw, 20px)").as_deref(), Some("clamp(10px,5vw,20px)"));
+		assert_eq!(simplify("clamp(1px + 1px, 5vw, 20px)").as_deref(), Some("clamp(2px,5vw,20px)"));
+		assert_eq!(simplify("round(up, 13px, 5px)").as_deref(), Some("15px"));
+		assert_eq!(simplify("round(nearest, 1vw, 5px)").as_deref(), Some("round(1vw,5px)"));
+		assert_eq!(simplify("mod(18px, 5px)").as_deref(), Some("3px"));
+		assert_eq!(simplify("rem(-18px, 5px)").as_deref(), Some("calc(-3px)"));
+		assert_eq!(simplify("abs(-2px)").as_deref(), Some("2px"));
+		assert_eq!(simplify("sign(-2px)").as_deref(), Some("calc(-1)"));
+		assert_eq!(simplify("sin(30deg)").as_deref(), Some("calc(.5)"));
+		assert_eq!(simplify("atan2(1px, 1px)").as_deref(), Some("45deg"));
+		assert_eq!(simplify("pow(2, 10)").as_deref(), Some("1024"));
+		assert_eq!(simplify("sqrt(16)").as_deref(), Some("4"));
+		assert_eq!(simplify("log(8, 2)").as_deref(), Some("3"));
+		assert_eq!(simplify("hypot(3px, 4px)").as_deref(), Some("5px"));
+	}
+
+	#[test]
+	fn test_simplify_invalid() {
+		assert_eq!(simplify("calc(1px + 1s)"), None);
+		assert_eq!(simplify("calc(1px * 1px + 1px)"), None);
+		assert_eq!(simplify("sin(1px)"), None);
+		assert_eq!(simplify("calc(1px / 0)"), None);
+	}
+}
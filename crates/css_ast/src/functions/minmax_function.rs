@@ -0,0 +1,52 @@
+use super::prelude::*;
+use crate::{InflexibleBreadth, TrackBreadth};
+
+/// <https://drafts.csswg.org/css-grid-2/#valdef-grid-template-columns-minmax>
+///
+/// ```text,ignore
+/// minmax( <inflexible-breadth> , <track-breadth> )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct MinmaxFunction {
+	#[atom(CssAtomSet::Minmax)]
+	pub name: T![Function],
+	pub params: MinmaxFunctionParams,
+	pub close: T![')'],
+}
+
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct MinmaxFunctionParams {
+	pub min: InflexibleBreadth,
+	pub comma: T![,],
+	pub max: TrackBreadth,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<MinmaxFunction>(), 68);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, MinmaxFunction, "minmax(100px,1fr)");
+		assert_parse!(CssAtomSet::ATOMS, MinmaxFunction, "minmax(auto,max-content)");
+		assert_parse!(CssAtomSet::ATOMS, MinmaxFunction, "minmax(0,50%)");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, MinmaxFunction, "minmax(1fr,1fr)");
+		assert_parse_error!(CssAtomSet::ATOMS, MinmaxFunction, "minmax(100px 1fr)");
+		assert_parse_error!(CssAtomSet::ATOMS, MinmaxFunction, "minmax(-1px,1fr)");
+	}
+}
@@ -5,11 +5,17 @@ mod content_function;
 mod counter_functions;
 mod dynamic_range_limit_mix_function;
 mod easing_functions;
+mod filter_functions;
 mod fit_content_function;
+mod font_variant_alternates_functions;
 mod gradient_functions;
 mod image_set_function;
 mod keypress_function;
 mod leader_function;
+mod math_functions;
+mod math_simplify;
+mod minmax_function;
+mod palette_mix_function;
 mod param_function;
 mod repeat_function;
 mod snap_block_function;
@@ -19,8 +25,11 @@ mod stripes_function;
 mod superellipse_function;
 mod symbols_function;
 mod target_functions;
+mod timeline_functions;
+mod track_repeat_function;
 mod transform_functions;
 mod url;
+mod var_function;
 
 pub use attr_function::*;
 pub use calc_size_function::*;
@@ -29,11 +38,16 @@ pub use content_function::*;
 pub use counter_functions::*;
 pub use dynamic_range_limit_mix_function::*;
 pub use easing_functions::*;
+pub use filter_functions::*;
 pub use fit_content_function::*;
+pub use font_variant_alternates_functions::*;
 pub use gradient_functions::*;
 pub use image_set_function::*;
 pub use keypress_function::*;
 pub use leader_function::*;
+pub use math_functions::*;
+pub use minmax_function::*;
+pub use palette_mix_function::*;
 pub use param_function::*;
 pub use repeat_function::*;
 pub use snap_block_function::*;
@@ -43,8 +57,11 @@ pub use stripes_function::*;
 pub use superellipse_function::*;
 pub use symbols_function::*;
 pub use target_functions::*;
+pub use timeline_functions::*;
+pub use track_repeat_function::*;
 pub use transform_functions::*;
 pub use url::*;
+pub use var_function::*;
 
 mod prelude {
 	pub(crate) use crate::{Angle, CSSInt, Color, CssAtomSet, Position, Todo};
@@ -0,0 +1,100 @@
+use super::prelude::*;
+use crate::{ColorInterpolationMethod, FontPaletteStyleValue, Percentage, Ranged};
+
+/// <https://drafts.csswg.org/css-fonts-5/#palette-mix-function>
+///
+/// ```text,ignore
+/// palette-mix() = palette-mix( <color-interpolation-method> , [ [ normal | light | dark | <palette-identifier> |
+///                              <palette-mix()> ] && <percentage [0,100]>? ]#{2} )
+/// ```
+#[derive(Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct PaletteMixFunction<'a> {
+	#[atom(CssAtomSet::PaletteMix)]
+	pub name: T![Function],
+	pub method: ColorInterpolationMethod,
+	pub comma: T![,],
+	pub params: CommaSeparated<'a, PaletteMixFunctionParams<'a>, 2>,
+	pub close: T![')'],
+}
+
+impl<'a> Parse<'a> for PaletteMixFunction<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let name = p.parse::<T![Function]>()?;
+		if !p.equals_atom(name.into(), &CssAtomSet::PaletteMix) {
+			Err(Diagnostic::new(name.into(), Diagnostic::unexpected))?
+		}
+		let method = p.parse::<ColorInterpolationMethod>()?;
+		let comma = p.parse::<T![,]>()?;
+		let params = p.parse::<CommaSeparated<'a, PaletteMixFunctionParams<'a>, 2>>()?;
+		// Exactly two palettes are mixed.
+		if let (_, Some(comma)) = &params[1] {
+			Err(Diagnostic::new((*comma).into(), Diagnostic::unexpected))?
+		}
+		let close = p.parse::<T![')']>()?;
+		Ok(Self { name, method, comma, params, close })
+	}
+}
+
+/// A palette and its optional weight within a [PaletteMixFunction], in either order.
+///
+/// ```text,ignore
+/// [ normal | light | dark | <palette-identifier> | <palette-mix()> ] && <percentage [0,100]>?
+/// ```
+#[derive(Peek, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct PaletteMixFunctionParams<'a> {
+	pub palette: FontPaletteStyleValue<'a>,
+	pub percentage: Option<Ranged<Percentage, 0, 100>>,
+}
+
+impl<'a> Parse<'a> for PaletteMixFunctionParams<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let mut percentage = p.parse_if_peek::<Ranged<Percentage, 0, 100>>()?;
+		let palette = p.parse::<FontPaletteStyleValue<'a>>()?;
+		if percentage.is_none() {
+			percentage = p.parse_if_peek::<Ranged<Percentage, 0, 100>>()?;
+		}
+		Ok(Self { palette, percentage })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<PaletteMixFunction>(), 128);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, PaletteMixFunction, "palette-mix(in lch,light,dark)");
+		assert_parse!(CssAtomSet::ATOMS, PaletteMixFunction, "palette-mix(in oklab,--blue 30%,normal 70%)");
+		assert_parse!(CssAtomSet::ATOMS, PaletteMixFunction, "palette-mix(in hsl longer hue,40% --a,--b)");
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			PaletteMixFunction,
+			"palette-mix(in srgb,palette-mix(in srgb,light,dark) 50%,--c)"
+		);
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, PaletteMixFunction, "palette-mix(light,dark)");
+		assert_parse_error!(CssAtomSet::ATOMS, PaletteMixFunction, "palette-mix(in srgb,light)");
+		assert_parse_error!(CssAtomSet::ATOMS, PaletteMixFunction, "palette-mix(in srgb,light,dark,normal)");
+		assert_parse_error!(CssAtomSet::ATOMS, PaletteMixFunction, "palette-mix(in srgb,light 120%,dark)");
+	}
+}
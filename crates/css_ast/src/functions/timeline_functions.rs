@@ -0,0 +1,115 @@
+use super::prelude::*;
+use crate::{AutoOr, LengthPercentage};
+use css_parse::Optionals;
+
+/// <https://drafts.csswg.org/scroll-animations-1/#scroll-notation>
+///
+/// An anonymous scroll progress timeline, as used by `animation-timeline`.
+///
+/// ```text,ignore
+/// scroll() = scroll( [ <scroller> || <axis> ]? )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct ScrollFunction {
+	#[atom(CssAtomSet::Scroll)]
+	pub name: T![Function],
+	pub params: Option<Optionals![Scroller, Axis]>,
+	pub close: T![')'],
+}
+
+/// <https://drafts.csswg.org/scroll-animations-1/#view-notation>
+///
+/// An anonymous view progress timeline, as used by `animation-timeline`.
+///
+/// ```text,ignore
+/// view() = view( [ <axis> || <'view-timeline-inset'> ]? )
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct ViewFunction {
+	#[atom(CssAtomSet::View)]
+	pub name: T![Function],
+	pub params: Option<Optionals![Axis, ViewTimelineInset]>,
+	pub close: T![')'],
+}
+
+/// The inset of a [ViewFunction] from each edge of its scrollport. A single value applies to both edges.
+///
+/// ```text,ignore
+/// [ auto | <length-percentage> ]{1,2}
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct ViewTimelineInset(pub AutoOr<LengthPercentage>, pub Option<AutoOr<LengthPercentage>>);
+
+/// <https://drafts.csswg.org/scroll-animations-1/#typedef-scroller>
+///
+/// ```text,ignore
+/// <scroller> = root | nearest | self
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum Scroller {
+	#[atom(CssAtomSet::Root)]
+	Root(T![Ident]),
+	#[atom(CssAtomSet::Nearest)]
+	Nearest(T![Ident]),
+	#[atom(CssAtomSet::Self_)]
+	Self_(T![Ident]),
+}
+
+/// <https://drafts.csswg.org/scroll-animations-1/#typedef-axis>
+///
+/// ```text,ignore
+/// <axis> = block | inline | x | y
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum Axis {
+	#[atom(CssAtomSet::Block)]
+	Block(T![Ident]),
+	#[atom(CssAtomSet::Inline)]
+	Inline(T![Ident]),
+	#[atom(CssAtomSet::X)]
+	X(T![Ident]),
+	#[atom(CssAtomSet::Y)]
+	Y(T![Ident]),
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<ScrollFunction>(), 56);
+		assert_eq!(std::mem::size_of::<ViewFunction>(), 72);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, ScrollFunction, "scroll()");
+		assert_parse!(CssAtomSet::ATOMS, ScrollFunction, "scroll(root)");
+		assert_parse!(CssAtomSet::ATOMS, ScrollFunction, "scroll(x)");
+		assert_parse!(CssAtomSet::ATOMS, ScrollFunction, "scroll(self inline)");
+		assert_parse!(CssAtomSet::ATOMS, ViewFunction, "view()");
+		assert_parse!(CssAtomSet::ATOMS, ViewFunction, "view(block)");
+		assert_parse!(CssAtomSet::ATOMS, ViewFunction, "view(10%)");
+		assert_parse!(CssAtomSet::ATOMS, ViewFunction, "view(y auto 20px)");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, ScrollFunction, "scroll(root root)");
+		assert_parse_error!(CssAtomSet::ATOMS, ScrollFunction, "scroll(10px)");
+		assert_parse_error!(CssAtomSet::ATOMS, ViewFunction, "view(nearest)");
+		assert_parse_error!(CssAtomSet::ATOMS, ViewFunction, "view(1px 2px 3px)");
+	}
+}
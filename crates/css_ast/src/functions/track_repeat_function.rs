@@ -0,0 +1,181 @@
+use super::prelude::*;
+
+/// <https://drafts.csswg.org/css-grid-2/#funcdef-grid-template-columns-repeat>
+///
+/// ```text,ignore
+/// <track-repeat> = repeat( [ <integer [1,∞]> ] , [ <line-names>? <track-size> ]+ <line-names>? )
+/// <auto-repeat>  = repeat( [ auto-fill | auto-fit ] , [ <line-names>? <fixed-size> ]+ <line-names>? )
+/// <fixed-repeat> = repeat( [ <integer [1,∞]> ] , [ <line-names>? <fixed-size> ]+ <line-names>? )
+/// <name-repeat>  = repeat( [ <integer [1,∞]> | auto-fill ], <line-names>+)
+/// ```
+///
+/// All four forms parse into this one type. Within an `auto-fill` or `auto-fit` repeat every track size must be a
+/// `<fixed-size>`. Where each form is allowed is checked by the track list the repeat appears in.
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct TrackRepeatFunction<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	#[atom(CssAtomSet::Repeat)]
+	pub name: T![Function],
+	pub params: TrackRepeatFunctionParams<'a>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub close: T![')'],
+}
+
+impl<'a> TrackRepeatFunction<'a> {
+	/// Whether this repeats an automatic number of times, as an `<auto-repeat>`.
+	pub fn is_auto(&self) -> bool {
+		!matches!(self.params.count, RepeatCount::Integer(_))
+	}
+
+	/// Whether this repeats only line names, as a `<name-repeat>`.
+	pub fn is_name_repeat(&self) -> bool {
+		self.params.tracks.iter().all(|track| matches!(track, TrackRepeatItem::LineNames(_)))
+	}
+
+	/// Whether every track size this repeats is a `<fixed-size>`.
+	pub fn is_fixed(&self) -> bool {
+		self.params.tracks.iter().all(|track| match track {
+			TrackRepeatItem::TrackSize(size) => size.is_fixed(),
+			TrackRepeatItem::LineNames(_) => true,
+		})
+	}
+}
+
+#[derive(ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable))]
+pub struct TrackRepeatFunctionParams<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub count: RepeatCount,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub comma: T![,],
+	pub tracks: Vec<'a, TrackRepeatItem<'a>>,
+}
+
+impl<'a> Parse<'a> for TrackRepeatFunctionParams<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let count_cursor = p.peek_n(1);
+		let count = p.parse::<RepeatCount>()?;
+		let comma = p.parse::<T![,]>()?;
+		let mut tracks = Vec::new_in(p.bump());
+		let mut has_size = false;
+		let mut adjacent_names = None;
+		while !p.at_end() && !p.peek::<T![')']>() {
+			let c = p.peek_n(1);
+			let track = p.parse::<TrackRepeatItem>()?;
+			match &track {
+				TrackRepeatItem::LineNames(_) => {
+					if adjacent_names.is_none() && matches!(tracks.last(), Some(TrackRepeatItem::LineNames(_))) {
+						adjacent_names = Some(c);
+					}
+				}
+				TrackRepeatItem::TrackSize(size) => {
+					if !matches!(count, RepeatCount::Integer(_)) && !size.is_fixed() {
+						Err(Diagnostic::new(c, Diagnostic::unexpected))?
+					}
+					has_size = true;
+				}
+			}
+			tracks.push(track);
+		}
+		if tracks.is_empty() {
+			Err(Diagnostic::new(p.next(), Diagnostic::unexpected))?
+		}
+		if has_size {
+			// Line names may only be adjacent within a <name-repeat>.
+			if let Some(c) = adjacent_names {
+				Err(Diagnostic::new(c, Diagnostic::unexpected))?
+			}
+		} else if matches!(count, RepeatCount::AutoFit(_)) {
+			// A <name-repeat> may only repeat a fixed number of times, or auto-fill.
+			Err(Diagnostic::new(count_cursor, Diagnostic::unexpected_ident))?
+		}
+		Ok(Self { count, comma, tracks })
+	}
+}
+
+/// The number of repetitions of a `repeat()` within a track list.
+///
+/// ```text,ignore
+/// <integer [1,∞]> | auto-fill | auto-fit
+/// ```
+#[syntax(" <integer [1,∞]> | auto-fill | auto-fit ")]
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum RepeatCount {}
+
+/// A single item repeated by a `repeat()` within a track list.
+///
+/// ```text,ignore
+/// <line-names> | <track-size>
+/// ```
+#[syntax(" <line-names> | <track-size> ")]
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum TrackRepeatItem<'a> {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<TrackRepeatFunction>(), 88);
+		assert_eq!(std::mem::size_of::<RepeatCount>(), 16);
+		assert_eq!(std::mem::size_of::<TrackRepeatItem>(), 72);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, TrackRepeatFunction, "repeat(2,1fr)");
+		assert_parse!(CssAtomSet::ATOMS, TrackRepeatFunction, "repeat(4,[col-start] 250px [col-end])");
+		assert_parse!(CssAtomSet::ATOMS, TrackRepeatFunction, "repeat(2,[a] 1fr [b] minmax(10px,auto))");
+		assert_parse!(CssAtomSet::ATOMS, TrackRepeatFunction, "repeat(auto-fill,minmax(100px,1fr))");
+		assert_parse!(CssAtomSet::ATOMS, TrackRepeatFunction, "repeat(auto-fit,[a] 100px)");
+		assert_parse!(CssAtomSet::ATOMS, TrackRepeatFunction, "repeat(auto-fill,[a][b])");
+		assert_parse!(CssAtomSet::ATOMS, TrackRepeatFunction, "repeat(3,[a])");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, TrackRepeatFunction, "repeat(0,1fr)");
+		assert_parse_error!(CssAtomSet::ATOMS, TrackRepeatFunction, "repeat(2)");
+		assert_parse_error!(CssAtomSet::ATOMS, TrackRepeatFunction, "repeat(2,)");
+		assert_parse_error!(CssAtomSet::ATOMS, TrackRepeatFunction, "repeat(auto,1fr)");
+		assert_parse_error!(CssAtomSet::ATOMS, TrackRepeatFunction, "repeat(auto-fill,1fr)");
+		assert_parse_error!(CssAtomSet::ATOMS, TrackRepeatFunction, "repeat(auto-fit,minmax(auto,1fr))");
+		assert_parse_error!(CssAtomSet::ATOMS, TrackRepeatFunction, "repeat(auto-fit,[a])");
+		assert_parse_error!(CssAtomSet::ATOMS, TrackRepeatFunction, "repeat(2,[a] [b] 1fr)");
+		assert_parse_error!(CssAtomSet::ATOMS, TrackRepeatFunction, "repeat(2,repeat(2,1fr))");
+	}
+
+	#[test]
+	fn test_forms() {
+		let bump = bumpalo::Bump::default();
+		for (source_text, auto, names, fixed) in [
+			("repeat(2,1fr)", false, false, false),
+			("repeat(2,10px)", false, false, true),
+			("repeat(auto-fit,10px)", true, false, true),
+			("repeat(auto-fill,[a])", true, true, true),
+		] {
+			let lexer = css_lexer::Lexer::new(&CssAtomSet::ATOMS, source_text);
+			let mut p = Parser::new(&bump, source_text, lexer);
+			let repeat = p.parse_entirely::<TrackRepeatFunction>().output.unwrap();
+			assert_eq!(repeat.is_auto(), auto, "{source_text}");
+			assert_eq!(repeat.is_name_repeat(), names, "{source_text}");
+			assert_eq!(repeat.is_fixed(), fixed, "{source_text}");
+		}
+	}
+}
@@ -0,0 +1,87 @@
+use super::prelude::*;
+use crate::{Computed, ComputedPart};
+
+/// <https://drafts.csswg.org/css-variables-2/#funcdef-var>
+///
+/// ```text,ignore
+/// var() = var( <custom-property-name> , <declaration-value>? )
+/// ```
+///
+/// The fallback is parsed in the same way as a [Computed] value, so it may itself contain `var()` or math functions.
+#[derive(ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+#[metadata(node_kinds = Function)]
+pub struct VarFunction<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub property: T![DashedIdent],
+	pub comma: Option<T![,]>,
+	pub fallback: Vec<'a, ComputedPart<'a>>,
+	pub close: T![')'],
+}
+
+impl<'a> Peek<'a> for VarFunction<'a> {
+	fn peek<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		<T![Function]>::peek(p, c) && p.to_atom::<CssAtomSet>(c) == CssAtomSet::Var
+	}
+}
+
+impl<'a> Parse<'a> for VarFunction<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let name = p.parse::<T![Function]>()?;
+		if p.to_atom::<CssAtomSet>(name.into()) != CssAtomSet::Var {
+			Err(Diagnostic::new(name.into(), Diagnostic::unexpected))?
+		}
+		let property = p.parse::<T![DashedIdent]>()?;
+		let comma = p.parse_if_peek::<T![,]>()?;
+		let fallback = if comma.is_some() { Computed::parse_parts(p)? } else { Vec::new_in(p.bump()) };
+		let close = p.parse::<T![')']>()?;
+		Ok(Self { name, property, comma, fallback, close })
+	}
+}
+
+impl<'a> VarFunction<'a> {
+	/// The fallback value, if one was given. An empty fallback (`var(--foo,)`) is still a fallback.
+	pub fn fallback(&self) -> Option<&[ComputedPart<'a>]> {
+		self.comma.map(|_| self.fallback.as_slice())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<VarFunction>(), 88);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, VarFunction, "var(--foo)");
+		assert_parse!(CssAtomSet::ATOMS, VarFunction, "var(--foo,)");
+		assert_parse!(CssAtomSet::ATOMS, VarFunction, "var(--foo,12px)");
+		assert_parse!(CssAtomSet::ATOMS, VarFunction, "var(--foo,1px solid red)");
+		assert_parse!(CssAtomSet::ATOMS, VarFunction, "var(--foo,var(--bar,calc(1px + 2px)))");
+		assert_parse!(CssAtomSet::ATOMS, VarFunction, "var(--foo,rgb(var(--r) 0 0))");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, VarFunction, "var()");
+		assert_parse_error!(CssAtomSet::ATOMS, VarFunction, "var(foo)");
+		assert_parse_error!(CssAtomSet::ATOMS, VarFunction, "env(--foo)");
+		assert_parse_error!(CssAtomSet::ATOMS, VarFunction, "var(--foo 1px)");
+	}
+}
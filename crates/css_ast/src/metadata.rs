@@ -48,10 +48,12 @@ pub enum AtRuleId {
 	Media,
 	Namespace,
 	Page,
+	PositionTry,
 	Property,
 	Scope,
 	StartingStyle,
 	Supports,
+	ViewTransition,
 	Document,
 	WebkitKeyframes,
 	MozDocument,
@@ -64,19 +66,25 @@ impl NodeId {
 	pub fn to_at_rule_id(self) -> Option<AtRuleId> {
 		match self {
 			Self::CharsetRule => Some(AtRuleId::Charset),
+			Self::ColorProfileRule => Some(AtRuleId::ColorProfile),
 			Self::ContainerRule => Some(AtRuleId::Container),
 			Self::CounterStyleRule => Some(AtRuleId::CounterStyle),
 			Self::DocumentRule => Some(AtRuleId::Document),
 			Self::FontFaceRule => Some(AtRuleId::FontFace),
+			Self::FontFeatureValuesRule => Some(AtRuleId::FontFeatureValues),
+			Self::FontPaletteValuesRule => Some(AtRuleId::FontPaletteValues),
 			Self::KeyframesRule => Some(AtRuleId::Keyframes),
 			Self::LayerRule => Some(AtRuleId::Layer),
 			Self::MediaRule => Some(AtRuleId::Media),
 			Self::MozDocumentRule => Some(AtRuleId::MozDocument),
 			Self::NamespaceRule => Some(AtRuleId::Namespace),
 			Self::PageRule => Some(AtRuleId::Page),
+			Self::PositionTryRule => Some(AtRuleId::PositionTry),
 			Self::PropertyRule => Some(AtRuleId::Property),
+			Self::ScopeRule => Some(AtRuleId::Scope),
 			Self::StartingStyleRule => Some(AtRuleId::StartingStyle),
 			Self::SupportsRule => Some(AtRuleId::Supports),
+			Self::ViewTransitionRule => Some(AtRuleId::ViewTransition),
 			Self::WebkitKeyframesRule => Some(AtRuleId::WebkitKeyframes),
 			_ => None,
 		}
@@ -158,6 +166,10 @@ pub enum NodeKinds {
 	Dimension,
 	/// Node is a custom element or custom property
 	Custom,
+	/// Contains style rules scoped to the roots matched by a `<scope-start>`
+	ScopeStart,
+	/// Contains style rules bounded by a `<scope-end>` scoping limit
+	ScopeEnd,
 }
 
 /// Queryable properties a node exposes for selector matching.
@@ -344,6 +356,12 @@ impl CssMetadata {
 		self.node_kinds.contains(NodeKinds::Dimension)
 	}
 
+	/// Returns true if metadata contains scoped style rules, which weigh scoping proximity before order of appearance.
+	#[inline]
+	pub fn has_scope_proximity(&self) -> bool {
+		self.used_at_rules.contains(AtRuleId::Scope)
+	}
+
 	/// Returns true if metadata contains nodes with the given property kind.
 	#[inline]
 	pub fn has_property_kind(&self, kind: PropertyKind) -> bool {
@@ -415,13 +433,17 @@ mod tests {
 
 	#[test]
 	fn test_block_metadata_merge() {
-		let mut meta1 = CssMetadata::default();
-		meta1.property_groups = PropertyGroup::Color;
-		meta1.declaration_kinds = DeclarationKind::Important;
-
-		let mut meta2 = CssMetadata::default();
-		meta2.property_groups = PropertyGroup::Position;
-		meta2.declaration_kinds = DeclarationKind::Custom;
+		let meta1 = CssMetadata {
+			property_groups: PropertyGroup::Color,
+			declaration_kinds: DeclarationKind::Important,
+			..Default::default()
+		};
+
+		let meta2 = CssMetadata {
+			property_groups: PropertyGroup::Position,
+			declaration_kinds: DeclarationKind::Custom,
+			..Default::default()
+		};
 
 		let merged = meta1.merge(meta2);
 
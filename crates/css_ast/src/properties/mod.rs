@@ -1,11 +1,12 @@
 use crate::{
-	AppliesTo, BoxPortion, BoxSide, CssAtomSet, CssMetadata, DeclarationKind, DeclarationMetadata, Inherits, NodeKinds,
-	PropertyGroup, PropertyKind, VendorPrefixes, values,
+	AppliesTo, BoxPortion, BoxSide, CssAtomSet, CssMetadata, DeclarationKind, DeclarationMetadata, Inherits,
+	MathFunction, NodeKinds, PropertyGroup, PropertyKind, VarFunction, VendorPrefixes, values,
 };
-use css_lexer::Kind;
+use bumpalo::collections::Vec;
+use css_lexer::{AssociatedWhitespaceRules, Kind};
 use css_parse::{
-	AtomSet, ComponentValues, Cursor, Declaration, DeclarationValue, Diagnostic, KindSet, NodeWithMetadata, Parser,
-	Peek, Result as ParserResult, SemanticEq as SemanticEqTrait, State, T,
+	AtomSet, ComponentValue, ComponentValues, Cursor, Declaration, DeclarationValue, Diagnostic, KindSet,
+	NodeWithMetadata, Parse, Parser, Peek, Result as ParserResult, SemanticEq as SemanticEqTrait, State, T,
 };
 use csskit_derives::{Parse, SemanticEq, ToCursors, ToSpan};
 use std::{fmt::Debug, hash::Hash};
@@ -13,17 +14,134 @@ use std::{fmt::Debug, hash::Hash};
 // The build.rs generates a list of CSS properties from the value mods
 include!(concat!(env!("OUT_DIR"), "/css_apply_properties.rs"));
 
-#[derive(Parse, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The value of a custom property, such as `--foo: 1px solid var(--bar)`. This is parsed into the same
+/// [ComputedPart]s as a [Computed] value, so that any `var()` references within it can be found.
+#[derive(ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-#[parse(state = State::Nested, stop = KindSet::RIGHT_CURLY_OR_SEMICOLON)]
-pub struct Custom<'a>(pub ComponentValues<'a>);
+pub struct Custom<'a>(pub Vec<'a, ComputedPart<'a>>);
 
-#[derive(Parse, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+impl<'a> Parse<'a> for Custom<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		p.parse::<Computed>().map(|Computed(parts)| Self(parts))
+	}
+}
+
+/// A value which needs computing, as it contains math functions or substitution functions (such as `var()`). Math
+/// functions and `var()` are parsed into a typed [MathFunction] or [VarFunction] where possible, while everything else
+/// is kept as [ComponentValue]s.
+#[derive(ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-#[parse(state = State::Nested, stop = KindSet::RIGHT_CURLY_OR_SEMICOLON)]
-pub struct Computed<'a>(pub ComponentValues<'a>);
+pub struct Computed<'a>(pub Vec<'a, ComputedPart<'a>>);
+
+impl<'a> Parse<'a> for Computed<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let old_state = p.set_state(State::Nested);
+		let old_stop = p.set_stop(KindSet::RIGHT_CURLY_OR_SEMICOLON);
+		let parts = Self::parse_parts(p);
+		p.set_state(old_state);
+		p.set_stop(old_stop);
+		Ok(Self(parts?))
+	}
+}
+
+impl<'a> Computed<'a> {
+	pub(crate) fn parse_parts<I>(p: &mut Parser<'a, I>) -> ParserResult<Vec<'a, ComputedPart<'a>>>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let mut parts = Vec::new_in(p.bump());
+		let mut last_was_whitespace = false;
+		while !p.at_end() && !p.next_is_stop() {
+			let c = p.peek_n(1);
+			let part = if MathFunction::peek(p, c) {
+				Self::parse_typed(p).map(ComputedPart::Math)
+			} else if VarFunction::peek(p, c) {
+				Self::parse_typed(p).map(ComputedPart::Var)
+			} else if <T![Function]>::peek(p, c) {
+				Self::parse_typed(p).map(ComputedPart::Function)
+			} else {
+				None
+			};
+			if let Some(part) = part {
+				parts.push(part);
+				last_was_whitespace = false;
+				continue;
+			}
+			if !ComponentValue::peek(p, c) {
+				break;
+			}
+			let mut value = p.parse::<ComponentValue>()?;
+			// Mirror ComponentValues, so that whitespace before a delim is retained when written.
+			if let ComponentValue::Delim(d) = value
+				&& last_was_whitespace
+			{
+				let rules = d.associated_whitespace() | AssociatedWhitespaceRules::EnforceBefore;
+				value = ComponentValue::Delim(d.with_associated_whitespace(rules))
+			}
+			last_was_whitespace = matches!(value, ComponentValue::Whitespace(_));
+			parts.push(ComputedPart::Value(value));
+		}
+		Ok(parts)
+	}
+
+	/// Parses a typed part, rewinding if it fails so that it can be parsed as a [ComponentValue] instead.
+	fn parse_typed<I, T>(p: &mut Parser<'a, I>) -> Option<T>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+		T: Parse<'a>,
+	{
+		let checkpoint = p.checkpoint();
+		match p.parse::<T>() {
+			Ok(part) => Some(part),
+			Err(_) => {
+				p.rewind(checkpoint);
+				None
+			}
+		}
+	}
+}
+
+#[derive(ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum ComputedPart<'a> {
+	Math(MathFunction<'a>),
+	Var(VarFunction<'a>),
+	Function(ComputedFunction<'a>),
+	Value(#[cfg_attr(feature = "visitable", visit(skip))] ComponentValue<'a>),
+}
+
+/// Any other function within a [Computed] value, such as `rgb(var(--r) 0 0)`. Its arguments are parsed into
+/// [ComputedPart]s so that math functions and `var()` nested within it are typed too.
+#[derive(ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct ComputedFunction<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub name: T![Function],
+	pub params: Vec<'a, ComputedPart<'a>>,
+	pub close: T![')'],
+}
+
+impl<'a> Parse<'a> for ComputedFunction<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let name = p.parse::<T![Function]>()?;
+		let params = Computed::parse_parts(p)?;
+		let close = p.parse::<T![')']>()?;
+		Ok(Self { name, params, close })
+	}
+}
 
 impl<'a> Peek<'a> for Computed<'a> {
 	fn peek<I>(p: &Parser<'a, I>, c: Cursor) -> bool
@@ -45,6 +163,7 @@ impl<'a> Peek<'a> for Computed<'a> {
 					| CssAtomSet::Cos
 					| CssAtomSet::Tan
 					| CssAtomSet::Asin
+					| CssAtomSet::Acos
 					| CssAtomSet::Atan
 					| CssAtomSet::Atan2
 					| CssAtomSet::Pow
@@ -248,6 +367,22 @@ impl<'a> StyleValue<'a> {
 		apply_properties!(get_box_portion_by_name)
 	}
 
+	/// Returns the logical property group for a given property name, which relates it to its physical or logical
+	/// counterparts (e.g., MarginLeft and MarginInlineStart are both in Margin).
+	pub fn logical_property_group_by_name(property_name: CssAtomSet) -> Option<CssAtomSet> {
+		macro_rules! get_logical_property_group_by_name {
+			( $( $name: ident: $ty: ident$(<$a: lifetime>)? = $str: tt,)+ ) => {
+				match property_name {
+					$(
+					CssAtomSet::$name => values::$ty::logical_property_group(),
+					)+
+					_ => None,
+				}
+			};
+		}
+		apply_properties!(get_logical_property_group_by_name)
+	}
+
 	/// Returns the shorthand group for a given property name.
 	/// For longhand properties, returns the shorthand they belong to (e.g., MarginTop -> Margin).
 	/// For shorthands and non-longhand properties, returns CssAtomSet::_None.
@@ -445,8 +580,8 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_eq!(std::mem::size_of::<Property>(), 488);
-		assert_eq!(std::mem::size_of::<StyleValue>(), 416);
+		assert_eq!(std::mem::size_of::<Property>(), 656);
+		assert_eq!(std::mem::size_of::<StyleValue>(), 584);
 	}
 
 	#[test]
@@ -469,6 +604,16 @@ mod tests {
 		assert_parse!(CssAtomSet::ATOMS, Property, "float:none!important");
 		assert_parse!(CssAtomSet::ATOMS, Property, "width:1px");
 		assert_parse!(CssAtomSet::ATOMS, Property, "width:min(1px, 2px)");
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			Property,
+			"width:calc(100% - 2px)",
+			Property { value: StyleValue::Computed(Computed(_)), .. }
+		);
+		assert_parse!(CssAtomSet::ATOMS, Property, "width:calc(1px + var(--a))!important");
+		assert_parse!(CssAtomSet::ATOMS, Property, "margin:calc(1px * 2) auto");
+		// Invalid math functions are kept as component values
+		assert_parse!(CssAtomSet::ATOMS, Property, "width:calc(1px 2px)");
 		assert_parse!(CssAtomSet::ATOMS, Property, "border:1px solid var(--red)");
 		// Should still parse unknown properties
 		assert_parse!(CssAtomSet::ATOMS, Property, "dunno:like whatever");
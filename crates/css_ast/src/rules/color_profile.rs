@@ -1,19 +1,202 @@
-use crate::Todo;
+use super::prelude::*;
+#[cfg(feature = "visitable")]
+use crate::visit::{NodeId, QueryableNode};
+use crate::{Computed, Inherits, PropertyGroup};
+use csskit_derives::DeclarationMetadata;
+use csskit_proc_macro::syntax;
 
 // https://drafts.csswg.org/css-color-5/#at-profile
-pub type ColorProfileRule = Todo;
+#[derive(Parse, Peek, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit, queryable(skip))]
+#[cfg_attr(
+	feature = "css_feature_data",
+	derive(::csskit_derives::ToCSSFeature),
+	css_feature("css.at-rules.color-profile")
+)]
+#[derive(csskit_derives::NodeWithMetadata)]
+#[metadata(node_kinds = AtRule, used_at_rules = ColorProfile, property_kinds = Name)]
+pub struct ColorProfileRule<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	#[atom(CssAtomSet::ColorProfile)]
+	pub name: T![AtKeyword],
+	pub prelude: ColorProfileName,
+	#[metadata(delegate)]
+	pub block: ColorProfileRuleBlock<'a>,
+}
+
+#[cfg(feature = "visitable")]
+impl<'a> QueryableNode for ColorProfileRule<'a> {
+	const NODE_ID: NodeId = NodeId::ColorProfileRule;
+
+	fn get_property(&self, kind: PropertyKind) -> Option<Cursor> {
+		match kind {
+			PropertyKind::Name => Some(self.prelude.into()),
+			_ => None,
+		}
+	}
+}
+
+/// The name of a color profile, which `color()` refers to it by, such as the `--swop5c` in
+/// `color(--swop5c 0% 70% 20% 0%)`.
+///
+/// ```text,ignore
+/// <dashed-ident> | device-cmyk
+/// ```
+#[derive(Parse, Peek, ToCursors, IntoCursor, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum ColorProfileName {
+	Custom(T![DashedIdent]),
+	#[atom(CssAtomSet::DeviceCmyk)]
+	DeviceCmyk(T![Ident]),
+}
+
+#[derive(Parse, Peek, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct ColorProfileRuleBlock<'a>(
+	#[metadata(delegate)] pub DeclarationList<'a, ColorProfileRuleStyleValue<'a>, CssMetadata>,
+);
+
+#[derive(ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+#[metadata(node_kinds = Declaration)]
+pub enum ColorProfileRuleStyleValue<'a> {
+	Unknown(ComponentValues<'a>),
+	Src(ColorProfileSrcStyleValue),
+	RenderingIntent(RenderingIntentStyleValue),
+	Components(ComponentsStyleValue<'a>),
+}
+
+impl<'a> DeclarationValue<'a, CssMetadata> for ColorProfileRuleStyleValue<'a> {
+	type ComputedValue = Computed<'a>;
+
+	fn valid_declaration_name<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		matches!(p.to_atom::<CssAtomSet>(c), CssAtomSet::Src | CssAtomSet::RenderingIntent | CssAtomSet::Components)
+	}
+
+	fn is_unknown(&self) -> bool {
+		matches!(self, Self::Unknown(_))
+	}
+
+	fn is_initial(&self) -> bool {
+		false
+	}
+
+	fn is_inherit(&self) -> bool {
+		false
+	}
+
+	fn is_unset(&self) -> bool {
+		false
+	}
+
+	fn is_revert(&self) -> bool {
+		false
+	}
+
+	fn is_revert_layer(&self) -> bool {
+		false
+	}
+
+	fn needs_computing(&self) -> bool {
+		matches!(self, Self::Unknown(_))
+	}
+
+	fn parse_declaration_value<I>(p: &mut Parser<'a, I>, c: Cursor) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		Ok(match p.to_atom::<CssAtomSet>(c) {
+			CssAtomSet::Src => Self::Src(p.parse::<ColorProfileSrcStyleValue>()?),
+			CssAtomSet::RenderingIntent => Self::RenderingIntent(p.parse::<RenderingIntentStyleValue>()?),
+			CssAtomSet::Components => Self::Components(p.parse::<ComponentsStyleValue<'a>>()?),
+			_ => Self::Unknown(p.parse::<ComponentValues<'a>>()?),
+		})
+	}
+}
+
+/// https://drafts.csswg.org/css-color-5/#descdef-color-profile-src
+#[syntax(" <url> ")]
+#[derive(
+	Peek, Parse, ToCursors, ToSpan, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[declaration_metadata(initial = "n/a", inherits = False, property_group = Color)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct ColorProfileSrcStyleValue;
+
+/// https://drafts.csswg.org/css-color-5/#descdef-color-profile-rendering-intent
+#[syntax(" relative-colorimetric | absolute-colorimetric | perceptual | saturation ")]
+#[derive(
+	Peek, Parse, ToCursors, ToSpan, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[declaration_metadata(initial = "n/a", inherits = False, property_group = Color)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum RenderingIntentStyleValue {}
+
+/// Names the components of a profile, so that relative colors can refer to them.
+///
+/// https://drafts.csswg.org/css-color-5/#descdef-color-profile-components
+#[syntax(" <custom-ident># ")]
+#[derive(
+	Peek, Parse, ToCursors, ToSpan, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[declaration_metadata(initial = "n/a", inherits = False, property_group = Color)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct ComponentsStyleValue<'a>;
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
 
 	#[test]
 	fn size_test() {
-		assert_eq!(std::mem::size_of::<ColorProfileRule>(), 0);
+		assert_eq!(std::mem::size_of::<ColorProfileRule>(), 128);
+		assert_eq!(std::mem::size_of::<ColorProfileName>(), 16);
+		assert_eq!(std::mem::size_of::<ColorProfileRuleBlock>(), 96);
+		assert_eq!(std::mem::size_of::<ColorProfileRuleStyleValue>(), 40);
+		assert_eq!(std::mem::size_of::<ColorProfileSrcStyleValue>(), 40);
+		assert_eq!(std::mem::size_of::<RenderingIntentStyleValue>(), 16);
+		assert_eq!(std::mem::size_of::<ComponentsStyleValue>(), 32);
 	}
 
 	#[test]
 	fn test_writes() {
-		//assert_parse!(CssAtomSet::ATOMS, ColorProfileRule, "@color-profile --swop5c {\n\tsrc: url(\"https://example.org/SWOP2006_Coated5v2.icc\");}");
+		assert_parse!(CssAtomSet::ATOMS, ColorProfileRule, "@color-profile --swop5c{}");
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			ColorProfileRule,
+			"@color-profile --swop5c{src:url(\"https://example.org/SWOP2006_Coated5v2.icc\")}"
+		);
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			ColorProfileRule,
+			"@color-profile --fogra55beta{src:url(\"https://example.org/2020_13.003_FOGRA55beta_CL_Profile.icc\");rendering-intent:perceptual;components:cyan,magenta,yellow,black,orange,green,violet}"
+		);
+		assert_parse!(CssAtomSet::ATOMS, ColorProfileRule, "@color-profile device-cmyk{src:url(swop.icc)}");
+		assert_parse!(CssAtomSet::ATOMS, ColorProfileRule, "@color-profile --x{unknown:foo}");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, ColorProfileRule, "@color-profile{}");
+		assert_parse_error!(CssAtomSet::ATOMS, ColorProfileRule, "@color-profile swop5c{}");
+		assert_parse_error!(CssAtomSet::ATOMS, ColorProfileRule, "@color-profile --x{rendering-intent:bogus}");
 	}
 }
@@ -372,7 +372,7 @@ mod tests {
 		assert_eq!(std::mem::size_of::<BlockSizeContainerFeature>(), 124);
 		assert_eq!(std::mem::size_of::<AspectRatioContainerFeature>(), 180);
 		assert_eq!(std::mem::size_of::<OrientationContainerFeature>(), 64);
-		assert_eq!(std::mem::size_of::<StyleQuery>(), 504);
+		assert_eq!(std::mem::size_of::<StyleQuery>(), 672);
 		assert_eq!(std::mem::size_of::<ScrollStateQuery>(), 96);
 		assert_eq!(std::mem::size_of::<ScrollStateFeature>(), 80);
 	}
@@ -246,8 +246,8 @@ mod tests {
 	fn size_test() {
 		assert_eq!(std::mem::size_of::<ContainerRule>(), 144);
 		assert_eq!(std::mem::size_of::<ContainerConditionList>(), 32);
-		assert_eq!(std::mem::size_of::<ContainerCondition>(), 560);
-		assert_eq!(std::mem::size_of::<ContainerQuery>(), 544);
+		assert_eq!(std::mem::size_of::<ContainerCondition>(), 728);
+		assert_eq!(std::mem::size_of::<ContainerQuery>(), 712);
 	}
 
 	#[test]
@@ -1,19 +1,207 @@
-use crate::Todo;
+use super::prelude::*;
+use crate::{Computed, FamilyName};
+use css_parse::RuleVariants;
+use csskit_proc_macro::syntax;
 
 // https://drafts.csswg.org/css-fonts/#at-ruledef-font-feature-values
-pub type FontFeatureValuesRule = Todo;
+#[derive(Parse, Peek, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[cfg_attr(
+	feature = "css_feature_data",
+	derive(::csskit_derives::ToCSSFeature),
+	css_feature("css.at-rules.font-feature-values")
+)]
+#[derive(csskit_derives::NodeWithMetadata)]
+#[metadata(node_kinds = AtRule, used_at_rules = FontFeatureValues)]
+pub struct FontFeatureValuesRule<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	#[atom(CssAtomSet::FontFeatureValues)]
+	pub name: T![AtKeyword],
+	pub prelude: FontFeatureValuesPrelude<'a>,
+	#[metadata(delegate)]
+	pub block: FontFeatureValuesRuleBlock<'a>,
+}
+
+/// The font families an `@font-feature-values` rule names its feature values for, such as `Font One, "Font Two"`.
+#[derive(Parse, Peek, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable))]
+pub struct FontFeatureValuesPrelude<'a>(pub CommaSeparated<'a, FamilyName<'a>, 1>);
+
+#[derive(Parse, Peek, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct FontFeatureValuesRuleBlock<'a>(
+	#[metadata(delegate)] pub Block<'a, StyleValue<'a>, FontFeatureValueTypeRule<'a>, CssMetadata>,
+);
+
+/// A feature value block nested within `@font-feature-values`, which names values for one of the
+/// `font-variant-alternates` functions, such as `@styleset { nice-style: 12; }`.
+///
+/// https://drafts.csswg.org/css-fonts/#font-feature-value-type
+#[derive(Parse, Peek, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[cfg_attr(
+	feature = "css_feature_data",
+	derive(::csskit_derives::ToCSSFeature),
+	css_feature("css.at-rules.font-feature-values")
+)]
+pub enum FontFeatureValueTypeRule<'a> {
+	#[cfg_attr(feature = "css_feature_data", css_feature("css.at-rules.font-feature-values.stylistic"))]
+	#[atom(CssAtomSet::Stylistic)]
+	Stylistic(#[cfg_attr(feature = "visitable", visit(skip))] T![AtKeyword], FontFeatureValueBlock<'a>),
+	#[cfg_attr(feature = "css_feature_data", css_feature("css.at-rules.font-feature-values.historical-forms"))]
+	#[atom(CssAtomSet::HistoricalForms)]
+	HistoricalForms(#[cfg_attr(feature = "visitable", visit(skip))] T![AtKeyword], FontFeatureValueBlock<'a>),
+	#[cfg_attr(feature = "css_feature_data", css_feature("css.at-rules.font-feature-values.styleset"))]
+	#[atom(CssAtomSet::Styleset)]
+	Styleset(#[cfg_attr(feature = "visitable", visit(skip))] T![AtKeyword], FontFeatureValueBlock<'a>),
+	#[cfg_attr(feature = "css_feature_data", css_feature("css.at-rules.font-feature-values.character-variant"))]
+	#[atom(CssAtomSet::CharacterVariant)]
+	CharacterVariant(#[cfg_attr(feature = "visitable", visit(skip))] T![AtKeyword], FontFeatureValueBlock<'a>),
+	#[cfg_attr(feature = "css_feature_data", css_feature("css.at-rules.font-feature-values.swash"))]
+	#[atom(CssAtomSet::Swash)]
+	Swash(#[cfg_attr(feature = "visitable", visit(skip))] T![AtKeyword], FontFeatureValueBlock<'a>),
+	#[cfg_attr(feature = "css_feature_data", css_feature("css.at-rules.font-feature-values.ornaments"))]
+	#[atom(CssAtomSet::Ornaments)]
+	Ornaments(#[cfg_attr(feature = "visitable", visit(skip))] T![AtKeyword], FontFeatureValueBlock<'a>),
+	#[cfg_attr(feature = "css_feature_data", css_feature("css.at-rules.font-feature-values.annotation"))]
+	#[atom(CssAtomSet::Annotation)]
+	Annotation(#[cfg_attr(feature = "visitable", visit(skip))] T![AtKeyword], FontFeatureValueBlock<'a>),
+}
+
+impl<'a> NodeWithMetadata<CssMetadata> for FontFeatureValueTypeRule<'a> {
+	fn metadata(&self) -> CssMetadata {
+		self.block().0.metadata()
+	}
+}
+
+impl<'a> FontFeatureValueTypeRule<'a> {
+	pub fn name(&self) -> &T![AtKeyword] {
+		match self {
+			Self::Stylistic(a, _) => a,
+			Self::HistoricalForms(a, _) => a,
+			Self::Styleset(a, _) => a,
+			Self::CharacterVariant(a, _) => a,
+			Self::Swash(a, _) => a,
+			Self::Ornaments(a, _) => a,
+			Self::Annotation(a, _) => a,
+		}
+	}
+
+	pub fn block(&self) -> &FontFeatureValueBlock<'a> {
+		match self {
+			Self::Stylistic(_, b) => b,
+			Self::HistoricalForms(_, b) => b,
+			Self::Styleset(_, b) => b,
+			Self::CharacterVariant(_, b) => b,
+			Self::Swash(_, b) => b,
+			Self::Ornaments(_, b) => b,
+			Self::Annotation(_, b) => b,
+		}
+	}
+}
+
+impl<'a> RuleVariants<'a> for FontFeatureValueTypeRule<'a> {
+	type DeclarationValue = StyleValue<'a>;
+	type Metadata = CssMetadata;
+}
+
+#[derive(Parse, Peek, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(children))]
+pub struct FontFeatureValueBlock<'a>(pub DeclarationList<'a, FontFeatureValue<'a>, CssMetadata>);
+
+/// The indexes a feature value name stands for, such as the `12` in `nice-style: 12`. Each feature value block
+/// accepts any name, and only the number of indexes allowed depends on the block.
+#[syntax(" <integer [0,∞]>+ ")]
+#[derive(Parse, Peek, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct FontFeatureValue<'a>;
+
+impl<'a> DeclarationValue<'a, CssMetadata> for FontFeatureValue<'a> {
+	type ComputedValue = Computed<'a>;
+
+	fn is_initial(&self) -> bool {
+		false
+	}
+
+	fn is_inherit(&self) -> bool {
+		false
+	}
+
+	fn is_unset(&self) -> bool {
+		false
+	}
+
+	fn is_revert(&self) -> bool {
+		false
+	}
+
+	fn is_revert_layer(&self) -> bool {
+		false
+	}
+
+	fn needs_computing(&self) -> bool {
+		false
+	}
+
+	fn parse_declaration_value<I>(p: &mut Parser<'a, I>, _name: Cursor) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		p.parse::<Self>()
+	}
+}
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
 
 	#[test]
 	fn size_test() {
-		assert_eq!(std::mem::size_of::<FontFeatureValuesRule>(), 0);
+		assert_eq!(std::mem::size_of::<FontFeatureValuesRule>(), 176);
+		assert_eq!(std::mem::size_of::<FontFeatureValuesPrelude>(), 32);
+		assert_eq!(std::mem::size_of::<FontFeatureValuesRuleBlock>(), 128);
+		assert_eq!(std::mem::size_of::<FontFeatureValueTypeRule>(), 112);
+		assert_eq!(std::mem::size_of::<FontFeatureValueBlock>(), 96);
+		assert_eq!(std::mem::size_of::<FontFeatureValue>(), 32);
 	}
 
 	#[test]
 	fn test_writes() {
-		//assert_parse!(CssAtomSet::ATOMS, FontFeatureValuesRule, "@font-feature-values Taisho Gothic {}");
+		assert_parse!(CssAtomSet::ATOMS, FontFeatureValuesRule, "@font-feature-values Taisho Gothic{}");
+		assert_parse!(CssAtomSet::ATOMS, FontFeatureValuesRule, "@font-feature-values \"Font One\",Font Two{}");
+		assert_parse!(CssAtomSet::ATOMS, FontFeatureValuesRule, "@font-feature-values Otaru Kisa{font-display:swap}");
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			FontFeatureValuesRule,
+			"@font-feature-values Jupiter Sans{@swash{delicate:1;flowing:2}}"
+		);
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			FontFeatureValuesRule,
+			"@font-feature-values Otaru Kisa{@annotation{circled:1;black-boxed:3}@styleset{nice-style:12;alt:3 5 7}}"
+		);
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			FontFeatureValuesRule,
+			"@font-feature-values Foo{@stylistic{a:1}@historical-forms{b:1}@character-variant{c:1 2}@ornaments{d:4}}"
+		);
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, FontFeatureValuesRule, "@font-feature-values{}");
+		assert_parse_error!(CssAtomSet::ATOMS, FontFeatureValuesRule, "@font-feature-values Foo{@swash{a:-1}}");
+		assert_parse_error!(CssAtomSet::ATOMS, FontFeatureValuesRule, "@font-feature-values Foo{@swash{a:b}}");
+		assert_parse_error!(CssAtomSet::ATOMS, FontFeatureValuesRule, "@font-feature-values Foo{@unknown{a:1}}");
 	}
 }
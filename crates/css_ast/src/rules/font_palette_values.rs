@@ -1,19 +1,221 @@
-use crate::Todo;
+use super::prelude::*;
+#[cfg(feature = "visitable")]
+use crate::visit::{NodeId, QueryableNode};
+use crate::{Computed, Inherits, PropertyGroup};
+use csskit_derives::DeclarationMetadata;
+use csskit_proc_macro::syntax;
 
 // https://drafts.csswg.org/css-fonts/#at-ruledef-font-palette-values
-pub type FontPaletteValuesRule = Todo;
+#[derive(Parse, Peek, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit, queryable(skip))]
+#[cfg_attr(
+	feature = "css_feature_data",
+	derive(::csskit_derives::ToCSSFeature),
+	css_feature("css.at-rules.font-palette-values")
+)]
+#[derive(csskit_derives::NodeWithMetadata)]
+#[metadata(node_kinds = AtRule, used_at_rules = FontPaletteValues, property_kinds = Name)]
+pub struct FontPaletteValuesRule<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	#[atom(CssAtomSet::FontPaletteValues)]
+	pub name: T![AtKeyword],
+	pub prelude: FontPaletteValuesPrelude,
+	#[metadata(delegate)]
+	pub block: FontPaletteValuesRuleBlock<'a>,
+}
+
+#[cfg(feature = "visitable")]
+impl<'a> QueryableNode for FontPaletteValuesRule<'a> {
+	const NODE_ID: NodeId = NodeId::FontPaletteValuesRule;
+
+	fn get_property(&self, kind: PropertyKind) -> Option<Cursor> {
+		match kind {
+			PropertyKind::Name => Some(self.prelude.ident()),
+			_ => None,
+		}
+	}
+}
+
+#[derive(Parse, Peek, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct FontPaletteValuesPrelude(T![DashedIdent]);
+
+impl FontPaletteValuesPrelude {
+	/// Returns a cursor to the dashed identifier (e.g., `--cooler`), which `font-palette` refers to the palette by.
+	pub fn ident(&self) -> Cursor {
+		self.0.into()
+	}
+}
+
+#[derive(Parse, Peek, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct FontPaletteValuesRuleBlock<'a>(
+	#[metadata(delegate)] pub DeclarationList<'a, FontPaletteValuesRuleStyleValue<'a>, CssMetadata>,
+);
+
+#[derive(ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+#[metadata(node_kinds = Declaration)]
+pub enum FontPaletteValuesRuleStyleValue<'a> {
+	Unknown(ComponentValues<'a>),
+	FontFamily(FontPaletteFontFamilyStyleValue<'a>),
+	BasePalette(BasePaletteStyleValue),
+	OverrideColors(OverrideColorsStyleValue<'a>),
+}
+
+impl<'a> DeclarationValue<'a, CssMetadata> for FontPaletteValuesRuleStyleValue<'a> {
+	type ComputedValue = Computed<'a>;
+
+	fn valid_declaration_name<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		matches!(
+			p.to_atom::<CssAtomSet>(c),
+			CssAtomSet::FontFamily | CssAtomSet::BasePalette | CssAtomSet::OverrideColors
+		)
+	}
+
+	fn is_unknown(&self) -> bool {
+		matches!(self, Self::Unknown(_))
+	}
+
+	fn is_initial(&self) -> bool {
+		false
+	}
+
+	fn is_inherit(&self) -> bool {
+		false
+	}
+
+	fn is_unset(&self) -> bool {
+		false
+	}
+
+	fn is_revert(&self) -> bool {
+		false
+	}
+
+	fn is_revert_layer(&self) -> bool {
+		false
+	}
+
+	fn needs_computing(&self) -> bool {
+		matches!(self, Self::Unknown(_))
+	}
+
+	fn parse_declaration_value<I>(p: &mut Parser<'a, I>, c: Cursor) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		Ok(match p.to_atom::<CssAtomSet>(c) {
+			CssAtomSet::FontFamily => Self::FontFamily(p.parse::<FontPaletteFontFamilyStyleValue<'a>>()?),
+			CssAtomSet::BasePalette => Self::BasePalette(p.parse::<BasePaletteStyleValue>()?),
+			CssAtomSet::OverrideColors => Self::OverrideColors(p.parse::<OverrideColorsStyleValue<'a>>()?),
+			_ => Self::Unknown(p.parse::<ComponentValues<'a>>()?),
+		})
+	}
+}
+
+/// The `font-family` descriptor, which names the font families the palette applies to. Unlike the `font-family`
+/// property, generic families are not allowed.
+///
+/// https://drafts.csswg.org/css-fonts/#font-palette-values-font-family
+#[syntax(" <family-name># ")]
+#[derive(
+	Peek, Parse, ToCursors, ToSpan, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[declaration_metadata(initial = "n/a", inherits = False, property_group = Fonts)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[cfg_attr(
+	feature = "css_feature_data",
+	derive(::csskit_derives::ToCSSFeature),
+	css_feature("css.at-rules.font-palette-values.font-family")
+)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct FontPaletteFontFamilyStyleValue<'a>;
+
+/// https://drafts.csswg.org/css-fonts/#descdef-font-palette-values-base-palette
+#[syntax(" light | dark | <integer [0,∞]> ")]
+#[derive(
+	Peek, Parse, ToCursors, ToSpan, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[declaration_metadata(initial = "n/a", inherits = False, property_group = Fonts)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[cfg_attr(
+	feature = "css_feature_data",
+	derive(::csskit_derives::ToCSSFeature),
+	css_feature("css.at-rules.font-palette-values.base-palette")
+)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum BasePaletteStyleValue {}
+
+/// https://drafts.csswg.org/css-fonts/#descdef-font-palette-values-override-colors
+#[syntax(" [ <integer [0,∞]> <color> ]# ")]
+#[derive(
+	Peek, Parse, ToCursors, ToSpan, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[declaration_metadata(initial = "n/a", inherits = False, property_group = Fonts)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[cfg_attr(
+	feature = "css_feature_data",
+	derive(::csskit_derives::ToCSSFeature),
+	css_feature("css.at-rules.font-palette-values.override-colors")
+)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct OverrideColorsStyleValue<'a>;
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
 
 	#[test]
 	fn size_test() {
-		assert_eq!(std::mem::size_of::<FontPaletteValuesRule>(), 0);
+		assert_eq!(std::mem::size_of::<FontPaletteValuesRule>(), 128);
+		assert_eq!(std::mem::size_of::<FontPaletteValuesPrelude>(), 12);
+		assert_eq!(std::mem::size_of::<FontPaletteValuesRuleBlock>(), 96);
+		assert_eq!(std::mem::size_of::<FontPaletteValuesRuleStyleValue>(), 40);
+		assert_eq!(std::mem::size_of::<FontPaletteFontFamilyStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<BasePaletteStyleValue>(), 16);
+		assert_eq!(std::mem::size_of::<OverrideColorsStyleValue>(), 32);
 	}
 
 	#[test]
 	fn test_writes() {
-		//assert_parse!(CssAtomSet::ATOMS, FontPaletteValuesRule, "@font-palette-values --cooler {}");
+		assert_parse!(CssAtomSet::ATOMS, FontPaletteValuesRule, "@font-palette-values --cooler{}");
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			FontPaletteValuesRule,
+			"@font-palette-values --cooler{font-family:Bixa;base-palette:1;override-colors:1 #7EB7E4}"
+		);
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			FontPaletteValuesRule,
+			"@font-palette-values --dark{font-family:\"Nabla\",Bungee Spice;base-palette:dark}"
+		);
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			FontPaletteValuesRule,
+			"@font-palette-values --x{override-colors:0 red,1 rgb(0 0 255),3 oklch(70% 0.1 120)}"
+		);
+		assert_parse!(CssAtomSet::ATOMS, FontPaletteValuesRule, "@font-palette-values --x{unknown-descriptor:foo}");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, FontPaletteValuesRule, "@font-palette-values cooler{}");
+		assert_parse_error!(CssAtomSet::ATOMS, FontPaletteValuesRule, "@font-palette-values{}");
 	}
 }
@@ -72,7 +72,7 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_eq!(std::mem::size_of::<ImportRule>(), 744);
+		assert_eq!(std::mem::size_of::<ImportRule>(), 912);
 	}
 
 	#[test]
@@ -1,4 +1,5 @@
 use super::prelude::*;
+use css_parse::{Span, ToSpan};
 
 // https://drafts.csswg.org/css-cascade-5/#layering
 #[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -23,12 +24,21 @@ pub struct LayerRule<'a> {
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub struct LayerNameList<'a>(pub CommaSeparated<'a, LayerName<'a>>);
 
-#[derive(Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Peek, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 #[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
 #[derive(csskit_derives::NodeWithMetadata)]
 pub struct LayerName<'a>(T![Ident], Vec<'a, (T![.], T![Ident])>);
 
+// The derived ToSpan would span from the start of the source when there are no dotted parts, as an empty Vec spans
+// Span::ZERO.
+impl ToSpan for LayerName<'_> {
+	fn to_span(&self) -> Span {
+		let first = self.0.to_span();
+		self.1.last().map_or(first, |(_, ident)| first + ident.to_span())
+	}
+}
+
 impl<'a> Parse<'a> for LayerName<'a> {
 	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
 	where
@@ -58,7 +68,7 @@ pub struct LayerRuleBlock<'a>(#[metadata(delegate)] pub RuleList<'a, Rule<'a>, C
 mod tests {
 	use super::*;
 	use crate::CssAtomSet;
-	use css_parse::assert_parse;
+	use css_parse::{SourceOffset, assert_parse};
 
 	#[test]
 	fn size_test() {
@@ -68,6 +78,20 @@ mod tests {
 		assert_eq!(std::mem::size_of::<LayerRuleBlock>(), 96);
 	}
 
+	#[test]
+	fn test_name_span() {
+		let source_text = "@layer foo, bar.baz;";
+		let bump = bumpalo::Bump::default();
+		let lexer = css_lexer::Lexer::new(&CssAtomSet::ATOMS, source_text);
+		let mut parser = Parser::new(&bump, source_text, lexer);
+		let rule = parser.parse_entirely::<LayerRule>().output.unwrap();
+		let spans = (&rule.prelude.0).into_iter().map(|(name, _)| name.to_span()).collect::<std::vec::Vec<_>>();
+		assert_eq!(
+			spans,
+			[Span::new(SourceOffset(7), SourceOffset(10)), Span::new(SourceOffset(12), SourceOffset(19))]
+		);
+	}
+
 	#[test]
 	fn test_writes() {
 		assert_parse!(CssAtomSet::ATOMS, LayerRule, "@layer foo{}");
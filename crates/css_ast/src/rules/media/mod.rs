@@ -92,6 +92,21 @@ pub struct MediaQuery<'a> {
 	condition: Option<MediaCondition<'a>>,
 }
 
+impl<'a> MediaQuery<'a> {
+	/// The `not` or `only` keyword at the start of the query, if present.
+	pub fn precondition(&self) -> Option<&MediaPreCondition> {
+		self.precondition.as_ref()
+	}
+
+	pub fn media_type(&self) -> Option<&MediaType> {
+		self.media_type.as_ref()
+	}
+
+	pub fn condition(&self) -> Option<&MediaCondition<'a>> {
+		self.condition.as_ref()
+	}
+}
+
 impl<'a> Peek<'a> for MediaQuery<'a> {
 	const PEEK_KINDSET: KindSet = KindSet::new(&[Kind::Ident, Kind::LeftParen]);
 }
@@ -13,10 +13,12 @@ mod media;
 mod moz;
 mod namespace;
 mod page;
+mod position_try;
 mod property;
 mod scope;
 mod starting_style;
 mod supports;
+mod view_transition;
 mod webkit;
 
 pub use charset::*;
@@ -34,10 +36,12 @@ pub use media::*;
 pub use moz::*;
 pub use namespace::*;
 pub use page::*;
+pub use position_try::*;
 pub use property::*;
 pub use scope::*;
 pub use starting_style::*;
 pub use supports::*;
+pub use view_transition::*;
 pub use webkit::*;
 
 mod prelude {
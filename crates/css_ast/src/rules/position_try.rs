@@ -0,0 +1,198 @@
+use super::prelude::*;
+use crate::Computed;
+#[cfg(feature = "visitable")]
+use crate::visit::{NodeId, QueryableNode};
+
+// https://drafts.csswg.org/css-anchor-position-1/#fallback-rule
+#[derive(Parse, Peek, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit, queryable(skip))]
+#[cfg_attr(
+	feature = "css_feature_data",
+	derive(::csskit_derives::ToCSSFeature),
+	css_feature("css.at-rules.position-try")
+)]
+#[derive(csskit_derives::NodeWithMetadata)]
+#[metadata(node_kinds = AtRule, used_at_rules = PositionTry, property_kinds = Name)]
+pub struct PositionTryRule<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	#[atom(CssAtomSet::PositionTry)]
+	pub name: T![AtKeyword],
+	pub prelude: PositionTryPrelude,
+	#[metadata(delegate)]
+	pub block: PositionTryRuleBlock<'a>,
+}
+
+#[cfg(feature = "visitable")]
+impl<'a> QueryableNode for PositionTryRule<'a> {
+	const NODE_ID: NodeId = NodeId::PositionTryRule;
+
+	fn get_property(&self, kind: PropertyKind) -> Option<Cursor> {
+		match kind {
+			PropertyKind::Name => Some(self.prelude.ident()),
+			_ => None,
+		}
+	}
+}
+
+/// The name of a position try fallback, which `position-try-fallbacks` refers to it by, such as the `--above` in
+/// `position-try-fallbacks: --above, flip-block`.
+#[derive(Parse, Peek, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct PositionTryPrelude(T![DashedIdent]);
+
+impl PositionTryPrelude {
+	/// Returns a cursor to the dashed identifier (e.g., `--above`).
+	pub fn ident(&self) -> Cursor {
+		self.0.into()
+	}
+}
+
+#[derive(Parse, Peek, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(children))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct PositionTryRuleBlock<'a>(
+	#[metadata(delegate)] pub DeclarationList<'a, PositionTryRuleStyleValue<'a>, CssMetadata>,
+);
+
+/// A declaration within `@position-try`. Only the inset, margin, sizing and self-alignment properties, along with
+/// `position-anchor` and `position-area`, are accepted; any other declaration is parsed as unknown.
+#[derive(ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct PositionTryRuleStyleValue<'a>(#[metadata(delegate)] pub StyleValue<'a>);
+
+impl<'a> DeclarationValue<'a, CssMetadata> for PositionTryRuleStyleValue<'a> {
+	type ComputedValue = Computed<'a>;
+
+	fn valid_declaration_name<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		matches!(
+			p.to_atom::<CssAtomSet>(c),
+			// Inset properties
+			CssAtomSet::Top
+				| CssAtomSet::Right
+				| CssAtomSet::Bottom
+				| CssAtomSet::Left
+				| CssAtomSet::Inset
+				| CssAtomSet::InsetBlock
+				| CssAtomSet::InsetBlockStart
+				| CssAtomSet::InsetBlockEnd
+				| CssAtomSet::InsetInline
+				| CssAtomSet::InsetInlineStart
+				| CssAtomSet::InsetInlineEnd
+				// Margin properties
+				| CssAtomSet::Margin
+				| CssAtomSet::MarginTop
+				| CssAtomSet::MarginRight
+				| CssAtomSet::MarginBottom
+				| CssAtomSet::MarginLeft
+				| CssAtomSet::MarginBlock
+				| CssAtomSet::MarginBlockStart
+				| CssAtomSet::MarginBlockEnd
+				| CssAtomSet::MarginInline
+				| CssAtomSet::MarginInlineStart
+				| CssAtomSet::MarginInlineEnd
+				// Sizing properties
+				| CssAtomSet::Width
+				| CssAtomSet::Height
+				| CssAtomSet::MinWidth
+				| CssAtomSet::MinHeight
+				| CssAtomSet::MaxWidth
+				| CssAtomSet::MaxHeight
+				| CssAtomSet::BlockSize
+				| CssAtomSet::InlineSize
+				| CssAtomSet::MinBlockSize
+				| CssAtomSet::MinInlineSize
+				| CssAtomSet::MaxBlockSize
+				| CssAtomSet::MaxInlineSize
+				// Self-alignment properties
+				| CssAtomSet::AlignSelf
+				| CssAtomSet::JustifySelf
+				| CssAtomSet::PlaceSelf
+				| CssAtomSet::PositionAnchor
+				| CssAtomSet::PositionArea
+		)
+	}
+
+	fn is_unknown(&self) -> bool {
+		self.0.is_unknown()
+	}
+
+	fn is_initial(&self) -> bool {
+		self.0.is_initial()
+	}
+
+	fn is_inherit(&self) -> bool {
+		self.0.is_inherit()
+	}
+
+	fn is_unset(&self) -> bool {
+		self.0.is_unset()
+	}
+
+	fn is_revert(&self) -> bool {
+		self.0.is_revert()
+	}
+
+	fn is_revert_layer(&self) -> bool {
+		self.0.is_revert_layer()
+	}
+
+	fn needs_computing(&self) -> bool {
+		self.0.needs_computing()
+	}
+
+	fn parse_declaration_value<I>(p: &mut Parser<'a, I>, name: Cursor) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		if !Self::valid_declaration_name(p, name) {
+			return Ok(Self(<StyleValue as DeclarationValue<CssMetadata>>::parse_unknown_declaration_value(p, name)?));
+		}
+		Ok(Self(<StyleValue as DeclarationValue<CssMetadata>>::parse_declaration_value(p, name)?))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use css_parse::assert_parse;
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<PositionTryRule>(), 128);
+		assert_eq!(std::mem::size_of::<PositionTryPrelude>(), 12);
+		assert_eq!(std::mem::size_of::<PositionTryRuleBlock>(), 96);
+		assert_eq!(std::mem::size_of::<PositionTryRuleStyleValue>(), 584);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, PositionTryRule, "@position-try --above{}");
+		assert_parse!(CssAtomSet::ATOMS, PositionTryRule, "@position-try --above{position-area:top;margin-bottom:4px}");
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			PositionTryRule,
+			"@position-try --flush{top:anchor(bottom);left:anchor(left);width:anchor-size(width)}"
+		);
+		assert_parse!(CssAtomSet::ATOMS, PositionTryRule, "@position-try --above{color:red}");
+	}
+
+	#[test]
+	fn test_unknown_descriptors() {
+		let bump = bumpalo::Bump::default();
+		let source_text = "@position-try --above{color:red;top:0;--x:1}";
+		let lexer = css_lexer::Lexer::new(&CssAtomSet::ATOMS, source_text);
+		let mut p = Parser::new(&bump, source_text, lexer);
+		let rule = p.parse_entirely::<PositionTryRule>().output.unwrap();
+		let unknown = rule.block.0.declarations.iter().map(|d| d.value.is_unknown()).collect::<std::vec::Vec<_>>();
+		assert_eq!(unknown, [true, false, true]);
+	}
+}
@@ -1,19 +1,167 @@
-use crate::Todo;
+use super::prelude::*;
+use crate::{AtRuleId, NodeKinds, SelectorList};
 
 // https://drafts.csswg.org/css-cascade-6/#at-ruledef-scope
-pub type ScopeRule = Todo;
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[cfg_attr(feature = "css_feature_data", derive(::csskit_derives::ToCSSFeature), css_feature("css.at-rules.scope"))]
+pub struct ScopeRule<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	#[atom(CssAtomSet::Scope)]
+	pub name: T![AtKeyword],
+	pub prelude: Option<ScopePrelude<'a>>,
+	pub block: ScopeRuleBlock<'a>,
+}
+
+/// Style rules within `@scope` rank by scoping proximity ahead of order of appearance, and the `<scope-start>` adds
+/// no specificity of its own, so the metadata records which boundaries constrain the scoped rules.
+impl<'a> NodeWithMetadata<CssMetadata> for ScopeRule<'a> {
+	fn self_metadata(&self) -> CssMetadata {
+		let mut node_kinds = NodeKinds::AtRule;
+		if let Some(prelude) = &self.prelude {
+			if prelude.start.is_some() {
+				node_kinds |= NodeKinds::ScopeStart;
+			}
+			if prelude.end.is_some() {
+				node_kinds |= NodeKinds::ScopeEnd;
+			}
+		}
+		CssMetadata { used_at_rules: AtRuleId::Scope, node_kinds, ..Default::default() }
+	}
+
+	fn metadata(&self) -> CssMetadata {
+		self.block.metadata().merge(self.self_metadata())
+	}
+}
+
+/// The optional `(<scope-start>) to (<scope-end>)` prelude of an `@scope` rule. Either side may be omitted; without a
+/// `<scope-start>` the scoping root is the parent element of the stylesheet's owner node.
+///
+/// ```md
+/// <scope-prelude>
+///  │├─╭─ "(" <selector-list> ")" ─╮─╭─ "to" "(" <selector-list> ")" ─╮─┤│
+///     ╰───────────────────────────╯ ╰────────────────────────────────╯
+/// ```
+#[derive(ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable))]
+pub struct ScopePrelude<'a> {
+	pub start: Option<ScopeBoundary<'a>>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub to: Option<T![Ident]>,
+	pub end: Option<ScopeBoundary<'a>>,
+}
+
+impl<'a> Peek<'a> for ScopePrelude<'a> {
+	const PEEK_KINDSET: KindSet = KindSet::new(&[Kind::LeftParen, Kind::Ident]);
+
+	fn peek<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		<T!['(']>::peek(p, c) || (<T![Ident]>::peek(p, c) && p.equals_atom(c, &CssAtomSet::To))
+	}
+}
+
+impl<'a> Parse<'a> for ScopePrelude<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let start = p.parse_if_peek::<ScopeBoundary>()?;
+		let c = p.peek_n(1);
+		if <T![Ident]>::peek(p, c) && p.equals_atom(c, &CssAtomSet::To) {
+			let to = p.parse::<T![Ident]>()?;
+			let end = p.parse::<ScopeBoundary>()?;
+			Ok(Self { start, to: Some(to), end: Some(end) })
+		} else if start.is_some() {
+			Ok(Self { start, to: None, end: None })
+		} else {
+			Err(Diagnostic::new(c, Diagnostic::unexpected))?
+		}
+	}
+}
+
+/// A parenthesised `<scope-start>` or `<scope-end>` selector list. Selectors in a `<scope-end>` are relative to the
+/// scoping root, so they may begin with a combinator or refer to the root with `:scope`.
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable))]
+pub struct ScopeBoundary<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub open: T!['('],
+	pub selectors: SelectorList<'a>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub close: T![')'],
+}
+
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct ScopeRuleBlock<'a>(#[metadata(delegate)] pub Block<'a, StyleValue<'a>, Rule<'a>, CssMetadata>);
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::CssAtomSet;
+	use css_lexer::Lexer;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	fn metadata(source_text: &str) -> CssMetadata {
+		let bump = bumpalo::Bump::new();
+		let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+		let mut parser = Parser::new(&bump, source_text, lexer);
+		parser.parse::<ScopeRule>().unwrap().metadata()
+	}
 
 	#[test]
 	fn size_test() {
-		assert_eq!(std::mem::size_of::<ScopeRule>(), 0);
+		assert_eq!(std::mem::size_of::<ScopeRule>(), 272);
+		assert_eq!(std::mem::size_of::<ScopePrelude>(), 128);
+		assert_eq!(std::mem::size_of::<ScopeBoundary>(), 56);
+		assert_eq!(std::mem::size_of::<ScopeRuleBlock>(), 128);
 	}
 
 	#[test]
 	fn test_writes() {
-		//assert_parse!(CssAtomSet::ATOMS, ScopeRule, "@scope");
+		assert_parse!(CssAtomSet::ATOMS, ScopeRule, "@scope{}");
+		assert_parse!(CssAtomSet::ATOMS, ScopeRule, "@scope{color:red}");
+		assert_parse!(CssAtomSet::ATOMS, ScopeRule, "@scope(.card){}");
+		assert_parse!(CssAtomSet::ATOMS, ScopeRule, "@scope(.card,.panel){img{border:1px solid black}}");
+		assert_parse!(CssAtomSet::ATOMS, ScopeRule, "@scope(.card)to (.content){img{border:1px solid black}}");
+		assert_parse!(CssAtomSet::ATOMS, ScopeRule, "@scope to (.content){p{color:red}}");
+		assert_parse!(CssAtomSet::ATOMS, ScopeRule, "@scope(.media-object)to (>.content>*){:scope{display:grid}}");
+		assert_parse!(CssAtomSet::ATOMS, ScopeRule, "@scope([data-theme=dark]){:scope>a{color:white}}");
+		assert_parse!(CssAtomSet::ATOMS, ScopeRule, "@scope(.card){@media (min-width:40em){:scope{padding:1em}}}");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, ScopeRule, "@scope");
+		assert_parse_error!(CssAtomSet::ATOMS, ScopeRule, "@scope(.card)to{}");
+		assert_parse_error!(CssAtomSet::ATOMS, ScopeRule, "@scope(.card)to .content{}");
+		assert_parse_error!(CssAtomSet::ATOMS, ScopeRule, "@scope .card{}");
+	}
+
+	#[test]
+	fn test_metadata() {
+		let meta = metadata("@scope{color:red}");
+		assert!(meta.has_scope_proximity());
+		assert!(!meta.node_kinds.intersects(NodeKinds::ScopeStart | NodeKinds::ScopeEnd));
+
+		let meta = metadata("@scope(.card){img{border:1px solid black}}");
+		assert!(meta.has_scope_proximity());
+		assert!(meta.has_style_rules());
+		assert!(meta.node_kinds.contains(NodeKinds::ScopeStart));
+		assert!(!meta.node_kinds.contains(NodeKinds::ScopeEnd));
+
+		let meta = metadata("@scope(.card)to (.content){img{border:1px solid black}}");
+		assert!(meta.node_kinds.contains(NodeKinds::ScopeStart | NodeKinds::ScopeEnd));
+
+		let meta = metadata("@scope to (.content){p{color:red}}");
+		assert!(!meta.node_kinds.contains(NodeKinds::ScopeStart));
+		assert!(meta.node_kinds.contains(NodeKinds::ScopeEnd));
 	}
 }
@@ -103,9 +103,6 @@ impl<'a> Parse<'a> for SupportsCondition<'a> {
 	where
 		I: Iterator<Item = Cursor> + Clone,
 	{
-		if p.peek::<T![Function]>() || p.peek::<T!['(']>() {
-			return Ok(Self::Is(p.parse::<SupportsFeature>()?));
-		}
 		Self::parse_condition(p)
 	}
 }
@@ -207,8 +204,8 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_eq!(std::mem::size_of::<SupportsRule>(), 656);
-		assert_eq!(std::mem::size_of::<SupportsCondition>(), 536);
+		assert_eq!(std::mem::size_of::<SupportsRule>(), 816);
+		assert_eq!(std::mem::size_of::<SupportsCondition>(), 704);
 		assert_eq!(std::mem::size_of::<SupportsRuleBlock>(), 96);
 	}
 
@@ -216,6 +213,9 @@ mod tests {
 	fn test_writes() {
 		assert_parse!(CssAtomSet::ATOMS, SupportsRule, "@supports(color:black){}");
 		assert_parse!(CssAtomSet::ATOMS, SupportsRule, "@supports(width:1px){body{width:1px}}");
+		assert_parse!(CssAtomSet::ATOMS, SupportsRule, "@supports(display:grid) and (gap:1px){}");
+		assert_parse!(CssAtomSet::ATOMS, SupportsRule, "@supports(position:-webkit-sticky) or (position:sticky){}");
+		assert_parse!(CssAtomSet::ATOMS, SupportsRule, "@supports not (display:grid){}");
 		// assert_parse!(CssAtomSet::ATOMS, SupportsRule, "@supports not (width:1--foo){}");
 		// assert_parse!(CssAtomSet::ATOMS, SupportsRule, "@supports(width: 1--foo) or (width: 1foo) {\n\n}");
 		// assert_parse!(CssAtomSet::ATOMS, SupportsRule, "@supports(width: 1--foo) and (width: 1foo) {\n\n}");
@@ -0,0 +1,149 @@
+use super::prelude::*;
+use crate::{Computed, Inherits, PropertyGroup};
+use csskit_derives::DeclarationMetadata;
+use csskit_proc_macro::syntax;
+
+// https://drafts.csswg.org/css-view-transitions-2/#view-transition-rule
+#[derive(Parse, Peek, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[cfg_attr(
+	feature = "css_feature_data",
+	derive(::csskit_derives::ToCSSFeature),
+	css_feature("css.at-rules.view-transition")
+)]
+#[derive(csskit_derives::NodeWithMetadata)]
+#[metadata(node_kinds = AtRule, used_at_rules = ViewTransition)]
+pub struct ViewTransitionRule<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	#[atom(CssAtomSet::ViewTransition)]
+	pub name: T![AtKeyword],
+	#[metadata(delegate)]
+	pub block: ViewTransitionRuleBlock<'a>,
+}
+
+#[derive(Parse, Peek, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct ViewTransitionRuleBlock<'a>(
+	#[metadata(delegate)] pub DeclarationList<'a, ViewTransitionRuleStyleValue<'a>, CssMetadata>,
+);
+
+#[derive(ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+#[metadata(node_kinds = Declaration)]
+pub enum ViewTransitionRuleStyleValue<'a> {
+	Unknown(ComponentValues<'a>),
+	Navigation(NavigationStyleValue),
+	Types(TypesStyleValue<'a>),
+}
+
+impl<'a> DeclarationValue<'a, CssMetadata> for ViewTransitionRuleStyleValue<'a> {
+	type ComputedValue = Computed<'a>;
+
+	fn valid_declaration_name<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		matches!(p.to_atom::<CssAtomSet>(c), CssAtomSet::Navigation | CssAtomSet::Types)
+	}
+
+	fn is_unknown(&self) -> bool {
+		matches!(self, Self::Unknown(_))
+	}
+
+	fn is_initial(&self) -> bool {
+		false
+	}
+
+	fn is_inherit(&self) -> bool {
+		false
+	}
+
+	fn is_unset(&self) -> bool {
+		false
+	}
+
+	fn is_revert(&self) -> bool {
+		false
+	}
+
+	fn is_revert_layer(&self) -> bool {
+		false
+	}
+
+	fn needs_computing(&self) -> bool {
+		matches!(self, Self::Unknown(_))
+	}
+
+	fn parse_declaration_value<I>(p: &mut Parser<'a, I>, c: Cursor) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		Ok(match p.to_atom::<CssAtomSet>(c) {
+			CssAtomSet::Navigation => Self::Navigation(p.parse::<NavigationStyleValue>()?),
+			CssAtomSet::Types => Self::Types(p.parse::<TypesStyleValue<'a>>()?),
+			_ => Self::Unknown(p.parse::<ComponentValues<'a>>()?),
+		})
+	}
+}
+
+/// Opts a document into cross-document view transitions when navigating to or from it.
+///
+/// https://drafts.csswg.org/css-view-transitions-2/#descdef-view-transition-navigation
+#[syntax(" auto | none ")]
+#[derive(
+	Peek, Parse, ToCursors, ToSpan, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[declaration_metadata(initial = "none", inherits = False, property_group = ViewTransitions)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum NavigationStyleValue {}
+
+/// The active types of a cross-document view transition, which `:active-view-transition-type()` can match.
+///
+/// https://drafts.csswg.org/css-view-transitions-2/#descdef-view-transition-types
+#[syntax(" none | <custom-ident>+ ")]
+#[derive(
+	Peek, Parse, ToCursors, ToSpan, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[declaration_metadata(initial = "none", inherits = False, property_group = ViewTransitions)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct TypesStyleValue<'a>;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<ViewTransitionRule>(), 112);
+		assert_eq!(std::mem::size_of::<ViewTransitionRuleBlock>(), 96);
+		assert_eq!(std::mem::size_of::<ViewTransitionRuleStyleValue>(), 40);
+		assert_eq!(std::mem::size_of::<NavigationStyleValue>(), 16);
+		assert_eq!(std::mem::size_of::<TypesStyleValue>(), 32);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, ViewTransitionRule, "@view-transition{}");
+		assert_parse!(CssAtomSet::ATOMS, ViewTransitionRule, "@view-transition{navigation:auto}");
+		assert_parse!(CssAtomSet::ATOMS, ViewTransitionRule, "@view-transition{navigation:none;types:none}");
+		assert_parse!(CssAtomSet::ATOMS, ViewTransitionRule, "@view-transition{navigation:auto;types:slide forwards}");
+		assert_parse!(CssAtomSet::ATOMS, ViewTransitionRule, "@view-transition{unknown:foo}");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, ViewTransitionRule, "@view-transition foo{}");
+		assert_parse_error!(CssAtomSet::ATOMS, ViewTransitionRule, "@view-transition{navigation:always}");
+	}
+}
@@ -2,9 +2,14 @@ use crate::{CssAtomSet, CssDiagnostic};
 use bumpalo::collections::Vec;
 use css_lexer::Kind;
 use css_parse::{Cursor, Diagnostic, Parse, Parser, Peek, Result as ParserResult, T};
-use csskit_derives::{Parse, SemanticEq, ToCursors, ToSpan};
+use csskit_derives::{Parse, Peek, SemanticEq, ToCursors, ToSpan};
 
 use super::CompoundSelector;
+#[cfg(feature = "visitable")]
+use crate::{
+	PropertyKind,
+	visit::{NodeId, QueryableNode},
+};
 
 #[derive(ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
@@ -116,58 +121,162 @@ pub struct PickerPseudoElement {
 
 #[derive(Parse, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
 #[derive(csskit_derives::NodeWithMetadata)]
 pub struct ViewTransitionGroupPseudoElement<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
 	pub colons: T![::],
+	#[cfg_attr(feature = "visitable", visit(skip))]
 	#[atom(CssAtomSet::ViewTransitionGroup)]
 	pub function: T![Function],
 	pub value: PtNameAndClassSelector<'a>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
 	pub close: Option<T![')']>,
 }
 
 #[derive(Parse, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
 #[derive(csskit_derives::NodeWithMetadata)]
 pub struct ViewTransitionImagePairPseudoElement<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
 	pub colons: T![::],
+	#[cfg_attr(feature = "visitable", visit(skip))]
 	#[atom(CssAtomSet::ViewTransitionImagePair)]
 	pub function: T![Function],
 	pub value: PtNameAndClassSelector<'a>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
 	pub close: Option<T![')']>,
 }
 
 #[derive(Parse, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
 #[derive(csskit_derives::NodeWithMetadata)]
 pub struct ViewTransitionNewPseudoElement<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
 	pub colons: T![::],
+	#[cfg_attr(feature = "visitable", visit(skip))]
 	#[atom(CssAtomSet::ViewTransitionNew)]
 	pub function: T![Function],
 	pub value: PtNameAndClassSelector<'a>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
 	pub close: Option<T![')']>,
 }
 
 #[derive(Parse, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
 #[derive(csskit_derives::NodeWithMetadata)]
 pub struct ViewTransitionOldPseudoElement<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
 	pub colons: T![::],
+	#[cfg_attr(feature = "visitable", visit(skip))]
 	#[atom(CssAtomSet::ViewTransitionOld)]
 	pub function: T![Function],
 	pub value: PtNameAndClassSelector<'a>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
 	pub close: Option<T![')']>,
 }
 
-#[derive(Parse, ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The argument of the view transition pseudo-elements, such as the `hero.card` in `::view-transition-old(hero.card)`.
+///
+/// ```text,ignore
+/// <pt-name-and-class-selector> = <pt-name-selector> <pt-class-selector>? | <pt-class-selector>
+/// ```
+///
+/// https://drafts.csswg.org/css-view-transitions-2/#typedef-pt-name-and-class-selector
+#[derive(ToSpan, ToCursors, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(children))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct PtNameAndClassSelector<'a> {
+	pub name: Option<PtNameSelector>,
+	pub classes: Vec<'a, PtClassSelector>,
+}
+
+impl<'a> Parse<'a> for PtNameAndClassSelector<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let name = p.parse_if_peek::<PtNameSelector>()?;
+		let mut classes = Vec::new_in(p.bump());
+		while let Some(class) = p.parse_if_peek::<PtClassSelector>()? {
+			classes.push(class);
+		}
+		if name.is_none() && classes.is_empty() {
+			Err(Diagnostic::new(p.next(), Diagnostic::unexpected))?
+		}
+		Ok(Self { name, classes })
+	}
+}
+
+/// The view transition name matched by a view transition pseudo-element, as set by `view-transition-name`. See
+/// [ViewTransitionNameStyleValue::matches()][crate::ViewTransitionNameStyleValue::matches].
+///
+/// ```text,ignore
+/// <pt-name-selector> = '*' | <custom-ident>
+/// ```
+///
+/// https://drafts.csswg.org/css-view-transitions-1/#typedef-pt-name-selector
+#[derive(Parse, Peek, ToSpan, ToCursors, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self), queryable(skip))]
 #[derive(csskit_derives::NodeWithMetadata)]
-pub enum PtNameAndClassSelector<'a> {
+#[metadata(property_kinds = Name)]
+pub enum PtNameSelector {
 	Wildcard(T![*]),
-	Named(T![Ident], Vec<'a, (T![.], T![Ident])>),
-	Classes(Vec<'a, (T![.], T![Ident])>),
+	Name(T![Ident]),
+}
+
+#[cfg(feature = "visitable")]
+impl QueryableNode for PtNameSelector {
+	const NODE_ID: NodeId = NodeId::PtNameSelector;
+
+	fn get_property(&self, kind: PropertyKind) -> Option<Cursor> {
+		match (kind, self) {
+			(PropertyKind::Name, Self::Name(name)) => Some((*name).into()),
+			_ => None,
+		}
+	}
+}
+
+/// A view transition class matched by a view transition pseudo-element, as set by `view-transition-class`. See
+/// [ViewTransitionClassStyleValue::matches()][crate::ViewTransitionClassStyleValue::matches].
+///
+/// ```text,ignore
+/// <pt-class-selector> = ['.' <custom-ident>]+
+/// ```
+///
+/// https://drafts.csswg.org/css-view-transitions-2/#typedef-pt-class-selector
+#[derive(Parse, ToSpan, ToCursors, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self), queryable(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+#[metadata(property_kinds = Name)]
+pub struct PtClassSelector {
+	pub dot: T![.],
+	pub name: T![Ident],
+}
+
+impl<'a> Peek<'a> for PtClassSelector {
+	fn peek<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		c == Kind::Delim && c == '.' && p.peek_n(2) == Kind::Ident
+	}
+}
+
+#[cfg(feature = "visitable")]
+impl QueryableNode for PtClassSelector {
+	const NODE_ID: NodeId = NodeId::PtClassSelector;
+
+	fn get_property(&self, kind: PropertyKind) -> Option<Cursor> {
+		match kind {
+			PropertyKind::Name => Some(self.name.into()),
+			_ => None,
+		}
+	}
 }
@@ -192,7 +192,7 @@ impl<'a> SelectorComponentTrait<'a> for SelectorComponent<'a> {
 mod tests {
 	use super::*;
 	use crate::CssAtomSet;
-	use css_parse::assert_parse;
+	use css_parse::{assert_parse, assert_parse_error};
 
 	#[test]
 	fn size_test() {
@@ -203,6 +203,9 @@ mod tests {
 		assert_eq!(std::mem::size_of::<SelectorComponent>(), 128);
 		assert_eq!(std::mem::size_of::<LegacyPseudoElement>(), 28);
 		assert_eq!(std::mem::size_of::<Combinator>(), 28);
+		assert_eq!(std::mem::size_of::<PtNameAndClassSelector>(), 48);
+		assert_eq!(std::mem::size_of::<PtNameSelector>(), 16);
+		assert_eq!(std::mem::size_of::<PtClassSelector>(), 24);
 	}
 
 	#[test]
@@ -251,6 +254,16 @@ mod tests {
 		assert_parse!(CssAtomSet::ATOMS, SelectorList, "button:-moz-focusring");
 		assert_parse!(CssAtomSet::ATOMS, SelectorList, "::view-transition-group(*)");
 		assert_parse!(CssAtomSet::ATOMS, SelectorList, "::view-transition-new(thing.foo.bar.baz)");
+		assert_parse!(CssAtomSet::ATOMS, SelectorList, "::view-transition-old(*.card)");
+		assert_parse!(CssAtomSet::ATOMS, SelectorList, "::view-transition-image-pair(.card)");
+		assert_parse!(CssAtomSet::ATOMS, SelectorList, "html::view-transition-group(hero)");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, SelectorList, "::view-transition-group()");
+		assert_parse_error!(CssAtomSet::ATOMS, SelectorList, "::view-transition-group(hero card)");
+		assert_parse_error!(CssAtomSet::ATOMS, SelectorList, "::view-transition-new(.)");
 	}
 
 	#[test]
@@ -274,6 +287,19 @@ mod tests {
 		assert_visits!("a + b", CompoundSelector, Tag, HtmlTag, Combinator, Tag, HtmlTag);
 		assert_visits!("a ~ b", CompoundSelector, Tag, HtmlTag, Combinator, Tag, HtmlTag);
 		assert_visits!(".foo > .bar + .baz", CompoundSelector, Class, Combinator, Class, Combinator, Class);
+		assert_visits!("&.foo", CompoundSelector, Combinator, Class);
+		assert_visits!("& .foo", CompoundSelector, Combinator, Combinator, Class);
+		assert_visits!("& > .foo", CompoundSelector, Combinator, Combinator, Class);
+		assert_visits!(".foo &", CompoundSelector, Class, Combinator, Combinator);
+		assert_visits!(".foo&", CompoundSelector, Class, Combinator);
+		assert_visits!(
+			"::view-transition-old(hero.card)",
+			CompoundSelector,
+			FunctionalPseudoElement,
+			ViewTransitionOldPseudoElement,
+			PtNameSelector,
+			PtClassSelector
+		);
 	}
 
 	#[test]
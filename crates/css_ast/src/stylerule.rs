@@ -44,7 +44,7 @@ macro_rules! apply_rules {
 			Container(ContainerRule<'a>): "container",
 			Layer(LayerRule<'a>): "layer",
 			Media(MediaRule<'a>): "media",
-			Scope(ScopeRule): "scope",
+			Scope(ScopeRule<'a>): "scope",
 			Supports(SupportsRule<'a>): "supports",
 		}
 	};
@@ -46,22 +46,24 @@ macro_rules! apply_rules {
 	($macro: ident) => {
 		$macro! {
 			Charset(CharsetRule): CssAtomSet::Charset,
-			ColorProfile(ColorProfileRule): CssAtomSet::ColorProfile,
+			ColorProfile(ColorProfileRule<'a>): CssAtomSet::ColorProfile,
 			Container(ContainerRule<'a>): CssAtomSet::Container,
 			CounterStyle(CounterStyleRule<'a>): CssAtomSet::CounterStyle,
 			FontFace(FontFaceRule<'a>): CssAtomSet::FontFace,
-			FontFeatureValues(FontFeatureValuesRule): CssAtomSet::FontFeatureValues,
-			FontPaletteValues(FontPaletteValuesRule): CssAtomSet::FontPaletteValues,
+			FontFeatureValues(FontFeatureValuesRule<'a>): CssAtomSet::FontFeatureValues,
+			FontPaletteValues(FontPaletteValuesRule<'a>): CssAtomSet::FontPaletteValues,
 			Import(ImportRule<'a>): CssAtomSet::Import,
 			Keyframes(KeyframesRule<'a>): CssAtomSet::Keyframes,
 			Layer(LayerRule<'a>): CssAtomSet::Layer,
 			Media(MediaRule<'a>): CssAtomSet::Media,
 			Namespace(NamespaceRule): CssAtomSet::Namespace,
 			Page(PageRule<'a>): CssAtomSet::Page,
+			PositionTry(PositionTryRule<'a>): CssAtomSet::PositionTry,
 			Property(PropertyRule<'a>): CssAtomSet::Property,
-			Scope(ScopeRule): CssAtomSet::Scope,
+			Scope(ScopeRule<'a>): CssAtomSet::Scope,
 			StartingStyle(StartingStyleRule<'a>): CssAtomSet::StartingStyle,
 			Supports(SupportsRule<'a>): CssAtomSet::Supports,
+			ViewTransition(ViewTransitionRule<'a>): CssAtomSet::ViewTransition,
 
 			// Deprecated Rules
 			Document(DocumentRule<'a>): CssAtomSet::Document,
@@ -187,7 +189,7 @@ mod tests {
 	#[test]
 	fn size_test() {
 		assert_eq!(std::mem::size_of::<StyleSheet>(), 64);
-		assert_eq!(std::mem::size_of::<Rule>(), 752);
+		assert_eq!(std::mem::size_of::<Rule>(), 912);
 	}
 
 	#[test]
@@ -0,0 +1,73 @@
+use super::prelude::*;
+use crate::{TrackListItem, parse_track_list_items};
+
+/// <https://drafts.csswg.org/css-grid-2/#typedef-auto-track-list>
+///
+/// ```text,ignore
+/// <auto-track-list> = [ <line-names>? [ <fixed-size> | <fixed-repeat> ] ]* <line-names>? <auto-repeat>
+///                     [ <line-names>? [ <fixed-size> | <fixed-repeat> ] ]* <line-names>?
+/// ```
+#[derive(Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct AutoTrackList<'a>(pub Vec<'a, TrackListItem<'a>>);
+
+impl<'a> Parse<'a> for AutoTrackList<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let c = p.peek_n(1);
+		let mut seen_auto = false;
+		let items = parse_track_list_items(p, |prev, item| match item {
+			TrackListItem::LineNames(_) => !matches!(prev, Some(TrackListItem::LineNames(_))),
+			TrackListItem::TrackSize(size) => size.is_fixed(),
+			TrackListItem::TrackRepeatFunction(repeat) => {
+				if repeat.is_name_repeat() || !repeat.is_fixed() {
+					return false;
+				}
+				// Only one <auto-repeat> is allowed.
+				if repeat.is_auto() {
+					if seen_auto {
+						return false;
+					}
+					seen_auto = true;
+				}
+				true
+			}
+		})?;
+		if !seen_auto {
+			Err(Diagnostic::new(c, Diagnostic::unexpected))?
+		}
+		Ok(Self(items))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<AutoTrackList>(), 32);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, AutoTrackList, "repeat(auto-fill,100px)");
+		assert_parse!(CssAtomSet::ATOMS, AutoTrackList, "repeat(auto-fit,minmax(200px,1fr))");
+		assert_parse!(CssAtomSet::ATOMS, AutoTrackList, "[a] 10px repeat(auto-fill,[b] 100px) [c] repeat(2,20px)");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, AutoTrackList, "100px");
+		assert_parse_error!(CssAtomSet::ATOMS, AutoTrackList, "repeat(auto-fill,100px) repeat(auto-fit,100px)");
+		assert_parse_error!(CssAtomSet::ATOMS, AutoTrackList, "1fr repeat(auto-fill,100px)");
+		assert_parse_error!(CssAtomSet::ATOMS, AutoTrackList, "repeat(2,1fr) repeat(auto-fill,100px)");
+		assert_parse_error!(CssAtomSet::ATOMS, AutoTrackList, "repeat(auto-fill,[a])");
+	}
+}
@@ -0,0 +1,196 @@
+use super::prelude::*;
+use crate::{Attachment, BgClip, BgImage, BgPosition, BgSize, Color, RepeatStyle, VisualBox};
+
+/// <https://drafts.csswg.org/css-backgrounds-3/#typedef-bg-layer>
+///
+/// ```text,ignore
+/// <bg-layer> = <bg-image> || <bg-position> [ / <bg-size> ]? || <repeat-style> || <attachment> || <visual-box> || <visual-box>
+/// ```
+///
+/// The first box sets `background-origin`, and the second `background-clip`. A `<bg-clip>` keyword which is not a
+/// `<visual-box>`, such as `text`, can only set `background-clip`.
+#[derive(ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct BgLayer<'a> {
+	pub image: Option<BgImage<'a>>,
+	pub position: Option<BgPosition>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub slash: Option<T![/]>,
+	pub size: Option<BgSize>,
+	pub repeat: Option<RepeatStyle>,
+	pub attachment: Option<Attachment>,
+	pub origin: Option<VisualBox>,
+	pub clip: Option<BgClip>,
+}
+
+impl<'a> BgLayer<'a> {
+	/// Whether none of the layer's components were given.
+	pub fn is_empty(&self) -> bool {
+		self.image.is_none()
+			&& self.position.is_none()
+			&& self.repeat.is_none()
+			&& self.attachment.is_none()
+			&& self.origin.is_none()
+			&& self.clip.is_none()
+	}
+
+	fn parse_components<I>(p: &mut Parser<'a, I>, mut color: Option<&mut Option<Color<'a>>>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let mut layer = Self {
+			image: None,
+			position: None,
+			slash: None,
+			size: None,
+			repeat: None,
+			attachment: None,
+			origin: None,
+			clip: None,
+		};
+		loop {
+			if layer.image.is_none() && p.peek::<BgImage>() {
+				layer.image = Some(p.parse::<BgImage>()?);
+				continue;
+			}
+			if layer.position.is_none() && p.peek::<BgPosition>() {
+				layer.position = Some(p.parse::<BgPosition>()?);
+				if let Some(slash) = p.parse_if_peek::<T![/]>()? {
+					layer.slash = Some(slash);
+					layer.size = Some(p.parse::<BgSize>()?);
+				}
+				continue;
+			}
+			if layer.repeat.is_none() && p.peek::<RepeatStyle>() {
+				layer.repeat = Some(p.parse::<RepeatStyle>()?);
+				continue;
+			}
+			if layer.attachment.is_none() && p.peek::<Attachment>() {
+				layer.attachment = Some(p.parse::<Attachment>()?);
+				continue;
+			}
+			if layer.origin.is_none() && p.peek::<VisualBox>() {
+				layer.origin = Some(p.parse::<VisualBox>()?);
+				continue;
+			}
+			if layer.clip.is_none() && p.peek::<BgClip>() {
+				layer.clip = Some(p.parse::<BgClip>()?);
+				continue;
+			}
+			if let Some(color) = color.as_deref_mut()
+				&& color.is_none()
+				&& p.peek::<Color>()
+			{
+				*color = Some(p.parse::<Color>()?);
+				continue;
+			}
+			break;
+		}
+		if layer.is_empty() && !color.is_some_and(|color| color.is_some()) {
+			Err(Diagnostic::new(p.next(), Diagnostic::unexpected))?
+		}
+		Ok(layer)
+	}
+}
+
+impl<'a> Peek<'a> for BgLayer<'a> {
+	fn peek<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		BgImage::peek(p, c)
+			|| BgPosition::peek(p, c)
+			|| RepeatStyle::peek(p, c)
+			|| Attachment::peek(p, c)
+			|| BgClip::peek(p, c)
+	}
+}
+
+impl<'a> Parse<'a> for BgLayer<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		Self::parse_components(p, None)
+	}
+}
+
+/// <https://drafts.csswg.org/css-backgrounds-3/#typedef-final-bg-layer>
+///
+/// ```text,ignore
+/// <final-bg-layer> = <'background-color'> || <bg-image> || <bg-position> [ / <bg-size> ]? || <repeat-style> ||
+///                    <attachment> || <visual-box> || <visual-box>
+/// ```
+#[derive(ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct FinalBgLayer<'a> {
+	pub layer: BgLayer<'a>,
+	pub color: Option<Color<'a>>,
+}
+
+impl<'a> Peek<'a> for FinalBgLayer<'a> {
+	fn peek<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		BgLayer::peek(p, c) || Color::peek(p, c)
+	}
+}
+
+impl<'a> Parse<'a> for FinalBgLayer<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let mut color = None;
+		let layer = BgLayer::parse_components(p, Some(&mut color))?;
+		Ok(Self { layer, color })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<BgLayer>(), 408);
+		assert_eq!(std::mem::size_of::<FinalBgLayer>(), 552);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, BgLayer, "none");
+		assert_parse!(CssAtomSet::ATOMS, BgLayer, "url(a.png)");
+		assert_parse!(CssAtomSet::ATOMS, BgLayer, "url(a.png) no-repeat");
+		assert_parse!(CssAtomSet::ATOMS, BgLayer, "no-repeat url(a.png)");
+		assert_parse!(CssAtomSet::ATOMS, BgLayer, "url(a.png) center/cover no-repeat fixed");
+		assert_parse!(CssAtomSet::ATOMS, BgLayer, "right 10px top/50% auto repeat-x");
+		assert_parse!(CssAtomSet::ATOMS, BgLayer, "linear-gradient(red,blue) padding-box content-box");
+		assert_parse!(CssAtomSet::ATOMS, BgLayer, "linear-gradient(red,blue) text");
+		assert_parse!(CssAtomSet::ATOMS, BgLayer, "local 0 0");
+
+		assert_parse!(CssAtomSet::ATOMS, FinalBgLayer, "red");
+		assert_parse!(CssAtomSet::ATOMS, FinalBgLayer, "#fff url(a.png)");
+		assert_parse!(CssAtomSet::ATOMS, FinalBgLayer, "url(a.png) 0 0/100% no-repeat transparent");
+		assert_parse!(CssAtomSet::ATOMS, FinalBgLayer, "border-box rgb(0 0 0)");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, BgLayer, "red");
+		assert_parse_error!(CssAtomSet::ATOMS, BgLayer, "none none");
+		assert_parse_error!(CssAtomSet::ATOMS, BgLayer, "center/");
+		assert_parse_error!(CssAtomSet::ATOMS, BgLayer, "/cover");
+		assert_parse_error!(CssAtomSet::ATOMS, BgLayer, "fixed scroll");
+		assert_parse_error!(CssAtomSet::ATOMS, BgLayer, "padding-box content-box border-box");
+		assert_parse_error!(CssAtomSet::ATOMS, FinalBgLayer, "red blue");
+		assert_parse_error!(CssAtomSet::ATOMS, FinalBgLayer, "");
+	}
+}
@@ -0,0 +1,228 @@
+use super::prelude::*;
+use crate::{
+	LengthPercentage, PositionHorizontal, PositionHorizontalKeyword, PositionSingleValue, PositionVertical,
+	PositionVerticalKeyword,
+};
+
+/// <https://drafts.csswg.org/css-backgrounds-3/#typedef-bg-position>
+///
+/// ```text,ignore
+/// <bg-position> = [ left | center | right | top | bottom | <length-percentage> ]
+/// |
+///   [ left | center | right | <length-percentage> ]
+///   [ top | center | bottom | <length-percentage> ]
+/// |
+///   [ center | [ left | right ] <length-percentage>? ] &&
+///   [ center | [ top | bottom ] <length-percentage>? ]
+/// ```
+///
+/// Unlike [Position][crate::Position], this allows the three value form, where only one of the edges is offset.
+#[derive(ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum BgPosition {
+	SingleValue(PositionSingleValue),
+	TwoValue(PositionHorizontal, PositionVertical),
+	ThreeValue(BgPositionHorizontal, BgPositionVertical),
+	FourValue(PositionHorizontalKeyword, LengthPercentage, PositionVerticalKeyword, LengthPercentage),
+}
+
+impl<'a> Peek<'a> for BgPosition {
+	fn peek<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		PositionSingleValue::peek(p, c)
+	}
+}
+
+impl<'a> Parse<'a> for BgPosition {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let first = p.parse::<PositionSingleValue>()?;
+		// Single case
+		if !p.peek::<PositionSingleValue>() {
+			return Ok(Self::SingleValue(first));
+		}
+		let second = p.parse::<PositionSingleValue>()?;
+		// Two value
+		if !p.peek::<PositionSingleValue>() {
+			if let (Some(horizontal), Some(vertical)) = (first.to_horizontal(), second.to_vertical()) {
+				return Ok(Self::TwoValue(horizontal, vertical));
+			}
+			// Keywords may be given in either order, but lengths must be horizontal first.
+			if !matches!(first, PositionSingleValue::LengthPercentage(_))
+				&& !matches!(second, PositionSingleValue::LengthPercentage(_))
+				&& let (Some(horizontal), Some(vertical)) = (second.to_horizontal(), first.to_vertical())
+			{
+				return Ok(Self::TwoValue(horizontal, vertical));
+			}
+			Err(Diagnostic::new(second.into(), Diagnostic::unexpected))?
+		}
+		// Three or four value: two edges, each a keyword optionally followed by an offset.
+		let third = p.parse::<PositionSingleValue>()?;
+		let fourth = p.parse_if_peek::<LengthPercentage>()?.map(PositionSingleValue::LengthPercentage);
+		let mut values = [first, second, third].into_iter().chain(fourth).peekable();
+		let mut next_edge = || -> ParserResult<(PositionSingleValue, Option<LengthPercentage>)> {
+			let Some(keyword) = values.next() else { Err(Diagnostic::new(third.into(), Diagnostic::unexpected))? };
+			if matches!(keyword, PositionSingleValue::LengthPercentage(_)) {
+				Err(Diagnostic::new(keyword.into(), Diagnostic::expected_ident))?
+			}
+			// `center` is never offset.
+			if matches!(keyword, PositionSingleValue::Center(_)) {
+				return Ok((keyword, None));
+			}
+			let offset = match values.peek() {
+				Some(PositionSingleValue::LengthPercentage(offset)) => Some(*offset),
+				_ => None,
+			};
+			if offset.is_some() {
+				values.next();
+			}
+			Ok((keyword, offset))
+		};
+		let mut horizontal = next_edge()?;
+		let mut vertical = next_edge()?;
+		if let Some(value) = values.next() {
+			Err(Diagnostic::new(value.into(), Diagnostic::unexpected))?
+		}
+		if matches!(horizontal.0, PositionSingleValue::Top(_) | PositionSingleValue::Bottom(_))
+			|| matches!(vertical.0, PositionSingleValue::Left(_) | PositionSingleValue::Right(_))
+		{
+			std::mem::swap(&mut horizontal, &mut vertical);
+		}
+		let Some(horizontal) = BgPositionHorizontal::from_edge(horizontal.0, horizontal.1) else {
+			Err(Diagnostic::new(horizontal.0.into(), Diagnostic::unexpected))?
+		};
+		let Some(vertical) = BgPositionVertical::from_edge(vertical.0, vertical.1) else {
+			Err(Diagnostic::new(vertical.0.into(), Diagnostic::unexpected))?
+		};
+		Ok(match (horizontal, vertical) {
+			(
+				BgPositionHorizontal::Edge(horizontal, Some(horizontal_offset)),
+				BgPositionVertical::Edge(vertical, Some(vertical_offset)),
+			) => Self::FourValue(horizontal, horizontal_offset, vertical, vertical_offset),
+			(horizontal, vertical) => Self::ThreeValue(horizontal, vertical),
+		})
+	}
+}
+
+/// The horizontal edge of a three value [BgPosition].
+///
+/// ```text,ignore
+/// center | [ left | right ] <length-percentage>?
+/// ```
+#[derive(ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum BgPositionHorizontal {
+	Center(T![Ident]),
+	Edge(PositionHorizontalKeyword, Option<LengthPercentage>),
+}
+
+/// The vertical edge of a three value [BgPosition].
+///
+/// ```text,ignore
+/// center | [ top | bottom ] <length-percentage>?
+/// ```
+#[derive(ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum BgPositionVertical {
+	Center(T![Ident]),
+	Edge(PositionVerticalKeyword, Option<LengthPercentage>),
+}
+
+impl BgPositionHorizontal {
+	#[inline]
+	fn from_edge(keyword: PositionSingleValue, offset: Option<LengthPercentage>) -> Option<Self> {
+		match keyword {
+			PositionSingleValue::Center(t) => Some(Self::Center(t)),
+			_ => keyword.to_horizontal_keyword().map(|keyword| Self::Edge(keyword, offset)),
+		}
+	}
+}
+
+impl BgPositionVertical {
+	#[inline]
+	fn from_edge(keyword: PositionSingleValue, offset: Option<LengthPercentage>) -> Option<Self> {
+		match keyword {
+			PositionSingleValue::Center(t) => Some(Self::Center(t)),
+			_ => keyword.to_vertical_keyword().map(|keyword| Self::Edge(keyword, offset)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<BgPosition>(), 68);
+		assert_eq!(std::mem::size_of::<BgPositionHorizontal>(), 32);
+		assert_eq!(std::mem::size_of::<BgPositionVertical>(), 32);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, BgPosition, "center", BgPosition::SingleValue(_));
+		assert_parse!(CssAtomSet::ATOMS, BgPosition, "10%", BgPosition::SingleValue(_));
+		assert_parse!(CssAtomSet::ATOMS, BgPosition, "left top", BgPosition::TwoValue(_, _));
+		assert_parse!(CssAtomSet::ATOMS, BgPosition, "top left", BgPosition::TwoValue(_, _));
+		assert_parse!(CssAtomSet::ATOMS, BgPosition, "10px 20px", BgPosition::TwoValue(_, _));
+		assert_parse!(CssAtomSet::ATOMS, BgPosition, "left 20px", BgPosition::TwoValue(_, _));
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			BgPosition,
+			"left 10px top",
+			BgPosition::ThreeValue(BgPositionHorizontal::Edge(_, Some(_)), BgPositionVertical::Edge(_, None))
+		);
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			BgPosition,
+			"right bottom 10%",
+			BgPosition::ThreeValue(BgPositionHorizontal::Edge(_, None), BgPositionVertical::Edge(_, Some(_)))
+		);
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			BgPosition,
+			"center bottom 10%",
+			BgPosition::ThreeValue(BgPositionHorizontal::Center(_), BgPositionVertical::Edge(_, Some(_)))
+		);
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			BgPosition,
+			"bottom 10px center",
+			BgPosition::ThreeValue(BgPositionHorizontal::Center(_), BgPositionVertical::Edge(_, Some(_)))
+		);
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			BgPosition,
+			"top 5px right",
+			BgPosition::ThreeValue(BgPositionHorizontal::Edge(_, None), BgPositionVertical::Edge(_, Some(_)))
+		);
+		assert_parse!(CssAtomSet::ATOMS, BgPosition, "right 10px bottom 20px", BgPosition::FourValue(_, _, _, _));
+		assert_parse!(CssAtomSet::ATOMS, BgPosition, "bottom 20px right 10px", BgPosition::FourValue(_, _, _, _));
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, BgPosition, "left right");
+		assert_parse_error!(CssAtomSet::ATOMS, BgPosition, "top bottom");
+		assert_parse_error!(CssAtomSet::ATOMS, BgPosition, "10px left");
+		assert_parse_error!(CssAtomSet::ATOMS, BgPosition, "top 10px");
+		assert_parse_error!(CssAtomSet::ATOMS, BgPosition, "10px 20px 30px");
+		assert_parse_error!(CssAtomSet::ATOMS, BgPosition, "center 10px top");
+		assert_parse_error!(CssAtomSet::ATOMS, BgPosition, "left 10px right");
+		assert_parse_error!(CssAtomSet::ATOMS, BgPosition, "left 10px top 20px 30px");
+		assert_parse_error!(CssAtomSet::ATOMS, BgPosition, "left top center");
+	}
+}
@@ -12,7 +12,7 @@ pub use system::*;
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 #[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
 #[derive(csskit_derives::NodeWithMetadata)]
-pub enum Color {
+pub enum Color<'a> {
 	#[cfg_attr(feature = "visitable", visit(skip))]
 	#[atom(CssAtomSet::Currentcolor)]
 	Currentcolor(T![Ident]),
@@ -24,13 +24,13 @@ pub enum Color {
 	Hex(T![Hash]),
 	#[cfg_attr(feature = "visitable", visit(skip))]
 	Named(NamedColor),
-	Function(ColorFunction),
+	Function(ColorFunction<'a>),
 	// TODO: need bumpalo::Box PartialEq, or bumpalo::Box serde
 	// Relative(Box<'a, Color<'a>>, ColorFunction),
 	// Mix(ColorMixSyntax, Box<'a, Color<'a>>, u8, Box<'a, Color<'a>>),
 }
 
-impl Color {
+impl<'a> Color<'a> {
 	// Alias CanvasText for #[initial()]
 	// #[allow(non_upper_case_globals)]
 	// pub const Canvastext: Color = Color::System(SystemColor::CanvasText);
@@ -51,7 +51,7 @@ impl ToChromashift for T![Hash] {
 }
 
 #[cfg(feature = "chromashift")]
-impl ToChromashift for Color {
+impl<'a> ToChromashift for Color<'a> {
 	fn to_chromashift(&self) -> Option<chromashift::Color> {
 		use chromashift::Srgb;
 
@@ -81,7 +81,7 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_eq!(std::mem::size_of::<Color>(), 140);
+		assert_eq!(std::mem::size_of::<Color>(), 144);
 	}
 
 	#[test]
@@ -100,6 +100,11 @@ mod tests {
 		assert_parse!(CssAtomSet::ATOMS, Color, "lab(63.673% 51.577 5.811)");
 		assert_parse!(CssAtomSet::ATOMS, Color, "hwb(740deg 20% 30%/50%)");
 		assert_parse!(CssAtomSet::ATOMS, Color, "lch(20% 30% 740deg/50%)");
+		assert_parse!(CssAtomSet::ATOMS, Color, "color(display-p3 1 0.5 0/50%)");
+		assert_parse!(CssAtomSet::ATOMS, Color, "color(--swop5c 0% 70% 20% 0%)");
+		assert_parse!(CssAtomSet::ATOMS, Color, "color(--gray 0.5)");
+		assert_parse!(CssAtomSet::ATOMS, Color, "color(--swop5c 0% 70% none 0%/50%)");
+		assert_parse!(CssAtomSet::ATOMS, Color, "color(--fogra55beta 10% 20% 30% 40% 50% 60% 70%)");
 	}
 
 	#[test]
@@ -128,6 +133,9 @@ mod tests {
 		assert_parse_error!(CssAtomSet::ATOMS, Color, "hsl(250, 255deg, 255)");
 		// Using degrees for wrong component in lch
 		assert_parse_error!(CssAtomSet::ATOMS, Color, "lch(250, 255deg, 255)");
+		assert_parse_error!(CssAtomSet::ATOMS, Color, "color(display-p3 1 0.5 0 0)");
+		assert_parse_error!(CssAtomSet::ATOMS, Color, "color(display-p3 1 0.5)");
+		assert_parse_error!(CssAtomSet::ATOMS, Color, "color(--swop5c)");
 	}
 
 	#[test]
@@ -148,7 +156,7 @@ mod tests {
 		use super::ToChromashift;
 		use crate::CssAtomSet;
 		use bumpalo::Bump;
-		use chromashift::{Hex, Named, Srgb};
+		use chromashift::{DisplayP3, Hex, Named, Srgb};
 		use css_lexer::Lexer;
 		use css_parse::Parser;
 		let bump = Bump::default();
@@ -170,5 +178,17 @@ mod tests {
 		let mut p = Parser::new(&bump, source_text, lexer);
 		let color = p.parse_entirely::<Color>().output.unwrap().to_chromashift();
 		assert_eq!(color, Some(chromashift::Color::Srgb(Srgb::new(255, 0, 0, 100.0))));
+
+		let source_text = "color(display-p3 1 0.5 0 / 50%)";
+		let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+		let mut p = Parser::new(&bump, source_text, lexer);
+		let color = p.parse_entirely::<Color>().output.unwrap().to_chromashift();
+		assert_eq!(color, Some(chromashift::Color::DisplayP3(DisplayP3::new(1.0, 0.5, 0.0, 50.0))));
+
+		let source_text = "color(--swop5c 0% 70% 20% 0%)";
+		let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+		let mut p = Parser::new(&bump, source_text, lexer);
+		let color = p.parse_entirely::<Color>().output.unwrap().to_chromashift();
+		assert_eq!(color, None);
 	}
 }
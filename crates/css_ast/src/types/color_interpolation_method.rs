@@ -0,0 +1,148 @@
+use super::prelude::*;
+
+/// <https://drafts.csswg.org/css-color-4/#color-interpolation-method>
+///
+/// ```text,ignore
+/// <color-interpolation-method> = in [ <rectangular-color-space> | <polar-color-space> <hue-interpolation-method>? ]
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct ColorInterpolationMethod {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	#[atom(CssAtomSet::In)]
+	pub in_keyword: T![Ident],
+	pub space: InterpolationColorSpace,
+}
+
+/// The color space a [ColorInterpolationMethod] interpolates in.
+///
+/// ```text,ignore
+/// <rectangular-color-space> | <polar-color-space> <hue-interpolation-method>?
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum InterpolationColorSpace {
+	Rectangular(RectangularColorSpace),
+	Polar(PolarColorSpace, Option<HueInterpolationMethod>),
+}
+
+/// <https://drafts.csswg.org/css-color-4/#typedef-rectangular-color-space>
+///
+/// ```text,ignore
+/// <rectangular-color-space> = srgb | srgb-linear | display-p3 | display-p3-linear | a98-rgb | prophoto-rgb | rec2020 |
+///                             lab | oklab | xyz | xyz-d50 | xyz-d65
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum RectangularColorSpace {
+	#[atom(CssAtomSet::Srgb)]
+	Srgb(T![Ident]),
+	#[atom(CssAtomSet::SrgbLinear)]
+	SrgbLinear(T![Ident]),
+	#[atom(CssAtomSet::DisplayP3)]
+	DisplayP3(T![Ident]),
+	#[atom(CssAtomSet::DisplayP3Linear)]
+	DisplayP3Linear(T![Ident]),
+	#[atom(CssAtomSet::A98Rgb)]
+	A98Rgb(T![Ident]),
+	#[atom(CssAtomSet::ProphotoRgb)]
+	ProphotoRgb(T![Ident]),
+	#[atom(CssAtomSet::Rec2020)]
+	Rec2020(T![Ident]),
+	#[atom(CssAtomSet::Lab)]
+	Lab(T![Ident]),
+	#[atom(CssAtomSet::Oklab)]
+	Oklab(T![Ident]),
+	#[atom(CssAtomSet::Xyz)]
+	Xyz(T![Ident]),
+	#[atom(CssAtomSet::XyzD50)]
+	XyzD50(T![Ident]),
+	#[atom(CssAtomSet::XyzD65)]
+	XyzD65(T![Ident]),
+}
+
+/// <https://drafts.csswg.org/css-color-4/#typedef-polar-color-space>
+///
+/// ```text,ignore
+/// <polar-color-space> = hsl | hwb | lch | oklch
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum PolarColorSpace {
+	#[atom(CssAtomSet::Hsl)]
+	Hsl(T![Ident]),
+	#[atom(CssAtomSet::Hwb)]
+	Hwb(T![Ident]),
+	#[atom(CssAtomSet::Lch)]
+	Lch(T![Ident]),
+	#[atom(CssAtomSet::Oklch)]
+	Oklch(T![Ident]),
+}
+
+/// <https://drafts.csswg.org/css-color-4/#typedef-hue-interpolation-method>
+///
+/// ```text,ignore
+/// <hue-interpolation-method> = [ shorter | longer | increasing | decreasing ] hue
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct HueInterpolationMethod {
+	pub direction: HueInterpolationDirection,
+	#[atom(CssAtomSet::Hue)]
+	pub hue: T![Ident],
+}
+
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum HueInterpolationDirection {
+	#[atom(CssAtomSet::Shorter)]
+	Shorter(T![Ident]),
+	#[atom(CssAtomSet::Longer)]
+	Longer(T![Ident]),
+	#[atom(CssAtomSet::Increasing)]
+	Increasing(T![Ident]),
+	#[atom(CssAtomSet::Decreasing)]
+	Decreasing(T![Ident]),
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<ColorInterpolationMethod>(), 56);
+		assert_eq!(std::mem::size_of::<InterpolationColorSpace>(), 44);
+		assert_eq!(std::mem::size_of::<HueInterpolationMethod>(), 28);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, ColorInterpolationMethod, "in srgb");
+		assert_parse!(CssAtomSet::ATOMS, ColorInterpolationMethod, "in display-p3-linear");
+		assert_parse!(CssAtomSet::ATOMS, ColorInterpolationMethod, "in oklch");
+		assert_parse!(CssAtomSet::ATOMS, ColorInterpolationMethod, "in hsl longer hue");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, ColorInterpolationMethod, "srgb");
+		assert_parse_error!(CssAtomSet::ATOMS, ColorInterpolationMethod, "in");
+		assert_parse_error!(CssAtomSet::ATOMS, ColorInterpolationMethod, "in srgb shorter hue");
+		assert_parse_error!(CssAtomSet::ATOMS, ColorInterpolationMethod, "in lch longer");
+	}
+}
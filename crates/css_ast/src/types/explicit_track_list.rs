@@ -0,0 +1,55 @@
+use super::prelude::*;
+use crate::{TrackListItem, parse_track_list_items};
+
+/// <https://drafts.csswg.org/css-grid-2/#typedef-explicit-track-list>
+///
+/// ```text,ignore
+/// <explicit-track-list> = [ <line-names>? <track-size> ]+ <line-names>?
+/// ```
+#[derive(Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct ExplicitTrackList<'a>(pub Vec<'a, TrackListItem<'a>>);
+
+impl<'a> Parse<'a> for ExplicitTrackList<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let c = p.peek_n(1);
+		let items = parse_track_list_items(p, |prev, item| match item {
+			TrackListItem::LineNames(_) => !matches!(prev, Some(TrackListItem::LineNames(_))),
+			TrackListItem::TrackSize(_) => true,
+			TrackListItem::TrackRepeatFunction(_) => false,
+		})?;
+		if items.iter().all(|item| matches!(item, TrackListItem::LineNames(_))) {
+			Err(Diagnostic::new(c, Diagnostic::unexpected))?
+		}
+		Ok(Self(items))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<ExplicitTrackList>(), 32);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, ExplicitTrackList, "1fr");
+		assert_parse!(CssAtomSet::ATOMS, ExplicitTrackList, "[a] auto [b] 1fr [c]");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, ExplicitTrackList, "[a]");
+		assert_parse_error!(CssAtomSet::ATOMS, ExplicitTrackList, "repeat(2,1fr)");
+	}
+}
@@ -0,0 +1,57 @@
+use super::prelude::*;
+
+use crate::FilterFunction;
+
+// https://drafts.csswg.org/filter-effects-1/#typedef-filter-value-list
+// <filter-value-list> = [ <filter-function> | <url> ]+
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct FilterValueList<'a>(pub Vec<'a, FilterFunction<'a>>);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<FilterValueList>(), 32);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, FilterValueList, "blur(2px)");
+		assert_parse!(CssAtomSet::ATOMS, FilterValueList, "url(#shadow)");
+		assert_parse!(CssAtomSet::ATOMS, FilterValueList, "grayscale(1)contrast(150%)");
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			FilterValueList,
+			"url(#noise)drop-shadow(0 2px 4px rgba(0,0,0,.5))opacity(.8)"
+		);
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, FilterValueList, "none");
+		assert_parse_error!(CssAtomSet::ATOMS, FilterValueList, "blur(2px),opacity(1)");
+		assert_parse_error!(CssAtomSet::ATOMS, FilterValueList, "blur(2px) auto");
+	}
+
+	#[test]
+	#[cfg(feature = "visitable")]
+	fn test_visits() {
+		use crate::assert_visits;
+		assert_visits!(
+			"drop-shadow(red 1px 2px)",
+			FilterValueList,
+			FilterFunction,
+			DropShadowFunction,
+			Color,
+			Length,
+			Length
+		);
+	}
+}
@@ -0,0 +1,91 @@
+use super::prelude::*;
+
+/// <https://drafts.csswg.org/css-fonts-5/#font-size-adjust-prop>
+///
+/// The value of `font-size-adjust` other than `none`: the font metric to normalize, and its value.
+///
+/// ```text,ignore
+/// [ ex-height | cap-height | ch-width | ic-width | ic-height ]? [ from-font | <number [0,∞]> ]
+/// ```
+#[derive(Parse, ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct FontSizeAdjustValue {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub metric: Option<FontSizeAdjustMetric>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub value: FontSizeAdjustAmount,
+}
+
+impl<'a> Peek<'a> for FontSizeAdjustValue {
+	fn peek<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		FontSizeAdjustMetric::peek(p, c) || FontSizeAdjustAmount::peek(p, c)
+	}
+}
+
+/// The font metric `font-size-adjust` normalizes.
+///
+/// ```text,ignore
+/// ex-height | cap-height | ch-width | ic-width | ic-height
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum FontSizeAdjustMetric {
+	#[atom(CssAtomSet::ExHeight)]
+	ExHeight(T![Ident]),
+	#[atom(CssAtomSet::CapHeight)]
+	CapHeight(T![Ident]),
+	#[atom(CssAtomSet::ChWidth)]
+	ChWidth(T![Ident]),
+	#[atom(CssAtomSet::IcWidth)]
+	IcWidth(T![Ident]),
+	#[atom(CssAtomSet::IcHeight)]
+	IcHeight(T![Ident]),
+}
+
+/// The value of the metric `font-size-adjust` normalizes to.
+///
+/// ```text,ignore
+/// from-font | <number [0,∞]>
+/// ```
+#[syntax(" from-font | <number [0,∞]> ")]
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum FontSizeAdjustAmount {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<FontSizeAdjustValue>(), 32);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, FontSizeAdjustValue, "0.5");
+		assert_parse!(CssAtomSet::ATOMS, FontSizeAdjustValue, "from-font");
+		assert_parse!(CssAtomSet::ATOMS, FontSizeAdjustValue, "ex-height 0.545");
+		assert_parse!(CssAtomSet::ATOMS, FontSizeAdjustValue, "cap-height from-font");
+		assert_parse!(CssAtomSet::ATOMS, FontSizeAdjustValue, "ic-height 0");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, FontSizeAdjustValue, "ex-height");
+		assert_parse_error!(CssAtomSet::ATOMS, FontSizeAdjustValue, "-1");
+		assert_parse_error!(CssAtomSet::ATOMS, FontSizeAdjustValue, "0.5 ex-height");
+		assert_parse_error!(CssAtomSet::ATOMS, FontSizeAdjustValue, "ex-height cap-height 1");
+	}
+}
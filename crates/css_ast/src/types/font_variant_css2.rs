@@ -0,0 +1,17 @@
+use super::prelude::*;
+
+/// <https://drafts.csswg.org/css-fonts-4/#font-variant-css21-values>
+///
+/// ```text,ignore
+/// <font-variant-css2> = [ normal | small-caps ]
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum FontVariantCss2 {
+	#[atom(CssAtomSet::Normal)]
+	Normal(T![Ident]),
+	#[atom(CssAtomSet::SmallCaps)]
+	SmallCaps(T![Ident]),
+}
@@ -0,0 +1,354 @@
+use super::prelude::*;
+use crate::{
+	AnnotationFunction, CharacterVariantFunction, OrnamentsFunction, StylesetFunction, StylisticFunction, SwashFunction,
+};
+
+/// <https://drafts.csswg.org/css-fonts-4/#typedef-font-variant-ligatures-common-lig-values>
+///
+/// ```text,ignore
+/// <common-lig-values> = [ common-ligatures | no-common-ligatures ]
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum CommonLigValues {
+	#[atom(CssAtomSet::CommonLigatures)]
+	CommonLigatures(T![Ident]),
+	#[atom(CssAtomSet::NoCommonLigatures)]
+	NoCommonLigatures(T![Ident]),
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#typedef-font-variant-ligatures-discretionary-lig-values>
+///
+/// ```text,ignore
+/// <discretionary-lig-values> = [ discretionary-ligatures | no-discretionary-ligatures ]
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum DiscretionaryLigValues {
+	#[atom(CssAtomSet::DiscretionaryLigatures)]
+	DiscretionaryLigatures(T![Ident]),
+	#[atom(CssAtomSet::NoDiscretionaryLigatures)]
+	NoDiscretionaryLigatures(T![Ident]),
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#typedef-font-variant-ligatures-historical-lig-values>
+///
+/// ```text,ignore
+/// <historical-lig-values> = [ historical-ligatures | no-historical-ligatures ]
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum HistoricalLigValues {
+	#[atom(CssAtomSet::HistoricalLigatures)]
+	HistoricalLigatures(T![Ident]),
+	#[atom(CssAtomSet::NoHistoricalLigatures)]
+	NoHistoricalLigatures(T![Ident]),
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#typedef-font-variant-ligatures-contextual-alt-values>
+///
+/// ```text,ignore
+/// <contextual-alt-values> = [ contextual | no-contextual ]
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum ContextualAltValues {
+	#[atom(CssAtomSet::Contextual)]
+	Contextual(T![Ident]),
+	#[atom(CssAtomSet::NoContextual)]
+	NoContextual(T![Ident]),
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#typedef-font-variant-numeric-numeric-figure-values>
+///
+/// ```text,ignore
+/// <numeric-figure-values> = [ lining-nums | oldstyle-nums ]
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum NumericFigureValues {
+	#[atom(CssAtomSet::LiningNums)]
+	LiningNums(T![Ident]),
+	#[atom(CssAtomSet::OldstyleNums)]
+	OldstyleNums(T![Ident]),
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#typedef-font-variant-numeric-numeric-spacing-values>
+///
+/// ```text,ignore
+/// <numeric-spacing-values> = [ proportional-nums | tabular-nums ]
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum NumericSpacingValues {
+	#[atom(CssAtomSet::ProportionalNums)]
+	ProportionalNums(T![Ident]),
+	#[atom(CssAtomSet::TabularNums)]
+	TabularNums(T![Ident]),
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#typedef-font-variant-numeric-numeric-fraction-values>
+///
+/// ```text,ignore
+/// <numeric-fraction-values> = [ diagonal-fractions | stacked-fractions ]
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum NumericFractionValues {
+	#[atom(CssAtomSet::DiagonalFractions)]
+	DiagonalFractions(T![Ident]),
+	#[atom(CssAtomSet::StackedFractions)]
+	StackedFractions(T![Ident]),
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#typedef-font-variant-east-asian-east-asian-variant-values>
+///
+/// ```text,ignore
+/// <east-asian-variant-values> = [ jis78 | jis83 | jis90 | jis04 | simplified | traditional ]
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum EastAsianVariantValues {
+	#[atom(CssAtomSet::Jis78)]
+	Jis78(T![Ident]),
+	#[atom(CssAtomSet::Jis83)]
+	Jis83(T![Ident]),
+	#[atom(CssAtomSet::Jis90)]
+	Jis90(T![Ident]),
+	#[atom(CssAtomSet::Jis04)]
+	Jis04(T![Ident]),
+	#[atom(CssAtomSet::Simplified)]
+	Simplified(T![Ident]),
+	#[atom(CssAtomSet::Traditional)]
+	Traditional(T![Ident]),
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#typedef-font-variant-east-asian-east-asian-width-values>
+///
+/// ```text,ignore
+/// <east-asian-width-values> = [ full-width | proportional-width ]
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum EastAsianWidthValues {
+	#[atom(CssAtomSet::FullWidth)]
+	FullWidth(T![Ident]),
+	#[atom(CssAtomSet::ProportionalWidth)]
+	ProportionalWidth(T![Ident]),
+}
+
+/// Small capital glyphs within [FontVariantValues].
+///
+/// ```text,ignore
+/// small-caps | all-small-caps | petite-caps | all-petite-caps | unicase | titling-caps
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum FontVariantCapsValues {
+	#[atom(CssAtomSet::SmallCaps)]
+	SmallCaps(T![Ident]),
+	#[atom(CssAtomSet::AllSmallCaps)]
+	AllSmallCaps(T![Ident]),
+	#[atom(CssAtomSet::PetiteCaps)]
+	PetiteCaps(T![Ident]),
+	#[atom(CssAtomSet::AllPetiteCaps)]
+	AllPetiteCaps(T![Ident]),
+	#[atom(CssAtomSet::Unicase)]
+	Unicase(T![Ident]),
+	#[atom(CssAtomSet::TitlingCaps)]
+	TitlingCaps(T![Ident]),
+}
+
+/// Superscript or subscript glyphs within [FontVariantValues].
+///
+/// ```text,ignore
+/// sub | super
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum FontVariantPositionValues {
+	#[atom(CssAtomSet::Sub)]
+	Sub(T![Ident]),
+	#[atom(CssAtomSet::Super)]
+	Super(T![Ident]),
+}
+
+/// Emoji or text presentation within [FontVariantValues].
+///
+/// ```text,ignore
+/// text | emoji | unicode
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum FontVariantEmojiValues {
+	#[atom(CssAtomSet::Text)]
+	Text(T![Ident]),
+	#[atom(CssAtomSet::Emoji)]
+	Emoji(T![Ident]),
+	#[atom(CssAtomSet::Unicode)]
+	Unicode(T![Ident]),
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#font-variant-alternates-prop>
+///
+/// The value of `font-variant-alternates` other than `normal`.
+///
+/// ```text,ignore
+/// stylistic(<feature-value-name>) || historical-forms || styleset(<feature-value-name>#) ||
+/// character-variant(<feature-value-name>#) || swash(<feature-value-name>) || ornaments(<feature-value-name>) ||
+/// annotation(<feature-value-name>)
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+#[parse(one_must_occur)]
+pub struct FontVariantAlternatesValues<'a> {
+	pub stylistic: Option<StylisticFunction>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	#[atom(CssAtomSet::HistoricalForms)]
+	pub historical_forms: Option<T![Ident]>,
+	pub styleset: Option<StylesetFunction<'a>>,
+	pub character_variant: Option<CharacterVariantFunction<'a>>,
+	pub swash: Option<SwashFunction>,
+	pub ornaments: Option<OrnamentsFunction>,
+	pub annotation: Option<AnnotationFunction>,
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#font-variant-prop>
+///
+/// The value of the `font-variant` shorthand other than `normal` or `none`.
+///
+/// ```text,ignore
+/// [ <common-lig-values> || <discretionary-lig-values> || <historical-lig-values> || <contextual-alt-values> ] ||
+/// [ small-caps | all-small-caps | petite-caps | all-petite-caps | unicase | titling-caps ] ||
+/// [ stylistic(<feature-value-name>) || historical-forms || styleset(<feature-value-name>#) ||
+///   character-variant(<feature-value-name>#) || swash(<feature-value-name>) || ornaments(<feature-value-name>) ||
+///   annotation(<feature-value-name>) ] ||
+/// [ <numeric-figure-values> || <numeric-spacing-values> || <numeric-fraction-values> || ordinal || slashed-zero ] ||
+/// [ <east-asian-variant-values> || <east-asian-width-values> || ruby ] ||
+/// [ sub | super ] || [ text | emoji | unicode ]
+/// ```
+///
+/// Each value sets one of the `font-variant-*` longhands, and values for different longhands may be interleaved.
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[derive(csskit_derives::NodeWithMetadata)]
+#[parse(one_must_occur)]
+pub struct FontVariantValues<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub common_lig: Option<CommonLigValues>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub discretionary_lig: Option<DiscretionaryLigValues>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub historical_lig: Option<HistoricalLigValues>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub contextual_alt: Option<ContextualAltValues>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub caps: Option<FontVariantCapsValues>,
+	pub stylistic: Option<StylisticFunction>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	#[atom(CssAtomSet::HistoricalForms)]
+	pub historical_forms: Option<T![Ident]>,
+	pub styleset: Option<StylesetFunction<'a>>,
+	pub character_variant: Option<CharacterVariantFunction<'a>>,
+	pub swash: Option<SwashFunction>,
+	pub ornaments: Option<OrnamentsFunction>,
+	pub annotation: Option<AnnotationFunction>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub numeric_figure: Option<NumericFigureValues>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub numeric_spacing: Option<NumericSpacingValues>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub numeric_fraction: Option<NumericFractionValues>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	#[atom(CssAtomSet::Ordinal)]
+	pub ordinal: Option<T![Ident]>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	#[atom(CssAtomSet::SlashedZero)]
+	pub slashed_zero: Option<T![Ident]>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub east_asian_variant: Option<EastAsianVariantValues>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub east_asian_width: Option<EastAsianWidthValues>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	#[atom(CssAtomSet::Ruby)]
+	pub ruby: Option<T![Ident]>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub position: Option<FontVariantPositionValues>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub emoji: Option<FontVariantEmojiValues>,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<CommonLigValues>(), 16);
+		assert_eq!(std::mem::size_of::<NumericFigureValues>(), 16);
+		assert_eq!(std::mem::size_of::<EastAsianVariantValues>(), 16);
+		assert_eq!(std::mem::size_of::<FontVariantAlternatesValues>(), 288);
+		assert_eq!(std::mem::size_of::<FontVariantValues>(), 528);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, CommonLigValues, "no-common-ligatures");
+		assert_parse!(CssAtomSet::ATOMS, DiscretionaryLigValues, "discretionary-ligatures");
+		assert_parse!(CssAtomSet::ATOMS, HistoricalLigValues, "no-historical-ligatures");
+		assert_parse!(CssAtomSet::ATOMS, ContextualAltValues, "contextual");
+		assert_parse!(CssAtomSet::ATOMS, NumericFigureValues, "oldstyle-nums");
+		assert_parse!(CssAtomSet::ATOMS, NumericSpacingValues, "tabular-nums");
+		assert_parse!(CssAtomSet::ATOMS, NumericFractionValues, "diagonal-fractions");
+		assert_parse!(CssAtomSet::ATOMS, EastAsianVariantValues, "jis04");
+		assert_parse!(CssAtomSet::ATOMS, EastAsianWidthValues, "full-width");
+		assert_parse!(CssAtomSet::ATOMS, FontVariantAlternatesValues, "historical-forms");
+		assert_parse!(CssAtomSet::ATOMS, FontVariantAlternatesValues, "swash(fancy) stylistic(alt) styleset(a,b)");
+		assert_parse!(CssAtomSet::ATOMS, FontVariantValues, "small-caps");
+		assert_parse!(CssAtomSet::ATOMS, FontVariantValues, "common-ligatures tabular-nums");
+		assert_parse!(CssAtomSet::ATOMS, FontVariantValues, "no-contextual all-petite-caps slashed-zero ordinal");
+		assert_parse!(CssAtomSet::ATOMS, FontVariantValues, "jis78 ruby full-width sub emoji");
+		assert_parse!(CssAtomSet::ATOMS, FontVariantValues, "swash(flowing) historical-forms oldstyle-nums");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, CommonLigValues, "common");
+		assert_parse_error!(CssAtomSet::ATOMS, NumericFigureValues, "tabular-nums");
+		assert_parse_error!(CssAtomSet::ATOMS, EastAsianVariantValues, "jis");
+		assert_parse_error!(CssAtomSet::ATOMS, FontVariantAlternatesValues, "swash(a) swash(b)");
+		assert_parse_error!(CssAtomSet::ATOMS, FontVariantValues, "small-caps all-small-caps");
+		assert_parse_error!(CssAtomSet::ATOMS, FontVariantValues, "sub super");
+		assert_parse_error!(CssAtomSet::ATOMS, FontVariantValues, "lining-nums oldstyle-nums");
+		assert_parse_error!(CssAtomSet::ATOMS, FontVariantValues, "normal");
+	}
+}
@@ -0,0 +1,32 @@
+use super::prelude::*;
+
+/// <https://drafts.csswg.org/css-fonts-4/#font-width-css3-values>
+///
+/// ```text,ignore
+/// <font-width-css3> = [ normal | ultra-condensed | extra-condensed | condensed | semi-condensed | semi-expanded |
+///                       expanded | extra-expanded | ultra-expanded ]
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum FontWidthCss3 {
+	#[atom(CssAtomSet::Normal)]
+	Normal(T![Ident]),
+	#[atom(CssAtomSet::UltraCondensed)]
+	UltraCondensed(T![Ident]),
+	#[atom(CssAtomSet::ExtraCondensed)]
+	ExtraCondensed(T![Ident]),
+	#[atom(CssAtomSet::Condensed)]
+	Condensed(T![Ident]),
+	#[atom(CssAtomSet::SemiCondensed)]
+	SemiCondensed(T![Ident]),
+	#[atom(CssAtomSet::SemiExpanded)]
+	SemiExpanded(T![Ident]),
+	#[atom(CssAtomSet::Expanded)]
+	Expanded(T![Ident]),
+	#[atom(CssAtomSet::ExtraExpanded)]
+	ExtraExpanded(T![Ident]),
+	#[atom(CssAtomSet::UltraExpanded)]
+	UltraExpanded(T![Ident]),
+}
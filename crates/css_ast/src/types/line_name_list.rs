@@ -0,0 +1,51 @@
+use super::prelude::*;
+use crate::{TrackListItem, parse_track_list_items};
+
+/// <https://drafts.csswg.org/css-grid-2/#typedef-line-name-list>
+///
+/// ```text,ignore
+/// <line-name-list> = [ <line-names> | <name-repeat> ]+
+/// ```
+#[derive(Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct LineNameList<'a>(pub Vec<'a, TrackListItem<'a>>);
+
+impl<'a> Parse<'a> for LineNameList<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let items = parse_track_list_items(p, |_, item| match item {
+			TrackListItem::LineNames(_) => true,
+			TrackListItem::TrackSize(_) => false,
+			TrackListItem::TrackRepeatFunction(repeat) => repeat.is_name_repeat(),
+		})?;
+		Ok(Self(items))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<LineNameList>(), 32);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, LineNameList, "[a]");
+		assert_parse!(CssAtomSet::ATOMS, LineNameList, "[a] [b c] repeat(2,[d]) repeat(auto-fill,[e][f])");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, LineNameList, "[a] 1fr");
+		assert_parse_error!(CssAtomSet::ATOMS, LineNameList, "repeat(2,10px)");
+	}
+}
@@ -0,0 +1,67 @@
+use super::prelude::*;
+use crate::CustomIdent;
+
+/// <https://drafts.csswg.org/css-grid-2/#typedef-line-names>
+///
+/// ```text,ignore
+/// <line-names> = '[' <custom-ident>* ']'
+/// ```
+///
+/// The `<custom-ident>` additionally excludes the keywords `span` and `auto`.
+#[derive(Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct LineNames<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub open: T!['['],
+	pub names: Vec<'a, CustomIdent>,
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	pub close: T![']'],
+}
+
+impl<'a> Parse<'a> for LineNames<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let open = p.parse::<T!['[']>()?;
+		let mut names = Vec::new_in(p.bump());
+		while <T![Ident]>::peek(p, p.peek_n(1)) {
+			let c = p.peek_n(1);
+			if matches!(p.to_atom::<CssAtomSet>(c), CssAtomSet::Span | CssAtomSet::Auto) {
+				Err(Diagnostic::new(c, Diagnostic::unexpected_ident))?
+			}
+			names.push(p.parse::<CustomIdent>()?);
+		}
+		let close = p.parse::<T![']']>()?;
+		Ok(Self { open, names, close })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<LineNames>(), 56);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, LineNames, "[]");
+		assert_parse!(CssAtomSet::ATOMS, LineNames, "[main-start]");
+		assert_parse!(CssAtomSet::ATOMS, LineNames, "[main-start sidebar-end]");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, LineNames, "[span]");
+		assert_parse_error!(CssAtomSet::ATOMS, LineNames, "[foo auto]");
+		assert_parse_error!(CssAtomSet::ATOMS, LineNames, "[1]");
+		assert_parse_error!(CssAtomSet::ATOMS, LineNames, "[foo");
+	}
+}
@@ -7,15 +7,19 @@ mod auto_line_color_list;
 mod auto_line_style_list;
 mod auto_line_width_list;
 mod auto_or;
+mod auto_track_list;
 mod autonone_or;
 mod autospace;
 mod baseline_position;
 mod basic_shape_rect;
 mod bg_clip;
+mod bg_layer;
+mod bg_position;
 mod bg_size;
 mod blend_mode;
 mod border_radius;
 mod color;
+mod color_interpolation_method;
 mod compat_auto;
 mod compat_special;
 mod content_distribution;
@@ -35,9 +39,15 @@ mod display_legacy;
 mod display_listitem;
 mod display_outside;
 mod event_trigger_event;
+mod explicit_track_list;
 mod family_name;
 mod feature_tag_value;
+mod filter_value_list;
+mod font_size_adjust_value;
+mod font_variant_css2;
+mod font_variant_values;
 mod font_weight_absolute;
+mod font_width_css3;
 mod gap_auto_rule_list;
 mod gap_rule_list;
 mod generic_family;
@@ -47,6 +57,8 @@ mod image_1d;
 mod isolation_mode;
 mod layout_box;
 mod line_color_list;
+mod line_name_list;
+mod line_names;
 mod line_style;
 mod line_style_list;
 mod line_width_list;
@@ -68,6 +80,7 @@ mod relative_size;
 mod repeat_style;
 mod self_position;
 mod shadow;
+mod single_animation;
 mod single_animation_composition;
 mod single_animation_direction;
 mod single_animation_fill_mode;
@@ -77,18 +90,23 @@ mod single_animation_timeline;
 mod single_animation_trigger;
 mod single_animation_trigger_behavior;
 mod single_animation_trigger_type;
+mod single_timeline_trigger;
 mod single_transition;
 mod single_transition_property;
 mod spacing_trim;
 mod spread_shadow;
 mod string_or_url;
 mod syntax;
+mod system_family_name;
 mod text_edge;
+mod timeline_range;
 mod timeline_range_name;
+mod track_list;
 mod track_size;
 mod transform_list;
 mod transition_behavior_value;
 mod try_size;
+mod try_tactic;
 mod variation_tag_value;
 mod visual_box;
 
@@ -101,15 +119,19 @@ pub use auto_line_color_list::*;
 pub use auto_line_style_list::*;
 pub use auto_line_width_list::*;
 pub use auto_or::*;
+pub use auto_track_list::*;
 pub use autonone_or::*;
 pub use autospace::*;
 pub use baseline_position::*;
 pub use basic_shape_rect::*;
 pub use bg_clip::*;
+pub use bg_layer::*;
+pub use bg_position::*;
 pub use bg_size::*;
 pub use blend_mode::*;
 pub use border_radius::*;
 pub use color::*;
+pub use color_interpolation_method::*;
 pub use compat_auto::*;
 pub use compat_special::*;
 pub use content_distribution::*;
@@ -129,9 +151,15 @@ pub use display_legacy::*;
 pub use display_listitem::*;
 pub use display_outside::*;
 pub use event_trigger_event::*;
+pub use explicit_track_list::*;
 pub use family_name::*;
 pub use feature_tag_value::*;
+pub use filter_value_list::*;
+pub use font_size_adjust_value::*;
+pub use font_variant_css2::*;
+pub use font_variant_values::*;
 pub use font_weight_absolute::*;
+pub use font_width_css3::*;
 pub use gap_auto_rule_list::*;
 pub use gap_rule_list::*;
 pub use generic_family::*;
@@ -141,6 +169,8 @@ pub use image_1d::*;
 pub use isolation_mode::*;
 pub use layout_box::*;
 pub use line_color_list::*;
+pub use line_name_list::*;
+pub use line_names::*;
 pub use line_style::*;
 pub use line_style_list::*;
 pub use line_width_list::*;
@@ -162,6 +192,7 @@ pub use relative_size::*;
 pub use repeat_style::*;
 pub use self_position::*;
 pub use shadow::*;
+pub use single_animation::*;
 pub use single_animation_composition::*;
 pub use single_animation_direction::*;
 pub use single_animation_fill_mode::*;
@@ -171,18 +202,23 @@ pub use single_animation_timeline::*;
 pub use single_animation_trigger::*;
 pub use single_animation_trigger_behavior::*;
 pub use single_animation_trigger_type::*;
+pub use single_timeline_trigger::*;
 pub use single_transition::*;
 pub use single_transition_property::*;
 pub use spacing_trim::*;
 pub use spread_shadow::*;
 pub use string_or_url::*;
 pub use syntax::*;
+pub use system_family_name::*;
 pub use text_edge::*;
+pub use timeline_range::*;
 pub use timeline_range_name::*;
+pub use track_list::*;
 pub use track_size::*;
 pub use transform_list::*;
 pub use transition_behavior_value::*;
 pub use try_size::*;
+pub use try_tactic::*;
 pub use variation_tag_value::*;
 pub use visual_box::*;
 
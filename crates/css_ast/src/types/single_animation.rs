@@ -0,0 +1,108 @@
+use super::prelude::*;
+use crate::{
+	AutoOr, EasingFunction, KeyframesName, NonNegative, NoneOr, SingleAnimationDirection, SingleAnimationFillMode,
+	SingleAnimationIterationCount, SingleAnimationPlayState, Time,
+};
+use css_parse::parse_optionals;
+
+/// <https://drafts.csswg.org/css-animations-2/#typedef-single-animation>
+///
+/// ```text,ignore
+/// <single-animation> = <'animation-duration'> || <easing-function> || <'animation-delay'> ||
+///                      <single-animation-iteration-count> || <single-animation-direction> ||
+///                      <single-animation-fill-mode> || <single-animation-play-state> || [ none | <keyframes-name> ]
+/// ```
+///
+/// The first `<time>` is the duration and the second is the delay. A keyword which is valid for one of the other
+/// longhands is taken by that longhand, unless it has already been set, so the keyframes name is only ever the ident
+/// left over: `ease ease` is an animation named `ease` with an `ease` timing function.
+#[derive(ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct SingleAnimation<'a> {
+	pub duration: Option<AutoOr<NonNegative<Time>>>,
+	pub easing: Option<EasingFunction<'a>>,
+	pub delay: Option<Time>,
+	pub iteration_count: Option<SingleAnimationIterationCount>,
+	pub direction: Option<SingleAnimationDirection>,
+	pub fill_mode: Option<SingleAnimationFillMode>,
+	pub play_state: Option<SingleAnimationPlayState>,
+	pub name: Option<NoneOr<KeyframesName>>,
+}
+
+impl<'a> Peek<'a> for SingleAnimation<'a> {
+	fn peek<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		<AutoOr<NonNegative<Time>>>::peek(p, c)
+			|| EasingFunction::peek(p, c)
+			|| SingleAnimationIterationCount::peek(p, c)
+			|| <NoneOr<KeyframesName>>::peek(p, c)
+	}
+}
+
+impl<'a> Parse<'a> for SingleAnimation<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		// The keyframes name must be tried last, as it would otherwise accept any of the other longhands' keywords.
+		let (duration, easing, delay, iteration_count, direction, fill_mode, play_state, name) = parse_optionals!(p, duration: AutoOr<NonNegative<Time>>, easing: EasingFunction, delay: Time, iteration_count: SingleAnimationIterationCount, direction: SingleAnimationDirection, fill_mode: SingleAnimationFillMode, play_state: SingleAnimationPlayState, name: NoneOr<KeyframesName>);
+		Ok(Self { duration, easing, delay, iteration_count, direction, fill_mode, play_state, name })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<SingleAnimation>(), 232);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimation, "none");
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimation, "spin");
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimation, "spin 1s");
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimation, "1s spin");
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimation, "spin 1s ease-in 2s infinite alternate both paused");
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimation, "paused both alternate infinite 2s ease-in 1s spin");
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimation, "auto linear \"fade out\"");
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimation, "3s steps(4,end) reverse");
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimation, "1s -500ms forwards slide");
+	}
+
+	#[test]
+	fn test_keywords_before_names() {
+		let bump = bumpalo::Bump::default();
+		let source_text = "ease ease infinite";
+		let lexer = css_lexer::Lexer::new(&CssAtomSet::ATOMS, source_text);
+		let mut p = Parser::new(&bump, source_text, lexer);
+		let animation = p.parse_entirely::<SingleAnimation>().output.unwrap();
+		assert!(animation.easing.is_some());
+		assert!(animation.iteration_count.is_some());
+		assert!(matches!(animation.name, Some(NoneOr::Some(KeyframesName::Ident(_)))));
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, SingleAnimation, "-1s spin");
+		assert_parse_error!(CssAtomSet::ATOMS, SingleAnimation, "1s 2s 3s");
+		assert_parse_error!(CssAtomSet::ATOMS, SingleAnimation, "spin slide");
+		assert_parse_error!(CssAtomSet::ATOMS, SingleAnimation, "none initial");
+		assert_parse_error!(CssAtomSet::ATOMS, SingleAnimation, "1deg");
+	}
+
+	#[test]
+	#[cfg(feature = "visitable")]
+	fn test_visits() {
+		use crate::assert_visits;
+		assert_visits!("spin 1s", SingleAnimation, Time, KeyframesName);
+	}
+}
@@ -1,17 +1,50 @@
 use super::prelude::*;
+use crate::{DashedIdent, ScrollFunction, ViewFunction};
 
 /// <https://drafts.csswg.org/css-animations-2/#typedef-single-animation-timeline>
 ///
 /// ```text,ignore
 /// <single-animation-timeline> = auto | none | <dashed-ident> | <scroll()> | <view()>
 /// ```
-#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(self))]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
 #[derive(csskit_derives::NodeWithMetadata)]
 pub enum SingleAnimationTimeline {
+	#[cfg_attr(feature = "visitable", visit(skip))]
 	#[atom(CssAtomSet::Auto)]
 	Auto(T![Ident]),
+	#[cfg_attr(feature = "visitable", visit(skip))]
 	#[atom(CssAtomSet::None)]
 	None(T![Ident]),
+	Timeline(DashedIdent),
+	Scroll(ScrollFunction),
+	View(ViewFunction),
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<SingleAnimationTimeline>(), 72);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimationTimeline, "auto", SingleAnimationTimeline::Auto(_));
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimationTimeline, "none", SingleAnimationTimeline::None(_));
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimationTimeline, "--tl", SingleAnimationTimeline::Timeline(_));
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimationTimeline, "scroll()", SingleAnimationTimeline::Scroll(_));
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimationTimeline, "view(x)", SingleAnimationTimeline::View(_));
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, SingleAnimationTimeline, "tl");
+		assert_parse_error!(CssAtomSet::ATOMS, SingleAnimationTimeline, "scroll(10px)");
+	}
 }
@@ -1,14 +1,139 @@
-#![allow(unused)]
 use super::prelude::*;
+use crate::{DashedIdent, ScrollFunction, SingleAnimationTriggerBehavior, TimelineRangeBoundary, ViewFunction};
+use css_parse::parse_optionals;
 
-use crate::Todo;
+/// <https://drafts.csswg.org/css-animations-2/#typedef-single-animation-trigger>
+///
+/// ```text,ignore
+/// <single-animation-trigger> = <single-animation-trigger-behavior> || [ none | auto | [ [ <dashed-ident> | <scroll()> | <view()> ] [ normal | <length-percentage> | <timeline-range-name> <length-percentage>? ]{0,4} ] ]
+/// ```
+#[derive(ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct SingleAnimationTrigger<'a> {
+	pub behavior: Option<SingleAnimationTriggerBehavior>,
+	pub timeline: Option<AnimationTriggerTimeline<'a>>,
+}
+
+impl<'a> Peek<'a> for SingleAnimationTrigger<'a> {
+	fn peek<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		SingleAnimationTriggerBehavior::peek(p, c) || AnimationTriggerTimeline::peek(p, c)
+	}
+}
+
+impl<'a> Parse<'a> for SingleAnimationTrigger<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let (behavior, timeline) =
+			parse_optionals!(p, behavior: SingleAnimationTriggerBehavior, timeline: AnimationTriggerTimeline);
+		Ok(Self { behavior, timeline })
+	}
+}
+
+/// The timeline of a [SingleAnimationTrigger], along with up to four boundaries of its trigger and exit ranges.
+///
+/// ```text,ignore
+/// none | auto | [ [ <dashed-ident> | <scroll()> | <view()> ] [ normal | <length-percentage> | <timeline-range-name> <length-percentage>? ]{0,4} ]
+/// ```
+#[derive(ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum AnimationTriggerTimeline<'a> {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	None(T![Ident]),
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	Auto(T![Ident]),
+	Timeline(DashedIdent, Vec<'a, TimelineRangeBoundary>),
+	Scroll(ScrollFunction, Vec<'a, TimelineRangeBoundary>),
+	View(ViewFunction, Vec<'a, TimelineRangeBoundary>),
+}
 
-// https://drafts.csswg.org/css-animations-2/#typedef-single-animation-trigger
-// <single-animation-trigger> = <single-animation-trigger-behavior> || [ none | auto | [ [ <dashed-ident> | <scroll()> | <view()> ] [ normal | <length-percentage> | <timeline-range-name> <length-percentage>? ]{0,4} ] ]
-pub type SingleAnimationTrigger = Todo;
+impl<'a> Peek<'a> for AnimationTriggerTimeline<'a> {
+	fn peek<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		DashedIdent::peek(p, c)
+			|| ScrollFunction::peek(p, c)
+			|| ViewFunction::peek(p, c)
+			|| (c == Kind::Ident && (p.equals_atom(c, &CssAtomSet::None) || p.equals_atom(c, &CssAtomSet::Auto)))
+	}
+}
+
+impl<'a> Parse<'a> for AnimationTriggerTimeline<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		if let Some(scroll) = p.parse_if_peek::<ScrollFunction>()? {
+			return Ok(Self::Scroll(scroll, Self::parse_boundaries(p)?));
+		}
+		if let Some(view) = p.parse_if_peek::<ViewFunction>()? {
+			return Ok(Self::View(view, Self::parse_boundaries(p)?));
+		}
+		if !p.peek::<DashedIdent>() {
+			let ident = p.parse::<T![Ident]>()?;
+			return match p.to_atom::<CssAtomSet>(ident.into()) {
+				CssAtomSet::None => Ok(Self::None(ident)),
+				CssAtomSet::Auto => Ok(Self::Auto(ident)),
+				_ => Err(Diagnostic::new(ident.into(), Diagnostic::unexpected))?,
+			};
+		}
+		let timeline = p.parse::<DashedIdent>()?;
+		Ok(Self::Timeline(timeline, Self::parse_boundaries(p)?))
+	}
+}
+
+impl<'a> AnimationTriggerTimeline<'a> {
+	fn parse_boundaries<I>(p: &mut Parser<'a, I>) -> ParserResult<Vec<'a, TimelineRangeBoundary>>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let mut boundaries = Vec::new_in(p.bump());
+		// A trigger behavior keyword may follow the timeline, and must not be mistaken for a timeline range name.
+		while boundaries.len() < 4 && p.peek::<TimelineRangeBoundary>() && !p.peek::<SingleAnimationTriggerBehavior>() {
+			boundaries.push(p.parse::<TimelineRangeBoundary>()?);
+		}
+		Ok(boundaries)
+	}
+}
 
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<SingleAnimationTrigger>(), 120);
+		assert_eq!(std::mem::size_of::<AnimationTriggerTimeline>(), 104);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimationTrigger, "once");
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimationTrigger, "none");
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimationTrigger, "repeat auto");
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimationTrigger, "--view");
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimationTrigger, "alternate --view entry 10% exit");
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimationTrigger, "--view contain 0% normal cover 50% 100px state");
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimationTrigger, "view() entry 0%");
+		assert_parse!(CssAtomSet::ATOMS, SingleAnimationTrigger, "once scroll(nearest) 10%");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, SingleAnimationTrigger, "once once");
+		assert_parse_error!(CssAtomSet::ATOMS, SingleAnimationTrigger, "none 10%");
+		assert_parse_error!(CssAtomSet::ATOMS, SingleAnimationTrigger, "--view 1% 2% 3% 4% 5%");
+		assert_parse_error!(CssAtomSet::ATOMS, SingleAnimationTrigger, "10%");
+	}
 }
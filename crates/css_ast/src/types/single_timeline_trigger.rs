@@ -0,0 +1,50 @@
+use super::prelude::*;
+use crate::{DashedIdent, SingleAnimationTimeline, TimelineExitRange, TimelineRange};
+
+/// <https://drafts.csswg.org/css-animations-2/#timeline-trigger>
+///
+/// A single trigger in the `timeline-trigger` shorthand: its name, the timeline it watches, the range which activates
+/// it, and optionally the range it must leave to deactivate.
+///
+/// ```text,ignore
+/// <'timeline-trigger-name'> <'timeline-trigger-source'> <'timeline-trigger-range'> [ '/' <'timeline-trigger-exit-range'> ]?
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct SingleTimelineTrigger {
+	pub name: DashedIdent,
+	pub source: SingleAnimationTimeline,
+	pub range: TimelineRange,
+	pub exit_range: Option<(T![/], TimelineExitRange)>,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<SingleTimelineTrigger>(), 208);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, SingleTimelineTrigger, "--t auto normal");
+		assert_parse!(CssAtomSet::ATOMS, SingleTimelineTrigger, "--t auto entry 10% exit 90%");
+		assert_parse!(CssAtomSet::ATOMS, SingleTimelineTrigger, "--t none contain/auto exit");
+		assert_parse!(CssAtomSet::ATOMS, SingleTimelineTrigger, "--t --tl normal");
+		assert_parse!(CssAtomSet::ATOMS, SingleTimelineTrigger, "--t view() entry 0%");
+		assert_parse!(CssAtomSet::ATOMS, SingleTimelineTrigger, "--t scroll(root block) 10% 90%/auto");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, SingleTimelineTrigger, "--t auto");
+		assert_parse_error!(CssAtomSet::ATOMS, SingleTimelineTrigger, "auto normal");
+		assert_parse_error!(CssAtomSet::ATOMS, SingleTimelineTrigger, "--t auto cover/");
+	}
+}
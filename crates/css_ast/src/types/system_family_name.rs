@@ -0,0 +1,25 @@
+use super::prelude::*;
+
+/// <https://drafts.csswg.org/css-fonts-4/#system-family-name-value>
+///
+/// ```text,ignore
+/// <system-family-name> = caption | icon | menu | message-box | small-caption | status-bar
+/// ```
+#[derive(Parse, Peek, IntoCursor, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit(skip))]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum SystemFamilyName {
+	#[atom(CssAtomSet::Caption)]
+	Caption(T![Ident]),
+	#[atom(CssAtomSet::Icon)]
+	Icon(T![Ident]),
+	#[atom(CssAtomSet::Menu)]
+	Menu(T![Ident]),
+	#[atom(CssAtomSet::MessageBox)]
+	MessageBox(T![Ident]),
+	#[atom(CssAtomSet::SmallCaption)]
+	SmallCaption(T![Ident]),
+	#[atom(CssAtomSet::StatusBar)]
+	StatusBar(T![Ident]),
+}
@@ -0,0 +1,137 @@
+use super::prelude::*;
+use crate::{AutoOr, LengthPercentage, TimelineRangeName};
+
+/// <https://drafts.csswg.org/scroll-animations-1/#animation-range-start>
+///
+/// One end of an animation's attachment range, as used by `animation-range-start`, `animation-range-end` and the
+/// `timeline-trigger-range` longhands.
+///
+/// ```text,ignore
+/// normal | <length-percentage> | <timeline-range-name> <length-percentage>?
+/// ```
+#[derive(ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum TimelineRangeBoundary {
+	#[cfg_attr(feature = "visitable", visit(skip))]
+	Normal(T![Ident]),
+	Offset(LengthPercentage),
+	Named(TimelineRangeName, Option<LengthPercentage>),
+}
+
+impl<'a> Peek<'a> for TimelineRangeBoundary {
+	fn peek<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		LengthPercentage::peek(p, c) || TimelineRangeName::peek(p, c)
+	}
+}
+
+impl<'a> Parse<'a> for TimelineRangeBoundary {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		if p.peek::<LengthPercentage>() {
+			return Ok(Self::Offset(p.parse::<LengthPercentage>()?));
+		}
+		let c = p.peek_n(1);
+		if c == Kind::Ident && p.equals_atom(c, &CssAtomSet::Normal) {
+			return Ok(Self::Normal(p.parse::<T![Ident]>()?));
+		}
+		let name = p.parse::<TimelineRangeName>()?;
+		let offset = p.parse_if_peek::<LengthPercentage>()?;
+		Ok(Self::Named(name, offset))
+	}
+}
+
+/// <https://drafts.csswg.org/scroll-animations-1/#animation-range>
+///
+/// An animation's attachment range, as used by the `animation-range` and `timeline-trigger-range` shorthands.
+///
+/// ```text,ignore
+/// <'animation-range-start'> <'animation-range-end'>?
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct TimelineRange {
+	pub start: TimelineRangeBoundary,
+	pub end: Option<TimelineRangeBoundary>,
+}
+
+/// <https://drafts.csswg.org/css-animations-2/#timeline-trigger-exit-range>
+///
+/// The range a triggered animation must leave before it is reversed or reset, as used by the
+/// `timeline-trigger-exit-range` shorthand. Either end may be `auto`, to match the trigger range.
+///
+/// ```text,ignore
+/// <'timeline-trigger-exit-range-start'> <'timeline-trigger-exit-range-end'>?
+/// ```
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct TimelineExitRange {
+	pub start: AutoOr<TimelineRangeBoundary>,
+	pub end: Option<AutoOr<TimelineRangeBoundary>>,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<TimelineRangeBoundary>(), 28);
+		assert_eq!(std::mem::size_of::<TimelineRange>(), 56);
+		assert_eq!(std::mem::size_of::<TimelineExitRange>(), 56);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, TimelineRangeBoundary, "normal", TimelineRangeBoundary::Normal(_));
+		assert_parse!(CssAtomSet::ATOMS, TimelineRangeBoundary, "10%", TimelineRangeBoundary::Offset(_));
+		assert_parse!(CssAtomSet::ATOMS, TimelineRangeBoundary, "100px", TimelineRangeBoundary::Offset(_));
+		assert_parse!(CssAtomSet::ATOMS, TimelineRangeBoundary, "cover", TimelineRangeBoundary::Named(_, None));
+		assert_parse!(CssAtomSet::ATOMS, TimelineRangeBoundary, "entry 10%", TimelineRangeBoundary::Named(_, Some(_)));
+		assert_parse!(CssAtomSet::ATOMS, TimelineRange, "normal");
+		assert_parse!(CssAtomSet::ATOMS, TimelineRange, "entry 10% exit 90%");
+		assert_parse!(CssAtomSet::ATOMS, TimelineRange, "entry exit");
+		assert_parse!(CssAtomSet::ATOMS, TimelineRange, "contain 0% 50%");
+		assert_parse!(CssAtomSet::ATOMS, TimelineRange, "10% normal");
+		assert_parse!(CssAtomSet::ATOMS, TimelineExitRange, "auto");
+		assert_parse!(CssAtomSet::ATOMS, TimelineExitRange, "auto exit 100%");
+		assert_parse!(CssAtomSet::ATOMS, TimelineExitRange, "cover 0% auto");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, TimelineRangeBoundary, "10deg");
+		assert_parse_error!(CssAtomSet::ATOMS, TimelineRangeBoundary, "normal 10%");
+		assert_parse_error!(CssAtomSet::ATOMS, TimelineRange, "entry 10% exit 90% cover");
+		assert_parse_error!(CssAtomSet::ATOMS, TimelineExitRange, "auto auto auto");
+	}
+
+	#[test]
+	#[cfg(feature = "visitable")]
+	fn test_visits() {
+		use crate::assert_visits;
+		assert_visits!(
+			"entry 10% exit 20px",
+			TimelineRange,
+			TimelineRangeBoundary,
+			TimelineRangeName,
+			LengthPercentage,
+			TimelineRangeBoundary,
+			TimelineRangeName,
+			LengthPercentage,
+			Length
+		);
+	}
+}
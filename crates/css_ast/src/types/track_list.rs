@@ -0,0 +1,130 @@
+use super::prelude::*;
+use crate::{LineNames, TrackRepeatFunction, TrackSize};
+
+/// <https://drafts.csswg.org/css-grid-2/#typedef-track-list>
+///
+/// ```text,ignore
+/// <track-list> = [ <line-names>? [ <track-size> | <track-repeat> ] ]+ <line-names>?
+/// ```
+#[derive(Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct TrackList<'a>(pub Vec<'a, TrackListItem<'a>>);
+
+impl<'a> Parse<'a> for TrackList<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let c = p.peek_n(1);
+		let items = parse_track_list_items(p, |prev, item| match item {
+			TrackListItem::LineNames(_) => !matches!(prev, Some(TrackListItem::LineNames(_))),
+			TrackListItem::TrackSize(_) => true,
+			TrackListItem::TrackRepeatFunction(repeat) => !repeat.is_auto() && !repeat.is_name_repeat(),
+		})?;
+		if items.iter().all(|item| matches!(item, TrackListItem::LineNames(_))) {
+			Err(Diagnostic::new(c, Diagnostic::unexpected))?
+		}
+		Ok(Self(items))
+	}
+}
+
+/// A single item within a grid track list.
+///
+/// ```text,ignore
+/// <line-names> | <track-size> | <track-repeat>
+/// ```
+#[derive(ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum TrackListItem<'a> {
+	LineNames(LineNames<'a>),
+	TrackSize(TrackSize),
+	TrackRepeatFunction(TrackRepeatFunction<'a>),
+}
+
+impl<'a> Peek<'a> for TrackListItem<'a> {
+	fn peek<I>(p: &Parser<'a, I>, c: Cursor) -> bool
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		<LineNames>::peek(p, c) || <TrackRepeatFunction>::peek(p, c) || <TrackSize>::peek(p, c)
+	}
+}
+
+impl<'a> Parse<'a> for TrackListItem<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParserResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		if let Some(names) = p.parse_if_peek::<LineNames>()? {
+			return Ok(Self::LineNames(names));
+		}
+		if let Some(repeat) = p.parse_if_peek::<TrackRepeatFunction>()? {
+			return Ok(Self::TrackRepeatFunction(repeat));
+		}
+		p.parse::<TrackSize>().map(Self::TrackSize)
+	}
+}
+
+/// Parses a run of [TrackListItem]s, rejecting any item for which `valid` (given the preceding item) returns false.
+pub(crate) fn parse_track_list_items<'a, I, F>(
+	p: &mut Parser<'a, I>,
+	mut valid: F,
+) -> ParserResult<Vec<'a, TrackListItem<'a>>>
+where
+	I: Iterator<Item = Cursor> + Clone,
+	F: FnMut(Option<&TrackListItem<'a>>, &TrackListItem<'a>) -> bool,
+{
+	let mut items: Vec<'a, TrackListItem<'a>> = Vec::new_in(p.bump());
+	while p.peek::<TrackListItem>() {
+		let c = p.peek_n(1);
+		let item = p.parse::<TrackListItem>()?;
+		if !valid(items.last(), &item) {
+			Err(Diagnostic::new(c, Diagnostic::unexpected))?
+		}
+		items.push(item);
+	}
+	if items.is_empty() {
+		Err(Diagnostic::new(p.next(), Diagnostic::unexpected))?
+	}
+	Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<TrackList>(), 32);
+		assert_eq!(std::mem::size_of::<TrackListItem>(), 88);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, TrackList, "1fr");
+		assert_parse!(CssAtomSet::ATOMS, TrackList, "100px 1fr auto");
+		assert_parse!(CssAtomSet::ATOMS, TrackList, "[a] 100px [b c] 1fr [d]");
+		assert_parse!(CssAtomSet::ATOMS, TrackList, "repeat(3,1fr)");
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			TrackList,
+			"[full-start] minmax(1em,1fr) [main-start] repeat(2,[a] 10px) [main-end]"
+		);
+		assert_parse!(CssAtomSet::ATOMS, TrackList, "fit-content(40%) 1fr");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, TrackList, "[a]");
+		assert_parse_error!(CssAtomSet::ATOMS, TrackList, "[a] [b] 1fr");
+		assert_parse_error!(CssAtomSet::ATOMS, TrackList, "repeat(auto-fill,100px)");
+		assert_parse_error!(CssAtomSet::ATOMS, TrackList, "repeat(2,[a])");
+		assert_parse_error!(CssAtomSet::ATOMS, TrackList, "none");
+	}
+}
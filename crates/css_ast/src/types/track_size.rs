@@ -1,16 +1,122 @@
-#![allow(unused)]
 use super::prelude::*;
 
-use crate::Todo;
+/// <https://drafts.csswg.org/css-grid-2/#typedef-track-size>
+///
+/// ```text,ignore
+/// <track-size> = <track-breadth> | minmax( <inflexible-breadth> , <track-breadth> ) | fit-content( <length-percentage [0,∞]> )
+/// ```
+#[syntax(" <track-breadth> | <minmax()> | <fit-content()> ")]
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum TrackSize {}
 
-// https://drafts.csswg.org/css-grid-2/#typedef-track-size
-// <track-size> = <track-breadth> | minmax( <inflexible-breadth> , <track-breadth> ) | fit-content( <length-percentage [0,∞]> )
-// <track-breadth> = <length-percentage [0,∞]> | <flex [0,∞]> | min-content | max-content | auto
-// <inflexible-breadth>  = <length-percentage [0,∞]> | min-content | max-content | auto
-pub type TrackSize = Todo;
+impl TrackSize {
+	/// Whether this is a `<fixed-size>`, which has a definite minimum or maximum, as required within `auto-fill` or
+	/// `auto-fit` repeats.
+	///
+	/// ```text,ignore
+	/// <fixed-size> = <fixed-breadth> | minmax( <fixed-breadth> , <track-breadth> ) | minmax( <inflexible-breadth> , <fixed-breadth> )
+	/// ```
+	pub fn is_fixed(&self) -> bool {
+		match self {
+			Self::TrackBreadth(breadth) => breadth.is_fixed(),
+			Self::MinmaxFunction(minmax) => minmax.params.min.is_fixed() || minmax.params.max.is_fixed(),
+			Self::FitContentFunction(_) => false,
+		}
+	}
+}
+
+/// <https://drafts.csswg.org/css-grid-2/#typedef-track-breadth>
+///
+/// ```text,ignore
+/// <track-breadth> = <length-percentage [0,∞]> | <flex [0,∞]> | min-content | max-content | auto
+/// ```
+#[syntax(" <length-percentage [0,∞]> | <flex [0,∞]> | min-content | max-content | auto ")]
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum TrackBreadth {}
+
+impl TrackBreadth {
+	/// Whether this is a `<fixed-breadth>`, i.e. a `<length-percentage [0,∞]>`.
+	pub fn is_fixed(&self) -> bool {
+		matches!(self, Self::LengthPercentage(_))
+	}
+}
+
+/// <https://drafts.csswg.org/css-grid-2/#typedef-inflexible-breadth>
+///
+/// ```text,ignore
+/// <inflexible-breadth> = <length-percentage [0,∞]> | min-content | max-content | auto
+/// ```
+#[syntax(" <length-percentage [0,∞]> | min-content | max-content | auto ")]
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum InflexibleBreadth {}
+
+impl InflexibleBreadth {
+	/// Whether this is a `<fixed-breadth>`, i.e. a `<length-percentage [0,∞]>`.
+	pub fn is_fixed(&self) -> bool {
+		matches!(self, Self::LengthPercentage(_))
+	}
+}
 
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<TrackSize>(), 68);
+		assert_eq!(std::mem::size_of::<TrackBreadth>(), 16);
+		assert_eq!(std::mem::size_of::<InflexibleBreadth>(), 16);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, TrackSize, "auto", TrackSize::TrackBreadth(TrackBreadth::Auto(_)));
+		assert_parse!(CssAtomSet::ATOMS, TrackSize, "1fr", TrackSize::TrackBreadth(TrackBreadth::Flex(_)));
+		assert_parse!(CssAtomSet::ATOMS, TrackSize, "100px");
+		assert_parse!(CssAtomSet::ATOMS, TrackSize, "25%");
+		assert_parse!(CssAtomSet::ATOMS, TrackSize, "min-content");
+		assert_parse!(CssAtomSet::ATOMS, TrackSize, "max-content");
+		assert_parse!(CssAtomSet::ATOMS, TrackSize, "minmax(100px,1fr)", TrackSize::MinmaxFunction(_));
+		assert_parse!(CssAtomSet::ATOMS, TrackSize, "minmax(min-content,max-content)");
+		assert_parse!(CssAtomSet::ATOMS, TrackSize, "fit-content(40%)", TrackSize::FitContentFunction(_));
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, TrackSize, "-1px");
+		assert_parse_error!(CssAtomSet::ATOMS, TrackSize, "-1fr");
+		assert_parse_error!(CssAtomSet::ATOMS, TrackSize, "minmax(1fr,100px)");
+		assert_parse_error!(CssAtomSet::ATOMS, TrackSize, "minmax(100px)");
+		assert_parse_error!(CssAtomSet::ATOMS, TrackSize, "none");
+	}
+
+	#[test]
+	fn test_is_fixed() {
+		let bump = bumpalo::Bump::default();
+		for (source_text, fixed) in [
+			("10px", true),
+			("1fr", false),
+			("auto", false),
+			("minmax(10px,1fr)", true),
+			("minmax(auto,10px)", true),
+			("minmax(auto,1fr)", false),
+			("fit-content(10px)", false),
+		] {
+			let lexer = css_lexer::Lexer::new(&CssAtomSet::ATOMS, source_text);
+			let mut p = Parser::new(&bump, source_text, lexer);
+			let size = p.parse_entirely::<TrackSize>().output.unwrap();
+			assert_eq!(size.is_fixed(), fixed, "{source_text}");
+		}
+	}
 }
@@ -0,0 +1,38 @@
+use super::prelude::*;
+
+/// <https://drafts.csswg.org/css-anchor-position-1/#typedef-position-try-fallbacks-try-tactic>
+///
+/// ```text,ignore
+/// <try-tactic> = flip-block || flip-inline || flip-start || flip-x || flip-y
+/// ```
+#[syntax(" flip-block || flip-inline || flip-start || flip-x || flip-y ")]
+#[derive(Parse, Peek, ToCursors, ToSpan, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "visitable", derive(csskit_derives::Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct TryTactic;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<TryTactic>(), 80);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, TryTactic, "flip-block");
+		assert_parse!(CssAtomSet::ATOMS, TryTactic, "flip-x flip-y");
+		assert_parse!(CssAtomSet::ATOMS, TryTactic, "flip-start flip-inline");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, TryTactic, "flip");
+		assert_parse_error!(CssAtomSet::ATOMS, TryTactic, "flip-x flip-x");
+	}
+}
@@ -6,7 +6,7 @@ pub(crate) use csskit_proc_macro::*;
 mod tests {
 	use super::super::*;
 	use crate::CssAtomSet;
-	use css_parse::assert_parse;
+	use css_parse::{assert_parse, assert_parse_error};
 
 	#[test]
 	pub fn size_test() {
@@ -14,10 +14,10 @@ mod tests {
 		assert_eq!(std::mem::size_of::<AnchorScopeStyleValue>(), 40);
 		assert_eq!(std::mem::size_of::<PositionAnchorStyleValue>(), 16);
 		assert_eq!(std::mem::size_of::<PositionAreaStyleValue>(), 36);
-		// assert_eq!(std::mem::size_of::<PositionVisibilityStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<PositionTryFallbacksStyleValue>(), 1);
+		assert_eq!(std::mem::size_of::<PositionVisibilityStyleValue>(), 48);
+		assert_eq!(std::mem::size_of::<PositionTryFallbacksStyleValue>(), 32);
 		assert_eq!(std::mem::size_of::<PositionTryOrderStyleValue>(), 16);
-		// assert_eq!(std::mem::size_of::<PositionTryStyleValue>(), 1);
+		assert_eq!(std::mem::size_of::<PositionTryStyleValue>(), 48);
 	}
 
 	#[test]
@@ -27,5 +27,23 @@ mod tests {
 		assert_parse!(CssAtomSet::ATOMS, AnchorScopeStyleValue, "all");
 		assert_parse!(CssAtomSet::ATOMS, AnchorScopeStyleValue, "--foo,--bar");
 		assert_parse!(CssAtomSet::ATOMS, PositionTryOrderStyleValue, "normal");
+		assert_parse!(CssAtomSet::ATOMS, PositionVisibilityStyleValue, "always");
+		assert_parse!(CssAtomSet::ATOMS, PositionVisibilityStyleValue, "anchors-visible");
+		assert_parse!(CssAtomSet::ATOMS, PositionVisibilityStyleValue, "anchors-valid no-overflow");
+		assert_parse!(CssAtomSet::ATOMS, PositionTryFallbacksStyleValue, "none");
+		assert_parse!(CssAtomSet::ATOMS, PositionTryFallbacksStyleValue, "--top");
+		assert_parse!(CssAtomSet::ATOMS, PositionTryFallbacksStyleValue, "flip-block");
+		assert_parse!(CssAtomSet::ATOMS, PositionTryFallbacksStyleValue, "--top,flip-block,--bottom flip-x flip-y");
+		assert_parse!(CssAtomSet::ATOMS, PositionTryFallbacksStyleValue, "top left,--top");
+		assert_parse!(CssAtomSet::ATOMS, PositionTryStyleValue, "--top,--bottom");
+		assert_parse!(CssAtomSet::ATOMS, PositionTryStyleValue, "most-height --top,flip-inline");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, PositionVisibilityStyleValue, "always no-overflow");
+		assert_parse_error!(CssAtomSet::ATOMS, PositionVisibilityStyleValue, "no-overflow no-overflow");
+		assert_parse_error!(CssAtomSet::ATOMS, PositionTryFallbacksStyleValue, "none,--top");
+		assert_parse_error!(CssAtomSet::ATOMS, PositionTryFallbacksStyleValue, "--top,");
 	}
 }
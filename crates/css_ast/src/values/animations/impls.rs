@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
 	use super::super::*;
-	use css_parse::assert_parse;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
 
 	#[test]
 	fn size_test() {
@@ -13,17 +14,42 @@ mod tests {
 		assert_eq!(std::mem::size_of::<AnimationPlayStateStyleValue>(), 32);
 		assert_eq!(std::mem::size_of::<AnimationDelayStyleValue>(), 32);
 		assert_eq!(std::mem::size_of::<AnimationFillModeStyleValue>(), 32);
-		// assert_eq!(std::mem::size_of::<AnimationStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<AnimationStyleValue>(), 32);
 		assert_eq!(std::mem::size_of::<AnimationCompositionStyleValue>(), 32);
 		assert_eq!(std::mem::size_of::<AnimationTimelineStyleValue>(), 32);
-		// assert_eq!(std::mem::size_of::<AnimationTriggerBehaviorStyleValue>(), 32);
-		// assert_eq!(std::mem::size_of::<AnimationTriggerTimelineStyleValue>(), 32);
-		// assert_eq!(std::mem::size_of::<AnimationTriggerRangeStyleValue>(), 32);
-		// assert_eq!(std::mem::size_of::<AnimationTriggerRangeStartStyleValue>(), 32);
-		// assert_eq!(std::mem::size_of::<AnimationTriggerRangeEndStyleValue>(), 32);
-		// assert_eq!(std::mem::size_of::<AnimationTriggerExitRangeStyleValue>(), 32);
-		// assert_eq!(std::mem::size_of::<AnimationTriggerExitRangeStartStyleValue>(), 32);
-		// assert_eq!(std::mem::size_of::<AnimationTriggerExitRangeEndStyleValue>(), 32);
-		// assert_eq!(std::mem::size_of::<AnimationTriggerStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<TimelineTriggerStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<TimelineTriggerRangeStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<TimelineTriggerRangeStartStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<TimelineTriggerRangeEndStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<TimelineTriggerExitRangeStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<TimelineTriggerExitRangeStartStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<TimelineTriggerExitRangeEndStyleValue>(), 32);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, AnimationStyleValue, "none");
+		assert_parse!(CssAtomSet::ATOMS, AnimationStyleValue, "spin 1s linear infinite");
+		assert_parse!(CssAtomSet::ATOMS, AnimationStyleValue, "fade-in .3s ease-out both,slide 1s 200ms");
+		assert_parse!(CssAtomSet::ATOMS, AnimationTimelineStyleValue, "view()");
+		assert_parse!(CssAtomSet::ATOMS, AnimationTimelineStyleValue, "auto,--tl,scroll(root y),view(inline 10% auto)");
+		assert_parse!(CssAtomSet::ATOMS, TimelineTriggerStyleValue, "none");
+		assert_parse!(CssAtomSet::ATOMS, TimelineTriggerStyleValue, "--a auto entry/auto exit,--b none cover 10% 90%");
+		assert_parse!(CssAtomSet::ATOMS, TimelineTriggerStyleValue, "--t view() entry 0%");
+		assert_parse!(CssAtomSet::ATOMS, TimelineTriggerStyleValue, "--t --tl normal");
+		assert_parse!(CssAtomSet::ATOMS, TimelineTriggerRangeStyleValue, "entry 10% exit 90%,normal");
+		assert_parse!(CssAtomSet::ATOMS, TimelineTriggerRangeStartStyleValue, "contain 25%");
+		assert_parse!(CssAtomSet::ATOMS, TimelineTriggerRangeEndStyleValue, "normal,100px");
+		assert_parse!(CssAtomSet::ATOMS, TimelineTriggerExitRangeStyleValue, "auto cover 100%");
+		assert_parse!(CssAtomSet::ATOMS, TimelineTriggerExitRangeStartStyleValue, "auto,exit");
+		assert_parse!(CssAtomSet::ATOMS, TimelineTriggerExitRangeEndStyleValue, "exit-crossing 50%");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, AnimationStyleValue, "spin 1s,");
+		assert_parse_error!(CssAtomSet::ATOMS, AnimationStyleValue, "spin slide");
+		assert_parse_error!(CssAtomSet::ATOMS, TimelineTriggerStyleValue, "none,--a auto normal");
+		assert_parse_error!(CssAtomSet::ATOMS, TimelineTriggerRangeStartStyleValue, "auto 10% 20%");
 	}
 }
@@ -1,3 +1,29 @@
+use super::BackgroundStyleValue;
+use crate::{BgLayer, FinalBgLayer};
+use bumpalo::collections::Vec;
+use css_parse::{Cursor, Parse, Parser, Result as ParseResult, T};
+
+impl<'a> Parse<'a> for BackgroundStyleValue<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParseResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		// Every layer but the last is followed by a comma, and only the last may include a color.
+		let mut layers = Vec::new_in(p.bump());
+		loop {
+			let checkpoint = p.checkpoint();
+			if let Ok(layer) = p.try_parse::<BgLayer>()
+				&& let Some(comma) = p.parse_if_peek::<T![,]>()?
+			{
+				layers.push((layer, comma));
+				continue;
+			}
+			p.rewind(checkpoint);
+			return Ok(Self(layers, p.parse::<FinalBgLayer>()?));
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::super::*;
@@ -6,28 +32,48 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_eq!(std::mem::size_of::<BackgroundColorStyleValue>(), 140);
-		// assert_eq!(std::mem::size_of::<BackgroundImageStyleValue>(), 1);
+		assert_eq!(std::mem::size_of::<BackgroundColorStyleValue>(), 144);
+		assert_eq!(std::mem::size_of::<BackgroundImageStyleValue>(), 32);
 		assert_eq!(std::mem::size_of::<BackgroundRepeatStyleValue>(), 32);
 		assert_eq!(std::mem::size_of::<BackgroundAttachmentStyleValue>(), 32);
-		// assert_eq!(std::mem::size_of::<BackgroundPositionStyleValue>(), 1);
+		assert_eq!(std::mem::size_of::<BackgroundPositionStyleValue>(), 32);
 		assert_eq!(std::mem::size_of::<BackgroundClipStyleValue>(), 32);
 		assert_eq!(std::mem::size_of::<BackgroundOriginStyleValue>(), 32);
-		// assert_eq!(std::mem::size_of::<BackgroundSizeStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<BackgroundStyleValue>(), 1);
+		assert_eq!(std::mem::size_of::<BackgroundSizeStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<BackgroundStyleValue>(), 584);
 		assert_eq!(std::mem::size_of::<BackgroundRepeatXStyleValue>(), 32);
 		assert_eq!(std::mem::size_of::<BackgroundRepeatYStyleValue>(), 32);
 		assert_eq!(std::mem::size_of::<BackgroundRepeatBlockStyleValue>(), 32);
 		assert_eq!(std::mem::size_of::<BackgroundRepeatInlineStyleValue>(), 32);
-		// assert_eq!(std::mem::size_of::<BackgroundPositionXStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<BackgroundPositionYStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<BackgroundPositionInlineStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<BackgroundPositionBlockStyleValue>(), 1);
+		assert_eq!(std::mem::size_of::<BackgroundPositionXStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<BackgroundPositionYStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<BackgroundPositionInlineStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<BackgroundPositionBlockStyleValue>(), 32);
 	}
 
 	#[test]
 	fn test_writes() {
 		assert_parse!(CssAtomSet::ATOMS, BackgroundRepeatStyleValue, "repeat-x");
 		assert_parse!(CssAtomSet::ATOMS, BackgroundRepeatStyleValue, "space round");
+		assert_parse!(CssAtomSet::ATOMS, BackgroundPositionStyleValue, "left 10px top,center");
+		assert_parse!(CssAtomSet::ATOMS, BackgroundPositionStyleValue, "right 10px bottom 20px");
+		assert_parse!(CssAtomSet::ATOMS, BackgroundPositionXStyleValue, "center,right 10px,20%,x-start");
+		assert_parse!(CssAtomSet::ATOMS, BackgroundPositionYStyleValue, "bottom 5px,top");
+		assert_parse!(CssAtomSet::ATOMS, BackgroundPositionInlineStyleValue, "end 1em,center");
+		assert_parse!(CssAtomSet::ATOMS, BackgroundPositionBlockStyleValue, "start");
+		assert_parse!(CssAtomSet::ATOMS, BackgroundStyleValue, "red");
+		assert_parse!(CssAtomSet::ATOMS, BackgroundStyleValue, "url(a.png) no-repeat,#fff");
+		assert_parse!(CssAtomSet::ATOMS, BackgroundStyleValue, "url(a) center/cover,url(b) 0 0 repeat-x red");
+		assert_parse!(CssAtomSet::ATOMS, BackgroundStyleValue, "none,none,none");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, BackgroundPositionStyleValue, "left 10px right");
+		assert_parse_error!(CssAtomSet::ATOMS, BackgroundPositionXStyleValue, "top");
+		assert_parse_error!(CssAtomSet::ATOMS, BackgroundPositionInlineStyleValue, "left");
+		assert_parse_error!(CssAtomSet::ATOMS, BackgroundStyleValue, "red,url(a)");
+		assert_parse_error!(CssAtomSet::ATOMS, BackgroundStyleValue, "url(a),");
+		assert_parse_error!(CssAtomSet::ATOMS, BackgroundStyleValue, "url(a)/cover");
 	}
 }
@@ -36,16 +36,16 @@ mod tests {
 		assert_eq!(std::mem::size_of::<BorderInlineEndWidthStyleValue>(), 16);
 		assert_eq!(std::mem::size_of::<BorderBlockWidthStyleValue>(), 32);
 		assert_eq!(std::mem::size_of::<BorderInlineWidthStyleValue>(), 32);
-		assert_eq!(std::mem::size_of::<BorderTopStyleValue>(), 172);
-		assert_eq!(std::mem::size_of::<BorderRightStyleValue>(), 172);
-		assert_eq!(std::mem::size_of::<BorderBottomStyleValue>(), 172);
-		assert_eq!(std::mem::size_of::<BorderLeftStyleValue>(), 172);
-		assert_eq!(std::mem::size_of::<BorderBlockStartStyleValue>(), 172);
-		assert_eq!(std::mem::size_of::<BorderBlockEndStyleValue>(), 172);
-		assert_eq!(std::mem::size_of::<BorderInlineStartStyleValue>(), 172);
-		assert_eq!(std::mem::size_of::<BorderInlineEndStyleValue>(), 172);
-		assert_eq!(std::mem::size_of::<BorderBlockStyleValue>(), 172);
-		assert_eq!(std::mem::size_of::<BorderInlineStyleValue>(), 172);
+		assert_eq!(std::mem::size_of::<BorderTopStyleValue>(), 176);
+		assert_eq!(std::mem::size_of::<BorderRightStyleValue>(), 176);
+		assert_eq!(std::mem::size_of::<BorderBottomStyleValue>(), 176);
+		assert_eq!(std::mem::size_of::<BorderLeftStyleValue>(), 176);
+		assert_eq!(std::mem::size_of::<BorderBlockStartStyleValue>(), 176);
+		assert_eq!(std::mem::size_of::<BorderBlockEndStyleValue>(), 176);
+		assert_eq!(std::mem::size_of::<BorderInlineStartStyleValue>(), 176);
+		assert_eq!(std::mem::size_of::<BorderInlineEndStyleValue>(), 176);
+		assert_eq!(std::mem::size_of::<BorderBlockStyleValue>(), 176);
+		assert_eq!(std::mem::size_of::<BorderInlineStyleValue>(), 176);
 		assert_eq!(std::mem::size_of::<BorderTopLeftRadiusStyleValue>(), 48);
 		assert_eq!(std::mem::size_of::<BorderTopRightRadiusStyleValue>(), 48);
 		assert_eq!(std::mem::size_of::<BorderBottomRightRadiusStyleValue>(), 48);
@@ -1 +1,29 @@
+#[cfg(test)]
+mod tests {
+	use super::super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
 
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<FilterStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<BackdropFilterStyleValue>(), 32);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, FilterStyleValue, "none");
+		assert_parse!(CssAtomSet::ATOMS, FilterStyleValue, "blur(5px)");
+		assert_parse!(CssAtomSet::ATOMS, FilterStyleValue, "drop-shadow(16px 16px 20px blue)invert(75%)");
+		assert_parse!(CssAtomSet::ATOMS, FilterStyleValue, "url(#svg-filter)hue-rotate(90deg)");
+		assert_parse!(CssAtomSet::ATOMS, BackdropFilterStyleValue, "none");
+		assert_parse!(CssAtomSet::ATOMS, BackdropFilterStyleValue, "blur(10px)saturate(180%)");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, FilterStyleValue, "none blur(5px)");
+		assert_parse_error!(CssAtomSet::ATOMS, FilterStyleValue, "blur(5px) none");
+		assert_parse_error!(CssAtomSet::ATOMS, BackdropFilterStyleValue, "opacity(-1)");
+	}
+}
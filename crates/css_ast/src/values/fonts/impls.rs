@@ -11,26 +11,26 @@ mod tests {
 		assert_eq!(std::mem::size_of::<FontWidthStyleValue>(), 16);
 		assert_eq!(std::mem::size_of::<FontStyleStyleValue>(), 28);
 		assert_eq!(std::mem::size_of::<FontSizeStyleValue>(), 20);
-		// assert_eq!(std::mem::size_of::<FontSizeAdjustStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<FontStyleValue>(), 1);
+		assert_eq!(std::mem::size_of::<FontSizeAdjustStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<FontStyleValue>(), 160);
 		assert_eq!(std::mem::size_of::<FontSynthesisWeightStyleValue>(), 16);
 		assert_eq!(std::mem::size_of::<FontSynthesisStyleStyleValue>(), 16);
 		assert_eq!(std::mem::size_of::<FontSynthesisSmallCapsStyleValue>(), 16);
 		assert_eq!(std::mem::size_of::<FontSynthesisPositionStyleValue>(), 16);
-		// assert_eq!(std::mem::size_of::<FontSynthesisStyleValue>(), 1);
+		assert_eq!(std::mem::size_of::<FontSynthesisStyleValue>(), 64);
 		assert_eq!(std::mem::size_of::<FontKerningStyleValue>(), 16);
-		// assert_eq!(std::mem::size_of::<FontVariantLigaturesStyleValue>(), 1);
+		assert_eq!(std::mem::size_of::<FontVariantLigaturesStyleValue>(), 64);
 		assert_eq!(std::mem::size_of::<FontVariantPositionStyleValue>(), 16);
 		assert_eq!(std::mem::size_of::<FontVariantCapsStyleValue>(), 16);
-		// assert_eq!(std::mem::size_of::<FontVariantNumericStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<FontVariantAlternatesStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<FontVariantEastAsianStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<FontVariantStyleValue>(), 1);
+		assert_eq!(std::mem::size_of::<FontVariantNumericStyleValue>(), 80);
+		assert_eq!(std::mem::size_of::<FontVariantAlternatesStyleValue>(), 288);
+		assert_eq!(std::mem::size_of::<FontVariantEastAsianStyleValue>(), 48);
+		assert_eq!(std::mem::size_of::<FontVariantStyleValue>(), 528);
 		// assert_eq!(std::mem::size_of::<FontFeatureSettingsStyleValue>(), 1);
 		assert_eq!(std::mem::size_of::<FontLanguageOverrideStyleValue>(), 16);
 		assert_eq!(std::mem::size_of::<FontOpticalSizingStyleValue>(), 16);
 		// assert_eq!(std::mem::size_of::<FontVariationSettingsStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<FontPaletteStyleValue>(), 1);
+		assert_eq!(std::mem::size_of::<FontPaletteStyleValue>(), 128);
 		assert_eq!(std::mem::size_of::<FontVariantEmojiStyleValue>(), 16);
 	}
 
@@ -41,6 +41,31 @@ mod tests {
 		assert_parse!(CssAtomSet::ATOMS, FontSizeStyleValue, "45rem");
 		assert_parse!(CssAtomSet::ATOMS, FontSizeStyleValue, "smaller");
 		assert_parse!(CssAtomSet::ATOMS, FontSizeStyleValue, "80%");
+		assert_parse!(CssAtomSet::ATOMS, FontStyleValue, "12px serif");
+		assert_parse!(CssAtomSet::ATOMS, FontStyleValue, "italic bold 12px/30px Georgia,serif");
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			FontStyleValue,
+			"bold italic small-caps condensed 1.2em \"Helvetica Neue\",sans-serif"
+		);
+		assert_parse!(CssAtomSet::ATOMS, FontStyleValue, "caption");
+		assert_parse!(CssAtomSet::ATOMS, FontStyleValue, "status-bar");
+		assert_parse!(CssAtomSet::ATOMS, FontVariantStyleValue, "normal");
+		assert_parse!(CssAtomSet::ATOMS, FontVariantStyleValue, "none");
+		assert_parse!(CssAtomSet::ATOMS, FontVariantStyleValue, "small-caps common-ligatures");
+		assert_parse!(CssAtomSet::ATOMS, FontVariantStyleValue, "tabular-nums small-caps slashed-zero");
+		assert_parse!(CssAtomSet::ATOMS, FontVariantLigaturesStyleValue, "no-common-ligatures contextual");
+		assert_parse!(CssAtomSet::ATOMS, FontVariantNumericStyleValue, "oldstyle-nums diagonal-fractions ordinal");
+		assert_parse!(CssAtomSet::ATOMS, FontVariantEastAsianStyleValue, "jis04 full-width ruby");
+		assert_parse!(CssAtomSet::ATOMS, FontVariantAlternatesStyleValue, "normal");
+		assert_parse!(CssAtomSet::ATOMS, FontVariantAlternatesStyleValue, "historical-forms styleset(a,b)");
+		assert_parse!(CssAtomSet::ATOMS, FontSynthesisStyleValue, "none");
+		assert_parse!(CssAtomSet::ATOMS, FontSynthesisStyleValue, "weight style");
+		assert_parse!(CssAtomSet::ATOMS, FontSizeAdjustStyleValue, "none");
+		assert_parse!(CssAtomSet::ATOMS, FontSizeAdjustStyleValue, "ex-height 0.5");
+		assert_parse!(CssAtomSet::ATOMS, FontPaletteStyleValue, "dark");
+		assert_parse!(CssAtomSet::ATOMS, FontPaletteStyleValue, "--custom");
+		assert_parse!(CssAtomSet::ATOMS, FontPaletteStyleValue, "palette-mix(in lch,light,dark)");
 	}
 
 	#[test]
@@ -48,6 +73,21 @@ mod tests {
 		assert_parse_error!(CssAtomSet::ATOMS, FontStyleStyleValue, "oblique 45px");
 		assert_parse_error!(CssAtomSet::ATOMS, FontStyleStyleValue, "oblique 91deg");
 		assert_parse_error!(CssAtomSet::ATOMS, FontStyleStyleValue, "oblique -91deg");
+		assert_parse_error!(CssAtomSet::ATOMS, FontStyleValue, "bold");
+		assert_parse_error!(CssAtomSet::ATOMS, FontStyleValue, "12px");
+		assert_parse_error!(CssAtomSet::ATOMS, FontStyleValue, "caption 12px serif");
+		assert_parse_error!(CssAtomSet::ATOMS, FontVariantStyleValue, "normal small-caps");
+		assert_parse_error!(CssAtomSet::ATOMS, FontVariantLigaturesStyleValue, "common-ligatures no-common-ligatures");
+		assert_parse_error!(CssAtomSet::ATOMS, FontSynthesisStyleValue, "none weight");
+		assert_parse_error!(CssAtomSet::ATOMS, FontSynthesisStyleValue, "foo");
+		assert_parse_error!(CssAtomSet::ATOMS, FontSynthesisStyleValue, "weight weight");
+		assert_parse_error!(CssAtomSet::ATOMS, FontVariantNumericStyleValue, "foo");
+		assert_parse_error!(CssAtomSet::ATOMS, FontVariantNumericStyleValue, "lining-nums oldstyle-nums");
+		assert_parse_error!(CssAtomSet::ATOMS, FontVariantNumericStyleValue, "ordinal ordinal");
+		assert_parse_error!(CssAtomSet::ATOMS, FontVariantEastAsianStyleValue, "jis78 jis83");
+		assert_parse_error!(CssAtomSet::ATOMS, FontVariantEastAsianStyleValue, "ruby foo");
+		assert_parse_error!(CssAtomSet::ATOMS, FontSizeAdjustStyleValue, "none 0.5");
+		assert_parse_error!(CssAtomSet::ATOMS, FontPaletteStyleValue, "custom");
 	}
 
 	#[test]
@@ -1,26 +1,256 @@
+use super::{
+	GridAutoColumnsStyleValue, GridAutoRowsStyleValue, GridStyleValue, GridTemplateAreasStyleValue,
+	GridTemplateColumnsStyleValue, GridTemplateRowsStyleValue, GridTemplateStyleValue,
+};
+use crate::{
+	AutoTrackList, CssAtomSet, CssDiagnostic, ExplicitTrackList, LineNameList, LineNames, NoneOr, TrackList, TrackSize,
+};
+use bumpalo::collections::Vec;
+use css_parse::{Cursor, Diagnostic, Parse, Parser, Peek, Result as ParseResult, T};
+
+macro_rules! impl_track_list_parse {
+	($ident: ident) => {
+		impl<'a> Parse<'a> for $ident<'a> {
+			fn parse<I>(p: &mut Parser<'a, I>) -> ParseResult<Self>
+			where
+				I: Iterator<Item = Cursor> + Clone,
+			{
+				let c = p.peek_n(1);
+				if <T![Ident]>::peek(p, c) {
+					match p.to_atom::<CssAtomSet>(c) {
+						CssAtomSet::None => return p.parse::<T![Ident]>().map(Self::None),
+						CssAtomSet::Masonry => return p.parse::<T![Ident]>().map(Self::Masonry),
+						CssAtomSet::Subgrid => {
+							let subgrid = p.parse::<T![Ident]>()?;
+							return Ok(Self::Subgrid(subgrid, p.parse_if_peek::<LineNameList>()?));
+						}
+						_ => {}
+					}
+				}
+				// A <track-list> and an <auto-track-list> can only be told apart once an auto-repeat is found.
+				if let Ok(list) = p.try_parse::<TrackList>() {
+					return Ok(Self::TrackList(list));
+				}
+				p.parse::<AutoTrackList>().map(Self::AutoTrackList)
+			}
+		}
+	};
+}
+
+impl_track_list_parse!(GridTemplateColumnsStyleValue);
+impl_track_list_parse!(GridTemplateRowsStyleValue);
+
+impl<'a> Parse<'a> for GridTemplateAreasStyleValue<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParseResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		if let Some(none) = p.parse_if_peek::<T![Ident]>()? {
+			if !p.equals_atom(none.into(), &CssAtomSet::None) {
+				Err(Diagnostic::new(none.into(), Diagnostic::unexpected_ident))?
+			}
+			return Ok(Self(NoneOr::None(none)));
+		}
+		let mut strings = Vec::new_in(p.bump());
+		loop {
+			strings.push(p.parse::<T![String]>()?);
+			if !p.peek::<T![String]>() {
+				break;
+			}
+		}
+		validate_grid_areas(p, strings.iter().copied())?;
+		Ok(Self(NoneOr::Some(strings)))
+	}
+}
+
+impl<'a> Parse<'a> for GridTemplateStyleValue<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParseResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let c = p.peek_n(1);
+		if <T![Ident]>::peek(p, c) && p.equals_atom(c, &CssAtomSet::None) {
+			return p.parse::<T![Ident]>().map(Self::None);
+		}
+		// Both the explicit form and the areas form may begin with line names, so try the explicit form first.
+		let checkpoint = p.checkpoint();
+		if let Ok(rows) = p.parse::<GridTemplateRowsStyleValue>()
+			&& let Some(slash) = p.parse_if_peek::<T![/]>()?
+			&& let Ok(columns) = p.parse::<GridTemplateColumnsStyleValue>()
+		{
+			return Ok(Self::GridTemplateRows(rows, slash, columns));
+		}
+		p.rewind(checkpoint);
+		let mut rows = Vec::new_in(p.bump());
+		loop {
+			let before = p.parse_if_peek::<LineNames>()?;
+			let string = p.parse::<T![String]>()?;
+			let size = p.parse_if_peek::<TrackSize>()?;
+			let after = p.parse_if_peek::<LineNames>()?;
+			rows.push((before, string, size, after));
+			if !p.peek::<LineNames>() && !p.peek::<T![String]>() {
+				break;
+			}
+		}
+		validate_grid_areas(p, rows.iter().map(|(_, string, _, _)| *string))?;
+		let columns = if let Some(slash) = p.parse_if_peek::<T![/]>()? {
+			Some((slash, p.parse::<ExplicitTrackList>()?))
+		} else {
+			None
+		};
+		Ok(Self::String(rows, columns))
+	}
+}
+
+impl<'a> Parse<'a> for GridStyleValue<'a> {
+	fn parse<I>(p: &mut Parser<'a, I>) -> ParseResult<Self>
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		if let Ok(template) = p.try_parse::<GridTemplateStyleValue>() {
+			return Ok(Self::GridTemplate(template));
+		}
+		let c = p.peek_n(1);
+		if <T![Ident]>::peek(p, c) && matches!(p.to_atom::<CssAtomSet>(c), CssAtomSet::AutoFlow | CssAtomSet::Dense) {
+			let (auto_flow, dense) = parse_auto_flow(p)?;
+			let rows = p.parse_if_peek::<GridAutoRowsStyleValue>()?;
+			let slash = p.parse::<T![/]>()?;
+			let columns = p.parse::<GridTemplateColumnsStyleValue>()?;
+			return Ok(Self::AutoFlow(auto_flow, dense, rows, slash, columns));
+		}
+		let rows = p.parse::<GridTemplateRowsStyleValue>()?;
+		let slash = p.parse::<T![/]>()?;
+		let (auto_flow, dense) = parse_auto_flow(p)?;
+		let columns = p.parse_if_peek::<GridAutoColumnsStyleValue>()?;
+		Ok(Self::GridTemplateRows(rows, (slash, (auto_flow, dense, columns))))
+	}
+}
+
+/// Parses `[ auto-flow && dense? ]`, in either order.
+fn parse_auto_flow<'a, I>(p: &mut Parser<'a, I>) -> ParseResult<(T![Ident], Option<T![Ident]>)>
+where
+	I: Iterator<Item = Cursor> + Clone,
+{
+	let mut auto_flow = None;
+	let mut dense = None;
+	while <T![Ident]>::peek(p, p.peek_n(1)) {
+		let c = p.peek_n(1);
+		match p.to_atom::<CssAtomSet>(c) {
+			CssAtomSet::AutoFlow if auto_flow.is_none() => auto_flow = Some(p.parse::<T![Ident]>()?),
+			CssAtomSet::Dense if dense.is_none() => dense = Some(p.parse::<T![Ident]>()?),
+			_ => break,
+		}
+	}
+	match auto_flow {
+		Some(auto_flow) => Ok((auto_flow, dense)),
+		None => Err(Diagnostic::new(p.next(), Diagnostic::expected_ident))?,
+	}
+}
+
+/// The extent of a named area within a grid template, in rows and columns.
+struct GridAreaBounds<'s> {
+	name: &'s str,
+	cursor: Cursor,
+	rows: (usize, usize),
+	columns: (usize, usize),
+	cells: usize,
+}
+
+/// Checks that strings of a grid template form a valid area map: each must have the same, non-zero, number of
+/// columns, and each named area must form a single filled rectangle.
+///
+/// <https://drafts.csswg.org/css-grid-2/#grid-template-areas-property>
+fn validate_grid_areas<'a, I>(p: &Parser<'a, I>, strings: impl Iterator<Item = T![String]>) -> ParseResult<()>
+where
+	I: Iterator<Item = Cursor> + Clone,
+{
+	let values = strings
+		.map(|string| {
+			let c: Cursor = string.into();
+			(c, p.to_source_cursor(c).parse(p.bump()))
+		})
+		.collect::<std::vec::Vec<_>>();
+	let mut areas: std::vec::Vec<GridAreaBounds> = vec![];
+	let mut column_count = None;
+	for (row, (c, value)) in values.iter().enumerate() {
+		let c = *c;
+		let mut column = 0;
+		let mut rest: &str = value;
+		while let Some(ch) = rest.chars().next() {
+			if matches!(ch, ' ' | '\t' | '\n' | '\r' | '\x0C') {
+				rest = &rest[1..];
+			} else if ch == '.' {
+				rest = rest.trim_start_matches('.');
+				column += 1;
+			} else if is_name_code_point(ch) {
+				let len = rest.find(|ch| !is_name_code_point(ch)).unwrap_or(rest.len());
+				let name = &rest[..len];
+				rest = &rest[len..];
+				if let Some(area) = areas.iter_mut().find(|area| area.name == name) {
+					area.rows.1 = row;
+					area.columns.0 = area.columns.0.min(column);
+					area.columns.1 = area.columns.1.max(column);
+					area.cells += 1;
+				} else {
+					areas.push(GridAreaBounds {
+						name,
+						cursor: c,
+						rows: (row, row),
+						columns: (column, column),
+						cells: 1,
+					});
+				}
+				column += 1;
+			} else {
+				Err(Diagnostic::new(c, Diagnostic::invalid_grid_area_string))?
+			}
+		}
+		if column == 0 {
+			Err(Diagnostic::new(c, Diagnostic::invalid_grid_area_string))?
+		}
+		match column_count {
+			None => column_count = Some(column),
+			Some(count) if count != column => Err(Diagnostic::new(c, Diagnostic::grid_area_column_mismatch))?,
+			_ => {}
+		}
+	}
+	for area in &areas {
+		let size = (area.rows.1 - area.rows.0 + 1) * (area.columns.1 - area.columns.0 + 1);
+		if size != area.cells {
+			Err(Diagnostic::new(area.cursor, Diagnostic::non_rectangular_grid_area))?
+		}
+	}
+	Ok(())
+}
+
+/// <https://drafts.csswg.org/css-syntax-3/#ident-code-point>
+fn is_name_code_point(ch: char) -> bool {
+	ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' || !ch.is_ascii()
+}
+
 #[cfg(test)]
 mod tests {
 	use super::super::*;
 	use crate::CssAtomSet;
-	use css_parse::assert_parse;
+	use css_parse::{assert_parse, assert_parse_error};
 
 	#[test]
 	pub fn size_test() {
-		// assert_eq!(std::mem::size_of::<GridTemplateColumnsStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<GridTemplateRowsStyleValue>(), 1);
+		assert_eq!(std::mem::size_of::<GridTemplateColumnsStyleValue>(), 48);
+		assert_eq!(std::mem::size_of::<GridTemplateRowsStyleValue>(), 48);
 		assert_eq!(std::mem::size_of::<GridTemplateAreasStyleValue>(), 32);
-		// assert_eq!(std::mem::size_of::<GridTemplateStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<GridAutoColumnsStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<GridAutoRowsStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<GridAutoFlowStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<GridStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<GridRowStartStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<GridColumnStartStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<GridRowEndStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<GridColumnEndStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<GridRowStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<GridColumnStyleValue>(), 1);
-		// assert_eq!(std::mem::size_of::<GridAreaStyleValue>(), 1);
+		assert_eq!(std::mem::size_of::<GridTemplateStyleValue>(), 112);
+		assert_eq!(std::mem::size_of::<GridAutoColumnsStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<GridAutoRowsStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<GridAutoFlowStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<GridStyleValue>(), 136);
+		assert_eq!(std::mem::size_of::<GridRowStartStyleValue>(), 44);
+		assert_eq!(std::mem::size_of::<GridColumnStartStyleValue>(), 44);
+		assert_eq!(std::mem::size_of::<GridRowEndStyleValue>(), 44);
+		assert_eq!(std::mem::size_of::<GridColumnEndStyleValue>(), 44);
+		assert_eq!(std::mem::size_of::<GridRowStyleValue>(), 100);
+		assert_eq!(std::mem::size_of::<GridColumnStyleValue>(), 100);
+		assert_eq!(std::mem::size_of::<GridAreaStyleValue>(), 212);
 		assert_eq!(std::mem::size_of::<FlowToleranceStyleValue>(), 16);
 		assert_eq!(std::mem::size_of::<ItemDirectionStyleValue>(), 16);
 		assert_eq!(std::mem::size_of::<ItemTrackStyleValue>(), 16);
@@ -34,6 +264,65 @@ mod tests {
 	fn test_writes() {
 		assert_parse!(CssAtomSet::ATOMS, GridTemplateAreasStyleValue, "none");
 		assert_parse!(CssAtomSet::ATOMS, GridTemplateAreasStyleValue, r#""foo""bar""#);
+		assert_parse!(CssAtomSet::ATOMS, GridTemplateAreasStyleValue, r#""head head""nav main""foot ....""#);
+		assert_parse!(CssAtomSet::ATOMS, GridTemplateAreasStyleValue, r#""a a b""a a b"". . b""#);
+		assert_parse!(CssAtomSet::ATOMS, GridTemplateAreasStyleValue, r#""a.b""c.d""#);
+
+		assert_parse!(CssAtomSet::ATOMS, GridTemplateColumnsStyleValue, "none");
+		assert_parse!(CssAtomSet::ATOMS, GridTemplateColumnsStyleValue, "masonry");
+		assert_parse!(CssAtomSet::ATOMS, GridTemplateColumnsStyleValue, "subgrid");
+		assert_parse!(CssAtomSet::ATOMS, GridTemplateColumnsStyleValue, "subgrid [a] repeat(auto-fill,[b])");
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			GridTemplateColumnsStyleValue,
+			"100px 1fr",
+			GridTemplateColumnsStyleValue::TrackList(_)
+		);
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			GridTemplateColumnsStyleValue,
+			"[full-start] minmax(1em,1fr) [main-start] 1fr"
+		);
+		assert_parse!(CssAtomSet::ATOMS, GridTemplateColumnsStyleValue, "repeat(3,1fr)");
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			GridTemplateColumnsStyleValue,
+			"repeat(auto-fill,minmax(200px,1fr))",
+			GridTemplateColumnsStyleValue::AutoTrackList(_)
+		);
+		assert_parse!(CssAtomSet::ATOMS, GridTemplateRowsStyleValue, "auto 1fr auto");
+		assert_parse!(CssAtomSet::ATOMS, GridTemplateRowsStyleValue, "fit-content(100px) repeat(3,50px)");
+		assert_parse!(CssAtomSet::ATOMS, GridTemplateRowsStyleValue, "minmax(100px,auto) repeat(auto-fit,50px)");
+
+		assert_parse!(CssAtomSet::ATOMS, GridTemplateStyleValue, "none");
+		assert_parse!(CssAtomSet::ATOMS, GridTemplateStyleValue, "auto 1fr/auto 1fr auto");
+		assert_parse!(CssAtomSet::ATOMS, GridTemplateStyleValue, "[header-top] auto/repeat(2,1fr)");
+		assert_parse!(CssAtomSet::ATOMS, GridTemplateStyleValue, r#""a a a""b b b""#);
+		assert_parse!(
+			CssAtomSet::ATOMS,
+			GridTemplateStyleValue,
+			r#"[header-top]"a a a"[header-bottom][main-top]"b b b"1fr[main-bottom]/auto 1fr auto"#
+		);
+
+		assert_parse!(CssAtomSet::ATOMS, GridStyleValue, "none");
+		assert_parse!(CssAtomSet::ATOMS, GridStyleValue, "auto 1fr/auto 1fr auto");
+		assert_parse!(CssAtomSet::ATOMS, GridStyleValue, r#""a b""c d""#);
+		assert_parse!(CssAtomSet::ATOMS, GridStyleValue, "auto-flow/1fr 1fr");
+		assert_parse!(CssAtomSet::ATOMS, GridStyleValue, "auto-flow dense 40px/1fr");
+		assert_parse!(CssAtomSet::ATOMS, GridStyleValue, "100px 300px/auto-flow 200px");
+		assert_parse!(CssAtomSet::ATOMS, GridStyleValue, "repeat(3,1fr)/auto-flow dense");
+
+		assert_parse!(CssAtomSet::ATOMS, GridAutoFlowStyleValue, "row");
+		assert_parse!(CssAtomSet::ATOMS, GridAutoFlowStyleValue, "column dense");
+		assert_parse!(CssAtomSet::ATOMS, GridAutoFlowStyleValue, "dense");
+
+		assert_parse!(CssAtomSet::ATOMS, GridAreaStyleValue, "auto");
+		assert_parse!(CssAtomSet::ATOMS, GridAreaStyleValue, "main");
+		assert_parse!(CssAtomSet::ATOMS, GridAreaStyleValue, "1/2/3/4");
+		assert_parse!(CssAtomSet::ATOMS, GridAreaStyleValue, "1/span 2");
+		assert_parse!(CssAtomSet::ATOMS, GridRowStyleValue, "1/-1");
+		assert_parse!(CssAtomSet::ATOMS, GridRowStyleValue, "span 2");
+		assert_parse!(CssAtomSet::ATOMS, GridColumnStyleValue, "main-start/main-end");
 
 		assert_parse!(CssAtomSet::ATOMS, FlowToleranceStyleValue, "infinite");
 		assert_parse!(CssAtomSet::ATOMS, FlowToleranceStyleValue, "30px");
@@ -48,4 +337,38 @@ mod tests {
 		assert_parse!(CssAtomSet::ATOMS, ItemPackStyleValue, "dense balance");
 		assert_parse!(CssAtomSet::ATOMS, ItemPackStyleValue, "balance dense");
 	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, GridTemplateAreasStyleValue, r#""a b""c""#);
+		assert_parse_error!(CssAtomSet::ATOMS, GridTemplateAreasStyleValue, r#""a b a""#);
+		assert_parse_error!(CssAtomSet::ATOMS, GridTemplateAreasStyleValue, r#""a a""a b""#);
+		assert_parse_error!(CssAtomSet::ATOMS, GridTemplateAreasStyleValue, r#""a""b""a""#);
+		assert_parse_error!(CssAtomSet::ATOMS, GridTemplateAreasStyleValue, r#""a #""#);
+		assert_parse_error!(CssAtomSet::ATOMS, GridTemplateAreasStyleValue, r#""""#);
+		assert_parse_error!(CssAtomSet::ATOMS, GridTemplateAreasStyleValue, "auto");
+
+		assert_parse_error!(CssAtomSet::ATOMS, GridTemplateColumnsStyleValue, "[a]");
+		assert_parse_error!(CssAtomSet::ATOMS, GridTemplateColumnsStyleValue, "subgrid 1fr");
+		assert_parse_error!(CssAtomSet::ATOMS, GridTemplateColumnsStyleValue, "repeat(auto-fill,1fr)");
+		assert_parse_error!(CssAtomSet::ATOMS, GridTemplateColumnsStyleValue, "1fr repeat(auto-fill,100px)");
+		assert_parse_error!(CssAtomSet::ATOMS, GridTemplateRowsStyleValue, "fit-content(100px) repeat(auto-fit,50px)");
+		assert_parse_error!(
+			CssAtomSet::ATOMS,
+			GridTemplateColumnsStyleValue,
+			"repeat(auto-fill,100px) repeat(auto-fit,100px)"
+		);
+
+		assert_parse_error!(CssAtomSet::ATOMS, GridTemplateStyleValue, r#""a b""c""#);
+		assert_parse_error!(CssAtomSet::ATOMS, GridTemplateStyleValue, r#""a"/repeat(2,1fr)"#);
+		assert_parse_error!(CssAtomSet::ATOMS, GridTemplateStyleValue, "auto 1fr");
+
+		assert_parse_error!(CssAtomSet::ATOMS, GridStyleValue, "auto-flow");
+		assert_parse_error!(CssAtomSet::ATOMS, GridStyleValue, "dense/1fr");
+		assert_parse_error!(CssAtomSet::ATOMS, GridStyleValue, "1fr/dense");
+
+		assert_parse_error!(CssAtomSet::ATOMS, GridAutoFlowStyleValue, "row column");
+		assert_parse_error!(CssAtomSet::ATOMS, GridAreaStyleValue, "1/2/3/4/5");
+		assert_parse_error!(CssAtomSet::ATOMS, GridRowStyleValue, "1/2/3");
+	}
 }
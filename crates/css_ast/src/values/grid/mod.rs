@@ -35,103 +35,71 @@ use impls::*;
 #[derive(csskit_derives::NodeWithMetadata)]
 pub enum FlowToleranceStyleValue {}
 
-// /// Represents the style value for `grid` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid).
-// ///
-// /// CSS grid is a two-dimensional layout system, which lays content out in rows and columns.
-// ///
-// /// The grammar is defined as:
-// ///
-// /// ```text,ignore
-// /**<'grid-template'> |
-// <'grid-template-rows'> / [ auto-flow && dense? ] <'grid-auto-columns'>? |
-// [ auto-flow && dense? ] <'grid-auto-rows'>? / <'grid-template-columns'>*/
-// /// ```
-// ///
-// /// https://drafts.csswg.org/css-grid-3/#grid
-// #[syntax(
-//     " <'grid-template'> | <'grid-template-rows'> / [ auto-flow && dense? ] <'grid-auto-columns'>? | [ auto-flow && dense? ] <'grid-auto-rows'>? / <'grid-template-columns'> "
-// )]
-// #[derive(
-//     Parse,
-//     Peek,
-//     ToSpan,
-//     ToCursors,
-//     DeclarationMetadata,
-//     SemanticEq,
-//     Debug,
-//     Clone,
-//     PartialEq,
-//     Eq,
-//     PartialOrd,
-//     Ord,
-//     Hash,
-// )]
-// #[declaration_metadata(
-//     initial = "none",
-//     inherits = Unknown,
-//     applies_to = Grid,
-//     animation_type = Unknown,
-//     percentages = Unknown,
-//     longhands = GridAutoColumns|GridAutoFlow|GridAutoRows|GridTemplateAreas|GridTemplateColumns|GridTemplateRows,
-//     property_group = Grid,
-//     computed_value_type = Unknown,
-//     canonical_order = "per grammar",
-// )]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-// #[cfg_attr(
-//     feature = "css_feature_data",
-//     derive(ToCSSFeature),
-//     css_feature("css.properties.grid")
-// )]
-// #[cfg_attr(feature = "visitable", derive(Visitable), visit)]
-// #[derive(csskit_derives::NodeWithMetadata)]
-// pub enum GridStyleValue {}
+/// Represents the style value for `grid` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid).
+///
+/// CSS grid is a two-dimensional layout system, which lays content out in rows and columns.
+///
+/// The grammar is defined as:
+///
+/// ```text,ignore
+/**<'grid-template'> |
+<'grid-template-rows'> / [ auto-flow && dense? ] <'grid-auto-columns'>? |
+[ auto-flow && dense? ] <'grid-auto-rows'>? / <'grid-template-columns'>*/
+/// ```
+///
+/// https://drafts.csswg.org/css-grid-3/#grid
+#[syntax(
+	" <'grid-template'> | <'grid-template-rows'> / [ auto-flow && dense? ] <'grid-auto-columns'>? | [ auto-flow && dense? ] <'grid-auto-rows'>? / <'grid-template-columns'> "
+)]
+#[derive(
+	Peek, ToSpan, ToCursors, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[declaration_metadata(
+    initial = "none",
+    inherits = Unknown,
+    applies_to = Grid,
+    animation_type = Unknown,
+    percentages = Unknown,
+    longhands = GridAutoColumns|GridAutoFlow|GridAutoRows|GridTemplateAreas|GridTemplateColumns|GridTemplateRows,
+    property_group = Grid,
+    computed_value_type = Unknown,
+    canonical_order = "per grammar",
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "css_feature_data", derive(ToCSSFeature), css_feature("css.properties.grid"))]
+#[cfg_attr(feature = "visitable", derive(Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum GridStyleValue<'a> {}
 
-// /// Represents the style value for `grid-area` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-area).
-// ///
-// /// CSS grid is a two-dimensional layout system, which lays content out in rows and columns.
-// ///
-// /// The grammar is defined as:
-// ///
-// /// ```text,ignore
-// /// <grid-line> [ / <grid-line> ]{0,3}
-// /// ```
-// ///
-// /// https://drafts.csswg.org/css-grid-3/#grid-area
-// #[syntax(" <grid-line> [ / <grid-line> ]{0,3} ")]
-// #[derive(
-//     Parse,
-//     Peek,
-//     ToSpan,
-//     ToCursors,
-//     DeclarationMetadata,
-//     SemanticEq,
-//     Debug,
-//     Clone,
-//     PartialEq,
-//     Eq,
-//     PartialOrd,
-//     Ord,
-//     Hash,
-// )]
-// #[declaration_metadata(
-//     initial = "auto",
-//     applies_to = Unknown,
-//     animation_type = Discrete,
-//     longhands = GridColumnEnd|GridColumnStart|GridRowEnd|GridRowStart,
-//     property_group = Grid,
-//     computed_value_type = Unknown,
-//     canonical_order = "per grammar",
-// )]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-// #[cfg_attr(
-//     feature = "css_feature_data",
-//     derive(ToCSSFeature),
-//     css_feature("css.properties.grid-area")
-// )]
-// #[cfg_attr(feature = "visitable", derive(Visitable), visit)]
-// #[derive(csskit_derives::NodeWithMetadata)]
-// pub struct GridAreaStyleValue;
+/// Represents the style value for `grid-area` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-area).
+///
+/// CSS grid is a two-dimensional layout system, which lays content out in rows and columns.
+///
+/// The grammar is defined as:
+///
+/// ```text,ignore
+/// <grid-line> [ / <grid-line> ]{0,3}
+/// ```
+///
+/// https://drafts.csswg.org/css-grid-3/#grid-area
+#[syntax(" <grid-line> [ / <grid-line> ]{0,3} ")]
+#[derive(
+	Parse, Peek, ToSpan, ToCursors, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[declaration_metadata(
+    initial = "auto",
+    applies_to = Unknown,
+    animation_type = Discrete,
+    longhands = GridColumnEnd|GridColumnStart|GridRowEnd|GridRowStart,
+    property_group = Grid,
+    computed_value_type = Unknown,
+    canonical_order = "per grammar",
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "css_feature_data", derive(ToCSSFeature), css_feature("css.properties.grid-area"))]
+#[cfg_attr(feature = "visitable", derive(Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct GridAreaStyleValue;
 
 /// Represents the style value for `grid-auto-columns` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-auto-columns).
 ///
@@ -164,51 +132,35 @@ pub enum FlowToleranceStyleValue {}
 #[derive(csskit_derives::NodeWithMetadata)]
 pub struct GridAutoColumnsStyleValue<'a>;
 
-// /// Represents the style value for `grid-auto-flow` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-auto-flow).
-// ///
-// /// CSS grid is a two-dimensional layout system, which lays content out in rows and columns.
-// ///
-// /// The grammar is defined as:
-// ///
-// /// ```text,ignore
-// /// [ row | column ] || dense
-// /// ```
-// ///
-// /// https://drafts.csswg.org/css-grid-3/#grid-auto-flow
-// #[syntax(" [ row | column ] || dense ")]
-// #[derive(
-//     Parse,
-//     Peek,
-//     ToSpan,
-//     ToCursors,
-//     DeclarationMetadata,
-//     SemanticEq,
-//     Debug,
-//     Clone,
-//     PartialEq,
-//     Eq,
-//     PartialOrd,
-//     Ord,
-//     Hash,
-// )]
-// #[declaration_metadata(
-//     initial = "row",
-//     applies_to = Grid,
-//     animation_type = Discrete,
-//     shorthand_group = Grid,
-//     property_group = Grid,
-//     computed_value_type = Unknown,
-//     canonical_order = "per grammar",
-// )]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-// #[cfg_attr(
-//     feature = "css_feature_data",
-//     derive(ToCSSFeature),
-//     css_feature("css.properties.grid-auto-flow")
-// )]
-// #[cfg_attr(feature = "visitable", derive(Visitable), visit)]
-// #[derive(csskit_derives::NodeWithMetadata)]
-// pub struct GridAutoFlowStyleValue;
+/// Represents the style value for `grid-auto-flow` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-auto-flow).
+///
+/// CSS grid is a two-dimensional layout system, which lays content out in rows and columns.
+///
+/// The grammar is defined as:
+///
+/// ```text,ignore
+/// [ row | column ] || dense
+/// ```
+///
+/// https://drafts.csswg.org/css-grid-3/#grid-auto-flow
+#[syntax(" [ row | column ] || dense ")]
+#[derive(
+	Parse, Peek, ToSpan, ToCursors, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[declaration_metadata(
+    initial = "row",
+    applies_to = Grid,
+    animation_type = Discrete,
+    shorthand_group = Grid,
+    property_group = Grid,
+    computed_value_type = Unknown,
+    canonical_order = "per grammar",
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "css_feature_data", derive(ToCSSFeature), css_feature("css.properties.grid-auto-flow"))]
+#[cfg_attr(feature = "visitable", derive(Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct GridAutoFlowStyleValue;
 
 /// Represents the style value for `grid-auto-rows` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-auto-rows).
 ///
@@ -241,51 +193,35 @@ pub struct GridAutoColumnsStyleValue<'a>;
 #[derive(csskit_derives::NodeWithMetadata)]
 pub struct GridAutoRowsStyleValue<'a>;
 
-// /// Represents the style value for `grid-column` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-column).
-// ///
-// /// CSS grid is a two-dimensional layout system, which lays content out in rows and columns.
-// ///
-// /// The grammar is defined as:
-// ///
-// /// ```text,ignore
-// /// <grid-line> [ / <grid-line> ]?
-// /// ```
-// ///
-// /// https://drafts.csswg.org/css-grid-3/#grid-column
-// #[syntax(" <grid-line> [ / <grid-line> ]? ")]
-// #[derive(
-//     Parse,
-//     Peek,
-//     ToSpan,
-//     ToCursors,
-//     DeclarationMetadata,
-//     SemanticEq,
-//     Debug,
-//     Clone,
-//     PartialEq,
-//     Eq,
-//     PartialOrd,
-//     Ord,
-//     Hash,
-// )]
-// #[declaration_metadata(
-//     initial = "auto",
-//     applies_to = Unknown,
-//     animation_type = Discrete,
-//     longhands = GridColumnEnd|GridColumnStart,
-//     property_group = Grid,
-//     computed_value_type = Unknown,
-//     canonical_order = "per grammar",
-// )]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-// #[cfg_attr(
-//     feature = "css_feature_data",
-//     derive(ToCSSFeature),
-//     css_feature("css.properties.grid-column")
-// )]
-// #[cfg_attr(feature = "visitable", derive(Visitable), visit)]
-// #[derive(csskit_derives::NodeWithMetadata)]
-// pub struct GridColumnStyleValue;
+/// Represents the style value for `grid-column` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-column).
+///
+/// CSS grid is a two-dimensional layout system, which lays content out in rows and columns.
+///
+/// The grammar is defined as:
+///
+/// ```text,ignore
+/// <grid-line> [ / <grid-line> ]?
+/// ```
+///
+/// https://drafts.csswg.org/css-grid-3/#grid-column
+#[syntax(" <grid-line> [ / <grid-line> ]? ")]
+#[derive(
+	Parse, Peek, ToSpan, ToCursors, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[declaration_metadata(
+    initial = "auto",
+    applies_to = Unknown,
+    animation_type = Discrete,
+    longhands = GridColumnEnd|GridColumnStart,
+    property_group = Grid,
+    computed_value_type = Unknown,
+    canonical_order = "per grammar",
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "css_feature_data", derive(ToCSSFeature), css_feature("css.properties.grid-column"))]
+#[cfg_attr(feature = "visitable", derive(Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct GridColumnStyleValue;
 
 /// Represents the style value for `grid-column-end` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-column-end).
 ///
@@ -347,51 +283,35 @@ pub struct GridColumnEndStyleValue;
 #[derive(csskit_derives::NodeWithMetadata)]
 pub struct GridColumnStartStyleValue;
 
-// /// Represents the style value for `grid-row` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-row).
-// ///
-// /// CSS grid is a two-dimensional layout system, which lays content out in rows and columns.
-// ///
-// /// The grammar is defined as:
-// ///
-// /// ```text,ignore
-// /// <grid-line> [ / <grid-line> ]?
-// /// ```
-// ///
-// /// https://drafts.csswg.org/css-grid-3/#grid-row
-// #[syntax(" <grid-line> [ / <grid-line> ]? ")]
-// #[derive(
-//     Parse,
-//     Peek,
-//     ToSpan,
-//     ToCursors,
-//     DeclarationMetadata,
-//     SemanticEq,
-//     Debug,
-//     Clone,
-//     PartialEq,
-//     Eq,
-//     PartialOrd,
-//     Ord,
-//     Hash,
-// )]
-// #[declaration_metadata(
-//     initial = "auto",
-//     applies_to = Unknown,
-//     animation_type = Discrete,
-//     longhands = GridRowEnd|GridRowStart,
-//     property_group = Grid,
-//     computed_value_type = Unknown,
-//     canonical_order = "per grammar",
-// )]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-// #[cfg_attr(
-//     feature = "css_feature_data",
-//     derive(ToCSSFeature),
-//     css_feature("css.properties.grid-row")
-// )]
-// #[cfg_attr(feature = "visitable", derive(Visitable), visit)]
-// #[derive(csskit_derives::NodeWithMetadata)]
-// pub struct GridRowStyleValue;
+/// Represents the style value for `grid-row` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-row).
+///
+/// CSS grid is a two-dimensional layout system, which lays content out in rows and columns.
+///
+/// The grammar is defined as:
+///
+/// ```text,ignore
+/// <grid-line> [ / <grid-line> ]?
+/// ```
+///
+/// https://drafts.csswg.org/css-grid-3/#grid-row
+#[syntax(" <grid-line> [ / <grid-line> ]? ")]
+#[derive(
+	Parse, Peek, ToSpan, ToCursors, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[declaration_metadata(
+    initial = "auto",
+    applies_to = Unknown,
+    animation_type = Discrete,
+    longhands = GridRowEnd|GridRowStart,
+    property_group = Grid,
+    computed_value_type = Unknown,
+    canonical_order = "per grammar",
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "css_feature_data", derive(ToCSSFeature), css_feature("css.properties.grid-row"))]
+#[cfg_attr(feature = "visitable", derive(Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub struct GridRowStyleValue;
 
 /// Represents the style value for `grid-row-end` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-row-end).
 ///
@@ -453,57 +373,41 @@ pub struct GridRowEndStyleValue;
 #[derive(csskit_derives::NodeWithMetadata)]
 pub struct GridRowStartStyleValue;
 
-// /// Represents the style value for `grid-template` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-template).
-// ///
-// /// CSS grid is a two-dimensional layout system, which lays content out in rows and columns.
-// ///
-// /// The grammar is defined as:
-// ///
-// /// ```text,ignore
-// /**none |
-// [ <'grid-template-rows'> / <'grid-template-columns'> ] |
-// [ <line-names>? <string> <track-size>? <line-names>? ]+ [ / <explicit-track-list> ]?*/
-// /// ```
-// ///
-// /// https://drafts.csswg.org/css-grid-3/#grid-template
-// #[syntax(
-//     " none | [ <'grid-template-rows'> / <'grid-template-columns'> ] | [ <line-names>? <string> <track-size>? <line-names>? ]+ [ / <explicit-track-list> ]? "
-// )]
-// #[derive(
-//     Parse,
-//     Peek,
-//     ToSpan,
-//     ToCursors,
-//     DeclarationMetadata,
-//     SemanticEq,
-//     Debug,
-//     Clone,
-//     PartialEq,
-//     Eq,
-//     PartialOrd,
-//     Ord,
-//     Hash,
-// )]
-// #[declaration_metadata(
-//     initial = "none",
-//     inherits = Unknown,
-//     applies_to = Grid,
-//     animation_type = Unknown,
-//     percentages = Unknown,
-//     longhands = GridTemplateAreas|GridTemplateColumns|GridTemplateRows,
-//     property_group = Grid,
-//     computed_value_type = Unknown,
-//     canonical_order = "per grammar",
-// )]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-// #[cfg_attr(
-//     feature = "css_feature_data",
-//     derive(ToCSSFeature),
-//     css_feature("css.properties.grid-template")
-// )]
-// #[cfg_attr(feature = "visitable", derive(Visitable), visit)]
-// #[derive(csskit_derives::NodeWithMetadata)]
-// pub enum GridTemplateStyleValue<'a> {}
+/// Represents the style value for `grid-template` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-template).
+///
+/// CSS grid is a two-dimensional layout system, which lays content out in rows and columns.
+///
+/// The grammar is defined as:
+///
+/// ```text,ignore
+/**none |
+[ <'grid-template-rows'> / <'grid-template-columns'> ] |
+[ <line-names>? <string> <track-size>? <line-names>? ]+ [ / <explicit-track-list> ]?*/
+/// ```
+///
+/// https://drafts.csswg.org/css-grid-3/#grid-template
+#[syntax(
+	" none | [ <'grid-template-rows'> / <'grid-template-columns'> ] | [ <line-names>? <string> <track-size>? <line-names>? ]+ [ / <explicit-track-list> ]? "
+)]
+#[derive(
+	Peek, ToSpan, ToCursors, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[declaration_metadata(
+    initial = "none",
+    inherits = Unknown,
+    applies_to = Grid,
+    animation_type = Unknown,
+    percentages = Unknown,
+    longhands = GridTemplateAreas|GridTemplateColumns|GridTemplateRows,
+    property_group = Grid,
+    computed_value_type = Unknown,
+    canonical_order = "per grammar",
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "css_feature_data", derive(ToCSSFeature), css_feature("css.properties.grid-template"))]
+#[cfg_attr(feature = "visitable", derive(Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum GridTemplateStyleValue<'a> {}
 
 /// Represents the style value for `grid-template-areas` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-template-areas).
 ///
@@ -518,7 +422,7 @@ pub struct GridRowStartStyleValue;
 /// https://drafts.csswg.org/css-grid-3/#grid-template-areas
 #[syntax(" none | <string>+ ")]
 #[derive(
-	Parse, Peek, ToSpan, ToCursors, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+	Peek, ToSpan, ToCursors, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
 )]
 #[declaration_metadata(
     initial = "none",
@@ -535,99 +439,67 @@ pub struct GridRowStartStyleValue;
 #[derive(csskit_derives::NodeWithMetadata)]
 pub struct GridTemplateAreasStyleValue<'a>;
 
-// /// Represents the style value for `grid-template-columns` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-template-columns).
-// ///
-// /// CSS grid is a two-dimensional layout system, which lays content out in rows and columns.
-// ///
-// /// The grammar is defined as:
-// ///
-// /// ```text,ignore
-// /// none | <track-list> | <auto-track-list> | subgrid <line-name-list>?
-// /// ```
-// ///
-// /// https://drafts.csswg.org/css-grid-3/#grid-template-columns
-// #[syntax(" none | <track-list> | <auto-track-list> | subgrid <line-name-list>? ")]
-// #[derive(
-//     Parse,
-//     Peek,
-//     ToSpan,
-//     ToCursors,
-//     DeclarationMetadata,
-//     SemanticEq,
-//     Debug,
-//     Clone,
-//     PartialEq,
-//     Eq,
-//     PartialOrd,
-//     Ord,
-//     Hash,
-// )]
-// #[declaration_metadata(
-//     initial = "none",
-//     applies_to = Grid,
-//     animation_type = Discrete,
-//     percentages = ContentArea,
-//     shorthand_group = Grid,
-//     property_group = Grid,
-//     computed_value_type = Unknown,
-//     canonical_order = "per grammar",
-// )]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-// #[cfg_attr(
-//     feature = "css_feature_data",
-//     derive(ToCSSFeature),
-//     css_feature("css.properties.grid-template-columns")
-// )]
-// #[cfg_attr(feature = "visitable", derive(Visitable), visit)]
-// #[derive(csskit_derives::NodeWithMetadata)]
-// pub enum GridTemplateColumnsStyleValue {}
+/// Represents the style value for `grid-template-columns` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-template-columns).
+///
+/// CSS grid is a two-dimensional layout system, which lays content out in rows and columns.
+///
+/// The grammar is defined as:
+///
+/// ```text,ignore
+/// none | <track-list> | <auto-track-list> | subgrid <line-name-list>? | masonry
+/// ```
+///
+/// https://drafts.csswg.org/css-grid-3/#grid-template-columns
+#[syntax(" none | <track-list> | <auto-track-list> | subgrid <line-name-list>? | masonry ")]
+#[derive(
+	Peek, ToSpan, ToCursors, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[declaration_metadata(
+    initial = "none",
+    applies_to = Grid,
+    animation_type = Discrete,
+    percentages = ContentArea,
+    shorthand_group = Grid,
+    property_group = Grid,
+    computed_value_type = Unknown,
+    canonical_order = "per grammar",
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "css_feature_data", derive(ToCSSFeature), css_feature("css.properties.grid-template-columns"))]
+#[cfg_attr(feature = "visitable", derive(Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum GridTemplateColumnsStyleValue<'a> {}
 
-// /// Represents the style value for `grid-template-rows` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-template-rows).
-// ///
-// /// CSS grid is a two-dimensional layout system, which lays content out in rows and columns.
-// ///
-// /// The grammar is defined as:
-// ///
-// /// ```text,ignore
-// /// none | <track-list> | <auto-track-list> | subgrid <line-name-list>?
-// /// ```
-// ///
-// /// https://drafts.csswg.org/css-grid-3/#grid-template-rows
-// #[syntax(" none | <track-list> | <auto-track-list> | subgrid <line-name-list>? ")]
-// #[derive(
-//     Parse,
-//     Peek,
-//     ToSpan,
-//     ToCursors,
-//     DeclarationMetadata,
-//     SemanticEq,
-//     Debug,
-//     Clone,
-//     PartialEq,
-//     Eq,
-//     PartialOrd,
-//     Ord,
-//     Hash,
-// )]
-// #[declaration_metadata(
-//     initial = "none",
-//     applies_to = Grid,
-//     animation_type = Discrete,
-//     percentages = ContentArea,
-//     shorthand_group = Grid,
-//     property_group = Grid,
-//     computed_value_type = Unknown,
-//     canonical_order = "per grammar",
-// )]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-// #[cfg_attr(
-//     feature = "css_feature_data",
-//     derive(ToCSSFeature),
-//     css_feature("css.properties.grid-template-rows")
-// )]
-// #[cfg_attr(feature = "visitable", derive(Visitable), visit)]
-// #[derive(csskit_derives::NodeWithMetadata)]
-// pub enum GridTemplateRowsStyleValue {}
+/// Represents the style value for `grid-template-rows` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-template-rows).
+///
+/// CSS grid is a two-dimensional layout system, which lays content out in rows and columns.
+///
+/// The grammar is defined as:
+///
+/// ```text,ignore
+/// none | <track-list> | <auto-track-list> | subgrid <line-name-list>? | masonry
+/// ```
+///
+/// https://drafts.csswg.org/css-grid-3/#grid-template-rows
+#[syntax(" none | <track-list> | <auto-track-list> | subgrid <line-name-list>? | masonry ")]
+#[derive(
+	Peek, ToSpan, ToCursors, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[declaration_metadata(
+    initial = "none",
+    applies_to = Grid,
+    animation_type = Discrete,
+    percentages = ContentArea,
+    shorthand_group = Grid,
+    property_group = Grid,
+    computed_value_type = Unknown,
+    canonical_order = "per grammar",
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[cfg_attr(feature = "css_feature_data", derive(ToCSSFeature), css_feature("css.properties.grid-template-rows"))]
+#[cfg_attr(feature = "visitable", derive(Visitable), visit)]
+#[derive(csskit_derives::NodeWithMetadata)]
+pub enum GridTemplateRowsStyleValue<'a> {}
 
 // /// Represents the style value for `item-cross` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#item-cross).
 // ///
@@ -1 +1,29 @@
+#[cfg(test)]
+mod tests {
+	use super::super::*;
+	use crate::CssAtomSet;
+	use css_parse::{assert_parse, assert_parse_error};
 
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<AnimationRangeStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<AnimationRangeStartStyleValue>(), 32);
+		assert_eq!(std::mem::size_of::<AnimationRangeEndStyleValue>(), 32);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CssAtomSet::ATOMS, AnimationRangeStyleValue, "normal");
+		assert_parse!(CssAtomSet::ATOMS, AnimationRangeStyleValue, "entry 10% exit 90%,cover");
+		assert_parse!(CssAtomSet::ATOMS, AnimationRangeStyleValue, "contain 0% 50%");
+		assert_parse!(CssAtomSet::ATOMS, AnimationRangeStartStyleValue, "entry-crossing 25%,100px");
+		assert_parse!(CssAtomSet::ATOMS, AnimationRangeEndStyleValue, "normal");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CssAtomSet::ATOMS, AnimationRangeStyleValue, "entry 10% exit 90% cover");
+		assert_parse_error!(CssAtomSet::ATOMS, AnimationRangeStartStyleValue, "entry 10% exit");
+		assert_parse_error!(CssAtomSet::ATOMS, AnimationRangeEndStyleValue, "10deg");
+	}
+}
@@ -18,7 +18,7 @@ use impls::*;
 /// ```
 ///
 /// https://drafts.csswg.org/scroll-animations-1/#animation-range
-#[syntax(" [ <'animation-range-start'> <'animation-range-end'>? ]# ")]
+#[syntax(" <timeline-range># ")]
 #[derive(
 	Parse, Peek, ToSpan, ToCursors, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
 )]
@@ -50,7 +50,7 @@ pub struct AnimationRangeStyleValue<'a>;
 /// ```
 ///
 /// https://drafts.csswg.org/scroll-animations-1/#animation-range-end
-#[syntax(" [ normal | <length-percentage> | <timeline-range-name> <length-percentage>? ]# ")]
+#[syntax(" <timeline-range-boundary># ")]
 #[derive(
 	Parse, Peek, ToSpan, ToCursors, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
 )]
@@ -80,7 +80,7 @@ pub struct AnimationRangeEndStyleValue<'a>;
 /// ```
 ///
 /// https://drafts.csswg.org/scroll-animations-1/#animation-range-start
-#[syntax(" [ normal | <length-percentage> | <timeline-range-name> <length-percentage>? ]# ")]
+#[syntax(" <timeline-range-boundary># ")]
 #[derive(
 	Parse, Peek, ToSpan, ToCursors, DeclarationMetadata, SemanticEq, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash,
 )]
@@ -1,8 +1,45 @@
+use super::{ViewTransitionClassStyleValue, ViewTransitionNameStyleValue};
+use crate::{NoneOr, PtClassSelector, PtNameSelector};
+use css_parse::Cursor;
+
+impl ViewTransitionNameStyleValue {
+	/// Whether an element with this `view-transition-name` is matched by the name of a view transition pseudo-element,
+	/// such as `hero` in `::view-transition-group(hero)`. The `*` selector matches any name other than `none`. Names
+	/// are compared as written, so both must have been parsed from `source_text`.
+	pub fn matches(&self, selector: &PtNameSelector, source_text: &str) -> bool {
+		match (&self.0, selector) {
+			(NoneOr::None(_), _) => false,
+			(NoneOr::Some(_), PtNameSelector::Wildcard(_)) => true,
+			(NoneOr::Some(name), PtNameSelector::Name(selector)) => {
+				Cursor::from(*name).str_slice(source_text) == Cursor::from(*selector).str_slice(source_text)
+			}
+		}
+	}
+}
+
+impl<'a> ViewTransitionClassStyleValue<'a> {
+	/// Whether an element with this `view-transition-class` is matched by the classes of a view transition
+	/// pseudo-element, such as `.card.large` in `::view-transition-old(hero.card.large)`, which requires every class.
+	/// Classes are compared as written, so both must have been parsed from `source_text`.
+	pub fn matches(&self, selectors: &[PtClassSelector], source_text: &str) -> bool {
+		let classes = match &self.0 {
+			NoneOr::None(_) => return selectors.is_empty(),
+			NoneOr::Some(classes) => classes,
+		};
+		selectors.iter().all(|selector| {
+			let selector = Cursor::from(selector.name).str_slice(source_text);
+			classes.iter().any(|class| Cursor::from(*class).str_slice(source_text) == selector)
+		})
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::super::*;
-	use crate::CssAtomSet;
-	use css_parse::assert_parse;
+	use crate::{CssAtomSet, PtNameAndClassSelector};
+	use bumpalo::Bump;
+	use css_lexer::Lexer;
+	use css_parse::{Parser, assert_parse};
 
 	#[test]
 	pub fn size_test() {
@@ -22,4 +59,28 @@ mod tests {
 		assert_parse!(CssAtomSet::ATOMS, ViewTransitionGroupStyleValue, "nearest");
 		assert_parse!(CssAtomSet::ATOMS, ViewTransitionGroupStyleValue, "foo");
 	}
+
+	#[test]
+	fn test_matches_selectors() {
+		fn matches(name: &str, class: &str, selector: &str) -> bool {
+			let source_text = format!("{name} {class} {selector}");
+			let bump = Bump::default();
+			let lexer = Lexer::new(&CssAtomSet::ATOMS, &source_text);
+			let mut p = Parser::new(&bump, &source_text, lexer);
+			let name = p.parse::<ViewTransitionNameStyleValue>().unwrap();
+			let class = p.parse::<ViewTransitionClassStyleValue>().unwrap();
+			let selector = p.parse::<PtNameAndClassSelector>().unwrap();
+			selector.name.is_none_or(|selector| name.matches(&selector, &source_text))
+				&& class.matches(&selector.classes, &source_text)
+		}
+		assert!(matches("hero", "none", "hero"));
+		assert!(matches("hero", "none", "*"));
+		assert!(!matches("hero", "none", "card"));
+		assert!(!matches("none", "none", "*"));
+		assert!(matches("hero", "card large", "hero.card"));
+		assert!(matches("hero", "card large", "*.large.card"));
+		assert!(matches("hero", "card", ".card"));
+		assert!(!matches("hero", "card", "hero.card.large"));
+		assert!(!matches("hero", "none", "hero.card"));
+	}
 }
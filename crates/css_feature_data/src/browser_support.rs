@@ -13,18 +13,49 @@ pub struct BrowserSupport {
 
 impl BrowserSupport {
 	pub fn supports(&self, version: NamedBrowserVersion) -> bool {
-		match version {
-			NamedBrowserVersion::Chrome(ver) => ver >= self.chrome,
-			NamedBrowserVersion::ChromeAndroid(ver) => ver >= self.chrome_android,
-			NamedBrowserVersion::Edge(ver) => ver >= self.edge,
-			NamedBrowserVersion::Firefox(ver) => ver >= self.firefox,
-			NamedBrowserVersion::FirefoxAndroid(ver) => ver >= self.firefox_android,
-			NamedBrowserVersion::Safari(ver) => ver >= self.safari,
-			NamedBrowserVersion::SafariIos(ver) => ver >= self.safari_ios,
-			_ => {
-				dbg!(version);
-				false
-			}
-		}
+		self.supported_by(version).unwrap_or(false)
+	}
+
+	/// Checks whether the given browser version supports this feature, returning None for browsers which there is no
+	/// data for. Browsers which have never supported the feature are recorded as version `0.0`.
+	pub fn supported_by(&self, version: NamedBrowserVersion) -> Option<bool> {
+		let (ver, since) = match version {
+			NamedBrowserVersion::Chrome(ver) => (ver, self.chrome),
+			NamedBrowserVersion::ChromeAndroid(ver) => (ver, self.chrome_android),
+			NamedBrowserVersion::Edge(ver) => (ver, self.edge),
+			NamedBrowserVersion::Firefox(ver) => (ver, self.firefox),
+			NamedBrowserVersion::FirefoxAndroid(ver) => (ver, self.firefox_android),
+			NamedBrowserVersion::Safari(ver) => (ver, self.safari),
+			NamedBrowserVersion::SafariIos(ver) => (ver, self.safari_ios),
+			_ => return None,
+		};
+		Some(since != BrowserVersion(0, 0) && ver >= since)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{BrowserVersion, CSSFeature, NamedBrowserVersion};
+
+	#[test]
+	fn test_supported_by() {
+		let field_sizing = CSSFeature::by_property_name("field-sizing").unwrap();
+		assert_eq!(
+			field_sizing.browser_support.supported_by(NamedBrowserVersion::Chrome(BrowserVersion(123, 0))),
+			Some(true)
+		);
+		assert_eq!(
+			field_sizing.browser_support.supported_by(NamedBrowserVersion::Chrome(BrowserVersion(122, 0))),
+			Some(false)
+		);
+		assert_eq!(
+			field_sizing.browser_support.supported_by(NamedBrowserVersion::Firefox(BrowserVersion(140, 0))),
+			Some(false)
+		);
+		assert_eq!(
+			field_sizing.browser_support.supported_by(NamedBrowserVersion::Samsung(BrowserVersion(20, 0))),
+			None
+		);
+		assert!(!field_sizing.supports(NamedBrowserVersion::Firefox(BrowserVersion(140, 0))));
 	}
 }
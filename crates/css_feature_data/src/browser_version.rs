@@ -1,6 +1,6 @@
 #[cfg(feature = "browserslist")]
 use browserslist::Distrib;
-use core::num::ParseIntError;
+use core::{fmt, num::ParseIntError, str::FromStr};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct BrowserVersion(pub u16, pub u16);
@@ -65,14 +65,10 @@ pub enum NamedBrowserVersionErr {
 	UnknownNameError(String),
 }
 
-#[cfg(feature = "browserslist")]
-impl TryFrom<Distrib> for NamedBrowserVersion {
-	type Error = NamedBrowserVersionErr;
-
-	fn try_from(value: Distrib) -> Result<Self, Self::Error> {
-		let ver =
-			BrowserVersion::from_string_as_range(value.version()).map_err(NamedBrowserVersionErr::ParseIntError)?.0;
-		match value.name() {
+impl NamedBrowserVersion {
+	/// Creates a [NamedBrowserVersion] from a browser name as used by browserslist, such as `chrome` or `ios_saf`.
+	pub fn from_name(name: &str, ver: BrowserVersion) -> Result<Self, NamedBrowserVersionErr> {
+		match name {
 			"chrome" => Ok(Self::Chrome(ver)),
 			"chrome_android" | "and_chr" => Ok(Self::ChromeAndroid(ver)),
 			"edge" => Ok(Self::Edge(ver)),
@@ -95,6 +91,39 @@ impl TryFrom<Distrib> for NamedBrowserVersion {
 	}
 }
 
+/// Parses a browser name and version separated by a space, such as `chrome 120` or `safari 17.4`.
+impl FromStr for NamedBrowserVersion {
+	type Err = NamedBrowserVersionErr;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (name, version) = s.trim().split_once(' ').unwrap_or((s.trim(), ""));
+		let ver = BrowserVersion::from_string(version.trim()).map_err(NamedBrowserVersionErr::ParseIntError)?;
+		Self::from_name(&name.to_lowercase(), ver)
+	}
+}
+
+impl fmt::Display for NamedBrowserVersionErr {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::ParseIntError(err) => write!(f, "invalid browser version: {err}"),
+			Self::UnknownNameError(name) => write!(f, "unknown browser {name:?}"),
+		}
+	}
+}
+
+impl std::error::Error for NamedBrowserVersionErr {}
+
+#[cfg(feature = "browserslist")]
+impl TryFrom<Distrib> for NamedBrowserVersion {
+	type Error = NamedBrowserVersionErr;
+
+	fn try_from(value: Distrib) -> Result<Self, Self::Error> {
+		let ver =
+			BrowserVersion::from_string_as_range(value.version()).map_err(NamedBrowserVersionErr::ParseIntError)?.0;
+		Self::from_name(value.name(), ver)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -142,6 +171,18 @@ mod tests {
 		assert!(BrowserVersion(0, 2) > BrowserVersion(0, 1));
 	}
 
+	#[test]
+	fn test_namedbrowserversion_from_str() {
+		assert_eq!("chrome 120".parse(), Ok(NamedBrowserVersion::Chrome(BrowserVersion(120, 0))));
+		assert_eq!("Safari 17.4".parse(), Ok(NamedBrowserVersion::Safari(BrowserVersion(17, 4))));
+		assert_eq!("ios_saf 16".parse(), Ok(NamedBrowserVersion::SafariIos(BrowserVersion(16, 0))));
+		assert!("chrome".parse::<NamedBrowserVersion>().is_err());
+		assert_eq!(
+			"netscape 4".parse::<NamedBrowserVersion>(),
+			Err(NamedBrowserVersionErr::UnknownNameError("netscape".into()))
+		);
+	}
+
 	#[test]
 	fn test_browserversion_in_range() {
 		assert!(BrowserVersion(0, 2).in_range((BrowserVersion(0, 0), BrowserVersion(1, 0))));
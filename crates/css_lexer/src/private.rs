@@ -430,7 +430,7 @@ impl<'a> CharsConsumer for Chars<'a> {
 	fn consume_hash_token(&mut self, atoms: &dyn DynAtomSet) -> Token {
 		self.next();
 		let hex_reader = self.clone();
-		let first_is_ascii = is_ident(self.peek_nth(0));
+		let first_is_ascii = is_ident(self.peek_nth(0)) || is_escape_sequence(self.peek_nth(0), self.peek_nth(1));
 		let (len, contains_non_lower_ascii, _, contains_escape, _, _) = self.consume_ident_sequence(atoms);
 		let mut hex_value = 0;
 		let mut is_hex = false;
@@ -2,7 +2,11 @@ use crate::{
 	AssociatedWhitespaceRules, CommentStyle, CowStr, Cursor, Kind, KindSet, QuoteStyle, SourceOffset, Span, ToSpan,
 	Token,
 	small_str_buf::SmallStrBuf,
-	syntax::{ParseEscape, is_newline},
+	syntax::{
+		ParseEscape,
+		identifier::{is_ident, is_ident_start},
+		is_newline,
+	},
 };
 use allocator_api2::{alloc::Allocator, boxed::Box, vec::Vec};
 use std::char::REPLACEMENT_CHARACTER;
@@ -268,18 +272,31 @@ impl<'a> SourceCursor<'a> {
 
 		let mut chars = source.chars().peekable();
 		let mut i = 0;
+		let mut last = None;
 		while let Some(c) = chars.next() {
 			if c == '\0' {
 				write!(f, "{}", REPLACEMENT_CHARACTER)?;
+				last = Some(REPLACEMENT_CHARACTER);
 				i += 1;
 			} else if c == '\\' {
-				i += 1;
-				let (ch, n) = source[i..].chars().parse_escape_sequence();
-				write!(f, "{}", if ch == '\0' { REPLACEMENT_CHARACTER } else { ch })?;
-				i += n as usize;
+				let (ch, n) = source[i + 1..].chars().parse_escape_sequence();
+				let ch = if ch == '\0' { REPLACEMENT_CHARACTER } else { ch };
+				// Only unescape characters which are valid where they are, for example `#\#` or `.\31 0` must stay
+				// escaped. Hash tokens may start with any name character.
+				let starts_ident = token.kind() != Kind::Hash && (last.is_none() || last == Some('-') && i == 1);
+				let valid =
+					if starts_ident { is_ident_start(ch) || (last.is_none() && ch == '-') } else { is_ident(ch) };
+				if valid {
+					write!(f, "{}", ch)?;
+				} else {
+					f.write_str(&source[i..i + 1 + n as usize])?;
+				}
+				last = Some(ch);
+				i += 1 + n as usize;
 				chars = source[i..].chars().peekable();
 			} else {
 				write!(f, "{}", c)?;
+				last = Some(c);
 				i += c.len_utf8();
 			}
 		}
@@ -815,6 +832,20 @@ mod test {
 		assert_eq!(format!("{}", sc.compact()), "foo");
 	}
 
+	#[test]
+	fn test_compact_keeps_required_escapes() {
+		let compact = |source: &str| {
+			let c = crate::Lexer::new(&crate::EmptyAtomSet::ATOMS, source).next().unwrap();
+			format!("{}", SourceCursor::from(c, c.str_slice(source)).compact())
+		};
+		assert_eq!(compact(r"a\:b\/c"), r"a\:b\/c");
+		assert_eq!(compact(r"#\#"), r"#\#");
+		assert_eq!(compact(r"\31 0"), r"\31 0");
+		assert_eq!(compact(r"-\31 0"), r"-\31 0");
+		assert_eq!(compact(r"\2d foo"), "-foo");
+		assert_eq!(compact(r"#\31 0"), "#10");
+	}
+
 	#[test]
 	fn test_compact_function_with_escapes() {
 		let c = Cursor::new(SourceOffset(0), Token::new_ident(false, false, true, 0, 6));
@@ -219,9 +219,7 @@ mod test {
 
 	#[test]
 	fn test_span_vec() {
-		let mut vec = vec![];
-		vec.push(Span::new(SourceOffset(3), SourceOffset(10)));
-		vec.push(Span::new(SourceOffset(13), SourceOffset(15)));
+		let vec = vec![Span::new(SourceOffset(3), SourceOffset(10)), Span::new(SourceOffset(13), SourceOffset(15))];
 		assert_eq!(vec.to_span(), Span::new(SourceOffset(3), SourceOffset(15)));
 	}
 }
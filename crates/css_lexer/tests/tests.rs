@@ -1206,6 +1206,16 @@ fn tokenizes_hex_values_correctly() {
 	assert_eq!(format!("{:x}", token.hex_value()), "ccaaffee");
 }
 
+#[test]
+fn tokenizes_escaped_hashes_as_id_like() {
+	let mut lexer = Lexer::new(&EmptyAtomSet::ATOMS, "#\\# #a #\\31 0");
+	assert!(lexer.advance().hash_is_id_like());
+	lexer.advance();
+	assert!(lexer.advance().hash_is_id_like());
+	lexer.advance();
+	assert!(lexer.advance().hash_is_id_like());
+}
+
 #[test]
 fn tokenizes_atoms_correctly() {
 	#[derive(AtomSet, Debug, Default, PartialEq, Copy, Clone)]
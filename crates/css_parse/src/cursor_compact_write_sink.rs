@@ -33,8 +33,11 @@ impl<'a, T: SourceCursorSink<'a>> CursorCompactWriteSink<'a, T> {
 				&& (c == REDUNDANT_SEMI_KINDSET || self.last_token.is_some_and(|c| c == REDUNDANT_SEMI_KINDSET));
 			let no_whitespace_after_last =
 				prev == Kind::Whitespace && self.last_token.is_some_and(|c| c == NO_WHITESPACE_AFTER_KINDSET);
+			// Whitespace before a `.`, `*` or `&` may be a descendant combinator, as in `a .b`.
+			let is_combinator = c.token() == '.' || c.token() == '*' || c.token() == '&';
 			let is_redundant_whitespace = self.last_token.is_none()
-				|| prev == Kind::Whitespace && (c == NO_WHITESPACE_BEFORE_KINDSET || no_whitespace_after_last);
+				|| prev == Kind::Whitespace
+					&& ((c == NO_WHITESPACE_BEFORE_KINDSET && !is_combinator) || no_whitespace_after_last);
 			if !is_redundant_semi && !is_redundant_whitespace {
 				self.last_token = Some(prev.token());
 				self.sink.append(prev.compact());
@@ -119,6 +122,11 @@ mod test {
 		assert_format!("div dialog:modal > td p a", "div dialog:modal > td p a");
 	}
 
+	#[test]
+	fn test_keeps_descendant_combinators() {
+		assert_format!("a .b, .c *, .d &", "a .b,.c *,.d &");
+	}
+
 	#[test]
 	fn test_compacts_whitespace() {
 		assert_format!(
@@ -48,6 +48,11 @@ impl<'a, S: CursorSink> CursorSink for CursorInterleaveSink<'a, S> {
 			if associated_cursor.span().start() > c.span().start() {
 				break;
 			}
+			// The associated cursor was passed over without being appended (for example whitespace which the AST does
+			// not output), so emit its trivia here rather than losing it.
+			for cursor in trivia {
+				self.sink.append(*cursor);
+			}
 			self.current_index += 1;
 		}
 
@@ -65,3 +70,48 @@ impl<'a, S: CursorSink> CursorSink for CursorInterleaveSink<'a, S> {
 		self.sink.append(c);
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{CursorWriteSink, EmptyAtomSet};
+	use bumpalo::{Bump, collections::Vec};
+	use css_lexer::Lexer;
+
+	/// Writes the non-trivia cursors of `source_text` through a [CursorInterleaveSink], interleaving each comment with
+	/// the cursor that follows it.
+	fn interleave(source_text: &str, skip: impl Fn(Cursor) -> bool) -> String {
+		let bump = Bump::default();
+		let mut trivia = Vec::new_in(&bump);
+		let mut interleave = Vec::new_in(&bump);
+		let mut cursors = Vec::new_in(&bump);
+		for c in Lexer::new(&EmptyAtomSet::ATOMS, source_text) {
+			if c == Kind::Comment {
+				trivia.push(c);
+				continue;
+			}
+			if !trivia.is_empty() {
+				interleave.push((std::mem::replace(&mut trivia, Vec::new_in(&bump)), c));
+			}
+			cursors.push(c);
+		}
+		let mut str = String::new();
+		let mut write_sink = CursorWriteSink::new(source_text, &mut str);
+		let mut sink = CursorInterleaveSink::new(&mut write_sink, &interleave);
+		for c in cursors.into_iter().filter(|c| !skip(*c)) {
+			sink.append(c);
+		}
+		str
+	}
+
+	#[test]
+	fn test_interleaves_trivia() {
+		assert_eq!(interleave("/*a*/foo/*b*/bar", |_| false), "/*a*/foo/*b*/bar");
+	}
+
+	#[test]
+	fn test_interleaves_trivia_of_skipped_cursors() {
+		// The whitespace which `/*b*/` is associated with is never appended, but the comment must still be written.
+		assert_eq!(interleave("foo/*b*/ bar", |c| c == Kind::Whitespace), "foo/*b*/bar");
+	}
+}
@@ -16,28 +16,29 @@ impl<'a, T: Write> CursorWriteSink<'a, T> {
 		Self { source_text, writer, last_token: None, err: Ok(()) }
 	}
 
-	fn write(&mut self, c: Cursor, source: &'a str) -> Result {
+	fn write(&mut self, token: Token, str: &str) -> Result {
 		self.err?;
 		if let Some(last) = self.last_token
-			&& last.needs_separator_for(c.token())
+			&& last.needs_separator_for(token)
 		{
 			self.writer.write_char(' ')?;
 		}
-		self.last_token = Some(c.token());
-		self.writer.write_str(c.str_slice(source))?;
+		self.last_token = Some(token);
+		self.writer.write_str(str)?;
 		Ok(())
 	}
 }
 
 impl<'a, T: Write> CursorSink for CursorWriteSink<'a, T> {
 	fn append(&mut self, c: Cursor) {
-		self.err = self.write(c, self.source_text);
+		self.err = self.write(c.token(), c.str_slice(self.source_text));
 	}
 }
 
 impl<'a, T: Write> SourceCursorSink<'a> for CursorWriteSink<'a, T> {
 	fn append(&mut self, c: SourceCursor<'a>) {
-		self.err = self.write(c.cursor(), c.source());
+		// A SourceCursor's source is the text of the cursor alone, rather than the whole source text.
+		self.err = self.write(c.token(), c.source());
 	}
 }
 
@@ -59,4 +60,20 @@ mod test {
 		parser.parse_entirely::<ComponentValues>().output.unwrap().to_cursors(&mut stream);
 		assert_eq!(str, "foo{bar:baz();}");
 	}
+
+	#[test]
+	fn test_source_cursors() {
+		let source_text = "foo{bar:baz();}";
+		let bump = Bump::default();
+		let mut str = String::new();
+		let mut stream = CursorWriteSink::new(source_text, &mut str);
+		let lexer = Lexer::new(&EmptyAtomSet::ATOMS, source_text);
+		let mut parser = Parser::new(&bump, source_text, lexer);
+		let mut cursors = bumpalo::collections::Vec::new_in(&bump);
+		parser.parse_entirely::<ComponentValues>().output.unwrap().to_cursors(&mut cursors);
+		for c in cursors.iter().copied() {
+			SourceCursorSink::append(&mut stream, SourceCursor::from(c, c.str_slice(source_text)));
+		}
+		assert_eq!(str, "foo{bar:baz();}");
+	}
 }
@@ -27,6 +27,21 @@ impl<'a, T: ToCursors> ParserReturn<'a, T> {
 		self.with_trivia = true;
 		self
 	}
+
+	/// Returns true if trivia will be interleaved, see [ParserReturn::with_trivia()].
+	pub fn keeps_trivia(&self) -> bool {
+		self.with_trivia
+	}
+
+	/// Like [ParserReturn::with_trivia()], but only the trivia [Cursors][Cursor] for which `f` returns true will be
+	/// interleaved. This is useful for keeping some comments (e.g. license comments) while discarding whitespace.
+	pub fn retain_trivia(mut self, mut f: impl FnMut(Cursor) -> bool) -> Self {
+		for (trivia, _) in self.trivia.iter_mut() {
+			trivia.retain(|c| f(*c));
+		}
+		self.trivia.retain(|(trivia, _)| !trivia.is_empty());
+		self.with_trivia()
+	}
 }
 
 impl<T: ToCursors> ToCursors for ParserReturn<'_, T> {
@@ -239,7 +239,15 @@ macro_rules! custom_delim {
 			}
 		}
 
+		impl $ident {
+			pub fn associated_whitespace(&self) -> $crate::AssociatedWhitespaceRules {
+				self.0.associated_whitespace()
+			}
 
+			pub fn with_associated_whitespace(&self, rules: $crate::AssociatedWhitespaceRules) -> Self {
+				Self(self.0.with_associated_whitespace(rules))
+			}
+		}
 
 		impl From<$ident> for $crate::Cursor {
 			fn from(value: $ident) -> Self {
@@ -31,7 +31,7 @@ pub trait CompoundSelector<'a>: Sized + Parse<'a> {
 	{
 		let mut components = Vec::new_in(p.bump());
 		// Trim leading whitespace
-		p.consume_trivia();
+		p.consume_trivia_as_leading();
 		while let Some(component) = Self::parse_compound_selector_part(p)? {
 			components.push(component);
 		}
@@ -121,9 +121,13 @@ pub trait SelectorComponent<'a>: Sized {
 				_ => {
 					p.set_skip(skip);
 					let value = p.parse::<Self::Combinator>().map(Self::build_combinator);
-					p.set_skip(KindSet::WHITESPACE);
-					p.consume_trivia_as_leading();
-					p.set_skip(skip);
+					// Whitespace following the nesting selector is a descendant combinator, as in `& .foo`, so must be
+					// kept.
+					if t != '&' {
+						p.set_skip(KindSet::WHITESPACE);
+						p.consume_trivia_as_leading();
+						p.set_skip(skip);
+					}
 					value
 				}
 			},
@@ -159,13 +163,14 @@ pub trait SelectorComponent<'a>: Sized {
 			_ => {
 				// If this is whitespace, check if there's an explicit combinator ahead.
 				// Combinators cannot be adjacent, so whitespace before an explicit
-				// combinator (>, +, ~, ||, &) should be consumed as trivia, not parsed
-				// as a Descendant combinator.
+				// combinator (>, +, ~, ||) should be consumed as trivia, not parsed
+				// as a Descendant combinator. The nesting selector (&) is not a combinator, so
+				// whitespace before it is a Descendant combinator.
 				if t.kind() == Kind::Whitespace {
 					p.set_skip(KindSet::TRIVIA);
 					let next = p.peek_n(1);
 					let next_is_explicit_combinator = match next.token().kind() {
-						Kind::Delim => matches!(next.token().char(), Some('>' | '+' | '~' | '|' | '&')),
+						Kind::Delim => matches!(next.token().char(), Some('>' | '+' | '~' | '|')),
 						_ => false,
 					};
 					if next_is_explicit_combinator {
@@ -173,8 +178,9 @@ pub trait SelectorComponent<'a>: Sized {
 						p.set_skip(skip);
 						return Self::parse_selector_component(p);
 					}
-					p.set_skip(skip);
+					p.set_skip(KindSet::COMMENTS);
 				}
+				// Whitespace must not be skipped here, otherwise it would be passed over in favour of a nesting selector.
 				let value = p.parse::<Self::Combinator>().map(Self::build_combinator);
 				// Given descendant combinators cannot appear in sequence with other combinators, we can safely eat trivia here
 				// in order to remove unecessary conjoined descendant combinators
@@ -98,7 +98,8 @@ impl DefType {
 		matches!(
 			self.ident_str(),
 			// Hand-written types that contain other allocating types
-			"Image"          // contains Gradient<'a>
+			"Color"          // contains ColorFunction<'a>
+				| "Image"    // contains Gradient<'a>
 				| "Image1d"  // contains StripesFunction<'a>
 				| "ContentList"  // Vec<'a, ContentListItem<'a>>
 				| "CounterStyle"  // complex hand-written type
@@ -110,13 +111,25 @@ impl DefType {
 				| "AutoLineWidthList"  // contains Repeat<'a> and LineWidthOrRepeat<'a>
 				| "FamilyName"  // may contain allocating elements
 				| "BgImage"  // contains Image<'a>
+				| "BgLayer" | "FinalBgLayer"  // contain BgImage<'a>
+				| "Shadow"  // contains Color<'a>
+				| "FontVariantAlternatesValues" | "FontVariantValues"  // contain StylesetFunction<'a>
 				| "DynamicRangeLimit"  // contains DynamicRangeLimitMixFunction<'a>
 				| "DynamicRangeLimitMixFunction"  // contains allocating params
 				// Additional types that reference allocating types
 				| "Outline"
+				| "SingleAnimation"
+				| "SingleAnimationTrigger"  // Vec<'a, TimelineRangeBoundary>
 				| "SingleTransition"
 				| "Symbol" // Symbol<'a>
 				| "TransformList"
+				| "FilterValueList"  // Vec<'a, FilterFunction>
+				// Grid track lists
+				| "LineNames"  // Vec<'a, CustomIdent>
+				| "TrackList"  // Vec<'a, TrackListItem<'a>>
+				| "AutoTrackList"  // Vec<'a, TrackListItem<'a>>
+				| "ExplicitTrackList"  // Vec<'a, TrackListItem<'a>>
+				| "LineNameList" // Vec<'a, TrackListItem<'a>>
 		)
 	}
 }
@@ -316,13 +329,14 @@ impl Def {
 			// Functions that contain multipliers or known allocating types
 			Self::Function(_, inner) => inner.maybe_unsized(),
 			Self::FunctionType(ty) => {
-				matches!(ty.ident_str(), "DynamicRangeLimitMix" | "Param" | "Repeat")
+				matches!(ty.ident_str(), "DynamicRangeLimitMix" | "PaletteMix" | "Param" | "Repeat")
 			}
 			Self::Type(d) => d.maybe_unsized(),
 			Self::StyleValue(ty) => {
 				matches!(
 					ty.ident_str(),
-					"BorderTopColor"
+					"BorderBlockStart"
+						| "BorderTopColor" | "CaretColor"
 						| "ColumnRuleWidth"
 						| "DynamicRangeLimit"
 						| "EventTriggerName"
@@ -335,7 +349,12 @@ impl Def {
 						| "ScrollTimelineName"
 						| "ViewTimelineAxis"
 						| "ViewTimelineName"
-						| "BorderTopClip"
+						| "PositionTryFallbacks"
+						| "FontFamily" | "FontPalette"
+						| "BorderTopClip" | "GridAutoColumns"
+						| "GridAutoRows" | "GridTemplate"
+						| "GridTemplateColumns"
+						| "GridTemplateRows"
 				)
 			}
 			Self::AutoOr(d) | Self::NoneOr(d) | Self::AutoNoneOr(d) | Self::NormalOr(d) => d.maybe_unsized(),
@@ -355,6 +374,37 @@ impl Def {
 
 	pub fn optimize(&self) -> Self {
 		match self {
+			// "<a> | [ <b>? <c>? ]!" can be flattened to "<a> | <b> <c>? | <c>"
+			Self::Combinator(defs, DefCombinatorStyle::Alternatives)
+				if defs.iter().any(|def| matches!(def, Def::Group(_, DefGroupStyle::OneMustOccur))) =>
+			{
+				let defs = defs
+					.iter()
+					.flat_map(|def| match def {
+						Def::Group(_, DefGroupStyle::OneMustOccur) => match def.optimize() {
+							Def::Combinator(defs, DefCombinatorStyle::Alternatives) => defs,
+							def => vec![def],
+						},
+						def => vec![def.clone()],
+					})
+					.collect();
+				Self::Combinator(defs, DefCombinatorStyle::Alternatives)
+			}
+			// "[ <a>? <b>? ]!" can be expanded to "<a> <b>? | <b>"
+			Self::Group(inner, DefGroupStyle::OneMustOccur) => match inner.deref() {
+				Self::Combinator(defs, DefCombinatorStyle::Ordered)
+					if let [Def::Optional(first), Def::Optional(second)] = defs.as_slice() =>
+				{
+					Self::Combinator(
+						vec![
+							Self::Combinator(vec![*first.clone(), Self::Optional(second.clone())], DefCombinatorStyle::Ordered),
+							*second.clone(),
+						],
+						DefCombinatorStyle::Alternatives,
+					)
+				}
+				_ => return Self::Group(Box::new(inner.optimize()), DefGroupStyle::OneMustOccur),
+			},
 			Self::Combinator(defs, DefCombinatorStyle::Alternatives) if defs.len() == 2 => {
 				let [first, second] = defs.as_slice() else { panic!("defs.len() was 2!") };
 				match (first, second) {
@@ -491,6 +541,24 @@ impl Def {
 					}
 				}
 			}
+			// "<a>#? , <b>" can be expanded to "[ <a> , ]* <b>", as the comma only occurs alongside an <a>
+			Self::Combinator(defs, DefCombinatorStyle::Ordered)
+				if let Some(i) = defs.windows(2).position(|pair| {
+					matches!(pair, [Def::Multiplier(_, DefMultiplierSeparator::Commas, DefRange::RangeFrom(min)), Def::Punct(',')] if *min == 0.)
+				}) =>
+			{
+				let Def::Multiplier(inner, _, range) = &defs[i] else { unreachable!() };
+				let mut defs = defs.clone();
+				defs.splice(
+					i..=i + 1,
+					[Def::Multiplier(
+						Box::new(Def::Combinator(vec![*inner.clone(), Def::Punct(',')], DefCombinatorStyle::Ordered)),
+						DefMultiplierSeparator::None,
+						range.clone(),
+					)],
+				);
+				Self::Combinator(defs, DefCombinatorStyle::Ordered)
+			}
 			Self::Combinator(defs, style) => {
 				return Self::Combinator(defs.iter().map(|d| d.optimize()).collect(), *style);
 			}
@@ -228,6 +228,54 @@ fn def_optimizes_length_or_auto_range_to_ordered_combinator_lengthorauto_type()
 	);
 }
 
+#[test]
+fn def_optimizes_one_must_occur_group_to_alternatives() {
+	assert_eq!(
+		to_valuedef! { center | [ [ start | end ]? <length-percentage>? ]! },
+		Def::Combinator(
+			vec![
+				Def::Ident(DefIdent("center".into())),
+				Def::Combinator(
+					vec![
+						Def::Group(
+							Box::new(Def::Combinator(
+								vec![Def::Ident(DefIdent("start".into())), Def::Ident(DefIdent("end".into()))],
+								DefCombinatorStyle::Alternatives,
+							)),
+							DefGroupStyle::None,
+						),
+						Def::Optional(Box::new(Def::Type(DefType::new("LengthPercentage", DefRange::None)))),
+					],
+					DefCombinatorStyle::Ordered,
+				),
+				Def::Type(DefType::new("LengthPercentage", DefRange::None)),
+			],
+			DefCombinatorStyle::Alternatives,
+		)
+	);
+}
+
+#[test]
+fn def_optimizes_optional_comma_list_to_repeated_group() {
+	assert_eq!(
+		to_valuedef! { <bg-layer>#? , <final-bg-layer> },
+		Def::Combinator(
+			vec![
+				Def::Multiplier(
+					Box::new(Def::Combinator(
+						vec![Def::Type(DefType::new("BgLayer", DefRange::None)), Def::Punct(',')],
+						DefCombinatorStyle::Ordered,
+					)),
+					DefMultiplierSeparator::None,
+					DefRange::RangeFrom(0.)
+				),
+				Def::Type(DefType::new("FinalBgLayer", DefRange::None)),
+			],
+			DefCombinatorStyle::Ordered,
+		)
+	);
+}
+
 #[test]
 fn def_builds_multiplier_of_types() {
 	assert_eq!(
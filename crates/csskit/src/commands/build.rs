@@ -1,11 +1,46 @@
-use crate::{CliError, CliResult, GlobalConfig, InputArgs};
+use crate::{
+	CliError, CliResult, GlobalConfig, InputArgs,
+	commands::{
+		colors::{location, read_palette},
+		min::{Comments, MAX_PASSES},
+	},
+};
 use bumpalo::Bump;
-use clap::Args;
-use css_ast::{CssAtomSet, StyleSheet};
-use css_lexer::Lexer;
-use css_parse::{CursorCompactWriteSink, Parser, ToCursors};
+use clap::{Args, ValueEnum};
+use css_ast::{CssAtomSet, CssMetadata, StyleSheet};
+use css_feature_data::NamedBrowserVersion;
+use css_lexer::{Kind, Lexer};
+use css_parse::{CursorCompactWriteSink, CursorOverlaySink, Parser, ParserReturn, ToCursors};
+use csskit_transform::{
+	CascadeLayers, ColorConsolidation, CommentRetention, ConvertToRtl, CssBuildFeature, CssBuildOptions,
+	CssMinifierFeature, CssMinifierOptions, LengthConversion, LengthUnitConversion, Transformer, TransformerFeatures,
+};
 use std::io::Read;
 
+/// Which units to convert lengths between.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ConvertLengths {
+	/// Convert `px` to `rem`
+	PxToRem,
+	/// Convert `px` to `em`
+	PxToEm,
+	/// Convert `rem` to `px`
+	RemToPx,
+	/// Convert `em` to `px`
+	EmToPx,
+}
+
+impl From<ConvertLengths> for LengthConversion {
+	fn from(convert: ConvertLengths) -> Self {
+		match convert {
+			ConvertLengths::PxToRem => Self::PxToRem,
+			ConvertLengths::PxToEm => Self::PxToEm,
+			ConvertLengths::RemToPx => Self::RemToPx,
+			ConvertLengths::EmToPx => Self::EmToPx,
+		}
+	}
+}
+
 /// Convert one or more CSS files into production ready CSS.
 #[derive(Debug, Args)]
 #[command(arg_required_else_help(true))]
@@ -16,31 +51,177 @@ pub struct Build {
 	/// Where to save files.
 	#[arg(short, long, group = "output_file", value_parser)]
 	output: Option<String>,
+
+	/// Which comments to keep, such as license banners.
+	#[arg(long, value_enum, default_value_t)]
+	comments: Comments,
+
+	/// Convert lengths between `px` and `rem` or `em`.
+	#[arg(long, value_enum, value_name = "CONVERSION")]
+	convert_lengths: Option<ConvertLengths>,
+
+	/// How many `px` make up one `rem` or `em`, when converting lengths.
+	#[arg(long, value_name = "PX", default_value_t = 16.0)]
+	root_font_size: f32,
+
+	/// Only convert lengths within these properties. Names may start or end with `*`, such as `margin*`.
+	#[arg(long, value_name = "PROPERTY", value_delimiter = ',')]
+	convert_properties: Vec<String>,
+
+	/// Never convert lengths within these properties. Names may start or end with `*`, such as `border*`.
+	#[arg(long, value_name = "PROPERTY", value_delimiter = ',')]
+	skip_properties: Vec<String>,
+
+	/// Leave lengths smaller than this many `px` unconverted.
+	#[arg(long, value_name = "PX", default_value_t = 0.0)]
+	min_px: f32,
+
+	/// Mirror the stylesheet for right-to-left text, swapping left and right. Rules or declarations following a
+	/// `/* csskit: rtl-ignore */` comment are left as written.
+	#[arg(long, value_parser)]
+	rtl: bool,
+
+	/// Rewrite near-identical colours to whichever of them is used most.
+	#[arg(long, value_parser)]
+	consolidate_colors: bool,
+
+	/// How different (in Delta E 2000) two colours can be while still being consolidated.
+	#[arg(long, value_name = "DELTA_E", default_value_t = ColorConsolidation::default().tolerance)]
+	color_tolerance: f64,
+
+	/// Rewrite colours to the nearest colour in this file, if one is within the tolerance. The file can either be JSON
+	/// (an object of names to colours, or an array of colours) or CSS custom properties.
+	#[arg(long, value_name = "FILE")]
+	palette: Option<String>,
+
+	/// Rewrite the stylesheet without `@layer`, for browsers which do not support cascade layers. Rules are moved into
+	/// the order of their layers, and their selectors are given extra specificity where needed so that the same
+	/// declarations apply.
+	#[arg(long, value_parser)]
+	flatten_layers: bool,
+
+	/// The browsers to build for, such as `chrome 120,safari 17.4`. `@supports` rules which all of them support are
+	/// unwrapped, and those which none of them support are removed, along with `@media` rules which can never match.
+	#[arg(long, value_name = "BROWSER", value_delimiter = ',')]
+	targets: Vec<NamedBrowserVersion>,
+}
+
+/// Parses the source, keeping every comment.
+fn parse<'a>(bump: &'a Bump, source_text: &'a str) -> ParserReturn<'a, StyleSheet<'a>> {
+	let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+	Parser::new(bump, source_text, lexer).parse_entirely::<StyleSheet>().retain_trivia(|c| c == Kind::Comment)
+}
+
+/// Runs the transformer over the stylesheet until its edits settle, returning the transformed source and the stylesheet
+/// parsed from it.
+fn apply<'a, F>(
+	bump: &'a Bump,
+	result: ParserReturn<'a, StyleSheet<'a>>,
+	mut transformer: Transformer<'a, CssMetadata, StyleSheet<'a>, F>,
+) -> Result<(&'a str, ParserReturn<'a, StyleSheet<'a>>), CliError>
+where
+	F: TransformerFeatures<CssMetadata, StyleSheet<'a>>,
+{
+	let result = transformer.transform_until_stable(result, MAX_PASSES)?;
+	let source_text = result.source_text;
+	let mut output = String::new();
+	{
+		let overlays = transformer.overlays();
+		let mut stream =
+			CursorOverlaySink::new(source_text, &overlays, CursorCompactWriteSink::new(source_text, &mut output));
+		result.to_cursors(&mut stream);
+	}
+	let source_text = bump.alloc_str(&output);
+	Ok((source_text, parse(bump, source_text)))
 }
 
 impl Build {
 	pub fn run(&self, _config: GlobalConfig) -> CliResult {
-		let Build { content, output } = self;
+		let Build {
+			content,
+			output,
+			comments,
+			convert_lengths,
+			root_font_size,
+			convert_properties,
+			skip_properties,
+			min_px,
+			rtl,
+			consolidate_colors,
+			color_tolerance,
+			palette,
+			flatten_layers,
+			targets,
+		} = self;
+		let retention = CommentRetention::from(*comments);
+		let mut features = CssBuildFeature::none();
+		let mut length_units = LengthUnitConversion {
+			root_font_size: *root_font_size,
+			allow_properties: convert_properties.clone(),
+			deny_properties: skip_properties.clone(),
+			min_px: *min_px,
+			..Default::default()
+		};
+		if let Some(convert_lengths) = convert_lengths {
+			features |= CssBuildFeature::ConvertLengthUnits;
+			length_units.conversion = LengthConversion::from(*convert_lengths);
+		}
+		let colors = ColorConsolidation {
+			tolerance: *color_tolerance,
+			palette: palette.as_deref().map(read_palette).transpose()?.unwrap_or_default(),
+		};
+		if *consolidate_colors || palette.is_some() {
+			features |= CssBuildFeature::ConsolidateColors;
+		}
+		if *flatten_layers {
+			features |= CssBuildFeature::FlattenLayers;
+		}
+		let options = CssBuildOptions { length_units, colors, ..Default::default() };
+		let minifier_options = CssMinifierOptions { targets: targets.clone() };
 		let bump = Bump::default();
 		let mut str = String::new();
 		let start = std::time::Instant::now();
 		for (file_name, mut source) in content.sources()? {
 			let mut source_string = String::new();
 			source.read_to_string(&mut source_string)?;
-			let source_text = source_string.as_str();
-			let mut stream = CursorCompactWriteSink::new(source_text, &mut str);
-			let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
-			let mut parser = Parser::new(&bump, source_text, lexer);
-			let result = parser.parse_entirely::<StyleSheet>();
-			if result.output.is_some() {
-				result.to_cursors(&mut stream);
-			} else {
-				for compact_err in result.errors {
-					let report = crate::commands::format_diagnostic_error(&compact_err, &source_string, file_name);
+			let mut source_text: &str = bump.alloc_str(&source_string);
+			let mut result = parse(&bump, source_text);
+			if result.output.is_none() {
+				for compact_err in &result.errors {
+					let report = crate::commands::format_diagnostic_error(compact_err, &source_string, file_name);
 					println!("{report}");
 				}
-				Err(CliError::ParseFailed)?;
+				Err(CliError::ParseFailed)?
+			}
+			if *flatten_layers && let Some(stylesheet) = result.output.as_ref() {
+				for warning in CascadeLayers::new(stylesheet, source_text).warnings {
+					eprintln!("{}: warning: {warning}", location(warning.span, source_text, file_name));
+				}
+			}
+			// Each transform runs over the output of the one before, as their edits may overlap. Comments are kept
+			// until the end, so that directives such as `/* csskit: rtl-ignore */` are seen by every transform.
+			for (_, feature) in CssBuildFeature::flags().filter(|(_, feature)| features.contains(*feature)) {
+				let transformer =
+					Transformer::new_in(&bump, *feature, &CssAtomSet::ATOMS, source_text).with_options(options.clone());
+				(source_text, result) = apply(&bump, result, transformer)?;
+			}
+			if *rtl {
+				let transformer = Transformer::new_in(&bump, CssBuildFeature::none(), &CssAtomSet::ATOMS, source_text)
+					.with_plugin(ConvertToRtl::default());
+				(source_text, result) = apply(&bump, result, transformer)?;
+			}
+			if !targets.is_empty() {
+				let transformer = Transformer::new_in(
+					&bump,
+					CssMinifierFeature::PruneConditionalRules,
+					&CssAtomSet::ATOMS,
+					source_text,
+				)
+				.with_options(minifier_options.clone());
+				(source_text, result) = apply(&bump, result, transformer)?;
 			}
+			let result = result.retain_trivia(|c| retention.retains(c, source_text));
+			result.to_cursors(&mut CursorCompactWriteSink::new(source_text, &mut str));
 		}
 		if let Some(file) = output {
 			std::fs::write(file, str.as_bytes())?;
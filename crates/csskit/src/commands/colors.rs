@@ -1,35 +1,35 @@
 use super::GlobalConfig;
-use crate::{CliResult, InputArgs, InputSource, bg, bold, dimmed, fg};
+use crate::{CliError, CliResult, InputArgs, InputSource, bg, bold, commands::min::MAX_PASSES, dimmed, fg};
 use bumpalo::Bump;
 use chromashift::*;
-use clap::Args;
-use css_ast::{Color as ASTColor, CssAtomSet, StyleSheet, ToChromashift, Visitable};
-use css_lexer::Lexer;
-use css_parse::{Parser, Span, ToSpan};
+use clap::{Args, ValueEnum};
+use css_ast::{Color as ASTColor, CssAtomSet, StyleSheet, Visitable};
+use css_lexer::{Kind, Lexer};
+use css_parse::{CursorOverlaySink, CursorSink, CursorWriteSink, Parser, Span};
+use csskit_transform::{
+	ColorCluster, ColorConsolidation, ColorNotation, ColorNotationConversion, ColorUsages, CssBuildFeature,
+	CssBuildOptions, PaletteColor, Transformer,
+};
 use itertools::Itertools;
-use std::{collections::HashSet, io::Read};
+use serde_json::Value;
+use std::io::Read;
 
-struct ColorExtractor {
-	colors: Vec<(Color, Span)>,
-	seen: HashSet<Hex>,
-}
-
-impl ColorExtractor {
-	fn new() -> Self {
-		Self { colors: Vec::new(), seen: HashSet::new() }
+/// Reads a palette of colours from a file. A `.json` file may hold an object of names to colours, or an array of
+/// colours. Any other file is read as CSS, using each custom property with a colour value, such as `--brand: #639`.
+pub(crate) fn read_palette(path: &str) -> Result<Vec<PaletteColor>, CliError> {
+	let text = std::fs::read_to_string(path)?;
+	if !path.ends_with(".json") {
+		return Ok(PaletteColor::from_custom_properties(&text));
 	}
-}
-
-impl css_ast::Visit for ColorExtractor {
-	fn visit_color(&mut self, color: &ASTColor) {
-		if let Some(raw_color) = color.to_chromashift() {
-			let hex = raw_color.into();
-			if !self.seen.contains(&hex) {
-				self.seen.insert(hex);
-				self.colors.push((raw_color, color.to_span()));
-			}
+	Ok(match serde_json::from_str::<Value>(&text)? {
+		Value::Object(entries) => {
+			entries.iter().filter_map(|(name, value)| PaletteColor::new(name.as_str(), value.as_str()?)).collect()
 		}
-	}
+		Value::Array(values) => {
+			values.iter().filter_map(Value::as_str).filter_map(|v| PaletteColor::new(v, v)).collect()
+		}
+		_ => vec![],
+	})
 }
 
 fn format_wcag_status(level: WcagLevel) -> &'static str {
@@ -259,6 +259,42 @@ fn print_color_info(
 	}
 }
 
+/// Which notation to convert colours to.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ConvertColors {
+	/// Hex notation, such as `#663399`
+	Hex,
+	/// The `rgb()` function
+	Rgb,
+	/// The `hsl()` function
+	Hsl,
+	/// The `oklch()` function
+	Oklch,
+	/// The `oklab()` function
+	Oklab,
+	/// The `lab()` function
+	Lab,
+	/// The `lch()` function
+	Lch,
+	/// The `color()` function, in the `display-p3` colour space
+	P3,
+}
+
+impl From<ConvertColors> for ColorNotation {
+	fn from(convert: ConvertColors) -> Self {
+		match convert {
+			ConvertColors::Hex => Self::Hex,
+			ConvertColors::Rgb => Self::Rgb,
+			ConvertColors::Hsl => Self::Hsl,
+			ConvertColors::Oklch => Self::Oklch,
+			ConvertColors::Oklab => Self::Oklab,
+			ConvertColors::Lab => Self::Lab,
+			ConvertColors::Lch => Self::Lch,
+			ConvertColors::P3 => Self::DisplayP3,
+		}
+	}
+}
+
 /// Extract the colours from a CSS file.
 #[derive(Debug, Args)]
 pub struct ColorCommand {
@@ -276,19 +312,128 @@ pub struct ColorCommand {
 	/// Print similar Named colours for each colour
 	#[arg(long, value_parser)]
 	named: bool,
+
+	/// Group near-identical colours together, printing each group and how often its colours are used
+	#[arg(long, value_parser)]
+	clusters: bool,
+
+	/// Fail if any colours are near-identical to one another (or to a colour in the palette), printing where they are
+	/// used
+	#[arg(long, value_parser)]
+	lint: bool,
+
+	/// How different (in Delta E 2000) two colours can be while still being grouped together
+	#[arg(long, value_name = "DELTA_E", default_value_t = ColorConsolidation::default().tolerance)]
+	tolerance: f64,
+
+	/// Group colours around the colours in this file, rather than the most used colours. The file can either be JSON
+	/// (an object of names to colours, or an array of colours) or CSS custom properties
+	#[arg(long, value_name = "FILE")]
+	palette: Option<String>,
+
+	/// Print each file with every colour rewritten into this notation. Colours the notation cannot represent, such as
+	/// colours outside of the sRGB gamut in hex, are left as written
+	#[arg(long, value_enum, value_name = "NOTATION")]
+	convert: Option<ConvertColors>,
+
+	/// How many decimal places to round each channel to, when converting colours
+	#[arg(long, value_name = "DIGITS", default_value_t = ColorNotationConversion::default().precision)]
+	precision: u8,
+}
+
+pub(crate) fn location(span: Span, source_text: &str, file_name: &str) -> String {
+	let (line, column) = span.line_and_column(source_text);
+	format!("{file_name}:{line}:{column}")
+}
+
+fn print_cluster(cluster: &ColorCluster, config: &GlobalConfig, source_text: &str, file_name: &str) {
+	let uses: usize = cluster.members.iter().map(|member| member.spans.len()).sum();
+	let name = cluster.palette_name.as_ref().map(|name| format!(" ({name})")).unwrap_or_default();
+	if config.colors() {
+		println!(
+			" {}  {}{name} {}",
+			bg("          ", cluster.canonical),
+			bold(&cluster.value),
+			dimmed(format!("{uses} uses"))
+		);
+	} else {
+		println!(" {:10}  {}{name} {uses} uses", "", cluster.value);
+	}
+	for member in &cluster.members {
+		let delta_e = member.color.delta_e(cluster.canonical);
+		let value = &member.value;
+		let locations = member.spans.iter().map(|span| location(*span, source_text, file_name)).join(", ");
+		if config.colors() {
+			println!(" {}  {value:9} ΔE {delta_e:<5.2} {}", bg("          ", member.color), dimmed(locations));
+		} else {
+			println!(" {:10}  {value:9} ΔE {delta_e:<5.2} {locations}", "");
+		}
+	}
+	println!();
+}
+
+/// Prints the source with every colour converted. Rather than re-printing the stylesheet, every token of the source is
+/// written through the overlays, so that everything else (including whitespace and comments) is kept as written. Edits
+/// which overlap an earlier edit are applied to the output of the pass before, until none are left.
+fn print_converted(bump: &Bump, source_text: &str, file_name: &str, options: &CssBuildOptions) -> CliResult {
+	let mut source_text: &str = bump.alloc_str(source_text);
+	for _ in 0..MAX_PASSES {
+		let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+		let mut parser = Parser::new(bump, source_text, lexer);
+		let mut result = parser.parse_entirely::<StyleSheet>();
+		let Some(stylesheet) = result.output.as_mut() else {
+			for compact_err in &result.errors {
+				let report = crate::commands::format_diagnostic_error(compact_err, source_text, file_name);
+				println!("{report}");
+			}
+			Err(CliError::ParseFailed)?
+		};
+		let mut transformer =
+			Transformer::new_in(bump, CssBuildFeature::ConvertColorNotation, &CssAtomSet::ATOMS, source_text)
+				.with_options(options.clone());
+		let deferred = transformer.transform(stylesheet)?;
+		let overlays = transformer.overlays();
+		let mut output = String::new();
+		let mut stream = CursorOverlaySink::new(source_text, &overlays, CursorWriteSink::new(source_text, &mut output));
+		for cursor in Lexer::new(&CssAtomSet::ATOMS, source_text) {
+			if cursor == Kind::Eof {
+				break;
+			}
+			stream.append(cursor);
+		}
+		source_text = bump.alloc_str(&output);
+		if deferred == 0 {
+			break;
+		}
+	}
+	print!("{source_text}");
+	Ok(())
 }
 
 impl ColorCommand {
 	pub fn run(&self, config: GlobalConfig) -> CliResult {
 		let bump = Bump::default();
-		let ColorCommand { content, all, wcag, named } = self;
+		let ColorCommand { content, all, wcag, named, clusters, lint, tolerance, palette, convert, precision } = self;
 		let wcag = *wcag || *all;
 		let named = *named || *all;
+		let palette = palette.as_deref().map(read_palette).transpose()?.unwrap_or_default();
+		let consolidation = ColorConsolidation { tolerance: *tolerance, palette };
+		let mut checks = 0;
 		for (file_name, mut source) in content.sources()? {
 			let mut source_string = String::new();
 			source.read_to_string(&mut source_string)?;
 			let source_text = source_string.as_str();
-			let mut color_visitor = ColorExtractor::new();
+			if let Some(convert) = convert {
+				let color_notation = ColorNotationConversion { notation: (*convert).into(), precision: *precision };
+				print_converted(
+					&bump,
+					source_text,
+					file_name,
+					&CssBuildOptions { color_notation, ..Default::default() },
+				)?;
+				continue;
+			}
+			let mut color_visitor = ColorUsages::new(source_text);
 			let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
 			let mut parser = Parser::new(&bump, source_text, lexer);
 			let result = parser.parse_entirely::<bumpalo::collections::Vec<ASTColor>>();
@@ -308,14 +453,26 @@ impl ColorCommand {
 					}
 				}
 			}
-			if color_visitor.colors.is_empty() {
+			if *clusters || *lint {
+				let clusters = consolidation.cluster(&color_visitor.usages);
+				let consolidated = clusters.iter().filter(|cluster| cluster.is_consolidated()).collect::<Vec<_>>();
+				for cluster in if *lint { consolidated.clone() } else { clusters.iter().collect() } {
+					print_cluster(cluster, &config, source_text, file_name);
+				}
+				if *lint && !consolidated.is_empty() {
+					let i = consolidated.len();
+					eprintln!("{file_name} has {i} group{} of near-identical colors", if i > 1 { "s" } else { "" });
+					checks += 1;
+				}
+			} else if color_visitor.usages.is_empty() {
 				eprintln!("No colors found in {file_name}");
 			} else {
-				let i = color_visitor.colors.len();
+				let i = color_visitor.usages.len();
 				println!();
 				eprintln!("Found {i} color{}", if i > 0 { "s" } else { "" });
 				println!();
-				for (color, span) in color_visitor.colors {
+				for usage in color_visitor.usages {
+					let (color, span) = (usage.color, usage.spans[0]);
 					let lc = if matches!(source, InputSource::File(_)) {
 						let (line, col) = span.line_and_column(source_text);
 						Some((file_name, line, col))
@@ -328,6 +485,6 @@ impl ColorCommand {
 				println!();
 			}
 		}
-		Ok(())
+		if checks > 0 { Err(CliError::Checks(checks))? } else { Ok(()) }
 	}
 }
@@ -0,0 +1,850 @@
+use crate::{
+	CliResult, GlobalConfig, InputArgs,
+	html::{HtmlAttribute, HtmlDocument},
+	unused_rules::{cursor_text, remove_unused_rules},
+};
+use bumpalo::Bump;
+use clap::Args;
+use css_ast::{
+	AttributeModifier, AttributeOperator, ColorProfileRule, CompoundSelector, ContainerRule, CounterStyleRule,
+	CssMetadata, Declaration, DeclarationValue, FontFaceRule, FontFeatureValuesRule, FontPaletteValuesRule,
+	FunctionalPseudoClass, HeightMediaFeature, KeyframesRule, LayerRule, Length, MediaCondition, MediaFeature,
+	MediaPreCondition, MediaQuery, MediaRule, MediaType, OrientationMediaFeature, OrientationMediaFeatureKeyword,
+	PageRule, PositionTryRule, PropertyRule, PseudoClass, QueryableNode, ScopeRule, SelectorComponent, SelectorList,
+	StartingStyleRule, StyleRule, SupportsRule, Visit, Visitable, WebkitKeyframesRule, WidthMediaFeature,
+};
+use css_lexer::Cursor;
+use css_parse::{Comparison, Span, ToSpan};
+use std::{collections::HashSet, io::Read, str::FromStr};
+
+/// The font size used to resolve `em` & `rem` lengths within media queries.
+const DEFAULT_FONT_SIZE: f32 = 16.0;
+
+/// The dimensions used to evaluate media queries, such as `(min-width: 768px)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+	width: f32,
+	height: f32,
+}
+
+impl FromStr for Viewport {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (width, height) = s.split_once(['x', 'X']).ok_or_else(|| format!("expected WIDTHxHEIGHT, got {s:?}"))?;
+		let parse = |n: &str| n.trim().parse::<f32>().map_err(|err| format!("{n:?} is not a number: {err}"));
+		Ok(Self { width: parse(width)?, height: parse(height)? })
+	}
+}
+
+/// How two compound selectors relate to one another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Relation {
+	Descendant,
+	Child,
+	NextSibling,
+	SubsequentSibling,
+	/// Column relationships depend on table layout, so are always assumed to match.
+	Column,
+}
+
+/// A run of selector components with no combinators between them, such as `a.foo:hover`, along with how it relates to
+/// the compound selector to its left.
+struct Compound<'s, 'a> {
+	relation: Option<Relation>,
+	/// Whether this contains the nesting selector (`&`).
+	nesting: bool,
+	components: Vec<&'s SelectorComponent<'a>>,
+}
+
+/// Splits a selector into its compound selectors, left to right.
+fn compounds<'s, 'a>(selector: &'s CompoundSelector<'a>) -> Vec<Compound<'s, 'a>> {
+	let mut compounds = vec![Compound { relation: None, nesting: false, components: vec![] }];
+	for component in selector.0.iter() {
+		let relation = match component {
+			SelectorComponent::Combinator(combinator) => match combinator {
+				css_ast::Combinator::Nesting(_) => {
+					compounds.last_mut().unwrap().nesting = true;
+					continue;
+				}
+				css_ast::Combinator::Descendant(_) => Relation::Descendant,
+				css_ast::Combinator::Child(_) => Relation::Child,
+				css_ast::Combinator::NextSibling(_) => Relation::NextSibling,
+				css_ast::Combinator::SubsequentSibling(_) => Relation::SubsequentSibling,
+				css_ast::Combinator::Column(_) => Relation::Column,
+			},
+			_ => {
+				compounds.last_mut().unwrap().components.push(component);
+				continue;
+			}
+		};
+		let last = compounds.last_mut().unwrap();
+		if last.components.is_empty() && !last.nesting {
+			// A leading combinator, as in `> .child`.
+			last.relation = Some(relation);
+		} else {
+			compounds.push(Compound { relation: Some(relation), nesting: false, components: vec![] });
+		}
+	}
+	compounds
+}
+
+/// Matches selectors against the elements of a [HtmlDocument].
+///
+/// Without layout or script there are states which cannot be known, so pseudo classes are handled in one of three
+/// ways: structural ones (`:first-child`, `:nth-of-type()`, `:empty`) and those reflecting attributes (`:checked`,
+/// `:disabled`) are evaluated against the document; user interaction states (`:hover`, `:focus`, `:visited`) never
+/// match, as they cannot apply to the initial render; and anything else is assumed to match. Pseudo elements always
+/// match their originating element.
+struct SelectorMatcher<'a, 'd> {
+	document: &'d HtmlDocument<'d>,
+	source_text: &'a str,
+	bump: &'a Bump,
+}
+
+impl<'a, 'd> SelectorMatcher<'a, 'd> {
+	fn text(&self, cursor: Cursor) -> css_lexer::CowStr<'a, &'a Bump> {
+		cursor_text(cursor, self.source_text, self.bump)
+	}
+
+	/// Returns every element matching the selector list. If `scope` is given, the list is treated as a nested rule's
+	/// selector list, relative to the elements matched by the parent rule.
+	fn matching_elements(&self, list: &SelectorList, scope: Option<&HashSet<usize>>) -> HashSet<usize> {
+		(0..self.document.elements.len()).filter(|&el| self.matches_list(list, el, scope, scope.is_some())).collect()
+	}
+
+	/// Checks a selector list against an element. `scope` is the set of elements the nesting selector (`&`) refers
+	/// to, and when `relative` is set any selector lacking a `&` is treated as a descendant of the scope, as is the case
+	/// with nested rules and `:has()`.
+	fn matches_list(&self, list: &SelectorList, el: usize, scope: Option<&HashSet<usize>>, relative: bool) -> bool {
+		(&list.0).into_iter().any(|(selector, _)| {
+			let mut compounds = compounds(selector);
+			if relative && !compounds.iter().any(|compound| compound.nesting) {
+				let first = &mut compounds[0];
+				first.relation = first.relation.or(Some(Relation::Descendant));
+				compounds.insert(0, Compound { relation: None, nesting: true, components: vec![] });
+			}
+			self.matches_compounds(&compounds, el, scope)
+		})
+	}
+
+	fn matches_compounds(&self, compounds: &[Compound], el: usize, scope: Option<&HashSet<usize>>) -> bool {
+		let Some((compound, rest)) = compounds.split_last() else { return true };
+		if !self.matches_compound(compound, el, scope) {
+			return false;
+		}
+		if rest.is_empty() {
+			return true;
+		}
+		let element = &self.document.elements[el];
+		let previous = &self.document.siblings(el)[..element.index];
+		match compound.relation.unwrap_or(Relation::Descendant) {
+			Relation::Descendant => {
+				let mut parent = element.parent;
+				while let Some(ancestor) = parent {
+					if self.matches_compounds(rest, ancestor, scope) {
+						return true;
+					}
+					parent = self.document.elements[ancestor].parent;
+				}
+				false
+			}
+			Relation::Child => element.parent.is_some_and(|parent| self.matches_compounds(rest, parent, scope)),
+			Relation::NextSibling => {
+				previous.last().is_some_and(|&sibling| self.matches_compounds(rest, sibling, scope))
+			}
+			Relation::SubsequentSibling => previous.iter().any(|&sibling| self.matches_compounds(rest, sibling, scope)),
+			Relation::Column => true,
+		}
+	}
+
+	fn matches_compound(&self, compound: &Compound, el: usize, scope: Option<&HashSet<usize>>) -> bool {
+		// Outside of a nested rule `&` refers to the root element, just like `:scope`.
+		if compound.nesting && !scope.map_or(self.document.elements[el].parent.is_none(), |scope| scope.contains(&el)) {
+			return false;
+		}
+		compound.components.iter().all(|component| self.matches_component(component, el, scope))
+	}
+
+	fn matches_component(&self, component: &SelectorComponent, el: usize, scope: Option<&HashSet<usize>>) -> bool {
+		let element = &self.document.elements[el];
+		match component {
+			SelectorComponent::Id(id) => element
+				.attribute("id")
+				.and_then(|attr| attr.value)
+				.is_some_and(|value| value == &*self.text((*id).into())),
+			SelectorComponent::Class(class) => element.has_class(&self.text(class.name.into())),
+			SelectorComponent::Tag(tag) => element.name.eq_ignore_ascii_case(&self.text((*tag).into())),
+			SelectorComponent::Attribute(attribute) => {
+				let Some(HtmlAttribute { value, .. }) = element.attribute(&self.text(attribute.attribute.into()))
+				else {
+					return false;
+				};
+				let (Some(operator), Some(expected)) = (&attribute.operator, attribute.value) else { return true };
+				let value = value.unwrap_or_default();
+				let expected = self.text(expected.into());
+				let (value, expected) = if matches!(attribute.modifier, Some(AttributeModifier::Insensitive(_))) {
+					(value.to_lowercase(), expected.to_lowercase())
+				} else {
+					(value.to_string(), expected.to_string())
+				};
+				match operator {
+					AttributeOperator::Exact(_) => value == expected,
+					AttributeOperator::SpaceList(_) => value.split_whitespace().any(|v| v == expected),
+					AttributeOperator::LangPrefix(_) => {
+						value == expected || value.strip_prefix(&expected).is_some_and(|rest| rest.starts_with('-'))
+					}
+					AttributeOperator::Prefix(_) => !expected.is_empty() && value.starts_with(&expected),
+					AttributeOperator::Suffix(_) => !expected.is_empty() && value.ends_with(&expected),
+					AttributeOperator::Contains(_) => !expected.is_empty() && value.contains(&expected),
+				}
+			}
+			SelectorComponent::PseudoClass(pseudo) => self.matches_pseudo_class(pseudo, el),
+			SelectorComponent::FunctionalPseudoClass(pseudo) => match pseudo {
+				FunctionalPseudoClass::Is(f) => self.matches_list(&f.value, el, scope, false),
+				FunctionalPseudoClass::Where(f) => self.matches_list(&f.value, el, scope, false),
+				FunctionalPseudoClass::Not(f) => !self.matches_list(&f.value, el, scope, false),
+				FunctionalPseudoClass::Has(f) => {
+					let anchor = HashSet::from([el]);
+					// Relative selectors can only reach descendants, or later siblings and their descendants.
+					let siblings = &self.document.siblings(el)[element.index..];
+					siblings.iter().enumerate().any(|(i, &sibling)| {
+						(i > 0 && self.matches_list(&f.value, sibling, Some(&anchor), true))
+							|| self
+								.document
+								.descendants(sibling)
+								.any(|candidate| self.matches_list(&f.value, candidate, Some(&anchor), true))
+					})
+				}
+				FunctionalPseudoClass::NthChild(f) => f.value.matches(element.index as i32 + 1),
+				FunctionalPseudoClass::NthLastChild(f) => {
+					f.value.matches((self.document.siblings(el).len() - element.index) as i32)
+				}
+				FunctionalPseudoClass::NthOfType(f) => {
+					let (before, _) = self.position_of_type(el);
+					f.value.matches(before as i32 + 1)
+				}
+				FunctionalPseudoClass::NthLastOfType(f) => {
+					let (_, after) = self.position_of_type(el);
+					f.value.matches(after as i32 + 1)
+				}
+				FunctionalPseudoClass::Host(_) | FunctionalPseudoClass::HostContext(_) => false,
+				_ => true,
+			},
+			_ => true,
+		}
+	}
+
+	/// Returns how many siblings of the same type come before, and after, the given element.
+	fn position_of_type(&self, el: usize) -> (usize, usize) {
+		let element = &self.document.elements[el];
+		let siblings = self.document.siblings(el);
+		let same_type = |&&sibling: &&usize| self.document.elements[sibling].name == element.name;
+		let before = siblings[..element.index].iter().filter(same_type).count();
+		let after = siblings[element.index + 1..].iter().filter(same_type).count();
+		(before, after)
+	}
+
+	fn matches_pseudo_class(&self, pseudo: &PseudoClass, el: usize) -> bool {
+		let element = &self.document.elements[el];
+		let siblings = self.document.siblings(el);
+		let is_form_control = matches!(
+			element.name.as_str(),
+			"button" | "input" | "select" | "textarea" | "optgroup" | "option" | "fieldset"
+		);
+		match pseudo {
+			PseudoClass::Root(..) => element.parent.is_none(),
+			PseudoClass::Empty(..) => element.children.is_empty(),
+			PseudoClass::FirstChild(..) => element.index == 0,
+			PseudoClass::LastChild(..) => element.index == siblings.len() - 1,
+			PseudoClass::OnlyChild(..) => siblings.len() == 1,
+			PseudoClass::FirstOfType(..) => self.position_of_type(el).0 == 0,
+			PseudoClass::LastOfType(..) => self.position_of_type(el).1 == 0,
+			PseudoClass::OnlyOfType(..) => self.position_of_type(el) == (0, 0),
+			PseudoClass::Checked(..) => element.has_attribute("checked") || element.has_attribute("selected"),
+			PseudoClass::Disabled(..) => element.has_attribute("disabled"),
+			PseudoClass::Enabled(..) => is_form_control && !element.has_attribute("disabled"),
+			PseudoClass::Required(..) => element.has_attribute("required"),
+			PseudoClass::Optional(..) => is_form_control && !element.has_attribute("required"),
+			PseudoClass::PlaceholderShown(..) => element.has_attribute("placeholder"),
+			PseudoClass::Open(..) => element.has_attribute("open"),
+			PseudoClass::Link(..) | PseudoClass::AnyLink(..) => {
+				matches!(element.name.as_str(), "a" | "area") && element.has_attribute("href")
+			}
+			PseudoClass::Host(..)
+			| PseudoClass::Active(..)
+			| PseudoClass::Autofill(..)
+			| PseudoClass::Focus(..)
+			| PseudoClass::FocusVisible(..)
+			| PseudoClass::FocusWithin(..)
+			| PseudoClass::Hover(..)
+			| PseudoClass::Target(..)
+			| PseudoClass::TargetCurrent(..)
+			| PseudoClass::TargetWithin(..)
+			| PseudoClass::UserInvalid(..)
+			| PseudoClass::Visited(..) => false,
+			_ => true,
+		}
+	}
+}
+
+/// Returns the text of a declaration value split on commas, normalised to lowercase and stripped of quotes, so it can
+/// be compared against `@font-face` family names.
+fn font_families(value: &str) -> impl Iterator<Item = String> + '_ {
+	value.split(',').map(|family| {
+		family.replace(['"', '\''], "").split_whitespace().collect::<Vec<_>>().join(" ").to_ascii_lowercase()
+	})
+}
+
+/// What a rule in the stylesheet is, for the purposes of deciding whether it is critical.
+#[derive(Debug)]
+enum CriticalRuleKind {
+	Style,
+	/// Conditional group rules such as `@media`, which are kept if any of their contents are.
+	Group,
+	FontFace(Option<String>),
+	/// `@font-feature-values`, which is kept if any of the families it names are used.
+	FontFeatureValues(Vec<String>),
+	Keyframes(String),
+	Property(String),
+	CounterStyle(String),
+	FontPaletteValues(String),
+	/// `@color-profile`, which is kept if a `color()` in an active style rule names the profile.
+	ColorProfile(String),
+	/// `@position-try`, which is kept if `position-try-fallbacks` in an active style rule names it.
+	PositionTry(String),
+	/// Rules which never affect the rendering of a page on screen, such as `@page`.
+	Unused,
+}
+
+#[derive(Debug)]
+struct CriticalRule {
+	span: Span,
+	parent: Option<usize>,
+	kind: CriticalRuleKind,
+	/// Whether this rule (and every ancestor) applies to the document. Style rules are active when their selectors
+	/// match an element, and groups when their condition matches the viewport.
+	active: bool,
+}
+
+/// Collects every rule which could affect a document, and the names those rules reference.
+struct CriticalExtractor<'a, 'd> {
+	matcher: SelectorMatcher<'a, 'd>,
+	viewport: Viewport,
+	rules: Vec<CriticalRule>,
+	/// Indexes into `rules` of the rules currently being visited.
+	stack: Vec<usize>,
+	/// The elements matched by each style rule currently being visited, the innermost last.
+	scopes: Vec<HashSet<usize>>,
+	/// Every identifier used by active style rules, used to look up `@keyframes`, `@property` and `@counter-style`.
+	identifiers: HashSet<String>,
+	/// Every font family named by active style rules.
+	families: HashSet<String>,
+}
+
+impl<'a, 'd> CriticalExtractor<'a, 'd> {
+	fn new(document: &'d HtmlDocument<'d>, viewport: Viewport, source_text: &'a str, bump: &'a Bump) -> Self {
+		Self {
+			matcher: SelectorMatcher { document, source_text, bump },
+			viewport,
+			rules: vec![],
+			stack: vec![],
+			scopes: vec![],
+			identifiers: HashSet::new(),
+			families: HashSet::new(),
+		}
+	}
+
+	fn parent_active(&self) -> bool {
+		self.stack.last().is_none_or(|&parent| self.rules[parent].active)
+	}
+
+	fn enter(&mut self, span: Span, kind: CriticalRuleKind, active: bool) {
+		let parent = self.stack.last().copied();
+		let active = active && self.parent_active();
+		self.stack.push(self.rules.len());
+		self.rules.push(CriticalRule { span, parent, kind, active });
+	}
+
+	fn exit(&mut self) {
+		self.stack.pop();
+	}
+
+	fn name(&self, cursor: Cursor) -> String {
+		self.matcher.text(cursor).to_string()
+	}
+
+	fn length_to_px(&self, length: Length) -> Option<f32> {
+		let value = f32::from(length);
+		match length {
+			Length::Zero(_) | Length::Px(_) => Some(value),
+			Length::Em(_) | Length::Rem(_) => Some(value * DEFAULT_FONT_SIZE),
+			Length::Vw(_) => Some(value * self.viewport.width / 100.0),
+			Length::Vh(_) => Some(value * self.viewport.height / 100.0),
+			_ => length.to_px(),
+		}
+	}
+
+	fn media_query_matches(&self, query: &MediaQuery) -> bool {
+		let media_type = !matches!(query.media_type(), Some(MediaType::Print(_) | MediaType::Custom(_)));
+		let condition = query.condition().map_or(Some(true), |condition| self.media_condition_matches(condition));
+		let mut matches = if media_type { condition } else { Some(false) };
+		if matches!(query.precondition(), Some(MediaPreCondition::Not(_))) {
+			matches = matches.map(|matches| !matches);
+		}
+		// Queries which can't be evaluated are assumed to match.
+		matches.unwrap_or(true)
+	}
+
+	/// Evaluates a media condition, returning `None` if it depends on something other than the viewport.
+	fn media_condition_matches(&self, condition: &MediaCondition) -> Option<bool> {
+		match condition {
+			MediaCondition::Is(feature) => self.media_feature_matches(feature),
+			MediaCondition::Not(_, feature) => self.media_feature_matches(feature).map(|matches| !matches),
+			MediaCondition::And(features) => {
+				let results =
+					features.iter().map(|(feature, _)| self.media_feature_matches(feature)).collect::<Vec<_>>();
+				if results.contains(&Some(false)) {
+					Some(false)
+				} else {
+					results.iter().all(Option::is_some).then_some(true)
+				}
+			}
+			MediaCondition::Or(features) => {
+				let results =
+					features.iter().map(|(feature, _)| self.media_feature_matches(feature)).collect::<Vec<_>>();
+				if results.contains(&Some(true)) {
+					Some(true)
+				} else {
+					results.iter().all(Option::is_some).then_some(false)
+				}
+			}
+		}
+	}
+
+	fn media_feature_matches(&self, feature: &MediaFeature) -> Option<bool> {
+		let compare = |a: f32, comparison: &Comparison, b: f32| match comparison {
+			Comparison::LessThan(_) => a < b,
+			Comparison::GreaterThan(_) => a > b,
+			Comparison::LessThanEqual(_) => a <= b,
+			Comparison::GreaterThanEqual(_) => a >= b,
+			Comparison::Equal(_) => a == b,
+		};
+		macro_rules! ranged {
+			($feature: ident, $ty: ident, $actual: expr) => {
+				match $feature {
+					$ty::Left(_, _, comparison, value, _) => compare($actual, comparison, self.length_to_px(*value)?),
+					$ty::Right(_, value, comparison, _, _) => compare(self.length_to_px(*value)?, comparison, $actual),
+					$ty::Range(_, low, low_comparison, _, high_comparison, high, _) => {
+						compare(self.length_to_px(*low)?, low_comparison, $actual)
+							&& compare($actual, high_comparison, self.length_to_px(*high)?)
+					}
+					$ty::Min(_, _, _, value, _) => $actual >= self.length_to_px(*value)?,
+					$ty::Max(_, _, _, value, _) => $actual <= self.length_to_px(*value)?,
+					$ty::Exact(_, _, _, value, _) => $actual == self.length_to_px(*value)?,
+				}
+			};
+		}
+		Some(match feature {
+			MediaFeature::Width(width) => ranged!(width, WidthMediaFeature, self.viewport.width),
+			MediaFeature::Height(height) => ranged!(height, HeightMediaFeature, self.viewport.height),
+			MediaFeature::Orientation(OrientationMediaFeature::WithValue(_, _, _, orientation, _)) => {
+				let portrait = self.viewport.height >= self.viewport.width;
+				matches!(orientation, OrientationMediaFeatureKeyword::Portrait(_)) == portrait
+			}
+			_ => None?,
+		})
+	}
+
+	/// Returns the spans of every rule which is not needed to render the document. Rules nested within a removed rule
+	/// are not included, as they are removed along with their parent.
+	fn unused_spans(&self) -> Vec<Span> {
+		let mut keep = vec![false; self.rules.len()];
+		let mut has_content = vec![false; self.rules.len()];
+		// Children always come after their parents, so walking backwards visits every child before its parent.
+		for (i, rule) in self.rules.iter().enumerate().rev() {
+			keep[i] = rule.active
+				&& match &rule.kind {
+					CriticalRuleKind::Style => true,
+					CriticalRuleKind::Group => has_content[i],
+					CriticalRuleKind::FontFace(family) => family.as_ref().is_some_and(|f| self.families.contains(f)),
+					CriticalRuleKind::FontFeatureValues(families) => families.iter().any(|f| self.families.contains(f)),
+					CriticalRuleKind::Keyframes(name)
+					| CriticalRuleKind::Property(name)
+					| CriticalRuleKind::CounterStyle(name)
+					| CriticalRuleKind::FontPaletteValues(name)
+					| CriticalRuleKind::ColorProfile(name)
+					| CriticalRuleKind::PositionTry(name) => self.identifiers.contains(name),
+					CriticalRuleKind::Unused => false,
+				};
+			if let Some(parent) = rule.parent {
+				has_content[parent] |= keep[i];
+			}
+		}
+		self.rules
+			.iter()
+			.enumerate()
+			.filter(|(i, rule)| !keep[*i] && rule.parent.is_none_or(|parent| keep[parent]))
+			.map(|(_, rule)| rule.span)
+			.collect()
+	}
+}
+
+impl<'a, 'd> Visit for CriticalExtractor<'a, 'd> {
+	fn visit_style_rule(&mut self, rule: &StyleRule) {
+		let matched = if self.parent_active() {
+			self.matcher.matching_elements(&rule.rule.prelude, self.scopes.last())
+		} else {
+			HashSet::new()
+		};
+		self.enter(rule.to_span(), CriticalRuleKind::Style, !matched.is_empty());
+		self.scopes.push(matched);
+	}
+
+	fn exit_style_rule(&mut self, _rule: &StyleRule) {
+		self.scopes.pop();
+		self.exit();
+	}
+
+	fn visit_media_rule(&mut self, rule: &MediaRule) {
+		let matches = (&rule.prelude.0).into_iter().any(|(query, _)| self.media_query_matches(query));
+		self.enter(rule.to_span(), CriticalRuleKind::Group, matches);
+	}
+
+	fn exit_media_rule(&mut self, _rule: &MediaRule) {
+		self.exit();
+	}
+
+	fn visit_supports_rule(&mut self, rule: &SupportsRule) {
+		self.enter(rule.to_span(), CriticalRuleKind::Group, true);
+	}
+
+	fn exit_supports_rule(&mut self, _rule: &SupportsRule) {
+		self.exit();
+	}
+
+	fn visit_container_rule(&mut self, rule: &ContainerRule) {
+		self.enter(rule.to_span(), CriticalRuleKind::Group, true);
+	}
+
+	fn exit_container_rule(&mut self, _rule: &ContainerRule) {
+		self.exit();
+	}
+
+	fn visit_scope_rule(&mut self, rule: &ScopeRule) {
+		self.enter(rule.to_span(), CriticalRuleKind::Group, true);
+	}
+
+	fn exit_scope_rule(&mut self, _rule: &ScopeRule) {
+		self.exit();
+	}
+
+	fn visit_starting_style_rule(&mut self, rule: &StartingStyleRule) {
+		self.enter(rule.to_span(), CriticalRuleKind::Group, true);
+	}
+
+	fn exit_starting_style_rule(&mut self, _rule: &StartingStyleRule) {
+		self.exit();
+	}
+
+	fn visit_layer_rule(&mut self, rule: &LayerRule) {
+		// Layer statements such as `@layer reset, base;` establish layer order, so they are always kept.
+		if rule.block.is_some() {
+			self.enter(rule.to_span(), CriticalRuleKind::Group, true);
+		}
+	}
+
+	fn exit_layer_rule(&mut self, rule: &LayerRule) {
+		if rule.block.is_some() {
+			self.exit();
+		}
+	}
+
+	fn visit_font_face_rule(&mut self, rule: &FontFaceRule) {
+		self.enter(rule.to_span(), CriticalRuleKind::FontFace(None), true);
+	}
+
+	fn exit_font_face_rule(&mut self, _rule: &FontFaceRule) {
+		self.exit();
+	}
+
+	fn visit_font_feature_values_rule(&mut self, rule: &FontFeatureValuesRule) {
+		let span = rule.prelude.to_span();
+		let prelude = &self.matcher.source_text[usize::from(span.start())..usize::from(span.end())];
+		let families = font_families(prelude).collect();
+		self.enter(rule.to_span(), CriticalRuleKind::FontFeatureValues(families), true);
+	}
+
+	fn exit_font_feature_values_rule(&mut self, _rule: &FontFeatureValuesRule) {
+		self.exit();
+	}
+
+	fn visit_font_palette_values_rule(&mut self, rule: &FontPaletteValuesRule) {
+		let name = self.name(rule.prelude.ident());
+		self.enter(rule.to_span(), CriticalRuleKind::FontPaletteValues(name), true);
+	}
+
+	fn exit_font_palette_values_rule(&mut self, _rule: &FontPaletteValuesRule) {
+		self.exit();
+	}
+
+	fn visit_color_profile_rule(&mut self, rule: &ColorProfileRule) {
+		let name = self.name(rule.prelude.into());
+		self.enter(rule.to_span(), CriticalRuleKind::ColorProfile(name), true);
+	}
+
+	fn exit_color_profile_rule(&mut self, _rule: &ColorProfileRule) {
+		self.exit();
+	}
+
+	fn visit_position_try_rule(&mut self, rule: &PositionTryRule) {
+		let name = self.name(rule.prelude.ident());
+		self.enter(rule.to_span(), CriticalRuleKind::PositionTry(name), true);
+	}
+
+	fn exit_position_try_rule(&mut self, _rule: &PositionTryRule) {
+		self.exit();
+	}
+
+	fn visit_keyframes_rule(&mut self, rule: &KeyframesRule) {
+		let name = self.name(rule.prelude.into());
+		self.enter(rule.to_span(), CriticalRuleKind::Keyframes(name), true);
+	}
+
+	fn exit_keyframes_rule(&mut self, _rule: &KeyframesRule) {
+		self.exit();
+	}
+
+	fn visit_webkit_keyframes_rule(&mut self, rule: &WebkitKeyframesRule) {
+		let name = self.name(rule.prelude.into());
+		self.enter(rule.to_span(), CriticalRuleKind::Keyframes(name), true);
+	}
+
+	fn exit_webkit_keyframes_rule(&mut self, _rule: &WebkitKeyframesRule) {
+		self.exit();
+	}
+
+	fn visit_property_rule(&mut self, rule: &PropertyRule) {
+		let name = self.name(rule.prelude.ident());
+		self.enter(rule.to_span(), CriticalRuleKind::Property(name), true);
+	}
+
+	fn exit_property_rule(&mut self, _rule: &PropertyRule) {
+		self.exit();
+	}
+
+	fn visit_counter_style_rule(&mut self, rule: &CounterStyleRule) {
+		let name = self.name(rule.prelude.into());
+		self.enter(rule.to_span(), CriticalRuleKind::CounterStyle(name), true);
+	}
+
+	fn exit_counter_style_rule(&mut self, _rule: &CounterStyleRule) {
+		self.exit();
+	}
+
+	fn visit_page_rule(&mut self, rule: &PageRule) {
+		self.enter(rule.to_span(), CriticalRuleKind::Unused, false);
+	}
+
+	fn exit_page_rule(&mut self, _rule: &PageRule) {
+		self.exit();
+	}
+
+	fn visit_declaration<'b, T: DeclarationValue<'b, CssMetadata> + QueryableNode>(
+		&mut self,
+		declaration: &Declaration<'b, T, CssMetadata>,
+	) {
+		let Some(&current) = self.stack.last() else { return };
+		let source_text = self.matcher.source_text;
+		let span = declaration.value.to_span();
+		let value = &source_text[usize::from(span.start())..usize::from(span.end())];
+		let name = self.name(declaration.name.into()).to_ascii_lowercase();
+		let rule = &mut self.rules[current];
+		match &mut rule.kind {
+			CriticalRuleKind::FontFace(family) if name == "font-family" => {
+				*family = font_families(value).next();
+			}
+			// Declarations directly within a group are those of a parent style rule, e.g. `a { @media { color: red } }`.
+			CriticalRuleKind::Style | CriticalRuleKind::Group if rule.active => {
+				// Custom properties could be used for any of these, so treat them as fonts and identifiers too.
+				if matches!(name.as_str(), "font" | "font-family") || name.starts_with("--") {
+					self.families.extend(font_families(value));
+				}
+				let identifiers = value
+					.split(|c: char| !c.is_alphanumeric() && c != '-' && c != '_' && c.is_ascii())
+					.filter(|identifier| !identifier.is_empty())
+					.map(String::from);
+				self.identifiers.extend(identifiers);
+				self.identifiers.insert(name);
+			}
+			_ => {}
+		}
+	}
+}
+
+/// Extract the critical subset of a stylesheet needed to render a HTML document.
+#[derive(Debug, Args)]
+#[command(arg_required_else_help(true))]
+pub struct Critical {
+	#[command(flatten)]
+	content: InputArgs,
+
+	/// The HTML document to extract critical styles for.
+	#[arg(long, value_name = "FILE", required = true)]
+	html: String,
+
+	/// The size of the viewport, used to evaluate media queries.
+	#[arg(long, value_name = "WIDTHxHEIGHT", default_value = "1300x900")]
+	viewport: Viewport,
+
+	/// Where to save files.
+	#[arg(short, long, group = "output_file", value_parser)]
+	output: Option<String>,
+}
+
+impl Critical {
+	pub fn run(&self, _config: GlobalConfig) -> CliResult {
+		let Critical { content, html, viewport, output } = self;
+		let start = std::time::Instant::now();
+		let html_source = std::fs::read_to_string(html)?;
+		let document = HtmlDocument::parse(&html_source);
+		let bump = Bump::default();
+		let mut str = String::new();
+		let mut removed = 0;
+		for (file_name, mut source) in content.sources()? {
+			let mut source_string = String::new();
+			source.read_to_string(&mut source_string)?;
+			removed += remove_unused_rules(&source_string, file_name, &bump, &mut str, true, |stylesheet| {
+				let mut extractor = CriticalExtractor::new(&document, *viewport, &source_string, &bump);
+				stylesheet.accept(&mut extractor);
+				extractor.unused_spans()
+			})?;
+		}
+		if let Some(file) = output {
+			std::fs::write(file, str.as_bytes())?;
+		} else {
+			println!("{str}");
+		}
+		eprintln!(
+			"Extracted critical styles for {} element{}, removing {removed} rule{} in {:?}! Neat!",
+			document.elements.len(),
+			if document.elements.len() == 1 { "" } else { "s" },
+			if removed == 1 { "" } else { "s" },
+			start.elapsed()
+		);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn critical_for(css: &str, html: &str, viewport: Viewport) -> String {
+		let document = HtmlDocument::parse(html);
+		let bump = Bump::default();
+		let mut output = String::new();
+		remove_unused_rules(css, "test.css", &bump, &mut output, true, |stylesheet| {
+			let mut extractor = CriticalExtractor::new(&document, viewport, css, &bump);
+			stylesheet.accept(&mut extractor);
+			extractor.unused_spans()
+		})
+		.unwrap();
+		output
+	}
+
+	fn critical(css: &str, html: &str) -> String {
+		critical_for(css, html, Viewport { width: 1300.0, height: 900.0 })
+	}
+
+	#[test]
+	fn parses_viewports() {
+		assert_eq!("800x600".parse(), Ok(Viewport { width: 800.0, height: 600.0 }));
+		assert!("800".parse::<Viewport>().is_err());
+		assert!("wide x 600".parse::<Viewport>().is_err());
+	}
+
+	#[test]
+	fn matches_combinators() {
+		let html = r#"<div class="a"><p class="b"></p><span class="c"></span><em></em></div><p class="d"></p>"#;
+		assert_eq!(
+			critical(".a .b{top:0}.a>.c{top:0}body>.b{top:0}body .b{top:0}", html),
+			".a .b{top:0}.a>.c{top:0}body .b{top:0}"
+		);
+		assert_eq!(
+			critical(".b+.c{top:0}.c+.b{top:0}.b~em{top:0}em~.b{top:0}.a~.d{top:0}", html),
+			".b+.c{top:0}.b~em{top:0}.a~.d{top:0}"
+		);
+	}
+
+	#[test]
+	fn matches_nested_rules() {
+		let html = r#"<div class="a"><p class="b"><span class="c"></span></p></div>"#;
+		assert_eq!(
+			critical(".a{top:0;& .c{top:0}&>.b{top:0}&>.c{top:0}.x &{top:0}.b{top:0}}", html),
+			".a{top:0;& .c{top:0}&>.b{top:0}.b{top:0}}"
+		);
+		assert_eq!(critical(".b{.a &{top:0}.c &{top:0}}", html), ".b{.a &{top:0}}");
+	}
+
+	#[test]
+	fn matches_pseudo_classes() {
+		let html = r#"<ul><li>1</li><li class="x">2</li><li>3</li></ul><input disabled><input required>"#;
+		assert_eq!(
+			critical("li:first-child{top:0}li:nth-child(2).x{top:0}li:nth-child(4){top:0}li:last-child{top:0}", html),
+			"li:first-child{top:0}li:nth-child(2).x{top:0}li:last-child{top:0}"
+		);
+		// Text is not part of the document, so `:empty` can only rule out elements with child elements.
+		assert_eq!(
+			critical("ul:empty{top:0}li:only-child{top:0}li:empty{top:0}:root{top:0}", html),
+			"li:empty{top:0}:root{top:0}"
+		);
+		assert_eq!(
+			critical("input:disabled{top:0}input:required{top:0}input:checked{top:0}", html),
+			"input:disabled{top:0}input:required{top:0}"
+		);
+		assert_eq!(critical("li:hover{top:0}li:focus{top:0}li::before{top:0}", html), "li::before{top:0}");
+		assert_eq!(
+			critical("li:not(.x){top:0}ul:not(ul){top:0}ul:has(>.x){top:0}ul:has(.y){top:0}li:is(.y,.x){top:0}", html),
+			"li:not(.x){top:0}ul:has(>.x){top:0}li:is(.y,.x){top:0}"
+		);
+	}
+
+	#[test]
+	fn matches_media_queries() {
+		let html = r#"<div class="a"></div>"#;
+		assert_eq!(
+			critical(
+				"@media(min-width:768px){.a{top:0}}@media(max-width:600px){.a{top:0}}@media print{.a{top:0}}",
+				html
+			),
+			"@media(min-width:768px){.a{top:0}}"
+		);
+		assert_eq!(
+			critical(
+				"@media(width>=80em){.a{top:0}}@media not print{.a{top:0}}@media(min-width:768px){.b{top:0}}",
+				html
+			),
+			"@media(width>=80em){.a{top:0}}@media not print{.a{top:0}}"
+		);
+		let portrait = Viewport { width: 400.0, height: 800.0 };
+		assert_eq!(
+			critical_for("@media(orientation:landscape){.a{top:0}}@media(hover:hover){.a{top:0}}", html, portrait),
+			"@media(hover:hover){.a{top:0}}"
+		);
+	}
+
+	#[test]
+	fn keeps_at_rules_used_by_critical_styles() {
+		let html = r#"<div class="a"></div>"#;
+		let css =
+			"@keyframes spin{to{rotate:1turn}}@keyframes fade{to{opacity:0}}.a{animation-name:spin}.b{animation-name:fade}";
+		assert_eq!(critical(css, html), "@keyframes spin{to{rotate:1turn}}.a{animation-name:spin}");
+		let css =
+			"@font-face{font-family:\"Sans\"}@font-face{font-family:Serif}.a{font-family:sans,serif}@page{margin:0}";
+		assert_eq!(
+			critical(css, html),
+			"@font-face{font-family:\"Sans\"}@font-face{font-family:Serif}.a{font-family:sans,serif}"
+		);
+	}
+}
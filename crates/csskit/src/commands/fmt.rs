@@ -1,12 +1,22 @@
-use crate::{CliError, CliResult, GlobalConfig, InputArgs};
+use crate::{CliError, CliResult, GlobalConfig, InputArgs, commands::min::MAX_PASSES};
 use bumpalo::Bump;
-use clap::Args;
+use clap::{Args, ValueEnum};
 use css_ast::{CssAtomSet, StyleSheet, Visitable};
 use css_lexer::{Lexer, QuoteStyle};
-use css_parse::{CursorPrettyWriteSink, Parser, ToCursors};
+use css_parse::{CursorOverlaySink, CursorPrettyWriteSink, CursorWriteSink, Parser, ToCursors};
 use csskit_highlight::{AnsiHighlightCursorStream, DefaultAnsiTheme, TokenHighlighter};
+use csskit_transform::{CssBuildFeature, CssBuildOptions, DeclarationOrder, Transformer};
 use std::io::Read;
 
+/// How to order the declarations within each rule.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum SortOrder {
+	/// Sort declarations by property name
+	Alphabetical,
+	/// Sort declarations by what they style, such as positioning, then the box model, then typography
+	Grouped,
+}
+
 /// Format CSS files to make them more readable.
 #[derive(Debug, Args)]
 pub struct Fmt {
@@ -29,15 +39,56 @@ pub struct Fmt {
 	/// Rewrite quotes to single quotes
 	#[arg(long, value_parser)]
 	single_quotes: bool,
+
+	/// Sort the declarations within each rule. Custom properties are kept first, and declarations whose order matters
+	/// (such as a fallback and its replacement, or a shorthand and its longhands) keep their order.
+	#[arg(long, value_enum, value_name = "ORDER", conflicts_with = "property_order")]
+	sort: Option<SortOrder>,
+
+	/// Sort the declarations within each rule into this order of properties. Names may start or end with `*`, such as
+	/// `margin*`. Properties which are not listed are sorted by name, after those which are.
+	#[arg(long, value_name = "PROPERTY", value_delimiter = ',')]
+	property_order: Vec<String>,
+}
+
+/// Reorders the declarations in the source, returning the source unchanged if they are already in order.
+fn sort_declarations<'a>(bump: &'a Bump, source_text: &'a str, order: &DeclarationOrder) -> Result<&'a str, CliError> {
+	let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+	let mut parser = Parser::new(bump, source_text, lexer);
+	let result = parser.parse_entirely::<StyleSheet>();
+	if result.output.is_none() {
+		return Ok(source_text);
+	}
+	let options = CssBuildOptions { declaration_order: order.clone(), ..Default::default() };
+	let mut transformer = Transformer::new_in(bump, CssBuildFeature::SortDeclarations, &CssAtomSet::ATOMS, source_text)
+		.with_options(options);
+	let result = transformer.transform_until_stable(result, MAX_PASSES)?;
+	if !transformer.has_changed() {
+		return Ok(source_text);
+	}
+	let overlays = transformer.overlays();
+	let mut output = String::new();
+	result.to_cursors(&mut CursorOverlaySink::new(
+		result.source_text,
+		&overlays,
+		CursorWriteSink::new(result.source_text, &mut output),
+	));
+	Ok(bump.alloc_str(&output))
 }
 
 impl Fmt {
 	pub fn run(&self, config: GlobalConfig) -> CliResult {
-		let Fmt { content, output, check, expand_tab, single_quotes } = self;
+		let Fmt { content, output, check, expand_tab, single_quotes, sort, property_order } = self;
 		let color = config.colors() && output.is_none() && !*check;
 		let bump = Bump::default();
 		let start = std::time::Instant::now();
 		let quotes = if *single_quotes { QuoteStyle::Single } else { QuoteStyle::Double };
+		let order = match sort {
+			Some(SortOrder::Alphabetical) => Some(DeclarationOrder::Alphabetical),
+			Some(SortOrder::Grouped) => Some(DeclarationOrder::Grouped),
+			None if !property_order.is_empty() => Some(DeclarationOrder::Custom(property_order.clone())),
+			None => None,
+		};
 		if *check && output.is_some() {
 			eprintln!("Ignoring output option, because check was passed");
 		}
@@ -45,7 +96,10 @@ impl Fmt {
 		for (file_name, mut source) in content.sources()? {
 			let mut source_string = String::new();
 			source.read_to_string(&mut source_string)?;
-			let source_text = source_string.as_str();
+			let mut source_text = source_string.as_str();
+			if let Some(order) = &order {
+				source_text = sort_declarations(&bump, bump.alloc_str(source_text), order)?;
+			}
 			let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
 			let mut parser = Parser::new(&bump, source_text, lexer);
 			let result = parser.parse_entirely::<StyleSheet>();
@@ -62,7 +116,7 @@ impl Fmt {
 					result.to_cursors(&mut stream);
 				}
 				if *check {
-					if str != source_text {
+					if str != source_string {
 						println!("{str}");
 						checks += 1;
 					}
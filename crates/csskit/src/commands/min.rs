@@ -1,12 +1,114 @@
 use crate::{CliError, CliResult, GlobalConfig, InputArgs};
 use bumpalo::Bump;
-use clap::Args;
+use clap::{Args, ValueEnum};
 use css_ast::{CssAtomSet, StyleSheet, Visitable};
 use css_lexer::Lexer;
 use css_parse::{CursorCompactWriteSink, CursorOverlaySink, Parser, ToCursors};
 use csskit_highlight::{AnsiHighlightCursorStream, DefaultAnsiTheme, TokenHighlighter};
-use csskit_transform::{CssMinifierFeature, Transformer};
-use std::io::Read;
+use csskit_transform::{CommentRetention, CssMinifierFeature, TransformStats, Transformer};
+use serde::Serialize;
+use std::io::{Read, Write};
+
+/// The most times the minifier will re-run its transforms over their own output.
+pub(crate) const MAX_PASSES: usize = 8;
+
+/// Which comments to keep in the output.
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum Comments {
+	/// Remove every comment
+	None,
+	/// Keep comments starting with `/*!`
+	#[default]
+	Bang,
+	/// Keep comments starting with `/*!`, or containing `@license` or `@preserve`
+	License,
+}
+
+impl From<Comments> for CommentRetention {
+	fn from(comments: Comments) -> Self {
+		match comments {
+			Comments::None => Self::None,
+			Comments::Bang => Self::Bang,
+			Comments::License => Self::License,
+		}
+	}
+}
+
+/// Output format for minification stats.
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum StatsFormat {
+	/// Human readable text output
+	#[default]
+	Text,
+	/// JSON output
+	Json,
+}
+
+#[derive(Default, Serialize)]
+struct SizeStats {
+	bytes: usize,
+	gzip: usize,
+	brotli: usize,
+}
+
+impl SizeStats {
+	fn new(text: &str) -> std::io::Result<Self> {
+		let mut gzip = flate2::write::GzEncoder::new(vec![], flate2::Compression::best());
+		gzip.write_all(text.as_bytes())?;
+		let mut brotli = brotli::CompressorWriter::new(vec![], 4096, 11, 22);
+		brotli.write_all(text.as_bytes())?;
+		Ok(Self { bytes: text.len(), gzip: gzip.finish()?.len(), brotli: brotli.into_inner().len() })
+	}
+}
+
+#[derive(Serialize)]
+struct TransformStatsJson {
+	name: &'static str,
+	edits: usize,
+	bytes_saved: isize,
+}
+
+#[derive(Serialize)]
+struct MinStats {
+	original: SizeStats,
+	minified: SizeStats,
+	transforms: Vec<TransformStatsJson>,
+}
+
+impl MinStats {
+	fn print(&self, format: StatsFormat, to_stdout: bool) -> CliResult {
+		let str = match format {
+			StatsFormat::Json => serde_json::to_string_pretty(self)?,
+			StatsFormat::Text => {
+				let MinStats { original, minified, transforms } = self;
+				let percent = |a: usize, b: usize| if b == 0 { 0.0 } else { 100.0 - (a as f64 / b as f64) * 100.0 };
+				let mut str = String::new();
+				str.push_str(&format!("{:<10}{:>12}{:>12}{:>12}\n", "", "bytes", "gzip", "brotli"));
+				for (name, stats) in [("Original", original), ("Minified", minified)] {
+					str.push_str(&format!("{name:<10}{:>12}{:>12}{:>12}\n", stats.bytes, stats.gzip, stats.brotli));
+				}
+				str.push_str(&format!(
+					"{:<10}{:>11.1}%{:>11.1}%{:>11.1}%\n",
+					"Saved",
+					percent(minified.bytes, original.bytes),
+					percent(minified.gzip, original.gzip),
+					percent(minified.brotli, original.brotli),
+				));
+				for TransformStatsJson { name, edits, bytes_saved } in transforms {
+					let s = if *edits == 1 { " " } else { "s" };
+					str.push_str(&format!("\n{name:<22}{edits:>8} edit{s}{bytes_saved:>10} bytes saved"));
+				}
+				str
+			}
+		};
+		if to_stdout {
+			println!("{str}");
+		} else {
+			eprintln!("{str}");
+		}
+		Ok(())
+	}
+}
 
 /// Minify CSS files to compress them optimized delivery.
 #[derive(Debug, Args)]
@@ -23,11 +125,31 @@ pub struct Min {
 	/// This will exit with a non-zero status code if any changes need to be made. Useful for CI.
 	#[arg(long, value_parser)]
 	check: bool,
+
+	/// Which comments to keep, such as license banners.
+	#[arg(long, value_enum, default_value_t)]
+	comments: Comments,
+
+	/// Report the size savings of minification, along with the edits each transform made. Stats are written to stdout
+	/// when saving to a file, and stderr otherwise.
+	#[arg(long, value_enum, value_name = "FORMAT", num_args = 0..=1, require_equals = true, default_missing_value = "text")]
+	stats: Option<StatsFormat>,
+
+	/// Inline custom properties declared once on `:root` or `html` into each `var()` which uses them, removing their
+	/// declarations once unused. Only use this if custom properties are never set outside of these files, such as from
+	/// scripts or inline styles.
+	#[arg(long, value_parser)]
+	inline_custom_properties: bool,
 }
 
 impl Min {
 	pub fn run(&self, config: GlobalConfig) -> CliResult {
-		let Min { content, output, check } = self;
+		let Min { content, output, check, comments, stats, inline_custom_properties } = self;
+		let mut features = CssMinifierFeature::safe();
+		if *inline_custom_properties {
+			features |= CssMinifierFeature::InlineCustomProperties;
+		}
+		let retention = CommentRetention::from(*comments);
 		let color = config.colors() && output.is_none() && !*check;
 		let bump = Bump::default();
 		let start = std::time::Instant::now();
@@ -35,23 +157,38 @@ impl Min {
 			eprintln!("Ignoring output option, because check was passed");
 		}
 		let mut checks = 0;
+		let mut original = String::new();
+		let mut minified = String::new();
+		let mut transforms: Vec<TransformStatsJson> = vec![];
 		for (file_name, mut source) in content.sources()? {
 			let mut source_string = String::new();
 			source.read_to_string(&mut source_string)?;
 			let source_text = source_string.as_str();
 			let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
 			let mut parser = Parser::new(&bump, source_text, lexer);
-			let mut result = parser.parse_entirely::<StyleSheet>();
-			if let Some(ref mut stylesheet) = result.output {
-				let mut transformer =
-					Transformer::new_in(&bump, CssMinifierFeature::all_bits(), &CssAtomSet::ATOMS, source_text);
-				transformer.transform(stylesheet);
+			let result = parser.parse_entirely::<StyleSheet>().retain_trivia(|c| retention.retains(c, source_text));
+			if result.output.is_some() {
+				let mut transformer = Transformer::new_in(&bump, features, &CssAtomSet::ATOMS, source_text);
+				let result = transformer.transform_until_stable(result, MAX_PASSES)?;
+				let original_text = source_text;
+				let source_text = result.source_text;
+				for (feature, TransformStats { edits, bytes_saved }) in transformer.stats().iter() {
+					let name =
+						CssMinifierFeature::flags().find(|(_, f)| f == feature).map_or("Unknown", |(name, _)| name);
+					match transforms.iter_mut().find(|t| t.name == name) {
+						Some(t) => {
+							t.edits += edits;
+							t.bytes_saved += bytes_saved;
+						}
+						None => transforms.push(TransformStatsJson { name, edits: *edits, bytes_saved: *bytes_saved }),
+					}
+				}
 				let overlays = transformer.overlays();
 
 				let mut str = String::new();
 				if color {
 					let mut highlighter = TokenHighlighter::new();
-					stylesheet.accept(&mut highlighter);
+					result.output.accept(&mut highlighter);
 					let ansi = AnsiHighlightCursorStream::new(&mut str, &highlighter, DefaultAnsiTheme);
 					let mut stream =
 						CursorOverlaySink::new(source_text, &overlays, CursorCompactWriteSink::new(source_text, ansi));
@@ -64,8 +201,23 @@ impl Min {
 					);
 					result.to_cursors(&mut stream);
 				};
+				if stats.is_some() {
+					original.push_str(original_text);
+					if color {
+						let mut plain = String::new();
+						let mut stream = CursorOverlaySink::new(
+							source_text,
+							&overlays,
+							CursorCompactWriteSink::new(source_text, &mut plain),
+						);
+						result.to_cursors(&mut stream);
+						minified.push_str(&plain);
+					} else {
+						minified.push_str(&str);
+					}
+				}
 				if *check {
-					if str != source_text {
+					if str != original_text {
 						println!("{str}");
 						checks += 1;
 					}
@@ -81,6 +233,11 @@ impl Min {
 				}
 			}
 		}
+		if let Some(format) = stats {
+			let stats =
+				MinStats { original: SizeStats::new(&original)?, minified: SizeStats::new(&minified)?, transforms };
+			stats.print(*format, output.is_some())?;
+		}
 		eprintln!("Slurped up CSS in {:?}! Neat!", start.elapsed());
 		if checks > 0 { Err(CliError::Checks(checks))? } else { Ok(()) }
 	}
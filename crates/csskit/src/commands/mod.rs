@@ -5,6 +5,7 @@ use css_parse::{Diagnostic, DiagnosticMeta};
 mod build;
 mod check;
 mod colors;
+mod critical;
 mod dbg_lex;
 mod dbg_parse;
 mod expand;
@@ -12,6 +13,7 @@ mod find;
 mod fmt;
 mod lsp;
 mod min;
+mod purge;
 mod tree;
 
 #[derive(Subcommand, Debug)]
@@ -35,6 +37,14 @@ pub enum Commands {
 	#[command(hide = true)]
 	Expand(expand::Expand),
 
+	/// Remove unused style rules by checking selectors against HTML or template files.
+	#[command(arg_required_else_help(true))]
+	Purge(purge::Purge),
+
+	/// Extract the subset of a stylesheet needed to render a HTML document.
+	#[command(arg_required_else_help(true))]
+	Critical(critical::Critical),
+
 	/// Extract the colours from a CSS file.
 	Colors(colors::ColorCommand),
 
@@ -66,6 +76,8 @@ impl Commands {
 			Commands::Fmt(cmd) => cmd.run(config),
 			Commands::Min(cmd) => cmd.run(config),
 			Commands::Expand(cmd) => cmd.run(config),
+			Commands::Purge(cmd) => cmd.run(config),
+			Commands::Critical(cmd) => cmd.run(config),
 			Commands::Colors(cmd) => cmd.run(config),
 			Commands::Colours(cmd) => cmd.run(config),
 			Commands::DbgLex(cmd) => cmd.run(config),
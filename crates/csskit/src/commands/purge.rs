@@ -0,0 +1,306 @@
+use crate::{
+	CliError, CliResult, GlobalConfig, InputArgs,
+	html::{HtmlAttribute, HtmlToken, HtmlTokenizer},
+	unused_rules::{cursor_text, remove_unused_rules},
+};
+use bumpalo::Bump;
+use clap::Args;
+use css_ast::{CompoundSelector, FunctionalPseudoClass, SelectorComponent, SelectorList, StyleRule, Visit, Visitable};
+use css_lexer::Cursor;
+use css_parse::{Span, ToSpan};
+use glob::Pattern;
+use std::{collections::HashSet, io::Read};
+
+/// Characters which separate class names or ids within an attribute value. This is deliberately generous so that
+/// template expressions like `{{ active ? 'is-active' : '' }}` still yield `is-active`.
+fn is_name_separator(c: char) -> bool {
+	c.is_whitespace() || matches!(c, '"' | '\'' | '`' | '{' | '}' | '(' | ')' | ',' | ';' | '?' | '+' | '=' | '<' | '>')
+}
+
+/// Every tag name, class name, id, and attribute name found across a set of HTML or template documents.
+struct UsedNames {
+	tags: HashSet<String>,
+	classes: HashSet<String>,
+	ids: HashSet<String>,
+	attributes: HashSet<String>,
+	safelist: Vec<Pattern>,
+}
+
+impl UsedNames {
+	fn new(safelist: Vec<Pattern>) -> Self {
+		// Browsers create these elements even when a document omits them.
+		let tags = ["html", "head", "body"].into_iter().map(String::from).collect();
+		Self { tags, classes: HashSet::new(), ids: HashSet::new(), attributes: HashSet::new(), safelist }
+	}
+
+	fn add_document(&mut self, source: &str) {
+		for token in HtmlTokenizer::new(source) {
+			let HtmlToken::StartTag { name, attributes, .. } = token else { continue };
+			self.tags.insert(name.to_ascii_lowercase());
+			for HtmlAttribute { name, value } in attributes {
+				let name = name.to_ascii_lowercase();
+				// Strip framework binding syntax, e.g. Vue's `:class`, or Angular's `[class]`.
+				let bare = name.trim_matches(|c| matches!(c, '[' | ']' | ':'));
+				// Toggled classes, e.g. Svelte's `class:active` or Angular's `[class.active]`.
+				if let Some(class) = bare.strip_prefix("class:").or_else(|| bare.strip_prefix("class.")) {
+					self.classes.insert(class.to_string());
+				}
+				let short = bare.rsplit(':').next().unwrap_or(bare);
+				if let Some(value) = value {
+					let names = value.split(is_name_separator).filter(|s| !s.is_empty()).map(String::from);
+					// `class`, React's `className`, and Angular's `ngClass`, but not attributes which merely end in
+					// `class`, such as `data-subclass`.
+					if matches!(short, "class" | "classname" | "ngclass") {
+						self.classes.extend(names);
+					} else if short == "id" {
+						self.ids.extend(names);
+					}
+				}
+				self.attributes.insert(bare.to_string());
+			}
+		}
+	}
+
+	fn is_safelisted(&self, name: &str) -> bool {
+		self.safelist.iter().any(|pattern| pattern.matches(name))
+	}
+}
+
+/// Collects the spans of every [StyleRule] which has no selector that could match the [UsedNames].
+struct Purger<'a, 'u> {
+	used: &'u UsedNames,
+	source_text: &'a str,
+	bump: &'a Bump,
+	unused: Vec<Span>,
+	/// How many [StyleRule]s deep we are inside of an unused rule. Nested rules of an unused rule are removed along
+	/// with their parent, so they need no edits of their own.
+	unused_depth: usize,
+}
+
+impl<'a, 'u> Purger<'a, 'u> {
+	fn new(used: &'u UsedNames, source_text: &'a str, bump: &'a Bump) -> Self {
+		Self { used, source_text, bump, unused: vec![], unused_depth: 0 }
+	}
+
+	fn name(&self, cursor: Cursor) -> String {
+		cursor_text(cursor, self.source_text, self.bump).to_string()
+	}
+
+	fn may_match_list(&self, list: &SelectorList) -> bool {
+		(&list.0).into_iter().any(|(selector, _)| self.may_match(selector))
+	}
+
+	/// Returns false only if the selector definitely cannot match anything in the [UsedNames]. Anything which depends
+	/// on document state (pseudo classes, pseudo elements, combinators) is assumed to match.
+	fn may_match(&self, selector: &CompoundSelector) -> bool {
+		selector.0.iter().all(|component| match component {
+			SelectorComponent::Tag(tag) => self.used.tags.contains(&self.name((*tag).into()).to_ascii_lowercase()),
+			SelectorComponent::Class(class) => {
+				let name = self.name(class.name.into());
+				self.used.classes.contains(&name) || self.used.is_safelisted(&name)
+			}
+			SelectorComponent::Id(id) => {
+				let name = self.name((*id).into());
+				self.used.ids.contains(&name) || self.used.is_safelisted(&name)
+			}
+			SelectorComponent::Attribute(attribute) => {
+				self.used.attributes.contains(&self.name(attribute.attribute.into()).to_ascii_lowercase())
+			}
+			SelectorComponent::FunctionalPseudoClass(pseudo) => match pseudo {
+				FunctionalPseudoClass::Is(f) => self.may_match_list(&f.value),
+				FunctionalPseudoClass::Where(f) => self.may_match_list(&f.value),
+				FunctionalPseudoClass::Has(f) => self.may_match_list(&f.value),
+				// `:not()` can match almost anything, so it is never used to rule a selector out.
+				_ => true,
+			},
+			_ => true,
+		})
+	}
+}
+
+impl<'a, 'u> Visit for Purger<'a, 'u> {
+	fn visit_style_rule(&mut self, rule: &StyleRule) {
+		if self.unused_depth > 0 {
+			self.unused_depth += 1;
+		} else if !self.may_match_list(&rule.rule.prelude) {
+			self.unused.push(rule.to_span());
+			self.unused_depth = 1;
+		}
+	}
+
+	fn exit_style_rule(&mut self, _rule: &StyleRule) {
+		self.unused_depth = self.unused_depth.saturating_sub(1);
+	}
+}
+
+/// Remove style rules whose selectors cannot match any of the given HTML or template files.
+#[derive(Debug, Args)]
+#[command(arg_required_else_help(true))]
+pub struct Purge {
+	#[command(flatten)]
+	content: InputArgs,
+
+	/// HTML or template files to check selectors against. Accepts globs, such as `src/**/*.html`.
+	#[arg(long, value_name = "GLOB", required = true)]
+	html: Vec<String>,
+
+	/// Class names or ids that should always be kept, such as those generated at runtime. Accepts globs, such as
+	/// `js-*`.
+	#[arg(long, value_name = "GLOB")]
+	safelist: Vec<String>,
+
+	/// Where to save files.
+	#[arg(short, long, group = "output_file", value_parser)]
+	output: Option<String>,
+}
+
+impl Purge {
+	pub fn run(&self, _config: GlobalConfig) -> CliResult {
+		let Purge { content, html, safelist, output } = self;
+		let start = std::time::Instant::now();
+		let safelist = safelist.iter().map(|glob| Pattern::new(glob)).collect::<Result<_, _>>()?;
+		let mut used = UsedNames::new(safelist);
+		let mut documents = 0;
+		for glob in html {
+			for path in glob::glob(glob)? {
+				used.add_document(&std::fs::read_to_string(path.map_err(std::io::Error::from)?)?);
+				documents += 1;
+			}
+		}
+		// Without any documents every style rule would be removed, which is almost certainly a mistake in the globs.
+		if documents == 0 {
+			Err(CliError::NoFilesMatched(html.join(", ")))?;
+		}
+		let bump = Bump::default();
+		let mut str = String::new();
+		let mut removed = 0;
+		for (file_name, mut source) in content.sources()? {
+			let mut source_string = String::new();
+			source.read_to_string(&mut source_string)?;
+			removed += remove_unused_rules(&source_string, file_name, &bump, &mut str, false, |stylesheet| {
+				let mut purger = Purger::new(&used, &source_string, &bump);
+				stylesheet.accept(&mut purger);
+				purger.unused
+			})?;
+		}
+		if let Some(file) = output {
+			std::fs::write(file, str.as_bytes())?;
+		} else {
+			println!("{str}");
+		}
+		eprintln!(
+			"Purged {removed} unused rule{} against {documents} file{} in {:?}! Neat!",
+			if removed == 1 { "" } else { "s" },
+			if documents == 1 { "" } else { "s" },
+			start.elapsed()
+		);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use css_ast::{CssAtomSet, StyleSheet};
+	use css_lexer::Lexer;
+	use css_parse::Parser;
+
+	fn used(html: &str, safelist: &[&str]) -> UsedNames {
+		let mut used = UsedNames::new(safelist.iter().map(|glob| Pattern::new(glob).unwrap()).collect());
+		used.add_document(html);
+		used
+	}
+
+	/// Returns the source text of each rule purged from `css`.
+	fn purged(css: &str, html: &str, safelist: &[&str]) -> Vec<String> {
+		let used = used(html, safelist);
+		let bump = Bump::default();
+		let lexer = Lexer::new(&CssAtomSet::ATOMS, css);
+		let mut parser = Parser::new(&bump, css, lexer);
+		let stylesheet = parser.parse_entirely::<StyleSheet>().output.unwrap();
+		let mut purger = Purger::new(&used, css, &bump);
+		stylesheet.accept(&mut purger);
+		purger.unused.iter().map(|span| css[usize::from(span.start())..usize::from(span.end())].to_string()).collect()
+	}
+
+	/// Returns `css` with the rules which cannot match `html` removed.
+	fn purge(css: &str, html: &str) -> String {
+		let used = used(html, &[]);
+		let bump = Bump::default();
+		let mut output = String::new();
+		remove_unused_rules(css, "test.css", &bump, &mut output, false, |stylesheet| {
+			let mut purger = Purger::new(&used, css, &bump);
+			stylesheet.accept(&mut purger);
+			purger.unused
+		})
+		.unwrap();
+		output
+	}
+
+	#[test]
+	fn collects_names_from_html() {
+		let used = used(r#"<div class="a  b" id=c data-x><SPAN hidden>text</SPAN></div>"#, &[]);
+		assert_eq!(used.classes, HashSet::from(["a".into(), "b".into()]));
+		assert_eq!(used.ids, HashSet::from(["c".into()]));
+		assert!(used.tags.contains("div") && used.tags.contains("span") && used.tags.contains("body"));
+		assert!(used.attributes.contains("data-x") && used.attributes.contains("hidden"));
+	}
+
+	#[test]
+	fn collects_classes_from_template_bindings() {
+		let used = used(
+			r#"<a className="jsx" :class="open ? 'is-open' : ''" [class.active]="on" class:selected={on}></a>"#,
+			&[],
+		);
+		for class in ["jsx", "is-open", "active", "selected"] {
+			assert!(used.classes.contains(class), "expected {class:?} in {:?}", used.classes);
+		}
+	}
+
+	#[test]
+	fn only_collects_classes_from_class_attributes() {
+		let used = used(r#"<div data-subclass="a" subclass="b" class="c"></div>"#, &[]);
+		assert_eq!(used.classes, HashSet::from(["c".into()]));
+	}
+
+	#[test]
+	fn purges_rules_which_cannot_match() {
+		let html = r#"<div class="a" id="b" data-x></div>"#;
+		let css = ".a{}.c{}div{}span{}#b{}#c{}[data-x]{}[data-y]{}.a:hover{}.c:hover{}";
+		assert_eq!(purged(css, html, &[]), [".c{}", "span{}", "#c{}", "[data-y]{}", ".c:hover{}"]);
+	}
+
+	#[test]
+	fn purges_rules_with_unmatched_selector_lists() {
+		let html = r#"<div class="a"></div>"#;
+		let css = ".a,.b{}.b,.c{}:is(.a,.b){}:is(.b){}:where(.b){}:has(.b){}:not(.b){}.b .a{}";
+		assert_eq!(purged(css, html, &[]), [".b,.c{}", ":is(.b){}", ":where(.b){}", ":has(.b){}", ".b .a{}"]);
+	}
+
+	#[test]
+	fn purges_nested_rules_with_their_parent() {
+		let css = ".b{color:red;.a{color:blue}}.a{.b{color:red}}";
+		assert_eq!(purged(css, r#"<div class="a"></div>"#, &[]), [".b{color:red;.a{color:blue}}", ".b{color:red}"]);
+	}
+
+	#[test]
+	fn removes_group_rules_left_empty() {
+		let html = r#"<div class="a"></div>"#;
+		assert_eq!(purge(".a{color:red}@media(min-width:1px){.b{color:blue}}", html), ".a{color:red}");
+		assert_eq!(purge("@supports(display:grid){@media print{.b{}}.c{}}.a{}", html), ".a{}");
+		assert_eq!(purge("@media print{.a{}.b{}}", html), "@media print{.a{}}");
+		assert_eq!(purge("@layer base;@layer base{.b{}}", html), "@layer base;");
+	}
+
+	#[test]
+	fn keeps_formatting() {
+		let css = "/* banner */\n.a {\n\tcolor: red;\n}\n\n.b {\n\tcolor: blue;\n}\n";
+		assert_eq!(purge(css, r#"<div class="a"></div>"#), "/* banner */\n.a {\n\tcolor: red;\n}\n\n\n");
+	}
+
+	#[test]
+	fn keeps_safelisted_names() {
+		let css = ".js-menu{}#js-root{}.other{}";
+		assert_eq!(purged(css, "<div></div>", &["js-*"]), [".other{}"]);
+	}
+}
@@ -4,10 +4,13 @@ pub enum CliError {
 	ParseFailed,
 	Checks(usize),
 	FilesAndStdin,
+	NoFilesMatched(String),
 	#[allow(dead_code)]
 	Io(std::io::Error),
 	Fmt(std::fmt::Error),
 	SerdeJson(serde_json::Error),
+	Glob(glob::PatternError),
+	Transform(csskit_transform::CommitError),
 }
 
 impl From<std::io::Error> for CliError {
@@ -28,6 +31,18 @@ impl From<serde_json::Error> for CliError {
 	}
 }
 
+impl From<glob::PatternError> for CliError {
+	fn from(err: glob::PatternError) -> Self {
+		Self::Glob(err)
+	}
+}
+
+impl From<csskit_transform::CommitError> for CliError {
+	fn from(err: csskit_transform::CommitError) -> Self {
+		Self::Transform(err)
+	}
+}
+
 impl std::fmt::Debug for CliError {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		match self {
@@ -35,10 +50,13 @@ impl std::fmt::Debug for CliError {
 			Self::FilesAndStdin => {
 				write!(f, "Specified multiple files including stdin. Try passing just files, or use `-` for stdin.")
 			}
+			Self::NoFilesMatched(globs) => write!(f, "No files matched {globs}."),
 			Self::Checks(i) => f.write_str(&format!("{i} files failed check!")),
 			Self::Io(arg0) => f.debug_tuple("::io::Error").field(arg0).finish(),
 			Self::Fmt(arg0) => f.debug_tuple("::fmt::Error").field(arg0).finish(),
 			Self::SerdeJson(arg0) => f.debug_tuple("::serde_json::Error").field(arg0).finish(),
+			Self::Glob(arg0) => f.debug_tuple("::glob::PatternError").field(arg0).finish(),
+			Self::Transform(arg0) => f.debug_tuple("::csskit_transform::CommitError").field(arg0).finish(),
 		}
 	}
 }
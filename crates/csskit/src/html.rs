@@ -0,0 +1,388 @@
+/// A single attribute on an HTML start tag, such as `class="foo bar"` or a bare `hidden`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HtmlAttribute<'a> {
+	pub name: &'a str,
+	pub value: Option<&'a str>,
+}
+
+/// The subset of HTML tokens csskit cares about: element boundaries and their attributes. Text, comments and
+/// doctypes are skipped entirely.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HtmlToken<'a> {
+	StartTag { name: &'a str, attributes: Vec<HtmlAttribute<'a>>, self_closing: bool },
+	EndTag { name: &'a str },
+}
+
+/// A forgiving HTML tokenizer that is intended to work on HTML documents as well as template files (Handlebars,
+/// Vue, JSX, and so on). It does not attempt to be spec compliant; it only extracts start and end tags along with
+/// their attributes, and will skip over anything it does not understand rather than erroring.
+pub struct HtmlTokenizer<'a> {
+	source: &'a str,
+	pos: usize,
+	raw_text: Option<&'static str>,
+}
+
+impl<'a> HtmlTokenizer<'a> {
+	pub fn new(source: &'a str) -> Self {
+		Self { source, pos: 0, raw_text: None }
+	}
+
+	fn rest(&self) -> &'a str {
+		&self.source[self.pos..]
+	}
+
+	fn skip_whitespace(&mut self) {
+		let rest = self.rest();
+		self.pos += rest.len() - rest.trim_start().len();
+	}
+
+	fn skip_past(&mut self, needle: &str) {
+		match self.rest().find(needle) {
+			Some(i) => self.pos += i + needle.len(),
+			None => self.pos = self.source.len(),
+		}
+	}
+
+	fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+		let rest = self.rest();
+		let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+		self.pos += len;
+		&rest[..len]
+	}
+
+	fn take_attribute_value(&mut self) -> &'a str {
+		let rest = self.rest();
+		match rest.chars().next() {
+			Some(quote @ ('"' | '\'')) => {
+				let len = rest[1..].find(quote).unwrap_or(rest.len() - 1);
+				self.pos += (len + 2).min(rest.len());
+				&rest[1..1 + len]
+			}
+			// JSX style expressions, e.g. `className={cx("a", "b")}`
+			Some('{') => {
+				let mut depth = 0;
+				let mut len = rest.len();
+				for (i, c) in rest.char_indices() {
+					match c {
+						'{' => depth += 1,
+						'}' => {
+							depth -= 1;
+							if depth == 0 {
+								len = i + 1;
+								break;
+							}
+						}
+						_ => {}
+					}
+				}
+				self.pos += len;
+				&rest[..len]
+			}
+			_ => self.take_while(|c| !c.is_whitespace() && c != '>'),
+		}
+	}
+
+	fn parse_start_tag(&mut self) -> HtmlToken<'a> {
+		let name = self.take_while(|c| !c.is_whitespace() && c != '/' && c != '>');
+		let mut attributes = vec![];
+		let mut self_closing = false;
+		loop {
+			self.skip_whitespace();
+			let rest = self.rest();
+			if rest.is_empty() {
+				break;
+			}
+			if rest.starts_with('>') {
+				self.pos += 1;
+				break;
+			}
+			if rest.starts_with("/>") {
+				self.pos += 2;
+				self_closing = true;
+				break;
+			}
+			let name = self.take_while(|c| !c.is_whitespace() && !matches!(c, '=' | '>') && c != '/');
+			if name.is_empty() {
+				// Stray characters, such as a lone `/` or `=`, skip them.
+				self.pos += rest.chars().next().map_or(1, char::len_utf8);
+				continue;
+			}
+			self.skip_whitespace();
+			let value = if self.rest().starts_with('=') {
+				self.pos += 1;
+				self.skip_whitespace();
+				Some(self.take_attribute_value())
+			} else {
+				None
+			};
+			attributes.push(HtmlAttribute { name, value });
+		}
+		// The contents of `<script>` & `<style>` are raw text which could contain `<`, so skip to their end tags.
+		if !self_closing {
+			self.raw_text = ["script", "style"].into_iter().find(|raw| name.eq_ignore_ascii_case(raw));
+		}
+		HtmlToken::StartTag { name, attributes, self_closing }
+	}
+}
+
+impl<'a> Iterator for HtmlTokenizer<'a> {
+	type Item = HtmlToken<'a>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some(tag) = self.raw_text.take() {
+			let rest = self.rest();
+			let end = rest
+				.char_indices()
+				.find(|(i, _)| {
+					rest[*i..].starts_with("</")
+						&& rest.get(i + 2..i + 2 + tag.len()).is_some_and(|name| name.eq_ignore_ascii_case(tag))
+				})
+				.map_or(rest.len(), |(i, _)| i);
+			self.pos += end;
+		}
+		loop {
+			let lt = self.rest().find('<')?;
+			self.pos += lt + 1;
+			let rest = self.rest();
+			if rest.starts_with("!--") {
+				self.skip_past("-->");
+			} else if rest.starts_with('!') || rest.starts_with('?') {
+				self.skip_past(">");
+			} else if let Some(end) = rest.strip_prefix('/') {
+				if !end.starts_with(|c: char| c.is_ascii_alphabetic()) {
+					continue;
+				}
+				self.pos += 1;
+				let name = self.take_while(|c| !c.is_whitespace() && c != '>');
+				self.skip_past(">");
+				return Some(HtmlToken::EndTag { name });
+			} else if rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+				return Some(self.parse_start_tag());
+			}
+		}
+	}
+}
+
+/// Elements which never have children, and so never have an end tag.
+const VOID_ELEMENTS: &[&str] = &[
+	"area", "base", "br", "col", "embed", "hr", "img", "input", "keygen", "link", "meta", "param", "source", "track",
+	"wbr",
+];
+
+/// Elements which are implicitly closed when another of the same kind is opened, such as consecutive `<li>`s.
+const SELF_CLOSING_SIBLINGS: &[&[&str]] = &[&["p"], &["li"], &["option"], &["dt", "dd"], &["tr"], &["td", "th"]];
+
+/// A single element within a [HtmlDocument].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtmlElement<'a> {
+	/// The lowercase tag name of this element.
+	pub name: String,
+	pub attributes: Vec<HtmlAttribute<'a>>,
+	pub parent: Option<usize>,
+	pub children: Vec<usize>,
+	/// The position of this element within its parent's children.
+	pub index: usize,
+}
+
+impl<'a> HtmlElement<'a> {
+	pub fn attribute(&self, name: &str) -> Option<&HtmlAttribute<'a>> {
+		self.attributes.iter().find(|attribute| attribute.name.eq_ignore_ascii_case(name))
+	}
+
+	pub fn has_attribute(&self, name: &str) -> bool {
+		self.attribute(name).is_some()
+	}
+
+	pub fn has_class(&self, class: &str) -> bool {
+		self.attribute("class")
+			.and_then(|attr| attr.value)
+			.is_some_and(|value| value.split_whitespace().any(|c| c == class))
+	}
+}
+
+/// A tree of elements built from a [HtmlTokenizer]. Elements are stored in document order, and refer to each other by
+/// their index.
+///
+/// Like the tokenizer this is forgiving rather than spec compliant: void elements and a handful of implicitly closed
+/// elements are understood, unmatched end tags are ignored, and if the document has no `<html>` element then `<html>`
+/// and `<body>` elements are created to hold the content, much like a browser would.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct HtmlDocument<'a> {
+	pub elements: Vec<HtmlElement<'a>>,
+	/// Elements which have no parent, typically just the `<html>` element.
+	pub roots: Vec<usize>,
+}
+
+impl<'a> HtmlDocument<'a> {
+	pub fn parse(source: &'a str) -> Self {
+		let tokens = HtmlTokenizer::new(source).collect::<Vec<_>>();
+		let mut document = Self::default();
+		let mut open = vec![];
+		if !tokens
+			.iter()
+			.any(|token| matches!(token, HtmlToken::StartTag { name, .. } if name.eq_ignore_ascii_case("html")))
+		{
+			let html = document.push("html", vec![], None);
+			open.push(html);
+			open.push(document.push("body", vec![], Some(html)));
+		}
+		for token in tokens {
+			match token {
+				HtmlToken::StartTag { name, attributes, self_closing } => {
+					let name = name.to_ascii_lowercase();
+					if let Some(&current) = open.last() {
+						let current = &document.elements[current].name;
+						if SELF_CLOSING_SIBLINGS
+							.iter()
+							.any(|group| group.contains(&name.as_str()) && group.contains(&current.as_str()))
+						{
+							open.pop();
+						}
+					}
+					let is_void = self_closing || VOID_ELEMENTS.contains(&name.as_str());
+					let element = document.push(&name, attributes, open.last().copied());
+					if !is_void {
+						open.push(element);
+					}
+				}
+				HtmlToken::EndTag { name } => {
+					if let Some(i) = open.iter().rposition(|&i| document.elements[i].name.eq_ignore_ascii_case(name)) {
+						open.truncate(i);
+					}
+				}
+			}
+		}
+		document
+	}
+
+	fn push(&mut self, name: &str, attributes: Vec<HtmlAttribute<'a>>, parent: Option<usize>) -> usize {
+		let id = self.elements.len();
+		let siblings = match parent {
+			Some(parent) => &mut self.elements[parent].children,
+			None => &mut self.roots,
+		};
+		let index = siblings.len();
+		siblings.push(id);
+		self.elements.push(HtmlElement { name: name.to_string(), attributes, parent, children: vec![], index });
+		id
+	}
+
+	/// The element at the given index, and its siblings, in document order.
+	pub fn siblings(&self, element: usize) -> &[usize] {
+		match self.elements[element].parent {
+			Some(parent) => &self.elements[parent].children,
+			None => &self.roots,
+		}
+	}
+
+	/// Every element nested within the given element, in document order.
+	pub fn descendants(&self, element: usize) -> impl Iterator<Item = usize> + '_ {
+		let mut stack = self.elements[element].children.iter().rev().copied().collect::<Vec<_>>();
+		std::iter::from_fn(move || {
+			let next = stack.pop()?;
+			stack.extend(self.elements[next].children.iter().rev());
+			Some(next)
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn start_tags(source: &str) -> Vec<(&str, Vec<HtmlAttribute<'_>>)> {
+		HtmlTokenizer::new(source)
+			.filter_map(|token| match token {
+				HtmlToken::StartTag { name, attributes, .. } => Some((name, attributes)),
+				HtmlToken::EndTag { .. } => None,
+			})
+			.collect()
+	}
+
+	#[test]
+	fn tokenizes_tags() {
+		let tokens = HtmlTokenizer::new("<!doctype html><div><br/><p>text</p></div>").collect::<Vec<_>>();
+		assert_eq!(
+			tokens,
+			[
+				HtmlToken::StartTag { name: "div", attributes: vec![], self_closing: false },
+				HtmlToken::StartTag { name: "br", attributes: vec![], self_closing: true },
+				HtmlToken::StartTag { name: "p", attributes: vec![], self_closing: false },
+				HtmlToken::EndTag { name: "p" },
+				HtmlToken::EndTag { name: "div" },
+			]
+		);
+	}
+
+	#[test]
+	fn tokenizes_attributes() {
+		let tags = start_tags(r#"<input type=text value="a > b" data-x = 'y' disabled>"#);
+		assert_eq!(
+			tags[0].1,
+			[
+				HtmlAttribute { name: "type", value: Some("text") },
+				HtmlAttribute { name: "value", value: Some("a > b") },
+				HtmlAttribute { name: "data-x", value: Some("y") },
+				HtmlAttribute { name: "disabled", value: None },
+			]
+		);
+	}
+
+	#[test]
+	fn tokenizes_template_attributes() {
+		let tags = start_tags(r#"<Button className={cx("a", { b: c })} onClick={() => go()} />"#);
+		assert_eq!(
+			tags[0].1,
+			[
+				HtmlAttribute { name: "className", value: Some(r#"{cx("a", { b: c })}"#) },
+				HtmlAttribute { name: "onClick", value: Some("{() => go()}") },
+			]
+		);
+	}
+
+	#[test]
+	fn skips_comments_and_raw_text() {
+		let source = r#"<!-- <a class="x"> --><script>if (a <b) {}</script><style>a<b{}</style><p>1 < 2</p>"#;
+		let names = start_tags(source).into_iter().map(|(name, _)| name).collect::<Vec<_>>();
+		assert_eq!(names, ["script", "style", "p"]);
+	}
+
+	fn tree(document: &HtmlDocument, element: usize) -> String {
+		let element = &document.elements[element];
+		let children = element.children.iter().map(|&child| tree(document, child)).collect::<Vec<_>>();
+		if children.is_empty() { element.name.clone() } else { format!("{}({})", element.name, children.join(" ")) }
+	}
+
+	#[test]
+	fn builds_documents() {
+		let document = HtmlDocument::parse("<html><body><div><img><br/><P>a</p></div></body></html>");
+		assert_eq!(tree(&document, document.roots[0]), "html(body(div(img br p)))");
+	}
+
+	#[test]
+	fn builds_documents_without_html_elements() {
+		let document = HtmlDocument::parse("<main><ul><li>a<li>b</ul><p>a<p>b</span></main>");
+		assert_eq!(tree(&document, document.roots[0]), "html(body(main(ul(li li) p p)))");
+	}
+
+	#[test]
+	fn finds_siblings_and_descendants() {
+		let document = HtmlDocument::parse("<html><div><p></p><span><em></em></span></div><a></a></html>");
+		let div = document.elements.iter().position(|element| element.name == "div").unwrap();
+		let names = |elements: &mut dyn Iterator<Item = usize>| {
+			elements.map(|element| document.elements[element].name.as_str()).collect::<Vec<_>>()
+		};
+		assert_eq!(names(&mut document.descendants(div)), ["p", "span", "em"]);
+		assert_eq!(names(&mut document.siblings(div).iter().copied()), ["div", "a"]);
+		assert!(document.elements[div].children.iter().all(|&child| document.elements[child].parent == Some(div)));
+	}
+
+	#[test]
+	fn reads_element_attributes() {
+		let document = HtmlDocument::parse(r#"<input CLASS="a  b" Disabled>"#);
+		let input = document.elements.iter().find(|element| element.name == "input").unwrap();
+		assert!(input.has_class("a") && input.has_class("b") && !input.has_class("a  b"));
+		assert!(input.has_attribute("disabled"));
+		assert_eq!(input.attribute("class").and_then(|attribute| attribute.value), Some("a  b"));
+	}
+}
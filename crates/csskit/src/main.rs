@@ -6,7 +6,9 @@ use std::io::{IsTerminal, stderr};
 mod color_ext;
 mod commands;
 mod errors;
+mod html;
 mod input;
+mod unused_rules;
 
 pub use color_ext::{bg, bold, dimmed, fg, green, magenta};
 pub use input::{InputArgs, InputSource};
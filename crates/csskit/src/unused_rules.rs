@@ -0,0 +1,135 @@
+use crate::CliError;
+use bumpalo::{Bump, collections::Vec as BumpVec};
+use css_ast::{
+	ContainerRule, CssAtomSet, LayerRule, MediaRule, ScopeRule, StartingStyleRule, StyleSheet, SupportsRule, Visit,
+	Visitable,
+};
+use css_lexer::{CowStr, Cursor, Kind, Lexer, ToSpan};
+use css_parse::{
+	CursorCompactWriteSink, CursorOverlaySet, CursorOverlaySink, CursorSink, CursorWriteSink, Parser, SourceCursor,
+	SourceOffset, Span, ToCursors,
+};
+
+/// Returns the unescaped text of a cursor, such as the name of a class selector.
+pub fn cursor_text<'a>(cursor: Cursor, source_text: &'a str, bump: &'a Bump) -> CowStr<'a, &'a Bump> {
+	SourceCursor::from(cursor, cursor.str_slice(source_text)).parse(bump)
+}
+
+/// Collects the group rules, such as `@media`, which would be left empty once the `removed` rules are gone, adding them
+/// to `removed`.
+struct EmptyGroups<'a> {
+	source_text: &'a str,
+	removed: Vec<Span>,
+}
+
+impl EmptyGroups<'_> {
+	fn is_removed(&self, span: Span) -> bool {
+		self.removed.iter().any(|removed| removed.contains(span))
+	}
+
+	/// Removes the group rule if everything within its block is removed. Nested group rules are exited before their
+	/// parent, so an empty nested group is already removed by the time its parent is checked.
+	fn exit_group(&mut self, span: Span) {
+		if self.is_removed(span) {
+			return;
+		}
+		let text = &self.source_text[usize::from(span.start())..usize::from(span.end())];
+		let mut in_block = false;
+		for cursor in Lexer::new(&CssAtomSet::ATOMS, text) {
+			if cursor == Kind::Eof {
+				break;
+			}
+			let local = cursor.to_span();
+			let cursor_span =
+				Span::new(SourceOffset(span.start().0 + local.start().0), SourceOffset(span.start().0 + local.end().0));
+			if !in_block {
+				in_block = cursor == Kind::LeftCurly;
+			} else if cursor != Kind::Whitespace
+				&& cursor != Kind::Comment
+				&& !(cursor == Kind::RightCurly && cursor_span.end() == span.end())
+				&& !self.is_removed(cursor_span)
+			{
+				return;
+			}
+		}
+		self.removed.retain(|removed| !span.contains(*removed));
+		self.removed.push(span);
+	}
+}
+
+impl Visit for EmptyGroups<'_> {
+	fn exit_media_rule(&mut self, rule: &MediaRule) {
+		self.exit_group(rule.to_span());
+	}
+
+	fn exit_supports_rule(&mut self, rule: &SupportsRule) {
+		self.exit_group(rule.to_span());
+	}
+
+	fn exit_container_rule(&mut self, rule: &ContainerRule) {
+		self.exit_group(rule.to_span());
+	}
+
+	fn exit_scope_rule(&mut self, rule: &ScopeRule) {
+		self.exit_group(rule.to_span());
+	}
+
+	fn exit_starting_style_rule(&mut self, rule: &StartingStyleRule) {
+		self.exit_group(rule.to_span());
+	}
+
+	fn exit_layer_rule(&mut self, rule: &LayerRule) {
+		// Layer statements such as `@layer reset, base;` establish layer order, so they are always kept.
+		if rule.block.is_some() {
+			self.exit_group(rule.to_span());
+		}
+	}
+}
+
+/// Parses a stylesheet and writes it to `out` without the rules at the spans returned by `unused`, nor any group rules
+/// (such as `@media`) which those leave empty. This is shared by the commands which remove rules that a HTML document
+/// does not need, such as `purge` and `critical`. When `compact` is false the stylesheet is written as it was formatted,
+/// otherwise whitespace and comments are removed.
+///
+/// Returns the number of rules removed, or reports the parse errors and fails if the stylesheet could not be parsed.
+pub fn remove_unused_rules<'a>(
+	source_text: &'a str,
+	file_name: &str,
+	bump: &'a Bump,
+	out: &mut String,
+	compact: bool,
+	unused: impl FnOnce(&StyleSheet<'a>) -> Vec<Span>,
+) -> Result<usize, CliError> {
+	let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+	let mut parser = Parser::new(bump, source_text, lexer);
+	let result = parser.parse_entirely::<StyleSheet>();
+	let Some(stylesheet) = result.output.as_ref() else {
+		for compact_err in result.errors {
+			let report = crate::commands::format_diagnostic_error(&compact_err, source_text, file_name);
+			println!("{report}");
+		}
+		return Err(CliError::ParseFailed);
+	};
+	let unused = unused(stylesheet);
+	let removed = unused.len();
+	let mut groups = EmptyGroups { source_text, removed: unused };
+	stylesheet.accept(&mut groups);
+	let mut overlays = CursorOverlaySet::new(bump);
+	for span in &groups.removed {
+		overlays.insert(*span, BumpVec::new_in(bump));
+	}
+	if compact {
+		let mut stream = CursorOverlaySink::new(source_text, &overlays, CursorCompactWriteSink::new(source_text, out));
+		result.to_cursors(&mut stream);
+	} else {
+		// Every token of the source is written, including whitespace and comments, so that only the removed rules change.
+		let mut stream = CursorOverlaySink::new(source_text, &overlays, CursorWriteSink::new(source_text, out));
+		for cursor in Lexer::new(&CssAtomSet::ATOMS, source_text) {
+			if cursor == Kind::Eof {
+				break;
+			}
+			stream.append(cursor);
+		}
+	}
+	Ok(removed)
+}
@@ -0,0 +1,14 @@
+use std::process::Command;
+
+#[test]
+fn extracts_critical_styles() {
+	let fixtures = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/critical");
+	let output = Command::new(env!("CARGO_BIN_EXE_csskit"))
+		.args(["critical", &format!("{fixtures}/styles.css"), "--html", &format!("{fixtures}/index.html")])
+		.args(["--viewport", "1024x768"])
+		.output()
+		.unwrap();
+	assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+	let expected = std::fs::read_to_string(format!("{fixtures}/expected.css")).unwrap();
+	assert_eq!(String::from_utf8(output.stdout).unwrap().trim_end(), expected.trim_end());
+}
@@ -34,7 +34,7 @@ fn parse_sheet(c: &mut Criterion) {
 		group.bench_with_input(BenchmarkId::from_parameter(&file.name), &file.source_text, |b, source_text| {
 			b.iter_with_large_drop(|| {
 				let allocator = Bump::default();
-				let lexer = Lexer::new(&CsskitAtomSet::ATOMS, &source_text);
+				let lexer = Lexer::new(&CsskitAtomSet::ATOMS, source_text);
 				let _ = Parser::new(&allocator, source_text, lexer).parse_entirely::<Sheet>();
 
 				allocator
@@ -615,8 +615,8 @@ fn function_pseudo() {
 	assert_query!(
 		"a { background: linear-gradient(red, blue); transform: rotate(45deg); }",
 		"*:function",
-		2,
-		[NodeId::TransformFunction, NodeId::RotateFunction]
+		3,
+		[NodeId::LinearGradientFunction, NodeId::TransformFunction, NodeId::RotateFunction]
 	);
 }
 
@@ -1427,3 +1427,22 @@ fn unknown_type_selector_matches_nothing() {
 	assert_query!(".a {} #foo {} .b {}", "unknown-node-type", 0);
 	assert_query!("@media screen {}", "media-rule-invalid", 0);
 }
+
+#[test]
+fn position_try_rule_name() {
+	assert_query!(
+		"@position-try --above {} @position-try --below {}",
+		"position-try-rule[name=--above]",
+		1,
+		[NodeId::PositionTryRule]
+	);
+}
+
+#[test]
+fn view_transition_selector_names() {
+	let source = "::view-transition-group(hero) { z-index: 1 } ::view-transition-old(*.card) { opacity: 0 } ::view-transition-new(.card.wide) { opacity: 1 }";
+	assert_query!(source, "pt-name-selector", 2, [NodeId::PtNameSelector, NodeId::PtNameSelector]);
+	assert_query!(source, "pt-name-selector[name=hero]", 1, [NodeId::PtNameSelector]);
+	assert_query!(source, "pt-class-selector[name=card]", 2, [NodeId::PtClassSelector, NodeId::PtClassSelector]);
+	assert_query!(source, "view-transition-old-pseudo-element > pt-class-selector", 1, [NodeId::PtClassSelector]);
+}
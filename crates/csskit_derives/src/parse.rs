@@ -12,7 +12,7 @@ use syn::{
 };
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
-enum FieldParseMode {
+pub(crate) enum FieldParseMode {
 	#[default]
 	Sequential,
 	AllMustOccur,
@@ -44,7 +44,7 @@ impl ToVarsAndTypes for Fields {
 }
 
 #[derive(Debug, Default)]
-struct ParseArg {
+pub(crate) struct ParseArg {
 	pub state: Option<Ident>,
 	pub stop: Option<(Ident, Ident)>,
 	pub parse_mode: FieldParseMode,
@@ -1,7 +1,8 @@
 use crate::{
-	WhereCollector,
+	TypeIsOption, WhereCollector,
 	attributes::{Atom, extract_atom},
 	err,
+	parse::{FieldParseMode, ParseArg},
 };
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -36,13 +37,31 @@ pub fn derive(input: DeriveInput) -> TokenStream {
 		Data::Union(_) => err(ident.span(), "Cannot derive Peek on a Union"),
 
 		Data::Struct(DataStruct { fields, .. }) => {
-			let field = fields.iter().next().unwrap();
-			let ty = match &field.ty {
-				Type::Reference(refty) => refty.elem.as_ref(),
-				ty => ty,
-			};
-			let atom = extract_atom(&field.attrs);
-			generate_field_peek(ty, &atom, &mut where_collector)
+			let ParseArg { parse_mode, .. } = (&input.attrs).into();
+			if parse_mode == FieldParseMode::Sequential {
+				let field = fields.iter().next().unwrap();
+				let ty = match &field.ty {
+					Type::Reference(refty) => refty.elem.as_ref(),
+					ty => ty,
+				};
+				let atom = extract_atom(&field.attrs);
+				generate_field_peek(ty, &atom, &mut where_collector)
+			} else {
+				// Fields of a `one_must_occur` or `all_must_occur` struct may appear in any order, so any of them can
+				// begin the struct.
+				let field_checks: Vec<TokenStream> = fields
+					.iter()
+					.map(|field| {
+						let ty = match &field.ty {
+							Type::Reference(refty) => refty.elem.as_ref(),
+							ty => ty,
+						};
+						let atom = extract_atom(&field.attrs);
+						generate_field_peek(&ty.unpack_option(), &atom, &mut where_collector)
+					})
+					.collect();
+				quote! { #(#field_checks)||* }
+			}
 		}
 
 		Data::Enum(DataEnum { variants, .. }) => {
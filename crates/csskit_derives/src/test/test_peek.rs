@@ -99,3 +99,18 @@ fn peek_enum_with_lifetime() {
 	};
 	assert_peek_snapshot!(data, "peek_enum_with_lifetime");
 }
+
+#[test]
+fn peek_struct_one_must_occur() {
+	let data = to_deriveinput! {
+		#[parse(one_must_occur)]
+		struct TryTactic {
+			#[atom(CssAtomSet::FlipBlock)]
+			flip_block: Option<T![Ident]>,
+			#[atom(CssAtomSet::FlipInline)]
+			flip_inline: Option<T![Ident]>,
+			start: Option<Foo>,
+		}
+	};
+	assert_peek_snapshot!(data, "peek_struct_one_must_occur");
+}
@@ -1,6 +1,6 @@
 use css_ast::{
-	CSSInt, Color, CssMetadata, Declaration, DeclarationValue, NodeId, PropertyRule, QueryableNode, StyleRule,
-	ToChromashift, Visit,
+	CSSInt, Color, CssMetadata, Declaration, DeclarationValue, NodeId, PositionTryRule, PropertyRule, QueryableNode,
+	StyleRule, ToChromashift, Visit,
 };
 use css_lexer::ToSpan;
 use css_parse::NodeWithMetadata;
@@ -49,6 +49,8 @@ impl SemanticKind {
 			| NodeId::DocumentRule
 			| NodeId::MozDocumentRule
 			| NodeId::PropertyRule
+			| NodeId::PositionTryRule
+			| NodeId::ViewTransitionRule
 			| NodeId::CounterStyleRule
 			| NodeId::NamespaceRule
 			| NodeId::StartingStyleRule => Some(SemanticKind::AtKeyword),
@@ -135,6 +137,12 @@ impl Visit for TokenHighlighter {
 		self.insert(property.prelude.to_span(), SemanticKind::Declaration, SemanticModifier::Custom);
 	}
 
+	// Visit PositionTryRule to mark the AtKeyword and the Prelude
+	fn visit_position_try_rule<'a>(&mut self, rule: &PositionTryRule<'a>) {
+		self.insert(rule.name.to_span(), SemanticKind::AtKeyword, SemanticModifier::none());
+		self.insert(rule.prelude.to_span(), SemanticKind::Declaration, SemanticModifier::Custom);
+	}
+
 	// Visit Color nodes to decorate with the swatch
 	fn visit_color(&mut self, color: &Color) {
 		let metadata = color.metadata();
@@ -18,6 +18,11 @@ pub trait DefExt {
 	fn should_skip_visit(&self) -> bool;
 	fn type_attributes(&self, derives_parse: bool, derives_visitable: bool) -> TokenStream;
 	fn is_all_keywords(&self) -> bool;
+	fn is_keyword_options(&self) -> bool;
+	fn is_keyword_alternatives(&self) -> bool;
+	fn is_single_multiplier(&self) -> bool;
+	fn nested_keyword_alternatives(&self) -> Option<&Self>;
+	fn wrapped_keyword_options(&self) -> Option<&Self>;
 	fn get_generics(&self) -> Generics;
 	fn gather_keywords(&self) -> Vec<&Self>;
 	fn generate_additional_types(&self, vis: &Visibility, ident: &Ident, generics: &Generics) -> TokenStream;
@@ -95,7 +100,7 @@ impl ToFieldName for Def {
 			Self::Optional(def) => def.deref().to_variant_name(size_hint),
 			Self::IntLiteral(v) => format_ident!("Literal{}", v.to_string()),
 			Self::DimensionLiteral(int, dim) => format_ident!("Literal{int}{dim}"),
-			Self::Combinator(ds, DefCombinatorStyle::Ordered) => {
+			Self::Combinator(ds, DefCombinatorStyle::Ordered | DefCombinatorStyle::AllMustOccur) => {
 				let (optional, others): (Vec<&Def>, Vec<&Def>) = ds.iter().partition(|d| matches!(d, Def::Optional(_)));
 				let logical_first = others.first().or(optional.first());
 				logical_first.expect("At least one Def is required").to_variant_name(0)
@@ -105,6 +110,9 @@ impl ToFieldName for Def {
 					Def::Type(d) => {
 						format!("{}{}", str, d.to_variant_name(0))
 					}
+					Def::Ident(d) => {
+						format!("{}{}", str, d.to_variant_name(0))
+					}
 					_ => {
 						dbg!("TODO variant name for Combinator() of Options", d);
 						todo!("variant name")
@@ -112,6 +120,10 @@ impl ToFieldName for Def {
 				});
 				format_ident!("{}", get_type_rename(&auto_generated_name).unwrap_or(&auto_generated_name))
 			}
+			Self::Combinator(ds, DefCombinatorStyle::Alternatives) if self.is_keyword_alternatives() => {
+				let name = ds.iter().map(|d| d.to_variant_name(0).to_string()).join("Or");
+				format_ident!("{}", get_type_rename(&name).unwrap_or(&name))
+			}
 			Self::Combinator(_, _) => {
 				dbg!("TODO variant name for Combinator()", self);
 				todo!("variant name")
@@ -334,6 +346,74 @@ impl DefExt for Def {
 		}
 	}
 
+	/// Whether this is a set of keywords which may each occur once, in any order, such as `foo || bar`.
+	fn is_keyword_options(&self) -> bool {
+		matches!(self, Self::Combinator(defs, DefCombinatorStyle::Options) if defs.iter().all(|def| matches!(def, Self::Ident(_))))
+	}
+
+	/// Whether this is a choice of keywords, such as `[ foo | bar ]`.
+	fn is_keyword_alternatives(&self) -> bool {
+		match self {
+			Self::Group(def, _) => def.is_keyword_alternatives(),
+			Self::Combinator(defs, DefCombinatorStyle::Alternatives) => {
+				defs.iter().all(|def| matches!(def, Self::Ident(_)))
+			}
+			_ => false,
+		}
+	}
+
+	/// A choice of keywords leading an ordered variant of alternatives, such as the `[ foo | bar ]` in
+	/// `baz | [ foo | bar ] <length>?`.
+	fn nested_keyword_alternatives(&self) -> Option<&Self> {
+		match self {
+			Self::Combinator(defs, DefCombinatorStyle::Alternatives) => defs.iter().find_map(|def| match def {
+				Self::Combinator(defs, DefCombinatorStyle::Ordered) => {
+					defs.iter().find(|def| def.is_keyword_alternatives())
+				}
+				_ => None,
+			}),
+			_ => None,
+		}
+	}
+
+	/// Options mixing keywords and types within `NoneOr<>` and friends, such as the `[ foo || <bar> ]` in
+	/// `none | [ foo || <bar> ]`, which need a struct to check each keyword.
+	fn wrapped_keyword_options(&self) -> Option<&Self> {
+		let (Self::AutoOr(def) | Self::NoneOr(def) | Self::AutoNoneOr(def) | Self::NormalOr(def)) = self else {
+			return None;
+		};
+		let def = match def.deref() {
+			Self::Group(def, _) => def.deref(),
+			def => def,
+		};
+		match def {
+			Self::Combinator(defs, DefCombinatorStyle::Options)
+				if defs.iter().all(|def| matches!(def, Self::Ident(_) | Self::Type(_)))
+					&& defs.iter().any(|def| matches!(def, Self::Ident(_))) =>
+			{
+				Some(def)
+			}
+			_ => None,
+		}
+	}
+
+	/// Whether this is a repeated combinator such as `[ <foo> | <bar> ]#`, which needs a `Single` type to hold each
+	/// repetition.
+	fn is_single_multiplier(&self) -> bool {
+		match self {
+			Self::Multiplier(def, _, range) => match def.deref() {
+				Self::Combinator(defs, DefCombinatorStyle::Alternatives)
+					if defs.iter().all(|def| matches!(def, Def::Ident(_))) =>
+				{
+					false
+				}
+				Self::Combinator(_, _) => matches!(range, DefRange::RangeFrom(_) | DefRange::RangeTo(_)),
+				_ => false,
+			},
+			_ => false,
+		}
+	}
+
 	fn gather_keywords(&self) -> Vec<&Self> {
 		match self {
 			// Self::Ident shouldn't return itself because it can be used in a literal position.
@@ -377,18 +457,73 @@ impl DefExt for Def {
 	}
 
 	fn generate_additional_types(&self, vis: &Visibility, ident: &Ident, _generics: &Generics) -> TokenStream {
-		let needs_keyword_type = match self {
-			Self::Combinator(defs, DefCombinatorStyle::Ordered) => defs.iter().all(|def| def.is_all_keywords()),
+		match self {
+			// A repeated combinator wrapped in `NoneOr<>` and friends still needs its `Single` type.
+			Self::AutoOr(def) | Self::NoneOr(def) | Self::AutoNoneOr(def) | Self::NormalOr(def)
+				if def.is_single_multiplier() =>
+			{
+				return def.generate_additional_types(vis, ident, _generics);
+			}
+			Self::AutoOr(_) | Self::NoneOr(_) | Self::AutoNoneOr(_) | Self::NormalOr(_)
+				if let Some(def) = self.wrapped_keyword_options() =>
+			{
+				let keyword_name = Self::keyword_ident(ident);
+				let generics = def.get_generics();
+				let def = def.generate_definition(vis, &keyword_name, &generics, true, true);
+				return quote! {
+					#[derive(
+						::csskit_derives::Parse,
+						::csskit_derives::Peek,
+						::csskit_derives::ToCursors,
+						::csskit_derives::ToSpan,
+						::csskit_derives::SemanticEq,
+						Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+					#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+					#[cfg_attr(feature = "visitable", derive(::csskit_derives::Visitable), visit(skip))]
+					#def
+				};
+			}
+			// Keywords which may occur in any order within an enum variant need a struct to hold each keyword.
+			Self::Combinator(defs, DefCombinatorStyle::Alternatives) => {
+				if let Some(def) = defs.iter().find(|def| def.is_keyword_options()) {
+					let keyword_name = Self::keyword_ident(ident);
+					let def = def.generate_definition(vis, &keyword_name, &Default::default(), true, true);
+					return quote! {
+						#[derive(
+							::csskit_derives::Parse,
+							::csskit_derives::Peek,
+							::csskit_derives::ToCursors,
+							::csskit_derives::ToSpan,
+							::csskit_derives::SemanticEq,
+							Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+						#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+						#[cfg_attr(feature = "visitable", derive(::csskit_derives::Visitable), visit(skip))]
+						#def
+					};
+				}
+			}
+			_ => {}
+		}
+		let keyword_source = match self {
+			Self::Combinator(defs, DefCombinatorStyle::Ordered) if defs.iter().all(|def| def.is_all_keywords()) => {
+				Some(self)
+			}
+			// A choice of keywords which may occur in any order alongside other options, such as `[ foo | bar ] || baz`.
+			Self::Combinator(defs, DefCombinatorStyle::Options) => {
+				defs.iter().find(|def| def.is_keyword_alternatives())
+			}
 			Self::Multiplier(def, _, _) => match def.deref() {
-				Self::Combinator(defs, DefCombinatorStyle::Alternatives) => {
-					defs.iter().all(|def| matches!(def, Def::Ident(_)))
+				Self::Combinator(defs, DefCombinatorStyle::Alternatives)
+					if defs.iter().all(|def| matches!(def, Def::Ident(_))) =>
+				{
+					Some(self)
 				}
-				_ => false,
+				def => def.nested_keyword_alternatives(),
 			},
-			_ => false,
+			_ => self.nested_keyword_alternatives(),
 		};
-		let keyword_type = if needs_keyword_type {
-			let keywords: Vec<TokenStream> = self
+		let keyword_type = if let Some(keyword_source) = keyword_source {
+			let keywords: Vec<TokenStream> = keyword_source
 				.gather_keywords()
 				.iter()
 				.unique_by(|def| if let Self::Ident(DefIdent(str)) = def { str } else { "" })
@@ -480,7 +615,12 @@ impl GenerateDefinition for Def {
 					Self::Combinator(defs, DefCombinatorStyle::Options) => {
 						let members = defs.iter().map(|def| {
 							let name = def.to_member_name(0);
-							let ty = def.to_type();
+							let ty = if def.is_keyword_alternatives() {
+								let keyword_name = Self::keyword_ident(ident);
+								quote! { #keyword_name }
+							} else {
+								def.to_type()
+							};
 							let attrs = def.type_attributes(derives_parse, derives_visitable);
 							quote! { #attrs pub #name: Option<#ty> }
 						});
@@ -528,30 +668,8 @@ impl GenerateDefinition for Def {
 							quote! { ( pub #ty ); }
 						}
 						Self::Combinator(_, _) if matches!(range, DefRange::RangeFrom(_) | DefRange::RangeTo(_)) => {
-							let ty_ident = Self::single_ident(ident);
-							// Check if the inner combinator needs a lifetime - if so, add it manually
-							let needs_lifetime = def.maybe_unsized();
-							let generics = if needs_lifetime {
-								quote! { <'a> }
-							} else {
-								quote! {}
-							};
-							let inner_type_ref = quote! { crate::#ty_ident #generics };
-							let ty = match sep {
-								DefMultiplierSeparator::Commas => {
-									let min = match range {
-										DefRange::Range(Range { start, .. }) if *start != 1.0 => Some(*start as usize),
-										DefRange::RangeFrom(f) if *f != 1.0 => Some(*f as usize),
-										DefRange::Fixed(f) if *f != 1.0 => Some(*f as usize),
-										_ => None,
-									};
-									vec![quote! { ::css_parse::CommaSeparated<'a, #inner_type_ref, #min> }]
-								}
-								DefMultiplierSeparator::None => {
-									vec![quote! { ::bumpalo::collections::Vec<'a, #inner_type_ref> }]
-								}
-							};
-							quote! { ( #(pub #ty),* ); }
+							let ty = single_multiplier_type(ident, def, sep, range);
+							quote! { ( pub #ty ); }
 						}
 						_ => {
 							let ty = self.to_types();
@@ -559,6 +677,23 @@ impl GenerateDefinition for Def {
 							quote! { ( #(#attrs pub #ty),* ); }
 						}
 					},
+					Self::AutoOr(inner) | Self::NoneOr(inner) | Self::AutoNoneOr(inner) | Self::NormalOr(inner)
+						if inner.is_single_multiplier() =>
+					{
+						let Self::Multiplier(def, sep, range) = inner.deref() else { unreachable!() };
+						let ty = single_multiplier_type(ident, def, sep, range);
+						let wrapper = wrapper_type(self);
+						quote! { ( pub #wrapper<#ty> ); }
+					}
+					Self::AutoOr(_) | Self::NoneOr(_) | Self::AutoNoneOr(_) | Self::NormalOr(_)
+						if let Some(def) = self.wrapped_keyword_options() =>
+					{
+						let keyword_name = Self::keyword_ident(ident);
+						let generics = def.get_generics();
+						let wrapper = wrapper_type(self);
+						let attrs = self.type_attributes(derives_parse, derives_visitable);
+						quote! { ( #attrs pub #wrapper<#keyword_name #generics> ); }
+					}
 					_ => {
 						let ty = self.to_types();
 						let attrs = self.type_attributes(derives_parse, derives_visitable);
@@ -577,8 +712,23 @@ impl GenerateDefinition for Def {
 							let types = match d {
 								Self::Combinator(defs, DefCombinatorStyle::Ordered) => defs
 									.iter()
+									// Each member of a group such as `[ foo && bar? ]` gets its own position in the variant.
+									.flat_map(|d| match d {
+										Self::Group(inner, _) => match inner.deref() {
+											Self::Combinator(defs, DefCombinatorStyle::AllMustOccur) => {
+												defs.iter().collect()
+											}
+											_ => vec![d],
+										},
+										_ => vec![d],
+									})
 									.map(|d| {
-										let ty = d.to_type();
+										let ty = if d.is_keyword_alternatives() {
+											let keyword_name = Self::keyword_ident(ident);
+											quote! { #keyword_name }
+										} else {
+											d.to_type()
+										};
 										let attrs = d.type_attributes(derives_parse, derives_visitable);
 										quote! { #attrs #ty }
 									})
@@ -599,6 +749,11 @@ impl GenerateDefinition for Def {
 									let ty = d.to_type();
 									vec![quote! { #attrs #ty }]
 								}
+								Self::Combinator(_, DefCombinatorStyle::Options) if d.is_keyword_options() => {
+									let attrs = attrs.take().unwrap();
+									let keyword_name = Self::keyword_ident(ident);
+									vec![quote! { #attrs #keyword_name }]
+								}
 								_ => d.to_types(),
 							};
 							quote! { #attrs #name(#(#types),*), }
@@ -619,6 +774,40 @@ impl GenerateDefinition for Def {
 	}
 }
 
+/// The `NoneOr<>` or similar type wrapping the value of `def`.
+fn wrapper_type(def: &Def) -> TokenStream {
+	match def {
+		Def::AutoOr(_) => quote! { crate::AutoOr },
+		Def::NoneOr(_) => quote! { crate::NoneOr },
+		Def::AutoNoneOr(_) => quote! { crate::AutoNoneOr },
+		_ => quote! { crate::NormalOr },
+	}
+}
+
+/// The type of a repeated combinator such as `[ <foo> | <bar> ]#`, which holds each repetition in a `Single` type.
+fn single_multiplier_type(ident: &Ident, def: &Def, sep: &DefMultiplierSeparator, range: &DefRange) -> TokenStream {
+	let ty_ident = Def::single_ident(ident);
+	// Check if the inner combinator needs a lifetime - if so, add it manually
+	let generics = if def.maybe_unsized() {
+		quote! { <'a> }
+	} else {
+		quote! {}
+	};
+	let inner_type_ref = quote! { crate::#ty_ident #generics };
+	match sep {
+		DefMultiplierSeparator::Commas => {
+			let min = match range {
+				DefRange::Range(Range { start, .. }) if *start != 1.0 => Some(*start as usize),
+				DefRange::RangeFrom(f) if *f != 1.0 => Some(*f as usize),
+				DefRange::Fixed(f) if *f != 1.0 => Some(*f as usize),
+				_ => None,
+			};
+			quote! { ::css_parse::CommaSeparated<'a, #inner_type_ref, #min> }
+		}
+		DefMultiplierSeparator::None => quote! { ::bumpalo::collections::Vec<'a, #inner_type_ref> },
+	}
+}
+
 impl DefTypeExt for DefType {
 	fn get_generics(&self) -> Generics {
 		if self.maybe_unsized() { parse_quote!(<'a>) } else { Default::default() }
@@ -4,6 +4,7 @@ use crate::shorthands::get_shorthand_properties;
 use crate::spec_parser::PropertyDefinition;
 use crate::todo_properties::get_todo_properties;
 use crate::value_extensions::get_value_extensions;
+use crate::value_overrides::get_value_overrides;
 use crate::web_features_data::{BaselineStatus, FeatureData, StringOrArray, WebFeaturesData};
 use css_value_definition_parser::{Def, DefCombinatorStyle};
 use heck::ToPascalCase;
@@ -86,6 +87,9 @@ pub fn generate_spec_module(
 	let value_extensions = get_value_extensions();
 	let spec_extensions = value_extensions.get(lookup_name);
 
+	let value_overrides = get_value_overrides();
+	let spec_overrides = value_overrides.get(lookup_name);
+
 	let manual_parse_properties = get_manual_parse_properties();
 	let should_skip_parse: HashSet<String> = manual_parse_properties.get(lookup_name).cloned().unwrap_or_default();
 
@@ -95,9 +99,19 @@ pub fn generate_spec_module(
 	let property_types = filtered_properties.iter().map(|prop| {
 		let description = property_descriptions.get(&prop.name);
 		let extension = spec_extensions.and_then(|ext| ext.get(&prop.name).map(|s| s.as_str()));
+		let value_override = spec_overrides.and_then(|overrides| overrides.get(&prop.name).map(|s| s.as_str()));
 		let skip_parse = should_skip_parse.contains(&prop.name);
 		let expanded_longhands = expanded_longhands_map.get(&prop.name);
-		generate_property_type(spec_name, version, prop, description, extension, skip_parse, expanded_longhands)
+		generate_property_type(
+			spec_name,
+			version,
+			prop,
+			description,
+			extension,
+			value_override,
+			skip_parse,
+			expanded_longhands,
+		)
 	});
 
 	let tokens = quote! {
@@ -623,12 +637,14 @@ fn determine_box_portion(property_name: &str) -> Option<TokenStream> {
 	None
 }
 
+#[allow(clippy::too_many_arguments)]
 fn generate_property_type(
 	spec_name: &str,
 	version: usize,
 	prop: &PropertyDefinition,
 	description: Option<&String>,
 	value_extension: Option<&str>,
+	value_override: Option<&str>,
 	skip_parse: bool,
 	expanded_longhands: Option<&Vec<String>>,
 ) -> TokenStream {
@@ -643,7 +659,10 @@ fn generate_property_type(
 	let extended_value =
 		if let Some(extension) = value_extension { format!("{}{}", prop.value, extension) } else { prop.value.clone() };
 
-	let grammar_cleaned = extended_value.replace("'", "\"").replace("∞", "");
+	// The syntax macro is given the override where there is one, while the docs keep the spec grammar.
+	let syntax_grammar = value_override.unwrap_or(&extended_value);
+
+	let grammar_cleaned = syntax_grammar.replace("'", "\"").replace("∞", "");
 	let parsed_def = grammar_cleaned.parse::<TokenStream>().ok().and_then(|tokens| parse2::<Def>(tokens).ok());
 
 	let (is_enum, needs_lifetime) = match &parsed_def {
@@ -668,7 +687,7 @@ fn generate_property_type(
 
 	let doc_link = format!("https://drafts.csswg.org/{}-{}/#{}", spec_name, version, property_id);
 
-	let syntax_value = format!(" {} ", extended_value.replace('\n', " "));
+	let syntax_value = format!(" {} ", syntax_grammar.replace('\n', " "));
 
 	// Build declaration_metadata attributes
 	let initial = &prop.initial;
@@ -0,0 +1,13 @@
+use std::collections::HashMap;
+
+/// Returns a map of spec names to property value overrides
+///
+/// Each entry maps a property name to a grammar that replaces its spec grammar in the generated `#[syntax]`. The
+/// doc comment still shows the spec grammar.
+pub fn get_value_overrides() -> HashMap<String, HashMap<String, String>> {
+	let toml_str = include_str!("../value_overrides.toml");
+	let parsed: HashMap<String, HashMap<String, String>> =
+		toml::from_str(toml_str).expect("Failed to parse value_overrides.toml");
+
+	parsed
+}
@@ -0,0 +1,141 @@
+use css_lexer::{Cursor, Kind};
+
+/// Which comments should survive minification.
+///
+/// Comments are trivia and so are not part of the AST; to keep them, pass [CommentRetention::retains] to
+/// [ParserReturn::retain_trivia()][css_parse::ParserReturn::retain_trivia]. Retained comments are then written in
+/// place through any [CursorOverlaySink][css_parse::CursorOverlaySink], so they compose with every transform: a
+/// comment within a span that a transform replaces is dropped along with the rest of that span.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CommentRetention {
+	/// Remove every comment.
+	None,
+	/// Keep "bang" comments, which start with `/*!`. This is the long standing convention for license banners.
+	#[default]
+	Bang,
+	/// Keep bang comments, along with any comment containing `@license` or `@preserve`.
+	License,
+}
+
+impl CommentRetention {
+	/// Returns true if the given comment text (including the `/*` and `*/` delimiters) should be kept.
+	pub fn retains_comment(&self, comment: &str) -> bool {
+		let is_bang = comment.starts_with("/*!");
+		match self {
+			Self::None => false,
+			Self::Bang => is_bang,
+			Self::License => is_bang || comment.contains("@license") || comment.contains("@preserve"),
+		}
+	}
+
+	/// Returns true if the given [Cursor] is a comment which should be kept. Any other kind of [Cursor] (such as
+	/// whitespace) returns false.
+	pub fn retains(&self, cursor: Cursor, source_text: &str) -> bool {
+		cursor == Kind::Comment && self.retains_comment(cursor.str_slice(source_text))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::{assert_no_transform, assert_transform};
+	use css_ast::{CssAtomSet, StyleSheet};
+
+	#[test]
+	fn test_retains_comment() {
+		assert!(!CommentRetention::None.retains_comment("/*! MIT */"));
+		assert!(CommentRetention::Bang.retains_comment("/*! MIT */"));
+		assert!(!CommentRetention::Bang.retains_comment("/* @license MIT */"));
+		assert!(CommentRetention::License.retains_comment("/* @license MIT */"));
+		assert!(CommentRetention::License.retains_comment("/** @preserve */"));
+		assert!(CommentRetention::License.retains_comment("/*! MIT */"));
+		assert!(!CommentRetention::License.retains_comment("/* just a comment */"));
+	}
+
+	#[test]
+	fn test_minify_keeps_bang_comments() {
+		assert_transform!(
+			CssMinifierFeature::safe(),
+			retain CommentRetention::Bang,
+			CssAtomSet,
+			StyleSheet,
+			"/*! Bootstrap | MIT */\n/* layout */\nbody { /*! keep */ width: 0px; /* drop */ color: #ffffff; }",
+			"/*! Bootstrap | MIT */body{/*! keep */width:0;color:#fff;}"
+		);
+		assert_transform!(
+			CssMinifierFeature::safe(),
+			retain CommentRetention::None,
+			CssAtomSet,
+			StyleSheet,
+			"/*! Bootstrap | MIT */\n/* layout */\nbody { /*! keep */ width: 0px; /* drop */ color: #ffffff; }",
+			"body{width:0;color:#fff;}"
+		);
+	}
+
+	#[test]
+	fn test_minify_keeps_license_comments() {
+		assert_transform!(
+			CssMinifierFeature::safe(),
+			retain CommentRetention::License,
+			CssAtomSet,
+			StyleSheet,
+			"/* @license MIT */ a { color: #ff0000 } /*! v1 */ /* @preserve */",
+			"/* @license MIT */a{color:red}/*! v1 *//* @preserve */"
+		);
+		assert_transform!(
+			CssMinifierFeature::safe(),
+			retain CommentRetention::Bang,
+			CssAtomSet,
+			StyleSheet,
+			"/* @license MIT */ a { color: #ff0000 } /*! v1 */ /* @preserve */",
+			"a{color:red}/*! v1 */"
+		);
+	}
+
+	#[test]
+	fn test_minify_keeps_comments_before_selectors() {
+		assert_transform!(
+			CssMinifierFeature::safe(),
+			retain CommentRetention::Bang,
+			CssAtomSet,
+			StyleSheet,
+			"/*! MIT */a { color: #ffffff }",
+			"/*! MIT */a{color:#fff}"
+		);
+	}
+
+	#[test]
+	fn test_minify_keeps_comments_after_transformed_values() {
+		assert_transform!(
+			CssMinifierFeature::safe(),
+			retain CommentRetention::Bang,
+			CssAtomSet,
+			StyleSheet,
+			"a { color: #ffffff /*! after */; }",
+			"a{color:#fff/*! after */;}"
+		);
+	}
+
+	#[test]
+	fn test_minify_drops_comments_within_transformed_values() {
+		assert_transform!(
+			CssMinifierFeature::safe(),
+			retain CommentRetention::Bang,
+			CssAtomSet,
+			StyleSheet,
+			"a { color: rgb(255 /*! inside */ 255 255); }",
+			"a{color:#fff;}"
+		);
+	}
+
+	#[test]
+	fn test_minify_keeps_comments_without_changes() {
+		assert_no_transform!(
+			CssMinifierFeature::safe(),
+			retain CommentRetention::Bang,
+			CssAtomSet,
+			StyleSheet,
+			"/*! MIT */a { color: red }"
+		);
+	}
+}
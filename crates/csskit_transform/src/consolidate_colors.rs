@@ -0,0 +1,417 @@
+use crate::prelude::*;
+use bumpalo::Bump;
+use chromashift::{ColorDistance, Hex, Srgb};
+use css_ast::{Color, CssAtomSet, StyleSheet, ToChromashift, Visitable};
+use css_lexer::{Cursor, Lexer};
+use css_parse::{ComponentValues, Declaration, Parser, Span};
+use std::collections::HashMap;
+
+/// A color from a palette, which [ConsolidateColors] can rewrite similar colors to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteColor {
+	/// The name of the color in the palette, such as `brand` or `--brand`.
+	pub name: String,
+	/// The CSS to rewrite similar colors to. This is the color as written in the palette, or a `var()` of the custom
+	/// property for palettes read with [PaletteColor::from_custom_properties()].
+	pub value: String,
+	pub color: chromashift::Color,
+}
+
+impl PaletteColor {
+	/// Creates a palette color from its CSS, such as `#336699`. Returns None if the CSS is not a color which can be
+	/// compared with others, such as `currentcolor`.
+	pub fn new(name: impl Into<String>, value: &str) -> Option<Self> {
+		let value = value.trim();
+		let bump = Bump::default();
+		let lexer = Lexer::new(&CssAtomSet::ATOMS, value);
+		let mut parser = Parser::new(&bump, value, lexer);
+		let result = parser.parse_entirely::<Color>();
+		if !result.errors.is_empty() {
+			return None;
+		}
+		let color = result.output?.to_chromashift()?;
+		Some(Self { name: name.into(), value: value.to_string(), color })
+	}
+
+	/// Reads a palette from the custom properties in a stylesheet, such as `:root { --brand: #336699; }`, so that
+	/// similar colors are rewritten to `var(--brand)`. Custom properties whose value is not a color are skipped.
+	pub fn from_custom_properties(source_text: &str) -> Vec<Self> {
+		let bump = Bump::default();
+		let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+		let mut parser = Parser::new(&bump, source_text, lexer);
+		let mut collector = CustomPropertyColors { source_text, palette: vec![] };
+		if let Some(sheet) = parser.parse_entirely::<StyleSheet>().output {
+			sheet.accept(&mut collector);
+		}
+		collector.palette
+	}
+}
+
+struct CustomPropertyColors<'s> {
+	source_text: &'s str,
+	palette: Vec<PaletteColor>,
+}
+
+impl Visit for CustomPropertyColors<'_> {
+	fn visit_declaration<'b, T: css_ast::DeclarationValue<'b, CssMetadata> + css_ast::QueryableNode>(
+		&mut self,
+		decl: &Declaration<'b, T, CssMetadata>,
+	) {
+		if decl.value.is_custom() {
+			let name = Cursor::from(decl.name).str_slice(self.source_text);
+			let span = decl.value.to_span();
+			let value = &self.source_text[usize::from(span.start())..usize::from(span.end())];
+			self.palette.extend(
+				PaletteColor::new(name, value).map(|entry| PaletteColor { value: format!("var({name})"), ..entry }),
+			);
+		}
+	}
+}
+
+/// The largest [delta_e][ColorDistance::delta_e] a color can move by when written as hex, for it to be considered the
+/// same color as its hex value.
+const HEX_ROUND_TRIP_TOLERANCE: f64 = 0.5;
+
+/// What makes two colors the same color. Colors which can be written as hex are the same if their hex is, so that
+/// `#333` and `rgb(51 51 51)` are one color. Other colors (such as `display-p3` colors outside of sRGB) would be clipped
+/// by hex, so are only the same if their CSS is.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ColorKey {
+	Hex(Hex),
+	Css(String),
+}
+
+impl ColorKey {
+	fn new(color: chromashift::Color, value: &str) -> Self {
+		let hex = Hex::from(color);
+		if chromashift::Color::Hex(hex).delta_e(color) <= HEX_ROUND_TRIP_TOLERANCE {
+			Self::Hex(hex)
+		} else {
+			Self::Css(value.to_string())
+		}
+	}
+}
+
+/// A distinct color used within a stylesheet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorUsage {
+	pub color: chromashift::Color,
+	/// The CSS for the color, as written at its first use.
+	pub value: String,
+	/// Where each use of the color is within the stylesheet.
+	pub spans: Vec<Span>,
+}
+
+impl ColorUsage {
+	fn key(&self) -> ColorKey {
+		ColorKey::new(self.color, &self.value)
+	}
+}
+
+/// Collects each distinct color used within a stylesheet, in the order they first appear.
+#[derive(Debug)]
+pub struct ColorUsages<'a> {
+	source_text: &'a str,
+	pub usages: Vec<ColorUsage>,
+	index: HashMap<ColorKey, usize>,
+}
+
+impl<'a> ColorUsages<'a> {
+	pub fn new(source_text: &'a str) -> Self {
+		Self { source_text, usages: vec![], index: HashMap::new() }
+	}
+}
+
+impl Visit for ColorUsages<'_> {
+	fn visit_color(&mut self, color: &Color) {
+		let Some(chroma_color) = color.to_chromashift() else {
+			return;
+		};
+		let span = color.to_span();
+		let value = &self.source_text[usize::from(span.start())..usize::from(span.end())];
+		let index = *self.index.entry(ColorKey::new(chroma_color, value)).or_insert_with(|| {
+			self.usages.push(ColorUsage { color: chroma_color, value: value.to_string(), spans: vec![] });
+			self.usages.len() - 1
+		});
+		self.usages[index].spans.push(span);
+	}
+}
+
+/// A group of colors which are close enough to one another to be considered the same, see
+/// [ColorConsolidation::cluster()].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorCluster {
+	/// The color every member is rewritten to.
+	pub canonical: chromashift::Color,
+	/// The CSS every member is rewritten to: the canonical color as first written, or the palette entry's value.
+	pub value: String,
+	/// The name of the palette entry the canonical color came from, if a palette was given.
+	pub palette_name: Option<String>,
+	/// The distinct colors within the cluster (which may include the canonical color), with the most used first.
+	pub members: Vec<ColorUsage>,
+}
+
+impl ColorCluster {
+	/// Returns true if any member differs from the canonical color, and so would be rewritten.
+	pub fn is_consolidated(&self) -> bool {
+		let canonical = ColorKey::new(self.canonical, &self.value);
+		self.members.iter().any(|member| member.key() != canonical)
+	}
+}
+
+/// Configures the [ConsolidateColors] transformer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorConsolidation {
+	/// The largest [delta_e][ColorDistance::delta_e] between two colors for them to be considered the same.
+	pub tolerance: f64,
+	/// The colors to consolidate to. When empty, each cluster uses its most common color.
+	pub palette: Vec<PaletteColor>,
+}
+
+impl Default for ColorConsolidation {
+	fn default() -> Self {
+		Self { tolerance: 2.0, palette: vec![] }
+	}
+}
+
+impl ColorConsolidation {
+	/// Groups the given colors into clusters of similar colors. Colors are only ever grouped with colors of the same
+	/// opacity.
+	///
+	/// Without a palette the most used color (or the first to appear, if tied) becomes the canonical color for each
+	/// cluster, which takes every other color within the tolerance of it. With a palette each color joins the cluster
+	/// of its nearest palette color within the tolerance, and colors with no palette color within the tolerance are
+	/// left out.
+	pub fn cluster(&self, usages: &[ColorUsage]) -> Vec<ColorCluster> {
+		let alpha = |color: chromashift::Color| Srgb::from(color).alpha;
+		let close =
+			|a: chromashift::Color, b: chromashift::Color| alpha(a) == alpha(b) && a.delta_e(b) <= self.tolerance;
+		let mut clusters: Vec<ColorCluster> = vec![];
+		if self.palette.is_empty() {
+			let mut order: Vec<&ColorUsage> = usages.iter().collect();
+			order.sort_by_key(|usage| std::cmp::Reverse(usage.spans.len()));
+			let mut assigned = vec![false; order.len()];
+			for i in 0..order.len() {
+				if assigned[i] {
+					continue;
+				}
+				let ColorUsage { color: canonical, value, .. } = order[i].clone();
+				let mut members = vec![];
+				for j in i..order.len() {
+					if !assigned[j] && close(canonical, order[j].color) {
+						assigned[j] = true;
+						members.push(order[j].clone());
+					}
+				}
+				clusters.push(ColorCluster { canonical, value, palette_name: None, members });
+			}
+		} else {
+			let mut by_palette: Vec<Option<ColorCluster>> = vec![None; self.palette.len()];
+			for usage in usages {
+				let nearest = self
+					.palette
+					.iter()
+					.enumerate()
+					.filter(|(_, entry)| close(entry.color, usage.color))
+					.min_by(|(_, a), (_, b)| a.color.delta_e(usage.color).total_cmp(&b.color.delta_e(usage.color)));
+				if let Some((index, entry)) = nearest {
+					by_palette[index]
+						.get_or_insert_with(|| ColorCluster {
+							canonical: entry.color,
+							value: entry.value.clone(),
+							palette_name: Some(entry.name.clone()),
+							members: vec![],
+						})
+						.members
+						.push(usage.clone());
+				}
+			}
+			clusters.extend(by_palette.into_iter().flatten());
+			for cluster in clusters.iter_mut() {
+				cluster.members.sort_by_key(|member| std::cmp::Reverse(member.spans.len()));
+			}
+		}
+		clusters
+	}
+}
+
+/// Rewrites colors which are within a tolerance of one another to a single canonical color, as configured by
+/// [ColorConsolidation].
+///
+/// This changes how the stylesheet looks (if only slightly), so is a [CssBuildFeature] rather than a
+/// [CssMinifierFeature][crate::CssMinifierFeature].
+pub struct ConsolidateColors<'a, 'ctx, N: Visitable + NodeWithMetadata<CssMetadata>> {
+	pub transformer: &'ctx Transformer<'a, CssMetadata, N, CssBuildFeature>,
+	/// The CSS to rewrite the color at each span to.
+	replacements: HashMap<Span, String>,
+}
+
+impl<'a, 'ctx, N> Transform<'a, 'ctx, CssMetadata, N, CssBuildFeature> for ConsolidateColors<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn may_change(features: CssBuildFeature, _node: &N) -> bool {
+		features.contains(CssBuildFeature::ConsolidateColors)
+	}
+
+	fn new(transformer: &'ctx Transformer<'a, CssMetadata, N, CssBuildFeature>) -> Self {
+		Self { transformer, replacements: HashMap::new() }
+	}
+}
+
+impl<'a, 'ctx, N> Visit for ConsolidateColors<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn visit_style_sheet(&mut self, sheet: &StyleSheet) {
+		let mut usages = ColorUsages::new(self.transformer.source_text);
+		sheet.accept(&mut usages);
+		for cluster in self.transformer.options().colors.cluster(&usages.usages) {
+			let canonical = ColorKey::new(cluster.canonical, &cluster.value);
+			for member in cluster.members {
+				// Colors from a palette are always rewritten, so that they use the palette's syntax.
+				if member.key() != canonical || cluster.palette_name.is_some() {
+					for span in member.spans {
+						self.replacements.insert(span, cluster.value.clone());
+					}
+				}
+			}
+		}
+	}
+
+	fn visit_color(&mut self, color: &Color) {
+		let span = color.to_span();
+		let Some(replacement) = self.replacements.get(&span) else {
+			return;
+		};
+		let source_text = self.transformer.source_text;
+		if &source_text[usize::from(span.start())..usize::from(span.end())] != replacement {
+			// Palettes read from custom properties rewrite to `var()`, which is not a `<color>`.
+			self.transformer.replace_parsed::<ComponentValues>(span, replacement);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssBuildOptions;
+	use crate::test_helpers::{assert_no_transform, assert_transform};
+
+	#[test]
+	fn consolidates_to_most_used_color() {
+		assert_transform!(
+			CssBuildFeature::ConsolidateColors,
+			CssAtomSet,
+			StyleSheet,
+			"a { color: #323232; } b { color: #333333; } c { background-color: #333; } d { color: #343434; background: red; }",
+			"a { color: #333333; } b { color: #333333; } c { background-color: #333; } d { color: #333333; background: red; }"
+		);
+	}
+
+	#[test]
+	fn keeps_distinct_colors() {
+		assert_no_transform!(
+			CssBuildFeature::ConsolidateColors,
+			CssAtomSet,
+			StyleSheet,
+			"a { color: #333; } b { color: #444; }"
+		);
+	}
+
+	#[test]
+	fn keeps_colors_with_different_opacity() {
+		assert_no_transform!(
+			CssBuildFeature::ConsolidateColors,
+			CssAtomSet,
+			StyleSheet,
+			"a { color: #333; } b { color: #33333380; }"
+		);
+	}
+
+	#[test]
+	fn consolidates_drop_shadow_colors() {
+		assert_transform!(
+			CssBuildFeature::ConsolidateColors,
+			CssAtomSet,
+			StyleSheet,
+			"a { color: #333; } b { filter: drop-shadow(#323232 1px 1px) blur(2px); }",
+			"a { color: #333; } b { filter: drop-shadow(#333 1px 1px) blur(2px); }"
+		);
+	}
+
+	#[test]
+	fn consolidates_to_palette() {
+		let palette =
+			vec![PaletteColor::new("--text", "#333").unwrap(), PaletteColor::new("--accent", "rebeccapurple").unwrap()];
+		assert_transform!(
+			CssBuildFeature::ConsolidateColors,
+			CssBuildOptions { colors: ColorConsolidation { palette, ..Default::default() }, ..Default::default() },
+			CssAtomSet,
+			StyleSheet,
+			"a { color: #323232; outline-color: #663398; background: #0f0; }",
+			"a { color: #333; outline-color: rebeccapurple; background: #0f0; }"
+		);
+	}
+
+	#[test]
+	fn keeps_notation_of_canonical_color() {
+		assert_transform!(
+			CssBuildFeature::ConsolidateColors,
+			CssAtomSet,
+			StyleSheet,
+			"a { color: oklch(0.5 0.1 200); } b { color: oklch(0.5 0.1 200); } c { color: oklch(0.51 0.1 200); }",
+			"a { color: oklch(0.5 0.1 200); } b { color: oklch(0.5 0.1 200); } c { color: oklch(0.5 0.1 200); }"
+		);
+	}
+
+	#[test]
+	fn keeps_colors_outside_of_srgb() {
+		assert_no_transform!(
+			CssBuildFeature::ConsolidateColors,
+			CssAtomSet,
+			StyleSheet,
+			"a { color: color(display-p3 0 1 0); } b { color: #0f0; } c { color: color(display-p3 0.1 1 0); }"
+		);
+	}
+
+	#[test]
+	fn consolidates_to_custom_properties() {
+		let palette = PaletteColor::from_custom_properties(":root { --text: #333; }");
+		assert_transform!(
+			CssBuildFeature::ConsolidateColors,
+			CssBuildOptions { colors: ColorConsolidation { palette, ..Default::default() }, ..Default::default() },
+			CssAtomSet,
+			StyleSheet,
+			":root { --text: #333; } a { color: #323232; } b { color: #333; }",
+			":root { --text: #333; } a { color: var(--text); } b { color: var(--text); }"
+		);
+	}
+
+	#[test]
+	fn reads_palette_from_custom_properties() {
+		let palette =
+			PaletteColor::from_custom_properties(":root { --text: #333; --gap: 4px; --accent: rgb(0 0 255); }");
+		assert_eq!(
+			palette.iter().map(|entry| (&*entry.name, &*entry.value)).collect::<Vec<_>>(),
+			[("--text", "var(--text)"), ("--accent", "var(--accent)")]
+		);
+	}
+
+	#[test]
+	fn clusters_by_usage() {
+		let bump = Bump::default();
+		let source_text = "a { color: #323232; } b { color: #333; } c { color: #333; } d { color: #fff; }";
+		let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+		let mut parser = Parser::new(&bump, source_text, lexer);
+		let sheet = parser.parse_entirely::<StyleSheet>().output.unwrap();
+		let mut usages = ColorUsages::new(source_text);
+		sheet.accept(&mut usages);
+		let clusters = ColorConsolidation::default().cluster(&usages.usages);
+		assert_eq!(clusters.len(), 2);
+		assert_eq!(clusters[0].value, "#333");
+		assert_eq!(clusters[0].members.iter().map(|member| member.spans.len()).collect::<Vec<_>>(), [2, 1]);
+		assert!(clusters[0].is_consolidated());
+		assert!(!clusters[1].is_consolidated());
+	}
+}
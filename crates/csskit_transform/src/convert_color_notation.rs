@@ -0,0 +1,289 @@
+use crate::prelude::*;
+use chromashift::{DisplayP3, Hex, Hsl, Lab, Lch, LinearRgb, Oklab, Oklch, Srgb, ToAlpha};
+use css_ast::{Color, ColorFunction, ColorSpace, ToChromashift, Visitable};
+
+/// How far outside of a gamut a channel can be while still being considered within it, allowing for rounding errors
+/// when converting between colour spaces.
+const GAMUT_EPSILON: f64 = 0.0001;
+
+/// The notation [ConvertColorNotation] rewrites colors to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorNotation {
+	/// Hex notation, such as `#663399`.
+	Hex,
+	/// The `rgb()` function, such as `rgb(102 51 153)`.
+	Rgb,
+	/// The `hsl()` function, such as `hsl(270 50% 40%)`.
+	Hsl,
+	/// The `oklch()` function, such as `oklch(0.4403 0.1603 303.373)`.
+	#[default]
+	Oklch,
+	/// The `oklab()` function, such as `oklab(0.4403 0.0882 -0.1339)`.
+	Oklab,
+	/// The `lab()` function, such as `lab(32.3927 38.4256 -47.6918)`.
+	Lab,
+	/// The `lch()` function, such as `lch(32.3927 61.2397 308.862)`.
+	Lch,
+	/// The `color()` function in the `display-p3` color space, such as `color(display-p3 0.3824 0.2055 0.5769)`.
+	DisplayP3,
+}
+
+impl ColorNotation {
+	/// Returns the notation the given color is written in, if it is one of the notations colors can be converted to.
+	pub fn of(color: &Color) -> Option<Self> {
+		match color {
+			Color::Hex(_) => Some(Self::Hex),
+			Color::Function(ColorFunction::Rgb(_) | ColorFunction::Rgba(_)) => Some(Self::Rgb),
+			Color::Function(ColorFunction::Hsl(_) | ColorFunction::Hsla(_)) => Some(Self::Hsl),
+			Color::Function(ColorFunction::Oklch(_)) => Some(Self::Oklch),
+			Color::Function(ColorFunction::Oklab(_)) => Some(Self::Oklab),
+			Color::Function(ColorFunction::Lab(_)) => Some(Self::Lab),
+			Color::Function(ColorFunction::Lch(_)) => Some(Self::Lch),
+			Color::Function(ColorFunction::Color(color)) if matches!(color.params.0, ColorSpace::DisplayP3(_)) => {
+				Some(Self::DisplayP3)
+			}
+			_ => None,
+		}
+	}
+}
+
+/// Configures the [ConvertColorNotation] transformer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorNotationConversion {
+	pub notation: ColorNotation,
+	/// How many decimal places to round each channel to.
+	pub precision: u8,
+}
+
+impl Default for ColorNotationConversion {
+	fn default() -> Self {
+		Self { notation: ColorNotation::default(), precision: 4 }
+	}
+}
+
+impl ColorNotationConversion {
+	/// Returns the CSS for the given color in the configured notation, or None if the notation cannot represent it,
+	/// such as a color outside of the sRGB gamut in hex.
+	pub fn convert(&self, color: chromashift::Color) -> Option<String> {
+		let round = |value: f64| {
+			let scale = 10f64.powi(self.precision as i32);
+			// Avoid writing `-0`.
+			(value * scale).round() / scale + 0.0
+		};
+		let alpha = color.to_alpha();
+		let alpha = if alpha < 100.0 { format!(" / {}", round(alpha as f64 / 100.0)) } else { String::new() };
+		let in_gamut = |channels: [f64; 3]| channels.iter().all(|c| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(c));
+		let srgb_gamut = || {
+			let LinearRgb { red, green, blue, .. } = LinearRgb::from(color);
+			in_gamut([red, green, blue])
+		};
+		// Hue is meaningless without chroma, and would otherwise be left as noise from the conversion.
+		let hue = |chroma: f64, hue: f64| if round(chroma) == 0.0 { 0.0 } else { round(hue) };
+		let css = match self.notation {
+			ColorNotation::Hex => {
+				if !srgb_gamut() {
+					return None;
+				}
+				Hex::from(color).to_string()
+			}
+			ColorNotation::Rgb => {
+				if !srgb_gamut() {
+					return None;
+				}
+				let Srgb { red, green, blue, .. } = Srgb::from(color);
+				format!("rgb({red} {green} {blue}{alpha})")
+			}
+			ColorNotation::Hsl => {
+				if !srgb_gamut() {
+					return None;
+				}
+				let Hsl { hue: h, saturation, lightness, .. } = Hsl::from(color);
+				let saturation = saturation as f64;
+				format!("hsl({} {}% {}%{alpha})", hue(saturation, h as f64), round(saturation), round(lightness as f64))
+			}
+			ColorNotation::Oklch => {
+				let Oklch { lightness, chroma, hue: h, .. } = Oklch::from(color);
+				format!("oklch({} {} {}{alpha})", round(lightness), round(chroma), hue(chroma, h))
+			}
+			ColorNotation::Oklab => {
+				let Oklab { lightness, a, b, .. } = Oklab::from(color);
+				format!("oklab({} {} {}{alpha})", round(lightness), round(a), round(b))
+			}
+			ColorNotation::Lab => {
+				let Lab { lightness, a, b, .. } = Lab::from(color);
+				format!("lab({} {} {}{alpha})", round(lightness), round(a), round(b))
+			}
+			ColorNotation::Lch => {
+				let Lch { lightness, chroma, hue: h, .. } = Lch::from(color);
+				format!("lch({} {} {}{alpha})", round(lightness), round(chroma), hue(chroma, h))
+			}
+			ColorNotation::DisplayP3 => {
+				let DisplayP3 { red, green, blue, .. } = DisplayP3::from(color);
+				if !in_gamut([red, green, blue]) {
+					return None;
+				}
+				format!("color(display-p3 {} {} {}{alpha})", round(red), round(green), round(blue))
+			}
+		};
+		if css.contains("NaN") { None } else { Some(css) }
+	}
+}
+
+/// Rewrites every color into a single notation, as configured by [ColorNotationConversion]. Colors which the
+/// notation cannot represent, along with colors already written in it, are left as written.
+///
+/// Rounding each channel to the configured precision can change colors very slightly, so this is a [CssBuildFeature]
+/// rather than a [CssMinifierFeature][crate::CssMinifierFeature].
+pub struct ConvertColorNotation<'a, 'ctx, N: Visitable + NodeWithMetadata<CssMetadata>> {
+	pub transformer: &'ctx Transformer<'a, CssMetadata, N, CssBuildFeature>,
+}
+
+impl<'a, 'ctx, N> Transform<'a, 'ctx, CssMetadata, N, CssBuildFeature> for ConvertColorNotation<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn may_change(features: CssBuildFeature, _node: &N) -> bool {
+		features.contains(CssBuildFeature::ConvertColorNotation)
+	}
+
+	fn new(transformer: &'ctx Transformer<'a, CssMetadata, N, CssBuildFeature>) -> Self {
+		Self { transformer }
+	}
+}
+
+impl<'a, 'ctx, N> Visit for ConvertColorNotation<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn visit_color(&mut self, color: &Color) {
+		let options = &self.transformer.options().color_notation;
+		if ColorNotation::of(color) == Some(options.notation) {
+			return;
+		}
+		let Some(css) = color.to_chromashift().and_then(|chroma_color| options.convert(chroma_color)) else {
+			return;
+		};
+		self.transformer.replace_parsed::<Color>(color.to_span(), &css);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssBuildOptions;
+	use crate::test_helpers::{assert_no_transform, assert_transform};
+	use css_ast::{CssAtomSet, StyleSheet};
+
+	#[test]
+	fn converts_hex_to_oklch() {
+		assert_transform!(
+			CssBuildFeature::ConvertColorNotation,
+			CssBuildOptions {
+				color_notation: ColorNotationConversion { notation: ColorNotation::Oklch, precision: 3 },
+				..Default::default()
+			},
+			CssAtomSet,
+			StyleSheet,
+			"a { color: #663399; outline-color: white; }",
+			"a { color: oklch(0.44 0.16 303.373); outline-color: oklch(1 0 0); }"
+		);
+	}
+
+	#[test]
+	fn keeps_alpha() {
+		assert_transform!(
+			CssBuildFeature::ConvertColorNotation,
+			CssBuildOptions {
+				color_notation: ColorNotationConversion { notation: ColorNotation::Hsl, precision: 2 },
+				..Default::default()
+			},
+			CssAtomSet,
+			StyleSheet,
+			"a { color: rgb(255 0 0 / 50%); }",
+			"a { color: hsl(0 100% 50% / 0.5); }"
+		);
+		assert_transform!(
+			CssBuildFeature::ConvertColorNotation,
+			CssBuildOptions {
+				color_notation: ColorNotationConversion { notation: ColorNotation::Hex, precision: 2 },
+				..Default::default()
+			},
+			CssAtomSet,
+			StyleSheet,
+			"a { color: hsl(0 100% 50% / 0.5); }",
+			"a { color: #ff000080; }"
+		);
+	}
+
+	#[test]
+	fn converts_to_rgb() {
+		assert_transform!(
+			CssBuildFeature::ConvertColorNotation,
+			CssBuildOptions {
+				color_notation: ColorNotationConversion { notation: ColorNotation::Rgb, precision: 2 },
+				..Default::default()
+			},
+			CssAtomSet,
+			StyleSheet,
+			"a { color: hsl(270 50% 40%); }",
+			"a { color: rgb(102 51 153); }"
+		);
+	}
+
+	#[test]
+	fn converts_to_display_p3() {
+		assert_transform!(
+			CssBuildFeature::ConvertColorNotation,
+			CssBuildOptions {
+				color_notation: ColorNotationConversion { notation: ColorNotation::DisplayP3, precision: 2 },
+				..Default::default()
+			},
+			CssAtomSet,
+			StyleSheet,
+			"a { color: #000; }",
+			"a { color: color(display-p3 0 0 0); }"
+		);
+	}
+
+	#[test]
+	fn keeps_colors_outside_of_the_gamut() {
+		assert_no_transform!(
+			CssBuildFeature::ConvertColorNotation,
+			CssBuildOptions {
+				color_notation: ColorNotationConversion { notation: ColorNotation::Hex, precision: 2 },
+				..Default::default()
+			},
+			CssAtomSet,
+			StyleSheet,
+			"a { color: color(display-p3 1 0 0); }"
+		);
+	}
+
+	#[test]
+	fn keeps_colors_already_in_the_notation() {
+		assert_no_transform!(
+			CssBuildFeature::ConvertColorNotation,
+			CssBuildOptions {
+				color_notation: ColorNotationConversion { notation: ColorNotation::Oklch, precision: 2 },
+				..Default::default()
+			},
+			CssAtomSet,
+			StyleSheet,
+			"a { color: oklch(0.5 0.123456 100); }"
+		);
+	}
+
+	#[test]
+	fn keeps_colors_without_a_value() {
+		assert_no_transform!(
+			CssBuildFeature::ConvertColorNotation,
+			CssBuildOptions {
+				color_notation: ColorNotationConversion { notation: ColorNotation::Lab, precision: 2 },
+				..Default::default()
+			},
+			CssAtomSet,
+			StyleSheet,
+			"a { color: currentcolor; }"
+		);
+	}
+}
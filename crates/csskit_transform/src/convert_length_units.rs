@@ -0,0 +1,261 @@
+use crate::prelude::*;
+use css_ast::{DeclarationValue, Length, QueryableNode, Visitable};
+use css_lexer::Cursor;
+use css_parse::Declaration;
+
+/// Which units [ConvertLengthUnits] converts between.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LengthConversion {
+	/// Converts `px` lengths to `rem`.
+	#[default]
+	PxToRem,
+	/// Converts `px` lengths to `em`. This assumes each element's font size is the root font size.
+	PxToEm,
+	/// Converts `rem` lengths to `px`, for targets which do not support `rem`.
+	RemToPx,
+	/// Converts `em` lengths to `px`. This assumes each element's font size is the root font size.
+	EmToPx,
+}
+
+/// Configures the [ConvertLengthUnits] transformer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LengthUnitConversion {
+	pub conversion: LengthConversion,
+	/// How many `px` make up one `rem` (or `em`).
+	pub root_font_size: f32,
+	/// The properties to convert lengths within. When empty, every property is converted unless it is in
+	/// `deny_properties`. Each entry is a property name, which may start or end with `*` to match any prefix or suffix
+	/// (for example `border*` or `*-width`).
+	pub allow_properties: Vec<String>,
+	/// The properties which should never have their lengths converted, in the same format as `allow_properties`.
+	pub deny_properties: Vec<String>,
+	/// Lengths smaller than this many `px` (ignoring their sign) are left alone, so that hairlines such as `1px` borders
+	/// stay crisp.
+	pub min_px: f32,
+	/// How many decimal places to round converted values to.
+	pub precision: u8,
+}
+
+impl Default for LengthUnitConversion {
+	fn default() -> Self {
+		Self {
+			conversion: LengthConversion::default(),
+			root_font_size: 16.0,
+			allow_properties: vec![],
+			deny_properties: vec![],
+			min_px: 0.0,
+			precision: 5,
+		}
+	}
+}
+
+impl LengthUnitConversion {
+	/// Returns true if lengths within the given property should be converted.
+	pub fn converts_property(&self, property: &str) -> bool {
+		let matches = |patterns: &[String]| patterns.iter().any(|pattern| property_matches(pattern, property));
+		(self.allow_properties.is_empty() || matches(&self.allow_properties)) && !matches(&self.deny_properties)
+	}
+
+	/// Converts the given length, returning the CSS text to replace it with, or None if it should be left alone.
+	pub fn convert(&self, length: &Length) -> Option<String> {
+		let root = self.root_font_size as f64;
+		let (value, px, unit) = match (self.conversion, length) {
+			(LengthConversion::PxToRem, Length::Px(d)) => (f32::from(*d) as f64 / root, f32::from(*d) as f64, "rem"),
+			(LengthConversion::PxToEm, Length::Px(d)) => (f32::from(*d) as f64 / root, f32::from(*d) as f64, "em"),
+			(LengthConversion::RemToPx, Length::Rem(d)) | (LengthConversion::EmToPx, Length::Em(d)) => {
+				let px = f32::from(*d) as f64 * root;
+				(px, px, "px")
+			}
+			_ => return None,
+		};
+		if px == 0.0 || px.abs() < self.min_px as f64 || !value.is_finite() {
+			return None;
+		}
+		let scale = 10f64.powi(self.precision as i32);
+		let rounded = (value * scale).round() / scale;
+		if rounded == 0.0 {
+			return None;
+		}
+		Some(format!("{rounded}{unit}"))
+	}
+}
+
+pub(crate) fn property_matches(pattern: &str, property: &str) -> bool {
+	let property = property.to_ascii_lowercase();
+	let pattern = pattern.trim().to_ascii_lowercase();
+	match (pattern.strip_prefix('*'), pattern.strip_suffix('*')) {
+		(Some(""), _) => true,
+		(Some(suffix), Some(_)) => property.contains(&suffix[..suffix.len() - 1]),
+		(Some(suffix), None) => property.ends_with(suffix),
+		(None, Some(prefix)) => property.starts_with(prefix),
+		(None, None) => property == pattern,
+	}
+}
+
+/// Converts lengths between `px` and `rem` (or `em`), as configured by [LengthUnitConversion].
+///
+/// Only lengths within declarations are converted, so media queries and custom properties are left as written.
+pub struct ConvertLengthUnits<'a, 'ctx, N: Visitable + NodeWithMetadata<CssMetadata>> {
+	pub transformer: &'ctx Transformer<'a, CssMetadata, N, CssBuildFeature>,
+	/// Whether the visitor is within a declaration whose lengths should be converted.
+	converting: bool,
+}
+
+impl<'a, 'ctx, N> Transform<'a, 'ctx, CssMetadata, N, CssBuildFeature> for ConvertLengthUnits<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn may_change(features: CssBuildFeature, _node: &N) -> bool {
+		features.contains(CssBuildFeature::ConvertLengthUnits)
+	}
+
+	fn new(transformer: &'ctx Transformer<'a, CssMetadata, N, CssBuildFeature>) -> Self {
+		Self { transformer, converting: false }
+	}
+}
+
+impl<'a, 'ctx, N> Visit for ConvertLengthUnits<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn visit_declaration<'b, T: DeclarationValue<'b, CssMetadata> + QueryableNode>(
+		&mut self,
+		decl: &Declaration<'b, T, CssMetadata>,
+	) {
+		let property = Cursor::from(decl.name).str_slice(self.transformer.source_text);
+		self.converting =
+			!decl.value.is_custom() && self.transformer.options().length_units.converts_property(property);
+	}
+
+	fn exit_declaration<'b, T: DeclarationValue<'b, CssMetadata> + QueryableNode>(
+		&mut self,
+		_decl: &Declaration<'b, T, CssMetadata>,
+	) {
+		self.converting = false;
+	}
+
+	fn visit_length(&mut self, length: &Length) {
+		if !self.converting {
+			return;
+		}
+		if let Some(converted) = self.transformer.options().length_units.convert(length) {
+			self.transformer.replace_parsed::<Length>(length, &converted);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssBuildOptions;
+	use crate::test_helpers::{assert_no_transform, assert_transform};
+	use css_ast::{CssAtomSet, StyleSheet};
+
+	#[test]
+	fn converts_px_to_rem() {
+		assert_transform!(
+			CssBuildFeature::ConvertLengthUnits,
+			CssAtomSet,
+			StyleSheet,
+			"a { margin: 16px 8px; font-size: 14px; width: 10%; }",
+			"a { margin: 1rem 0.5rem; font-size: 0.875rem; width: 10%; }"
+		);
+	}
+
+	#[test]
+	fn converts_px_to_em() {
+		let length_units =
+			LengthUnitConversion { conversion: LengthConversion::PxToEm, root_font_size: 10.0, ..Default::default() };
+		assert_transform!(
+			CssBuildFeature::ConvertLengthUnits,
+			CssBuildOptions { length_units, ..Default::default() },
+			CssAtomSet,
+			StyleSheet,
+			"a { padding: 20px; }",
+			"a { padding: 2em; }"
+		);
+	}
+
+	#[test]
+	fn converts_back_to_px() {
+		let length_units = LengthUnitConversion { conversion: LengthConversion::RemToPx, ..Default::default() };
+		assert_transform!(
+			CssBuildFeature::ConvertLengthUnits,
+			CssBuildOptions { length_units, ..Default::default() },
+			CssAtomSet,
+			StyleSheet,
+			"a { margin: 1.5rem 2em; }",
+			"a { margin: 24px 2em; }"
+		);
+		let length_units = LengthUnitConversion { conversion: LengthConversion::EmToPx, ..Default::default() };
+		assert_transform!(
+			CssBuildFeature::ConvertLengthUnits,
+			CssBuildOptions { length_units, ..Default::default() },
+			CssAtomSet,
+			StyleSheet,
+			"a { margin: 1.5rem 2em; }",
+			"a { margin: 1.5rem 32px; }"
+		);
+	}
+
+	#[test]
+	fn filters_properties() {
+		let length_units = LengthUnitConversion {
+			allow_properties: vec!["margin*".into(), "*-width".into()],
+			deny_properties: vec!["border*".into()],
+			..Default::default()
+		};
+		assert_transform!(
+			CssBuildFeature::ConvertLengthUnits,
+			CssBuildOptions { length_units, ..Default::default() },
+			CssAtomSet,
+			StyleSheet,
+			"a { margin-top: 16px; max-width: 32px; border-width: 16px; padding: 16px; }",
+			"a { margin-top: 1rem; max-width: 2rem; border-width: 16px; padding: 16px; }"
+		);
+	}
+
+	#[test]
+	fn skips_lengths_below_threshold() {
+		let length_units = LengthUnitConversion { min_px: 2.0, ..Default::default() };
+		assert_transform!(
+			CssBuildFeature::ConvertLengthUnits,
+			CssBuildOptions { length_units, ..Default::default() },
+			CssAtomSet,
+			StyleSheet,
+			"a { margin: 1px -1px 2px -4px; }",
+			"a { margin: 1px -1px 0.125rem -0.25rem; }"
+		);
+	}
+
+	#[test]
+	fn rounds_to_precision() {
+		let length_units = LengthUnitConversion { precision: 2, ..Default::default() };
+		assert_transform!(
+			CssBuildFeature::ConvertLengthUnits,
+			CssBuildOptions { length_units, ..Default::default() },
+			CssAtomSet,
+			StyleSheet,
+			"a { width: 13px; height: 0.01px; }",
+			"a { width: 0.81rem; height: 0.01px; }"
+		);
+	}
+
+	#[test]
+	fn keeps_lengths_outside_declarations() {
+		assert_no_transform!(
+			CssBuildFeature::ConvertLengthUnits,
+			CssAtomSet,
+			StyleSheet,
+			"@media (min-width: 768px) { a { --gap: 16px; width: 0px; } }"
+		);
+	}
+
+	#[test]
+	fn matches_property_patterns() {
+		assert!(property_matches("*", "color"));
+		assert!(property_matches("Border-Width", "border-width"));
+		assert!(property_matches("*radius*", "border-top-left-radius"));
+		assert!(!property_matches("border", "border-width"));
+	}
+}
@@ -0,0 +1,692 @@
+use crate::{TransformPlugin, Transformer, TransformerFeatures};
+use bumpalo::collections::Vec as BumpVec;
+use css_ast::{
+	BgPosition, Computed, ComputedFunction, ComputedPart, CssAtomSet, CssMetadata, CustomIdent, DashedIdent,
+	DeclarationValue, GridLine, KeyframesName, LengthPercentage, NodeId, Position, PositionHorizontal,
+	PositionSingleValue, QueryableNode, TransformList, Translate3dFunction, TranslateFunction, TranslatexFunction,
+	Visit, Visitable,
+};
+use css_lexer::{Cursor, Kind, KindSet, Lexer, SourceOffset, Span, ToSpan};
+use css_parse::{ComponentValue, ComponentValues, Declaration, NodeWithMetadata, Parser, SourceCursor, T};
+use std::collections::HashSet;
+
+/// The comment which stops [ConvertToRtl] from flipping the node which follows it.
+const IGNORE_DIRECTIVE: &str = "rtl-ignore";
+
+/// Shorthands which take up to four values in `top right bottom left` order.
+const BOX_SHORTHANDS: &[&str] =
+	&["border-color", "border-style", "border-width", "inset", "margin", "padding", "scroll-margin", "scroll-padding"];
+
+/// Properties which take a `<position>`, whose horizontal percentage is mirrored. Values which parse into a typed
+/// [Position] or [BgPosition] are mirrored as they are visited, so these are only needed for the values which don't,
+/// such as `transform-origin` or a vendor prefixed property.
+const POSITION_PROPERTIES: &[&str] =
+	&["background-position", "mask-position", "object-position", "perspective-origin", "transform-origin"];
+
+/// Properties which take only horizontal positions, so every percentage is mirrored.
+const HORIZONTAL_POSITION_PROPERTIES: &[&str] = &["background-position-x", "mask-position-x"];
+
+/// Keywords which are swapped wherever they appear in a value.
+const KEYWORDS: &[(&str, &str)] = &[
+	("left", "right"),
+	("ltr", "rtl"),
+	("e-resize", "w-resize"),
+	("ne-resize", "nw-resize"),
+	("se-resize", "sw-resize"),
+	("nesw-resize", "nwse-resize"),
+];
+
+/// Mirrors a stylesheet written for left-to-right text so that it can be used for right-to-left text.
+///
+/// Within each declaration this:
+/// - swaps `left` and `right` in property names (such as `margin-left` or `border-top-left-radius`), and in keyword
+///   values (such as `float: left` or `linear-gradient(to left, ...)`), but not in names such as
+///   `animation-name: left` or `grid-area: right`,
+/// - mirrors four value shorthands such as `margin` and `padding`, along with `border-radius`,
+/// - negates the horizontal offset of `translate()` style transforms (including prefixed ones such as
+///   `-webkit-transform`) and the `translate` property,
+/// - mirrors horizontal percentages in positions, such as `background-position: 20% 50%` or the position within a
+///   `background` shorthand.
+///
+/// Any rule or declaration preceded by a `/* csskit: rtl-ignore */` comment is left as written.
+///
+/// Flipping a stylesheet twice returns it to how it started, so rather than being a feature flag (which would run on
+/// every pass of [Transformer::transform_until_stable()]) this is a [TransformPlugin], registered with
+/// [Transformer::with_plugin()], which only flips the stylesheet on its first pass. Each stylesheet to be flipped needs
+/// its own [ConvertToRtl].
+#[derive(Debug, Default)]
+pub struct ConvertToRtl {
+	flipped: bool,
+}
+
+impl<N, F> TransformPlugin<CssMetadata, N, F> for ConvertToRtl
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+	F: TransformerFeatures<CssMetadata, N>,
+{
+	fn name(&self) -> &str {
+		"convert-to-rtl"
+	}
+
+	fn may_change(&self, _features: F, _node: &N) -> bool {
+		!self.flipped
+	}
+
+	fn transform<'a>(&mut self, transformer: &Transformer<'a, CssMetadata, N, F>, node: &N) {
+		node.accept(&mut FlipVisitor::new(transformer));
+		self.flipped = true;
+	}
+}
+
+/// A declaration which [FlipVisitor] has entered, to be flipped once every node within its value has been seen.
+struct PendingDeclaration {
+	/// The lowercased property name, without any vendor prefix.
+	property: String,
+	/// The spans of names within the value (such as a `<custom-ident>`), whose keywords must not be swapped.
+	names: Vec<Span>,
+	/// The typed nodes within the value to replace, such as a mirrored `<position>` percentage.
+	flips: Vec<(Span, String)>,
+}
+
+struct FlipVisitor<'a, 'ctx, N: NodeWithMetadata<CssMetadata>, F: TransformerFeatures<CssMetadata, N>> {
+	transformer: &'ctx Transformer<'a, CssMetadata, N, F>,
+	/// The start of each node which follows an ignore directive.
+	ignored: HashSet<SourceOffset>,
+	/// The end of the last node to be ignored, so that anything within it is also left alone.
+	ignored_until: SourceOffset,
+	declaration: Option<PendingDeclaration>,
+}
+
+impl<'a, 'ctx, N, F> FlipVisitor<'a, 'ctx, N, F>
+where
+	N: NodeWithMetadata<CssMetadata>,
+	F: TransformerFeatures<CssMetadata, N>,
+{
+	fn new(transformer: &'ctx Transformer<'a, CssMetadata, N, F>) -> Self {
+		let source_text = transformer.source_text;
+		let mut ignored = HashSet::new();
+		let mut directive = false;
+		for cursor in Lexer::new(&CssAtomSet::ATOMS, source_text) {
+			if cursor == Kind::Comment {
+				directive |= is_ignore_directive(cursor.str_slice(source_text));
+			} else if cursor != Kind::Whitespace {
+				if directive {
+					ignored.insert(cursor.offset());
+				}
+				directive = false;
+			}
+		}
+		Self { transformer, ignored, ignored_until: SourceOffset(0), declaration: None }
+	}
+
+	fn add_name(&mut self, name: Cursor) {
+		if let Some(declaration) = &mut self.declaration {
+			declaration.names.push(name.to_span());
+		}
+	}
+
+	fn add_flip(&mut self, span: Span, replacement: String) {
+		if let Some(declaration) = &mut self.declaration {
+			declaration.flips.push((span, replacement));
+		}
+	}
+
+	fn source(&self, span: Span) -> &'a str {
+		&self.transformer.source_text[usize::from(span.start())..usize::from(span.end())]
+	}
+
+	/// Mirrors a horizontal percentage, so `20%` becomes `80%`.
+	fn mirror(&mut self, offset: &LengthPercentage) {
+		if let LengthPercentage::Percent(percent) = offset {
+			let percent = f64::from(Into::<f32>::into(*percent));
+			let mirrored = ((100.0 - percent) * 1_000_000.0).round() / 1_000_000.0;
+			self.add_flip(offset.to_span(), format!("{mirrored}%"));
+		}
+	}
+
+	/// Negates a horizontal offset such as `10px` or `-50%`, leaving a zero offset as written.
+	fn negate(&mut self, span: Span, value: f32) {
+		if value == 0.0 {
+			return;
+		}
+		let text = self.source(span);
+		let negated = match text.strip_prefix('-') {
+			Some(positive) => positive.to_string(),
+			None => format!("-{}", text.trim_start_matches('+')),
+		};
+		self.add_flip(span, negated);
+	}
+
+	fn negate_length_percentage(&mut self, offset: &LengthPercentage) {
+		self.negate(offset.to_span(), (*offset).into());
+	}
+
+	/// Negates the offset of a translation which needs computing, such as `translateX(var(--x))`.
+	fn negate_computed_translation(&mut self, function: &ComputedFunction) {
+		if !matches!(
+			self.transformer.to_atom::<CssAtomSet>(function.name.into()),
+			CssAtomSet::Translate | CssAtomSet::Translatex | CssAtomSet::Translate3d
+		) {
+			return;
+		}
+		let offset =
+			function.params.iter().find(|part| !matches!(part, ComputedPart::Value(ComponentValue::Whitespace(_))));
+		match offset {
+			Some(ComputedPart::Value(ComponentValue::Number(number))) => {
+				let cursor: Cursor = (*number).into();
+				self.negate(cursor.to_span(), cursor.token().value());
+			}
+			Some(ComputedPart::Value(ComponentValue::Dimension(dimension))) => {
+				let cursor: Cursor = (*dimension).into();
+				self.negate(cursor.to_span(), cursor.token().value());
+			}
+			// The value of a `var()` or math function isn't known, so it is negated when computed.
+			Some(part @ (ComputedPart::Var(_) | ComputedPart::Math(_))) => {
+				let span = part.to_span();
+				self.add_flip(span, format!("calc(-1*{})", self.source(span)));
+			}
+			_ => {}
+		}
+	}
+
+	/// Mirrors the horizontal percentage of each comma separated position, for values which did not parse into typed
+	/// positions.
+	fn mirror_position_layers<I>(&mut self, p: &mut Parser<'a, I>, property: &str)
+	where
+		I: Iterator<Item = Cursor> + Clone,
+	{
+		let horizontal_only = HORIZONTAL_POSITION_PROPERTIES.contains(&property);
+		let has_depth = property == "transform-origin" || property == "perspective-origin";
+		loop {
+			let mut layer = vec![];
+			while p.peek::<PositionSingleValue>() {
+				let Ok(value) = p.parse::<PositionSingleValue>() else { break };
+				layer.push(value);
+			}
+			let horizontal = if horizontal_only {
+				layer.first()
+			} else if layer.len() > 3 || (layer.len() == 3 && !has_depth) {
+				// Three and four value positions always use edge keywords, which are swapped instead.
+				None
+			} else if matches!(layer.first(), Some(PositionSingleValue::Top(_) | PositionSingleValue::Bottom(_))) {
+				layer.get(1)
+			} else {
+				layer.first()
+			};
+			if let Some(PositionSingleValue::LengthPercentage(offset)) = horizontal {
+				self.mirror(offset);
+			}
+			while !p.at_end() && !p.peek::<T![,]>() {
+				if p.parse::<ComponentValue>().is_err() {
+					return;
+				}
+			}
+			if !matches!(p.parse_if_peek::<T![,]>(), Ok(Some(_))) {
+				return;
+			}
+		}
+	}
+
+	/// Parses a value which did not parse into typed nodes (such as `-webkit-transform`, `transform-origin` or a value
+	/// with a `var()`) from its cursors, so that its typed nodes can be flipped.
+	fn flip_untyped(&mut self, property: &str, cursors: &[Cursor]) {
+		let bump = self.transformer.bump();
+		let mut p = Parser::new(bump, self.transformer.source_text, cursors.iter().copied());
+		if property == "transform" {
+			let checkpoint = p.checkpoint();
+			match p.parse::<TransformList>() {
+				Ok(list) if p.at_end() => list.accept(self),
+				_ => {
+					// A function with a `var()` within it can only be parsed into the parts of a [Computed] value.
+					p.rewind(checkpoint);
+					if let Ok(computed) = p.parse::<Computed>() {
+						for part in computed.0.iter() {
+							if let ComputedPart::Function(function) = part {
+								self.negate_computed_translation(function);
+							}
+						}
+					}
+				}
+			}
+		} else if property == "translate" {
+			if let Ok(offset) = p.parse::<LengthPercentage>() {
+				self.negate_length_percentage(&offset);
+			}
+		} else if POSITION_PROPERTIES.contains(&property) || HORIZONTAL_POSITION_PROPERTIES.contains(&property) {
+			self.mirror_position_layers(&mut p, property);
+		}
+	}
+
+	/// The cursors to write in place of the given cursors, with each keyword swapped.
+	fn swap_keywords(&self, cursors: &[Cursor], names: &[Span]) -> BumpVec<'a, SourceCursor<'a>> {
+		let source_text = self.transformer.source_text;
+		let mut swapped = BumpVec::new_in(self.transformer.bump());
+		for (i, cursor) in cursors.iter().enumerate() {
+			if i > 0 && cursors[i - 1].end_offset() != cursor.offset() {
+				swapped.push(SourceCursor::SPACE);
+			}
+			match self.swapped_keyword(*cursor, names) {
+				Some(keyword) => swapped.extend(self.transformer.parse_value::<ComponentValues>(keyword)),
+				None => swapped.push(SourceCursor::from(*cursor, cursor.str_slice(source_text))),
+			}
+		}
+		swapped
+	}
+
+	fn swapped_keyword(&self, cursor: Cursor, names: &[Span]) -> Option<&'static str> {
+		if cursor != Kind::Ident || names.iter().any(|name| name.start() == cursor.offset()) {
+			return None;
+		}
+		swap_keyword(cursor.str_slice(self.transformer.source_text))
+	}
+}
+
+impl<'a, 'ctx, N, F> Visit for FlipVisitor<'a, 'ctx, N, F>
+where
+	N: NodeWithMetadata<CssMetadata>,
+	F: TransformerFeatures<CssMetadata, N>,
+{
+	fn visit_queryable_node<T: QueryableNode>(&mut self, node: &T) {
+		// The stylesheet starts wherever its first rule does, but a directive only applies to that rule.
+		if node.node_id() == NodeId::StyleSheet {
+			return;
+		}
+		let span = node.to_span();
+		if self.ignored.contains(&span.start()) && span.end() > self.ignored_until {
+			self.ignored_until = span.end();
+		}
+	}
+
+	fn visit_declaration<'b, T: DeclarationValue<'b, CssMetadata> + QueryableNode>(
+		&mut self,
+		decl: &Declaration<'b, T, CssMetadata>,
+	) {
+		if decl.to_span().start() < self.ignored_until || decl.value.is_custom() {
+			return;
+		}
+		let source_text = self.transformer.source_text;
+		let name = Cursor::from(decl.name).str_slice(source_text);
+		let flipped_name = flip_property_name(name);
+		if flipped_name != name {
+			self.transformer.replace_parsed::<ComponentValues>(decl.name, &flipped_name);
+		}
+		let property = name.to_ascii_lowercase();
+		let property = unprefixed(&property).to_string();
+		self.declaration = Some(PendingDeclaration { property, names: vec![], flips: vec![] });
+	}
+
+	fn visit_custom_ident(&mut self, ident: &CustomIdent) {
+		self.add_name((*ident).into());
+	}
+
+	fn visit_dashed_ident(&mut self, ident: &DashedIdent) {
+		self.add_name((*ident).into());
+	}
+
+	fn visit_keyframes_name(&mut self, name: &KeyframesName) {
+		self.add_name((*name).into());
+	}
+
+	fn visit_grid_line(&mut self, line: &GridLine) {
+		match line {
+			GridLine::Area(ident) => self.add_name((*ident).into()),
+			GridLine::Span(_, _, Some(ident)) | GridLine::Placement(_, Some(ident)) => self.add_name((*ident).into()),
+			_ => {}
+		}
+	}
+
+	fn visit_bg_position(&mut self, position: &BgPosition) {
+		// Three and four value positions always use edge keywords, which are swapped instead.
+		if let BgPosition::SingleValue(PositionSingleValue::LengthPercentage(offset))
+		| BgPosition::TwoValue(PositionHorizontal::LengthPercentage(offset), _) = position
+		{
+			self.mirror(offset);
+		}
+	}
+
+	fn visit_position(&mut self, position: &Position) {
+		if let Position::SingleValue(PositionSingleValue::LengthPercentage(offset))
+		| Position::TwoValue(PositionHorizontal::LengthPercentage(offset), _) = position
+		{
+			self.mirror(offset);
+		}
+	}
+
+	fn visit_translate_function(&mut self, function: &TranslateFunction) {
+		self.negate_length_percentage(&function.x);
+	}
+
+	fn visit_translatex_function(&mut self, function: &TranslatexFunction) {
+		self.negate_length_percentage(&function.params);
+	}
+
+	fn visit_translate3d_function(&mut self, function: &Translate3dFunction) {
+		self.negate_length_percentage(&function.params.0);
+	}
+
+	fn exit_declaration<'b, T: DeclarationValue<'b, CssMetadata> + QueryableNode>(
+		&mut self,
+		decl: &Declaration<'b, T, CssMetadata>,
+	) {
+		let Some(property) = self.declaration.as_ref().map(|declaration| declaration.property.clone()) else {
+			return;
+		};
+		let mut cursors = BumpVec::new_in(self.transformer.bump());
+		decl.value.to_cursors(&mut cursors);
+		if decl.value.is_unknown()
+			|| decl.value.needs_computing()
+			|| HORIZONTAL_POSITION_PROPERTIES.contains(&property.as_str())
+		{
+			self.flip_untyped(&property, &cursors);
+		}
+		let Some(PendingDeclaration { names, flips, .. }) = self.declaration.take() else {
+			return;
+		};
+		cursors.retain(|cursor| *cursor != KindSet::TRIVIA);
+		let components = components(&cursors);
+		let arrangement = if BOX_SHORTHANDS.contains(&property.as_str()) {
+			if components.len() == 4 { vec![vec![0], vec![3], vec![2], vec![1]] } else { vec![] }
+		} else if property == "border-radius" {
+			// Each side of the `/` is flipped on its own.
+			let mut arrangement = vec![];
+			for (i, side) in components.split(|c| c.len() == 1 && c[0] == Kind::Delim && c[0] == '/').enumerate() {
+				if i > 0 {
+					arrangement.push(vec![arrangement.len()]);
+				}
+				let start = arrangement.len();
+				for radii in flip_radii(side.len()) {
+					arrangement.push(radii.into_iter().map(|r| start + r).collect());
+				}
+			}
+			arrangement
+		} else {
+			vec![]
+		};
+		let mut moved = vec![];
+		for (i, sources) in arrangement.iter().enumerate() {
+			if sources[..] == [i] {
+				continue;
+			}
+			let span = component_span(&components[i]);
+			let mut replacement = BumpVec::new_in(self.transformer.bump());
+			for (j, source) in sources.iter().enumerate() {
+				if j > 0 {
+					replacement.push(SourceCursor::SPACE);
+				}
+				replacement.extend(self.swap_keywords(&components[*source], &names));
+			}
+			self.transformer.replace(span, replacement);
+			moved.push(span);
+		}
+		for (span, replacement) in &flips {
+			if !moved.iter().any(|m| m.contains(*span)) {
+				self.transformer.replace_parsed::<ComponentValues>(*span, replacement);
+			}
+		}
+		let replaced = moved.iter().chain(flips.iter().map(|(span, _)| span)).copied().collect::<Vec<_>>();
+		for cursor in cursors.iter() {
+			if replaced.iter().any(|span| span.contains(cursor.to_span())) {
+				continue;
+			}
+			if let Some(keyword) = self.swapped_keyword(*cursor, &names) {
+				self.transformer.replace_parsed::<ComponentValues>(*cursor, keyword);
+			}
+		}
+	}
+}
+
+fn is_ignore_directive(comment: &str) -> bool {
+	let comment = comment.trim_start_matches("/*").trim_end_matches("*/");
+	comment
+		.split_once(':')
+		.is_some_and(|(tool, directive)| tool.trim() == "csskit" && directive.trim() == IGNORE_DIRECTIVE)
+}
+
+fn swap_keyword(keyword: &str) -> Option<&'static str> {
+	KEYWORDS.iter().find_map(|(a, b)| {
+		if keyword.eq_ignore_ascii_case(a) {
+			Some(*b)
+		} else if keyword.eq_ignore_ascii_case(b) {
+			Some(*a)
+		} else {
+			None
+		}
+	})
+}
+
+/// Strips any vendor prefix from a property name, so `-webkit-transform` becomes `transform`.
+fn unprefixed(name: &str) -> &str {
+	name.strip_prefix('-').and_then(|name| name.split_once('-')).map_or(name, |(_, name)| name)
+}
+
+/// Swaps each `left` and `right` part of a property name, so `border-top-left-radius` becomes
+/// `border-top-right-radius`.
+fn flip_property_name(name: &str) -> String {
+	name.split('-')
+		.map(|part| match part.to_ascii_lowercase().as_str() {
+			"left" => "right",
+			"right" => "left",
+			_ => part,
+		})
+		.collect::<Vec<_>>()
+		.join("-")
+}
+
+/// Groups the cursors of a value into its top level components (such as `1px`, `calc(1px + 2px)` or `/`), which are
+/// separated in the source by whitespace, or are a top level `,` or `/`.
+fn components(cursors: &[Cursor]) -> Vec<Vec<Cursor>> {
+	let mut components: Vec<Vec<Cursor>> = vec![];
+	let mut depth = 0;
+	let mut last: Option<Cursor> = None;
+	for cursor in cursors {
+		let is_delimiter = depth == 0 && (*cursor == Kind::Comma || (*cursor == Kind::Delim && *cursor == '/'));
+		let after_delimiter = last.is_some_and(|last| last == Kind::Comma || (last == Kind::Delim && last == '/'));
+		let separated = last.is_none_or(|last| last.end_offset() != cursor.offset());
+		match components.last_mut() {
+			Some(component) if depth > 0 || !(separated || is_delimiter || after_delimiter) => component.push(*cursor),
+			_ => components.push(vec![*cursor]),
+		}
+		match cursor.token().kind() {
+			Kind::Function | Kind::LeftParen | Kind::LeftSquare => depth += 1,
+			Kind::RightParen | Kind::RightSquare => depth -= 1,
+			_ => {}
+		}
+		last = Some(*cursor);
+	}
+	components
+}
+
+fn component_span(component: &[Cursor]) -> Span {
+	component[0].to_span() + component[component.len() - 1].to_span()
+}
+
+/// Mirrors `border-radius` values given in `top-left top-right bottom-right bottom-left` order, returning the radii
+/// to write at each position.
+fn flip_radii(len: usize) -> Vec<Vec<usize>> {
+	match len {
+		2 => vec![vec![1], vec![0]],
+		// `a b c` is shorthand for `a b c b`, which mirrors to `b a b c`.
+		3 => vec![vec![1], vec![0], vec![1, 2]],
+		4 => vec![vec![1], vec![0], vec![3], vec![2]],
+		_ => (0..len).map(|i| vec![i]).collect(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssBuildFeature;
+	use crate::test_helpers::{assert_no_transform, assert_transform};
+	use bumpalo::Bump;
+	use css_ast::StyleSheet;
+	use css_parse::{CursorCompactWriteSink, CursorOverlaySink, Parser, ToCursors};
+
+	#[test]
+	fn flips_property_names() {
+		assert_transform!(
+			plugin ConvertToRtl::default(),
+			CssAtomSet,
+			StyleSheet,
+			"a { margin-left: 1px; border-top-right-radius: 2px; left: 0; }",
+			"a { margin-right: 1px; border-top-left-radius: 2px; right: 0; }"
+		);
+	}
+
+	#[test]
+	fn swaps_keywords() {
+		assert_transform!(
+			plugin ConvertToRtl::default(),
+			CssAtomSet,
+			StyleSheet,
+			"a { float: left; text-align: right; direction: ltr; cursor: ne-resize; }",
+			"a { float: right; text-align: left; direction: rtl; cursor: nw-resize; }"
+		);
+		assert_transform!(
+			plugin ConvertToRtl::default(),
+			CssAtomSet,
+			StyleSheet,
+			"a { background: linear-gradient(to left, red, blue); }",
+			"a { background: linear-gradient(to right, red, blue); }"
+		);
+	}
+
+	#[test]
+	fn mirrors_box_shorthands() {
+		assert_transform!(
+			plugin ConvertToRtl::default(),
+			CssAtomSet,
+			StyleSheet,
+			"a { margin: 1px 2px 3px 4px; padding: 1px 2px 3px; border-color: red green blue black; }",
+			"a { margin: 1px 4px 3px 2px; padding: 1px 2px 3px; border-color: red black blue green; }"
+		);
+	}
+
+	#[test]
+	fn mirrors_border_radius() {
+		assert_transform!(
+			plugin ConvertToRtl::default(),
+			CssAtomSet,
+			StyleSheet,
+			"a { border-radius: 1px 2px 3px 4px; } b { border-radius: 1px 2px 3px; } c { border-radius: 1px; }",
+			"a { border-radius: 2px 1px 4px 3px; } b { border-radius: 2px 1px 2px 3px; } c { border-radius: 1px; }"
+		);
+		assert_transform!(
+			plugin ConvertToRtl::default(),
+			CssAtomSet,
+			StyleSheet,
+			"a { border-radius: 1px 2px / 3px 4px 5px 6px; }",
+			"a { border-radius: 2px 1px / 4px 3px 6px 5px; }"
+		);
+	}
+
+	#[test]
+	fn negates_translations() {
+		assert_transform!(
+			plugin ConvertToRtl::default(),
+			CssAtomSet,
+			StyleSheet,
+			"a { transform: translateX(10px) rotate(5deg); } b { transform: translate(-50%, 2px) translate3d(0, 1px, 2px); }",
+			"a { transform: translateX(-10px) rotate(5deg); } b { transform: translate(50%, 2px) translate3d(0, 1px, 2px); }"
+		);
+		assert_transform!(
+			plugin ConvertToRtl::default(),
+			CssAtomSet,
+			StyleSheet,
+			"a { transform: translateX(var(--x)); translate: 10px 20px; }",
+			"a { transform: translateX(calc(-1*var(--x))); translate: -10px 20px; }"
+		);
+	}
+
+	#[test]
+	fn negates_prefixed_translations() {
+		assert_transform!(
+			plugin ConvertToRtl::default(),
+			CssAtomSet,
+			StyleSheet,
+			"a { -webkit-transform: translateX(10px); -ms-transform: translate(-5px, 1px) scale(2); }",
+			"a { -webkit-transform: translateX(-10px); -ms-transform: translate(5px, 1px) scale(2); }"
+		);
+	}
+
+	#[test]
+	fn mirrors_positions() {
+		assert_transform!(
+			plugin ConvertToRtl::default(),
+			CssAtomSet,
+			StyleSheet,
+			"a { background-position: 20% 50%, left 10px; object-position: 25% top; }",
+			"a { background-position: 80% 50%, right 10px; object-position: 75% top; }"
+		);
+		assert_transform!(
+			plugin ConvertToRtl::default(),
+			CssAtomSet,
+			StyleSheet,
+			"a { background-position: right 10% top 5%; background-position-x: 30%, 100%; transform-origin: 0% 10% 2px; }",
+			"a { background-position: left 10% top 5%; background-position-x: 70%, 0%; transform-origin: 100% 10% 2px; }"
+		);
+	}
+
+	#[test]
+	fn mirrors_background_shorthand_positions() {
+		assert_transform!(
+			plugin ConvertToRtl::default(),
+			CssAtomSet,
+			StyleSheet,
+			"a { background: url(a.png) no-repeat 20% 50%; } b { background: url(a.png) 30%/cover, red; }",
+			"a { background: url(a.png) no-repeat 80% 50%; } b { background: url(a.png) 70%/cover, red; }"
+		);
+	}
+
+	#[test]
+	fn keeps_ignored_nodes() {
+		assert_transform!(
+			plugin ConvertToRtl::default(),
+			CssAtomSet,
+			StyleSheet,
+			"/* csskit: rtl-ignore */ a { float: left; } b { /* csskit: rtl-ignore */ float: left; clear: left; }",
+			"a { float: left; } b { float: left; clear: right; }"
+		);
+		assert_no_transform!(
+			plugin ConvertToRtl::default(),
+			CssAtomSet,
+			StyleSheet,
+			"/*csskit:rtl-ignore*/ @media (min-width: 1px) { a { float: left; } }"
+		);
+	}
+
+	#[test]
+	fn keeps_custom_properties() {
+		assert_no_transform!(plugin ConvertToRtl::default(), CssAtomSet, StyleSheet, "a { --side: left; }");
+	}
+
+	#[test]
+	fn keeps_names() {
+		assert_transform!(
+			plugin ConvertToRtl::default(),
+			CssAtomSet,
+			StyleSheet,
+			"a { animation-name: left; animation: 1s right; grid-area: right / span 2 left; float: left; }",
+			"a { animation-name: left; animation: 1s right; grid-area: right / span 2 left; float: right; }"
+		);
+	}
+
+	#[test]
+	fn flips_once_until_stable() {
+		let source_text = "a { float: left; margin-left: 1px; }";
+		let bump = Bump::default();
+		let mut transformer = Transformer::new_in(&bump, CssBuildFeature::none(), &CssAtomSet::ATOMS, source_text)
+			.with_plugin(ConvertToRtl::default());
+		let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+		let result = Parser::new(&bump, source_text, lexer).parse_entirely::<StyleSheet>();
+		let result = transformer.transform_until_stable(result, 4).unwrap();
+		let overlays = transformer.overlays();
+		let mut output = String::new();
+		let mut stream = CursorOverlaySink::new(
+			result.source_text,
+			&overlays,
+			CursorCompactWriteSink::new(result.source_text, &mut output),
+		);
+		result.to_cursors(&mut stream);
+		assert_eq!(output, "a{float:right;margin-right:1px}");
+	}
+}
@@ -0,0 +1,42 @@
+use crate::{
+	ColorConsolidation, ColorNotationConversion, ConsolidateColors, ConvertColorNotation, ConvertLengthUnits,
+	DeclarationOrder, FlattenLayers, LengthUnitConversion, SortDeclarations, transformer,
+};
+use bitmask_enum::bitmask;
+use css_ast::{CssMetadata, Visitable};
+
+transformer!(
+	/// Runtime feature flags for transforms which change what the stylesheet means or how it reads, such as converting
+	/// units or sorting declarations. These are opted in to one at a time, and are never part of minification.
+	pub enum CssBuildFeature[CssMetadata, Visitable] with CssBuildOptions {
+		/// Enables the [ConsolidateColors] transformer.
+		ConsolidateColors,
+		/// Enables the [ConvertColorNotation] transformer.
+		ConvertColorNotation,
+		/// Enables the [ConvertLengthUnits] transformer.
+		ConvertLengthUnits,
+		/// Enables the [FlattenLayers] transformer.
+		FlattenLayers,
+		/// Enables the [SortDeclarations] transformer.
+		SortDeclarations,
+	}
+);
+
+impl Default for CssBuildFeature {
+	fn default() -> Self {
+		Self::none()
+	}
+}
+
+/// Configuration for the transforms enabled by [CssBuildFeature].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CssBuildOptions {
+	/// Configures the [ConvertLengthUnits] transformer.
+	pub length_units: LengthUnitConversion,
+	/// Configures the [ConsolidateColors] transformer.
+	pub colors: ColorConsolidation,
+	/// Configures the [ConvertColorNotation] transformer.
+	pub color_notation: ColorNotationConversion,
+	/// Configures the [SortDeclarations] transformer.
+	pub declaration_order: DeclarationOrder,
+}
@@ -1,12 +1,24 @@
-use crate::{ReduceColors, ReduceLengths, ReduceTimeUnits, transformer};
+use crate::{
+	InlineCustomProperties, PruneConditionalRules, ReduceCalc, ReduceColors, ReduceFilters, ReduceLengths,
+	ReduceTimeUnits, transformer,
+};
 use bitmask_enum::bitmask;
 use css_ast::{CssMetadata, Visitable};
+use css_feature_data::NamedBrowserVersion;
 
 transformer!(
 	/// Runtime feature flags for the CSS minifier, enabling individual transforms.
-	pub enum CssMinifierFeature[CssMetadata, Visitable] {
+	pub enum CssMinifierFeature[CssMetadata, Visitable] with CssMinifierOptions {
+		/// Enables the [InlineCustomProperties] transformer.
+		InlineCustomProperties,
+		/// Enables the [PruneConditionalRules] transformer.
+		PruneConditionalRules,
+		/// Enables the [ReduceCalc] transformer.
+		ReduceCalc,
 		/// Enables the [ReduceColors] transformer.
 		ReduceColors,
+		/// Enables the [ReduceFilters] transformer.
+		ReduceFilters,
 		/// Enables the [ReduceLengths] transformer.
 		ReduceLengths,
 		/// Enables the [ReduceTimeUnits] transformer.
@@ -20,10 +32,27 @@ impl Default for CssMinifierFeature {
 	}
 }
 
+impl CssMinifierFeature {
+	/// Every transform which is safe to apply to any stylesheet. This excludes
+	/// [CssMinifierFeature::InlineCustomProperties], which assumes custom properties are never set outside of the
+	/// stylesheet.
+	pub fn safe() -> Self {
+		Self::all_bits() & !Self::InlineCustomProperties
+	}
+}
+
+/// Configuration for the transforms enabled by [CssMinifierFeature].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CssMinifierOptions {
+	/// The browsers which [PruneConditionalRules] decides `@supports` conditions for. When empty, `@supports` rules are
+	/// left alone.
+	pub targets: Vec<NamedBrowserVersion>,
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::Transformer;
+	use crate::{TransformStats, Transformer};
 	use bumpalo::Bump;
 	use css_ast::{CssAtomSet, StyleSheet};
 	use css_lexer::Lexer;
@@ -37,11 +66,11 @@ mod tests {
 		let mut result = parser.parse_entirely::<StyleSheet>();
 		let mut output = String::new();
 		if let Some(ref mut node) = result.output {
-			transformer.transform(node);
+			transformer.transform(node).unwrap();
 			let overlays = transformer.overlays();
 			let changed = transformer.has_changed();
 			let mut overlay_stream =
-				CursorOverlaySink::new(source_text, &*overlays, CursorCompactWriteSink::new(source_text, &mut output));
+				CursorOverlaySink::new(source_text, &overlays, CursorCompactWriteSink::new(source_text, &mut output));
 			result.output.to_cursors(&mut overlay_stream);
 			(output.clone(), changed)
 		} else {
@@ -66,10 +95,31 @@ mod tests {
 		assert!(output.contains("width:0px"));
 	}
 
+	#[test]
+	fn test_stats_attribute_edits_to_features() {
+		let source_text = "body { width: 0px; height: 0px; color: #ffffff; transition-delay: 0ms; }";
+		let bump = Bump::default();
+		let mut transformer =
+			Transformer::new_in(&bump, CssMinifierFeature::all_bits(), &CssAtomSet::ATOMS, source_text);
+		let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+		let mut parser = Parser::new(&bump, source_text, lexer);
+		let mut result = parser.parse_entirely::<StyleSheet>();
+		transformer.transform(result.output.as_mut().unwrap()).unwrap();
+		let stats = transformer.stats();
+		assert_eq!(
+			stats.as_slice(),
+			&[
+				(CssMinifierFeature::ReduceColors, TransformStats { edits: 1, bytes_saved: 3 }),
+				(CssMinifierFeature::ReduceLengths, TransformStats { edits: 2, bytes_saved: 4 }),
+				(CssMinifierFeature::ReduceTimeUnits, TransformStats { edits: 1, bytes_saved: 1 }),
+			]
+		);
+	}
+
 	#[test]
 	fn test_changed_flag_accuracy() {
 		let input = "body { width: 10px; }";
-		let (_, changed) = minify(input, CssMinifierFeature::all_bits());
+		let (_, changed) = minify(input, CssMinifierFeature::safe());
 		assert!(!changed, "Should report no changes when no optimizations apply");
 	}
 }
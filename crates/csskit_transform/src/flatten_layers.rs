@@ -0,0 +1,546 @@
+use crate::prelude::*;
+use css_ast::{
+	CompoundSelector, CssAtomSet, Id, LayerRule, QueryableNode, Rule, SelectorComponent, StyleRule, StyleSheet,
+	Visitable,
+};
+use css_lexer::{Kind, Lexer, SourceOffset};
+use css_parse::{CursorToSourceCursorSink, Declaration, DeclarationValue, Span, ToCursors};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Raises the specificity of a selector by one ID without changing what it matches (unless an element has the ID `#`).
+const ID_BOOST: &str = ":not(#\\#)";
+
+/// Something about a stylesheet's cascade layers which [FlattenLayers] cannot reproduce, see [CascadeLayers::warnings].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerWarning {
+	pub span: Span,
+	pub kind: LayerWarningKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayerWarningKind {
+	/// An `@import` into a layer, whose rules cannot be reordered without inlining the imported stylesheet.
+	LayeredImport,
+	/// A `@layer` within a rule other than another `@layer`, such as `@media`, whose rules cannot be moved out of it.
+	NestedLayer,
+	/// An `!important` declaration in a layer, for a property which is also `!important` in another layer (or outside
+	/// of any layer). Layers reverse the precedence of `!important` declarations, which specificity cannot emulate.
+	Important { property: String, layer: String },
+	/// A `revert-layer` keyword, which behaves like `revert` once there are no layers to revert to.
+	RevertLayer,
+}
+
+impl LayerWarning {
+	/// Returns true if the stylesheet cannot be flattened at all, rather than being flattened imperfectly.
+	pub fn prevents_flattening(&self) -> bool {
+		matches!(self.kind, LayerWarningKind::LayeredImport | LayerWarningKind::NestedLayer)
+	}
+}
+
+impl fmt::Display for LayerWarning {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match &self.kind {
+			LayerWarningKind::LayeredImport => {
+				write!(f, "`@import` into a layer cannot be flattened, as the imported stylesheet is not inlined")
+			}
+			LayerWarningKind::NestedLayer => write!(f, "`@layer` within another rule cannot be flattened"),
+			LayerWarningKind::Important { property, layer } => write!(
+				f,
+				"`{property}: ... !important` in layer `{layer}` cannot be emulated, and may no longer take precedence over \
+				 `!important` declarations in later layers"
+			),
+			LayerWarningKind::RevertLayer => {
+				write!(f, "`revert-layer` cannot be emulated, and will behave like `revert` once layers are flattened")
+			}
+		}
+	}
+}
+
+#[derive(Debug)]
+struct LayerNode {
+	/// The last part of the layer's name, or None for an anonymous layer.
+	part: Option<String>,
+	/// The full name of the layer, such as `base.reset`.
+	name: String,
+	children: Vec<usize>,
+}
+
+/// A top level rule (or a rule directly within a `@layer` block), and the layer it belongs to.
+#[derive(Debug, Clone, Copy)]
+struct LayeredRule {
+	span: Span,
+	layer: usize,
+}
+
+/// The cascade layers of a stylesheet, which [FlattenLayers] uses to rewrite the stylesheet without them.
+///
+/// Layers are ordered as the cascade orders them: each layer's sublayers come before the layer itself, and rules
+/// outside of any layer come last. As specificity decides between declarations before the order they appear in does,
+/// moving each rule into this order is not enough; the selectors of each layer are also given enough extra specificity
+/// to outweigh every selector in the layers before it.
+#[derive(Debug)]
+pub struct CascadeLayers {
+	/// The layer tree; the first node is the root, which holds the rules outside of any layer.
+	nodes: Vec<LayerNode>,
+	/// Rules which must stay at the start of the stylesheet, such as `@charset` and `@import`.
+	leading: Vec<Span>,
+	rules: Vec<LayeredRule>,
+	/// The top level style rules, with where to raise the specificity of each of their selectors.
+	style_rules: Vec<(Span, Vec<SourceOffset>)>,
+	/// The most IDs which any top level style rule (including the rules nested within it) has.
+	max_ids: usize,
+	pub warnings: Vec<LayerWarning>,
+}
+
+impl CascadeLayers {
+	pub fn new(sheet: &StyleSheet, source_text: &str) -> Self {
+		let root = LayerNode { part: None, name: String::new(), children: vec![] };
+		let mut layers = Self {
+			nodes: vec![root],
+			leading: vec![],
+			rules: vec![],
+			style_rules: vec![],
+			max_ids: 0,
+			warnings: vec![],
+		};
+		let mut layer_rules = vec![];
+		layers.collect(&sheet.rules, 0, source_text, &mut layer_rules);
+
+		let mut collector = LayerCollector { source_text, ..Default::default() };
+		sheet.accept(&mut collector);
+		for span in collector.layer_rules {
+			if !layer_rules.contains(&span) {
+				layers.warnings.push(LayerWarning { span, kind: LayerWarningKind::NestedLayer });
+			}
+		}
+		layers.max_ids = collector.style_rules.iter().map(|rule| rule.ids).max().unwrap_or(0);
+		layers.style_rules = collector.style_rules.into_iter().map(|rule| (rule.span, rule.boosts)).collect();
+
+		// Layers reverse the precedence of `!important`, so it can only be emulated where a property is `!important`
+		// in a single layer.
+		let mut important_layers: HashMap<&str, Vec<usize>> = HashMap::new();
+		let important = collector
+			.important
+			.iter()
+			.filter_map(|(property, span)| Some((property.as_str(), *span, layers.layer_of(span.start())?)))
+			.collect::<Vec<_>>();
+		for (property, _, layer) in &important {
+			let layers = important_layers.entry(property).or_default();
+			if !layers.contains(layer) {
+				layers.push(*layer);
+			}
+		}
+		for (property, span, layer) in important {
+			if layer != 0 && important_layers[property].len() > 1 {
+				let kind = LayerWarningKind::Important {
+					property: property.to_string(),
+					layer: layers.nodes[layer].name.clone(),
+				};
+				layers.warnings.push(LayerWarning { span, kind });
+			}
+		}
+
+		for cursor in Lexer::new(&CssAtomSet::ATOMS, source_text) {
+			if cursor == Kind::Ident && cursor.str_slice(source_text).eq_ignore_ascii_case("revert-layer") {
+				layers.warnings.push(LayerWarning { span: cursor.to_span(), kind: LayerWarningKind::RevertLayer });
+			}
+		}
+		layers.warnings.sort_by_key(|warning| warning.span.start());
+		layers
+	}
+
+	/// Returns the full name of every layer in the stylesheet, in the order the cascade gives them precedence (the
+	/// lowest first).
+	pub fn order(&self) -> Vec<&str> {
+		self.ranked().into_iter().filter(|layer| *layer != 0).map(|layer| self.nodes[layer].name.as_str()).collect()
+	}
+
+	/// Returns the stylesheet's rules without any layers, in the order of their layers and with how much to raise the
+	/// specificity of their selectors so that the cascade has the same outcome. Returns None if there are no layers, or
+	/// if any [LayerWarning::prevents_flattening()].
+	pub fn flatten(&self) -> Option<Vec<FlattenedRule>> {
+		if self.nodes.len() == 1 || self.warnings.iter().any(LayerWarning::prevents_flattening) {
+			return None;
+		}
+		let ranked = self.ranked();
+		let rank = |layer: usize| ranked.iter().position(|ranked| *ranked == layer).unwrap_or(0);
+
+		// Only layers which have style rules need to outweigh the layers before them.
+		let mut styled =
+			self.style_rules.iter().filter_map(|(span, _)| self.layer_of(span.start())).map(rank).collect::<Vec<_>>();
+		styled.sort_unstable();
+		styled.dedup();
+		let ids =
+			|layer: usize| styled.iter().position(|styled| *styled == rank(layer)).unwrap_or(0) * (self.max_ids + 1);
+
+		let mut rules = self.rules.clone();
+		rules.sort_by_key(|rule| rank(rule.layer));
+		let leading = self.leading.iter().map(|span| FlattenedRule { span: *span, boosts: vec![], ids: 0 });
+		let rules = rules.into_iter().map(|rule| {
+			let boosts = self
+				.style_rules
+				.iter()
+				.filter(|(span, _)| rule.span.contains(*span))
+				.flat_map(|(_, boosts)| boosts.iter().copied())
+				.collect();
+			FlattenedRule { span: rule.span, boosts, ids: ids(rule.layer) }
+		});
+		Some(leading.chain(rules).collect())
+	}
+
+	fn collect(&mut self, rules: &[Rule], layer: usize, source_text: &str, layer_rules: &mut Vec<Span>) {
+		for rule in rules {
+			match rule {
+				Rule::Layer(rule) => {
+					layer_rules.push(rule.to_span());
+					self.collect_layer(rule, layer, source_text, layer_rules);
+				}
+				Rule::Import(import) if import.layer.is_some() => {
+					self.warnings.push(LayerWarning { span: import.to_span(), kind: LayerWarningKind::LayeredImport });
+				}
+				Rule::Charset(_) | Rule::Import(_) | Rule::Namespace(_) if layer == 0 && self.rules.is_empty() => {
+					self.leading.push(rule.to_span());
+				}
+				_ => self.rules.push(LayeredRule { span: rule.to_span(), layer }),
+			}
+		}
+	}
+
+	fn collect_layer(&mut self, rule: &LayerRule, parent: usize, source_text: &str, layer_rules: &mut Vec<Span>) {
+		let mut names = (&rule.prelude.0).into_iter().map(|(name, _)| {
+			let span = name.to_span();
+			source_text[usize::from(span.start())..usize::from(span.end())]
+				.chars()
+				.filter(|c| !c.is_whitespace())
+				.collect::<String>()
+		});
+		let Some(block) = &rule.block else {
+			for name in names {
+				self.layer(parent, &name);
+			}
+			return;
+		};
+		let layer = match names.next() {
+			Some(name) => self.layer(parent, &name),
+			None => self.child(parent, None),
+		};
+		self.collect(&block.0.rules, layer, source_text, layer_rules);
+	}
+
+	/// Returns the layer with the given (possibly dotted) name within the parent layer, creating it if needed.
+	fn layer(&mut self, parent: usize, name: &str) -> usize {
+		name.split('.').fold(parent, |parent, part| self.child(parent, Some(part)))
+	}
+
+	fn child(&mut self, parent: usize, part: Option<&str>) -> usize {
+		if let Some(part) = part
+			&& let Some(child) =
+				self.nodes[parent].children.iter().find(|child| self.nodes[**child].part.as_deref() == Some(part))
+		{
+			return *child;
+		}
+		let part_name = part.unwrap_or("<anonymous>");
+		let name = if parent == 0 { part_name.to_string() } else { format!("{}.{part_name}", self.nodes[parent].name) };
+		self.nodes.push(LayerNode { part: part.map(str::to_string), name, children: vec![] });
+		let child = self.nodes.len() - 1;
+		self.nodes[parent].children.push(child);
+		child
+	}
+
+	/// Returns every layer, in the order the cascade gives them precedence (the lowest first).
+	fn ranked(&self) -> Vec<usize> {
+		fn visit(nodes: &[LayerNode], layer: usize, ranked: &mut Vec<usize>) {
+			for child in &nodes[layer].children {
+				visit(nodes, *child, ranked);
+			}
+			ranked.push(layer);
+		}
+		let mut ranked = vec![];
+		visit(&self.nodes, 0, &mut ranked);
+		ranked
+	}
+
+	/// Returns the layer of the rule at the given offset, or None if it is one of the leading rules.
+	fn layer_of(&self, offset: SourceOffset) -> Option<usize> {
+		self.rules.iter().find(|rule| rule.span.start() <= offset && offset < rule.span.end()).map(|rule| rule.layer)
+	}
+}
+
+/// A rule of a flattened stylesheet, see [CascadeLayers::flatten()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlattenedRule {
+	pub span: Span,
+	/// Where to raise the specificity of each selector within the rule.
+	pub boosts: Vec<SourceOffset>,
+	/// How many IDs to raise the specificity of each selector by.
+	pub ids: usize,
+}
+
+#[derive(Default)]
+struct OutermostStyleRule {
+	span: Span,
+	/// Where to insert [ID_BOOST] into each selector: before any pseudo-element, which must come last.
+	boosts: Vec<SourceOffset>,
+	/// How many IDs the rule's selectors have, including those of nested rules.
+	ids: usize,
+}
+
+#[derive(Default)]
+struct LayerCollector<'a> {
+	source_text: &'a str,
+	layer_rules: Vec<Span>,
+	style_rules: Vec<OutermostStyleRule>,
+	/// The lowercased property and span of each `!important` declaration in a style rule.
+	important: Vec<(String, Span)>,
+	depth: usize,
+}
+
+impl Visit for LayerCollector<'_> {
+	fn visit_layer_rule(&mut self, rule: &LayerRule) {
+		self.layer_rules.push(rule.to_span());
+	}
+
+	fn visit_style_rule(&mut self, rule: &StyleRule) {
+		self.depth += 1;
+		if self.depth > 1 {
+			return;
+		}
+		let boosts = (&rule.rule.prelude.0)
+			.into_iter()
+			.filter_map(|(selector, _)| {
+				let subject = selector
+					.0
+					.iter()
+					.rposition(|component| matches!(component, SelectorComponent::Combinator(_)))
+					.map_or(0, |combinator| combinator + 1);
+				let pseudo_element = selector.0[subject..].iter().find(|component| {
+					matches!(
+						component,
+						SelectorComponent::PseudoElement(_)
+							| SelectorComponent::FunctionalPseudoElement(_)
+							| SelectorComponent::LegacyPseudoElement(_)
+					)
+				});
+				match pseudo_element {
+					Some(pseudo_element) => Some(pseudo_element.to_span().start()),
+					None => selector.0.last().map(|component| component.to_span().end()),
+				}
+			})
+			.collect();
+		self.style_rules.push(OutermostStyleRule { span: rule.to_span(), boosts, ids: 0 });
+	}
+
+	fn exit_style_rule(&mut self, _rule: &StyleRule) {
+		self.depth -= 1;
+	}
+
+	fn visit_id(&mut self, _id: &Id) {
+		if self.depth > 0
+			&& let Some(rule) = self.style_rules.last_mut()
+		{
+			rule.ids += 1;
+		}
+	}
+
+	fn visit_declaration<'b, T: DeclarationValue<'b, CssMetadata> + QueryableNode>(
+		&mut self,
+		decl: &Declaration<'b, T, CssMetadata>,
+	) {
+		if self.depth > 0 && decl.important.is_some() {
+			let property = css_lexer::Cursor::from(decl.name).str_slice(self.source_text).to_ascii_lowercase();
+			self.important.push((property, decl.to_span()));
+		}
+	}
+}
+
+/// Rewrites a stylesheet without cascade layers, for browsers which do not support them, as described by
+/// [CascadeLayers]. Stylesheets with any [LayerWarning] which [LayerWarning::prevents_flattening()] are left as written.
+///
+/// This changes the specificity of selectors, which can change how they compete with other stylesheets, so it is a
+/// [CssBuildFeature] rather than a [CssMinifierFeature][crate::CssMinifierFeature].
+pub struct FlattenLayers<'a, 'ctx, N: Visitable + NodeWithMetadata<CssMetadata>> {
+	pub transformer: &'ctx Transformer<'a, CssMetadata, N, CssBuildFeature>,
+}
+
+impl<'a, 'ctx, N> Transform<'a, 'ctx, CssMetadata, N, CssBuildFeature> for FlattenLayers<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn may_change(features: CssBuildFeature, _node: &N) -> bool {
+		features.contains(CssBuildFeature::FlattenLayers)
+	}
+
+	fn new(transformer: &'ctx Transformer<'a, CssMetadata, N, CssBuildFeature>) -> Self {
+		Self { transformer }
+	}
+}
+
+impl<'a, 'ctx, N> Visit for FlattenLayers<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn visit_style_sheet(&mut self, sheet: &StyleSheet) {
+		let (Some(first), Some(last)) = (sheet.rules.first(), sheet.rules.last()) else {
+			return;
+		};
+		let source_text = self.transformer.source_text;
+		let Some(flattened) = CascadeLayers::new(sheet, source_text).flatten() else {
+			return;
+		};
+		let mut rules = HashMap::new();
+		index_rules(&sheet.rules, &mut rules);
+		let boost = self.transformer.parse_value::<CompoundSelector>(ID_BOOST);
+		let mut cursors = bumpalo::collections::Vec::new_in(self.transformer.bump());
+		for FlattenedRule { span, boosts, ids } in flattened {
+			let mut rule_cursors = bumpalo::collections::Vec::new_in(self.transformer.bump());
+			rules[&span].to_cursors(&mut CursorToSourceCursorSink::new(source_text, &mut rule_cursors));
+			let mut boosts = boosts.into_iter().peekable();
+			for cursor in rule_cursors {
+				while boosts.next_if(|offset| *offset <= cursor.to_span().start()).is_some() {
+					for _ in 0..ids {
+						cursors.extend(boost.iter().copied());
+					}
+				}
+				cursors.push(cursor);
+			}
+		}
+		self.transformer.replace(Span::new(first.to_span().start(), last.to_span().end()), cursors);
+	}
+}
+
+/// Indexes each rule which [CascadeLayers] may move by its span: those at the top level, and those directly within a
+/// `@layer` block.
+fn index_rules<'r, 'a>(rules: &'r [Rule<'a>], index: &mut HashMap<Span, &'r Rule<'a>>) {
+	for rule in rules {
+		if let Rule::Layer(LayerRule { block: Some(block), .. }) = rule {
+			index_rules(&block.0.rules, index);
+		} else {
+			index.insert(rule.to_span(), rule);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::{assert_no_transform, assert_transform};
+	use bumpalo::Bump;
+	use css_parse::Parser;
+
+	fn warnings(source_text: &str) -> Vec<LayerWarningKind> {
+		let bump = Bump::default();
+		let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+		let mut parser = Parser::new(&bump, source_text, lexer);
+		let result = parser.parse_entirely::<StyleSheet>();
+		CascadeLayers::new(result.output.as_ref().unwrap(), source_text)
+			.warnings
+			.into_iter()
+			.map(|warning| warning.kind)
+			.collect()
+	}
+
+	#[test]
+	fn orders_layers() {
+		let source_text =
+			"@layer a { @layer b { .b { color: red } } .a { color: red } } @layer c.d { .d { color: red } }";
+		let bump = Bump::default();
+		let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+		let mut parser = Parser::new(&bump, source_text, lexer);
+		let result = parser.parse_entirely::<StyleSheet>();
+		let layers = CascadeLayers::new(result.output.as_ref().unwrap(), source_text);
+		assert_eq!(layers.order(), vec!["a.b", "a", "c.d", "c"]);
+	}
+
+	#[test]
+	fn reorders_by_layer_order() {
+		assert_transform!(
+			CssBuildFeature::FlattenLayers,
+			CssAtomSet,
+			StyleSheet,
+			"@layer base, components; @layer components { .a { color: red } } @layer base { .b { color: blue } }\n",
+			".b{color:blue}.a:not(#\\#){color:red}"
+		);
+	}
+
+	#[test]
+	fn unlayered_rules_come_last() {
+		assert_transform!(
+			CssBuildFeature::FlattenLayers,
+			CssAtomSet,
+			StyleSheet,
+			".u { color: red } @layer a { #x .y { color: blue } }\n",
+			"#x .y{color:blue}.u:not(#\\#):not(#\\#){color:red}"
+		);
+	}
+
+	#[test]
+	fn sublayers_come_before_their_layer() {
+		assert_transform!(
+			CssBuildFeature::FlattenLayers,
+			CssAtomSet,
+			StyleSheet,
+			"@layer a { .a { color: red } @layer b { .b { color: blue } } }\n",
+			".b{color:blue}.a:not(#\\#){color:red}"
+		);
+	}
+
+	#[test]
+	fn boosts_before_pseudo_elements() {
+		assert_transform!(
+			CssBuildFeature::FlattenLayers,
+			CssAtomSet,
+			StyleSheet,
+			"@layer a { .x { color: red } } .y::before, .z { color: blue }\n",
+			".x{color:red}.y:not(#\\#)::before,.z:not(#\\#){color:blue}"
+		);
+	}
+
+	#[test]
+	fn moves_group_rules() {
+		assert_transform!(
+			CssBuildFeature::FlattenLayers,
+			CssAtomSet,
+			StyleSheet,
+			".b { color: red } @layer a { @media screen { .a { color: blue } } }\n",
+			"@media screen{.a{color:blue}}.b:not(#\\#){color:red}"
+		);
+	}
+
+	#[test]
+	fn keeps_leading_rules_first() {
+		assert_transform!(
+			CssBuildFeature::FlattenLayers,
+			CssAtomSet,
+			StyleSheet,
+			"@charset \"utf-8\"; @layer a { .a { color: red } }\n",
+			"@charset \"utf-8\";.a{color:red}"
+		);
+	}
+
+	#[test]
+	fn leaves_sheets_without_layers_alone() {
+		assert_no_transform!(CssBuildFeature::FlattenLayers, CssAtomSet, StyleSheet, ".a { color: red }");
+	}
+
+	#[test]
+	fn leaves_unflattenable_sheets_alone() {
+		assert_no_transform!(
+			CssBuildFeature::FlattenLayers,
+			CssAtomSet,
+			StyleSheet,
+			"@import \"a.css\" layer(a); .a { color: red }"
+		);
+		assert_eq!(warnings("@import \"a.css\" layer(a);"), vec![LayerWarningKind::LayeredImport]);
+		assert_eq!(warnings("@media screen { @layer a { .a { color: red } } }"), vec![LayerWarningKind::NestedLayer]);
+	}
+
+	#[test]
+	fn warns_about_important() {
+		assert_eq!(
+			warnings("@layer a { .a { color: red !important } } .b { color: blue !important; width: 0 !important }"),
+			vec![LayerWarningKind::Important { property: "color".into(), layer: "a".into() }]
+		);
+		assert_eq!(warnings("@layer a { .a { color: revert-layer } }"), vec![LayerWarningKind::RevertLayer]);
+	}
+}
@@ -0,0 +1,304 @@
+use crate::prelude::*;
+use css_ast::{
+	CssAtomSet, DeclarationValue, PropertyRule, QueryableNode, Rule, StyleSheet, StyleValue, VarFunction, Visitable,
+};
+use css_lexer::{Cursor, Kind, Lexer, SourceOffset};
+use css_parse::{ComponentValues, Declaration, Span};
+use std::collections::{HashMap, HashSet};
+
+/// Inlines custom properties which are declared once on `:root` (or `html`) into each `var()` which references them,
+/// and removes their declaration once nothing else references it.
+///
+/// This assumes custom properties are never set outside of the stylesheet (for example from scripts or inline styles),
+/// so it is not part of [CssMinifierFeature::safe()] and must be opted in to.
+pub struct InlineCustomProperties<'a, 'ctx, N: Visitable + NodeWithMetadata<CssMetadata>> {
+	pub transformer: &'ctx Transformer<'a, CssMetadata, N, CssMinifierFeature>,
+	index: CustomPropertyIndex<'a>,
+	/// The end of the last `var()` to be inlined, so that any `var()` within its fallback is left alone.
+	inlined_until: SourceOffset,
+}
+
+impl<'a, 'ctx, N> Transform<'a, 'ctx, CssMetadata, N, CssMinifierFeature> for InlineCustomProperties<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn may_change(features: CssMinifierFeature, _node: &N) -> bool {
+		features.contains(CssMinifierFeature::InlineCustomProperties)
+	}
+
+	fn new(transformer: &'ctx Transformer<'a, CssMetadata, N, CssMinifierFeature>) -> Self {
+		Self { transformer, index: CustomPropertyIndex::default(), inlined_until: SourceOffset(0) }
+	}
+}
+
+impl<'a, 'ctx, N> Visit for InlineCustomProperties<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn visit_style_sheet(&mut self, sheet: &StyleSheet) {
+		self.index = CustomPropertyIndex::new(sheet, self.transformer.source_text);
+		for property in self.index.properties.values() {
+			// Only the declaration itself refers to this property, so it can go.
+			if property.references == 0 {
+				self.transformer.delete(property.declaration);
+			}
+		}
+	}
+
+	fn visit_var_function(&mut self, function: &VarFunction) {
+		let span = function.to_span();
+		if span.start() < self.inlined_until {
+			return;
+		}
+		let name = Cursor::from(function.property).str_slice(self.transformer.source_text);
+		if let Some(property) = self.index.properties.get(name) {
+			self.transformer.replace_parsed::<ComponentValues>(span, property.value);
+			self.inlined_until = span.end();
+		}
+	}
+}
+
+/// A custom property which can be inlined, see [CustomPropertyIndex].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InlinableCustomProperty<'a> {
+	/// The source text of the property's value.
+	pub value: &'a str,
+	/// The declaration of the property.
+	pub declaration: Span,
+	/// How many times the property's name appears in the stylesheet, other than in its declaration.
+	pub references: usize,
+}
+
+/// An index of the custom properties in a stylesheet whose value is the same for every element, and so can be
+/// substituted for any `var()` which references them.
+///
+/// These are the custom properties declared exactly once in the stylesheet, within a top level `:root` or `html` rule,
+/// which are not registered with `@property`. Their value must not depend on the element it is computed for, so values
+/// which reference other custom properties, use `attr()`, or are a CSS-wide keyword are excluded.
+#[derive(Debug, Default)]
+pub struct CustomPropertyIndex<'a> {
+	pub properties: HashMap<&'a str, InlinableCustomProperty<'a>>,
+}
+
+impl<'a> CustomPropertyIndex<'a> {
+	pub fn new(sheet: &StyleSheet, source_text: &'a str) -> Self {
+		let mut collector = CustomPropertyCollector { source_text, ..Default::default() };
+		sheet.accept(&mut collector);
+		let slice = |span: Span| &source_text[usize::from(span.start())..usize::from(span.end())];
+
+		let mut properties = HashMap::new();
+		for rule in &sheet.rules {
+			let Rule::Style(rule) = rule else { continue };
+			let selector = slice(rule.rule.prelude.to_span());
+			if !selector.eq_ignore_ascii_case(":root") && !selector.eq_ignore_ascii_case("html") {
+				continue;
+			}
+			for declaration in &rule.rule.block.declarations {
+				let StyleValue::Custom(custom) = &declaration.value else { continue };
+				let name = Cursor::from(declaration.name).str_slice(source_text);
+				if collector.declarations.get(name) != Some(&1)
+					|| collector.registered.contains(name)
+					|| collector.dependent.contains(name)
+				{
+					continue;
+				}
+				let value = slice(custom.to_span()).trim();
+				let lowercase = value.to_ascii_lowercase();
+				if matches!(lowercase.as_str(), "" | "initial" | "inherit" | "unset" | "revert" | "revert-layer")
+					|| lowercase.contains("attr(")
+				{
+					continue;
+				}
+				properties
+					.insert(name, InlinableCustomProperty { value, declaration: declaration.to_span(), references: 0 });
+			}
+		}
+
+		// Count every token which could refer to a property, not just those in a `var()` which will be inlined, so that a
+		// declaration is only ever removed once nothing could possibly reference it.
+		if !properties.is_empty() {
+			for cursor in Lexer::new(&CssAtomSet::ATOMS, source_text) {
+				if cursor == Kind::Ident
+					&& let Some(property) = properties.get_mut(cursor.str_slice(source_text))
+				{
+					property.references += 1;
+				}
+			}
+			for property in properties.values_mut() {
+				property.references -= 1;
+			}
+		}
+
+		Self { properties }
+	}
+}
+
+#[derive(Default)]
+struct CustomPropertyCollector<'a> {
+	source_text: &'a str,
+	/// How many times each custom property is declared.
+	declarations: HashMap<&'a str, usize>,
+	/// Custom properties registered with `@property`.
+	registered: HashSet<&'a str>,
+	/// Custom properties whose value contains a `var()`.
+	dependent: HashSet<&'a str>,
+	current: Option<&'a str>,
+}
+
+impl<'a> Visit for CustomPropertyCollector<'a> {
+	fn visit_declaration<'b, T: DeclarationValue<'b, CssMetadata> + QueryableNode>(
+		&mut self,
+		decl: &Declaration<'b, T, CssMetadata>,
+	) {
+		if decl.value.is_custom() {
+			let name = Cursor::from(decl.name).str_slice(self.source_text);
+			*self.declarations.entry(name).or_default() += 1;
+			self.current = Some(name);
+		}
+	}
+
+	fn exit_declaration<'b, T: DeclarationValue<'b, CssMetadata> + QueryableNode>(
+		&mut self,
+		_decl: &Declaration<'b, T, CssMetadata>,
+	) {
+		self.current = None;
+	}
+
+	fn visit_property_rule(&mut self, rule: &PropertyRule) {
+		self.registered.insert(rule.prelude.ident().str_slice(self.source_text));
+	}
+
+	fn visit_var_function(&mut self, _function: &VarFunction) {
+		if let Some(name) = self.current {
+			self.dependent.insert(name);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::test_helpers::{assert_no_transform, assert_transform};
+	use crate::{CssMinifierFeature, Transformer};
+	use bumpalo::Bump;
+	use css_ast::{CssAtomSet, StyleSheet};
+	use css_lexer::Lexer;
+	use css_parse::{CursorCompactWriteSink, CursorOverlaySink, Parser, ToCursors};
+
+	fn minify(source_text: &str) -> String {
+		let bump = Bump::default();
+		let mut transformer =
+			Transformer::new_in(&bump, CssMinifierFeature::InlineCustomProperties, &CssAtomSet::ATOMS, source_text);
+		let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+		let mut parser = Parser::new(&bump, source_text, lexer);
+		let result = transformer.transform_until_stable(parser.parse_entirely::<StyleSheet>(), 8).unwrap();
+		let overlays = transformer.overlays();
+		let mut output = String::new();
+		let mut stream = CursorOverlaySink::new(
+			result.source_text,
+			&overlays,
+			CursorCompactWriteSink::new(result.source_text, &mut output),
+		);
+		result.to_cursors(&mut stream);
+		output
+	}
+
+	#[test]
+	fn inlines_root_properties() {
+		assert_transform!(
+			CssMinifierFeature::InlineCustomProperties,
+			CssAtomSet,
+			StyleSheet,
+			":root { --gap: 4px; } a { margin: var(--gap) 0; }",
+			":root { --gap: 4px; } a { margin: 4px 0; }"
+		);
+	}
+
+	#[test]
+	fn inlines_into_fallbacks() {
+		assert_eq!(
+			minify("html { --red: #f00; } a { color: var(--brand, var(--red)); border-color: var(--red, blue); }"),
+			"html{}a{color:var(--brand,#f00);border-color:#f00}"
+		);
+	}
+
+	#[test]
+	fn removes_unreferenced_declarations() {
+		assert_transform!(
+			CssMinifierFeature::InlineCustomProperties,
+			CssAtomSet,
+			StyleSheet,
+			":root { --a: 1px; --b: 2px; } a { width: 1px; }",
+			":root { } a { width: 1px; }"
+		);
+	}
+
+	#[test]
+	fn keeps_overridden_properties() {
+		assert_no_transform!(
+			CssMinifierFeature::InlineCustomProperties,
+			CssAtomSet,
+			StyleSheet,
+			":root { --a: 1px; } @media (min-width: 10px) { :root { --a: 2px; } } a { width: var(--a); }"
+		);
+		assert_no_transform!(
+			CssMinifierFeature::InlineCustomProperties,
+			CssAtomSet,
+			StyleSheet,
+			":root { --a: 1px; } a { --a: 2px; width: var(--a); }"
+		);
+	}
+
+	#[test]
+	fn keeps_properties_outside_of_root() {
+		assert_no_transform!(
+			CssMinifierFeature::InlineCustomProperties,
+			CssAtomSet,
+			StyleSheet,
+			"body { --a: 1px; } a { width: var(--a); }"
+		);
+	}
+
+	#[test]
+	fn keeps_registered_properties() {
+		assert_no_transform!(
+			CssMinifierFeature::InlineCustomProperties,
+			CssAtomSet,
+			StyleSheet,
+			"@property --a { syntax: '<length>'; inherits: false; initial-value: 0px; } :root { --a: 1px; } a { width: var(--a); }"
+		);
+	}
+
+	#[test]
+	fn keeps_element_dependent_values() {
+		assert_no_transform!(
+			CssMinifierFeature::InlineCustomProperties,
+			CssAtomSet,
+			StyleSheet,
+			":root { --a: inherit; --b: attr(data-b); } a { width: var(--a); height: var(--b); }"
+		);
+	}
+
+	#[test]
+	fn resolves_chains_over_passes() {
+		assert_eq!(
+			minify(":root { --blue: #00f; --brand: var(--blue); } a { color: var(--brand); }"),
+			":root{}a{color:#00f}"
+		);
+	}
+
+	#[test]
+	fn inlines_within_math_functions() {
+		assert_eq!(
+			minify(":root { --gap: 4px; } a { padding: calc(var(--gap) * 2) var(--gap); }"),
+			":root{}a{padding:calc(4px*2)4px}"
+		);
+	}
+
+	#[test]
+	fn keeps_declarations_referenced_elsewhere() {
+		assert_eq!(
+			minify(":root { --a: 1px; } a { width: var(--a); -x-unknown: var(--a); }"),
+			":root{--a:1px}a{width:1px;-x-unknown:var(--a)}"
+		);
+	}
+}
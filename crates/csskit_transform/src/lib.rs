@@ -6,21 +6,45 @@ pub use transform::*;
 pub use transformer::*;
 
 pub(crate) mod prelude {
-	pub(crate) use crate::{CssMinifierFeature, Transform, Transformer};
+	pub(crate) use crate::{CssBuildFeature, CssMinifierFeature, Transform, Transformer};
 	pub(crate) use css_ast::{CssMetadata, Visit};
 	pub(crate) use css_lexer::ToSpan;
 	pub(crate) use css_parse::NodeWithMetadata;
 }
 
+mod comment_retention;
+mod consolidate_colors;
+mod convert_color_notation;
+mod convert_length_units;
+mod convert_to_rtl;
+mod css_build;
 mod css_minifier;
+mod flatten_layers;
+mod inline_custom_properties;
+mod prune_conditional_rules;
+mod reduce_calc;
 mod reduce_colors;
+mod reduce_filters;
 mod reduce_lengths;
 mod reduce_time_units;
+mod sort_declarations;
 
+pub use comment_retention::*;
+pub use consolidate_colors::*;
+pub use convert_color_notation::*;
+pub use convert_length_units::*;
+pub use convert_to_rtl::*;
+pub use css_build::*;
 pub use css_minifier::*;
+pub use flatten_layers::*;
+pub use inline_custom_properties::*;
+pub use prune_conditional_rules::*;
+pub use reduce_calc::*;
 pub use reduce_colors::*;
+pub use reduce_filters::*;
 pub use reduce_lengths::*;
 pub use reduce_time_units::*;
+pub use sort_declarations::*;
 
 #[cfg(test)]
 pub mod test_helpers;
@@ -0,0 +1,448 @@
+use crate::prelude::*;
+use css_ast::{
+	CssAtomSet, HeightMediaFeature, Length, MediaCondition, MediaFeature, MediaPreCondition, MediaQuery, MediaRule,
+	StyleValue, SupportsCondition, SupportsFeature, SupportsRule, Visitable, WidthMediaFeature,
+};
+use css_feature_data::{CSSFeature, NamedBrowserVersion};
+use css_lexer::{Cursor, Kind, Lexer};
+use css_parse::{Comparison, Declaration, Span};
+
+/// Removes conditional rules whose condition can be decided ahead of time.
+///
+/// `@supports` rules are checked against each browser in [CssMinifierOptions::targets][crate::CssMinifierOptions],
+/// using the browser support data for each property, keyword and selector they test. A rule which every target supports
+/// is unwrapped so its contents apply unconditionally, and a rule which no target supports is removed. Conditions which
+/// are supported by some targets but not others, or which the data does not cover, are left alone.
+///
+/// `@media` rules are removed if every query in them can never match, such as `(min-width: 600px) and (max-width:
+/// 400px)`. This does not depend on the targets.
+pub struct PruneConditionalRules<'a, 'ctx, N: Visitable + NodeWithMetadata<CssMetadata>> {
+	pub transformer: &'ctx Transformer<'a, CssMetadata, N, CssMinifierFeature>,
+}
+
+impl<'a, 'ctx, N> Transform<'a, 'ctx, CssMetadata, N, CssMinifierFeature> for PruneConditionalRules<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn may_change(features: CssMinifierFeature, _node: &N) -> bool {
+		features.contains(CssMinifierFeature::PruneConditionalRules)
+	}
+
+	fn new(transformer: &'ctx Transformer<'a, CssMetadata, N, CssMinifierFeature>) -> Self {
+		Self { transformer }
+	}
+}
+
+impl<'a, 'ctx, N> Visit for PruneConditionalRules<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn visit_supports_rule(&mut self, rule: &SupportsRule) {
+		let targets = &self.transformer.options().targets;
+		if targets.is_empty() {
+			return;
+		}
+		let source_text = self.transformer.source_text;
+		let results = targets.iter().map(|target| supports_condition(&rule.prelude, source_text, *target));
+		let mut results = results.collect::<Vec<_>>();
+		results.dedup();
+		match results.as_slice() {
+			[Some(false)] => self.transformer.delete(rule),
+			[Some(true)] => {
+				let block = &rule.block.0;
+				self.transformer.delete(Span::new(rule.to_span().start(), block.open_curly.to_span().end()));
+				if let Some(close_curly) = block.close_curly {
+					self.transformer.delete(close_curly);
+				}
+			}
+			_ => {}
+		}
+	}
+
+	fn visit_media_rule(&mut self, rule: &MediaRule) {
+		if (&rule.prelude.0).into_iter().all(|(query, _)| never_matches(query)) {
+			self.transformer.delete(rule);
+		}
+	}
+}
+
+/// Checks whether the given browser supports an `@supports` condition, returning None if it cannot be known.
+pub fn supports_condition(
+	condition: &SupportsCondition,
+	source_text: &str,
+	target: NamedBrowserVersion,
+) -> Option<bool> {
+	let supports = |feature| supports_feature(feature, source_text, target);
+	match condition {
+		SupportsCondition::Is(feature) => supports(feature),
+		SupportsCondition::Not(_, feature) => supports(feature).map(|supported| !supported),
+		SupportsCondition::And(features) => features.iter().map(|(feature, _)| supports(feature)).fold(Some(true), and),
+		SupportsCondition::Or(features) => features.iter().map(|(feature, _)| supports(feature)).fold(Some(false), or),
+	}
+}
+
+fn and(a: Option<bool>, b: Option<bool>) -> Option<bool> {
+	match (a, b) {
+		(Some(false), _) | (_, Some(false)) => Some(false),
+		(Some(true), Some(true)) => Some(true),
+		_ => None,
+	}
+}
+
+fn or(a: Option<bool>, b: Option<bool>) -> Option<bool> {
+	match (a, b) {
+		(Some(true), _) | (_, Some(true)) => Some(true),
+		(Some(false), Some(false)) => Some(false),
+		_ => None,
+	}
+}
+
+fn supports_feature(feature: &SupportsFeature, source_text: &str, target: NamedBrowserVersion) -> Option<bool> {
+	match feature {
+		SupportsFeature::Property(_, declaration, _) => supports_declaration(declaration, source_text, target),
+		SupportsFeature::Selector(_, _, selector, _, _) => {
+			let span = selector.to_span();
+			supports_selector(&source_text[span.start().0 as usize..span.end().0 as usize], target)
+		}
+		SupportsFeature::FontTech(..) | SupportsFeature::FontFormat(..) => None,
+	}
+}
+
+fn supports_declaration<'a>(
+	declaration: &Declaration<'a, StyleValue<'a>, CssMetadata>,
+	source_text: &str,
+	target: NamedBrowserVersion,
+) -> Option<bool> {
+	let name = Cursor::from(declaration.name).str_slice(source_text).to_ascii_lowercase();
+	if name.starts_with("--") {
+		return Some(true);
+	}
+	let property = CSSFeature::by_property_name(&name)?.browser_support.supported_by(target)?;
+	if !property {
+		return Some(false);
+	}
+	if matches!(declaration.value, StyleValue::Initial(_) | StyleValue::Inherit(_) | StyleValue::Unset(_)) {
+		return Some(true);
+	}
+	// Keywords often have support data of their own, such as `display: grid`.
+	let span = declaration.value.to_span();
+	let keyword = source_text[span.start().0 as usize..span.end().0 as usize].trim().to_ascii_lowercase();
+	if keyword.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+		&& let Some(feature) = CSSFeature::by_feature_name(&format!("css.properties.{name}.{keyword}"))
+	{
+		return feature.browser_support.supported_by(target);
+	}
+	// Any other value may use units or functions which the target lacks, such as `cqw` or `calc()`.
+	None
+}
+
+/// Checks each pseudo-class and pseudo-element in a selector, as any other kind of selector is supported everywhere.
+fn supports_selector(selector: &str, target: NamedBrowserVersion) -> Option<bool> {
+	let mut supported = Some(true);
+	let mut after_colon = false;
+	for cursor in Lexer::new(&CssAtomSet::ATOMS, selector) {
+		if after_colon && (cursor == Kind::Ident || cursor == Kind::Function) {
+			let name = cursor.str_slice(selector).trim_end_matches('(').to_ascii_lowercase();
+			let feature = CSSFeature::by_feature_name(&format!("css.selectors.{name}"));
+			supported = and(supported, feature.and_then(|feature| feature.browser_support.supported_by(target)));
+		}
+		if cursor == Kind::Eof {
+			break;
+		}
+		after_colon = cursor == Kind::Colon;
+	}
+	supported
+}
+
+/// Returns true if a media query can never match, because the ranges it requires of a dimension do not overlap.
+pub fn never_matches(query: &MediaQuery) -> bool {
+	if matches!(query.precondition(), Some(MediaPreCondition::Not(_))) {
+		return false;
+	}
+	let features = match query.condition() {
+		Some(MediaCondition::Is(feature)) => vec![feature],
+		Some(MediaCondition::And(features)) => features.iter().map(|(feature, _)| feature).collect(),
+		_ => return false,
+	};
+	let mut width = PxRange::default();
+	let mut height = PxRange::default();
+	for feature in features {
+		match feature {
+			MediaFeature::Width(feature) => width.constrain_width(feature),
+			MediaFeature::Height(feature) => height.constrain_height(feature),
+			_ => {}
+		}
+	}
+	width.is_empty() || height.is_empty()
+}
+
+/// The range of `px` sizes a media query allows for a dimension.
+#[derive(Debug, Clone, Copy)]
+struct PxRange {
+	min: f32,
+	min_inclusive: bool,
+	max: f32,
+	max_inclusive: bool,
+}
+
+impl Default for PxRange {
+	fn default() -> Self {
+		Self { min: f32::NEG_INFINITY, min_inclusive: true, max: f32::INFINITY, max_inclusive: true }
+	}
+}
+
+/// Converts an absolute length to `px`. Relative lengths such as `em` are left unknown, as media queries resolve them
+/// against the user's preferred font size.
+fn px(length: &Length) -> Option<f32> {
+	match length {
+		Length::Px(px) => Some((*px).into()),
+		length => length.to_px(),
+	}
+}
+
+macro_rules! constrain_ranged {
+	($range: ident, $feature: ident, $ty: ident) => {
+		match $feature {
+			$ty::Min(_, _, _, value, _) => $range.above(px(value), true),
+			$ty::Max(_, _, _, value, _) => $range.below(px(value), true),
+			$ty::Exact(_, _, _, value, _) => {
+				$range.above(px(value), true);
+				$range.below(px(value), true);
+			}
+			$ty::Left(_, _, comparison, value, _) => $range.compare(comparison, px(value), true),
+			$ty::Right(_, value, comparison, _, _) => $range.compare(comparison, px(value), false),
+			$ty::Range(_, low, low_comparison, _, high_comparison, high, _) => {
+				$range.compare(low_comparison, px(low), false);
+				$range.compare(high_comparison, px(high), true);
+			}
+		}
+	};
+}
+
+impl PxRange {
+	fn constrain_width(&mut self, feature: &WidthMediaFeature) {
+		constrain_ranged!(self, feature, WidthMediaFeature)
+	}
+
+	fn constrain_height(&mut self, feature: &HeightMediaFeature) {
+		constrain_ranged!(self, feature, HeightMediaFeature)
+	}
+
+	fn above(&mut self, px: Option<f32>, inclusive: bool) {
+		if let Some(px) = px
+			&& (px > self.min || (px == self.min && !inclusive))
+		{
+			self.min = px;
+			self.min_inclusive = inclusive;
+		}
+	}
+
+	fn below(&mut self, px: Option<f32>, inclusive: bool) {
+		if let Some(px) = px
+			&& (px < self.max || (px == self.max && !inclusive))
+		{
+			self.max = px;
+			self.max_inclusive = inclusive;
+		}
+	}
+
+	/// Applies a comparison between the dimension and a value, such as `width < 400px`, or `400px < width` when
+	/// `dimension_first` is false.
+	fn compare(&mut self, comparison: &Comparison, px: Option<f32>, dimension_first: bool) {
+		match (comparison, dimension_first) {
+			(Comparison::LessThan(_), true) | (Comparison::GreaterThan(_), false) => self.below(px, false),
+			(Comparison::LessThanEqual(_), true) | (Comparison::GreaterThanEqual(_), false) => self.below(px, true),
+			(Comparison::GreaterThan(_), true) | (Comparison::LessThan(_), false) => self.above(px, false),
+			(Comparison::GreaterThanEqual(_), true) | (Comparison::LessThanEqual(_), false) => self.above(px, true),
+			(Comparison::Equal(_), _) => {
+				self.above(px, true);
+				self.below(px, true);
+			}
+		}
+	}
+
+	fn is_empty(&self) -> bool {
+		self.min > self.max || (self.min == self.max && !(self.min_inclusive && self.max_inclusive))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssMinifierOptions;
+	use crate::test_helpers::{assert_no_transform, assert_transform};
+	use bumpalo::Bump;
+	use css_ast::StyleSheet;
+	use css_feature_data::BrowserVersion;
+	use css_parse::Parser;
+
+	fn options(targets: &[&str]) -> CssMinifierOptions {
+		CssMinifierOptions { targets: targets.iter().map(|target| target.parse().unwrap()).collect() }
+	}
+
+	#[test]
+	fn unwraps_supported_conditions() {
+		assert_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			options(&["chrome 120", "safari 17"]),
+			CssAtomSet,
+			StyleSheet,
+			"@supports (display: grid) { a { display: grid } } b{}",
+			"a{display:grid}b{}"
+		);
+		assert_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			options(&["chrome 120"]),
+			CssAtomSet,
+			StyleSheet,
+			"@supports (--x: 1) { a { color: red } }\n",
+			"a{color:red}"
+		);
+		assert_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			options(&["firefox 40"]),
+			CssAtomSet,
+			StyleSheet,
+			"@supports not (display: grid) { a { float: left } }\n",
+			"a{float:left}"
+		);
+	}
+
+	#[test]
+	fn removes_unsupported_conditions() {
+		assert_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			options(&["firefox 120"]),
+			CssAtomSet,
+			StyleSheet,
+			"@supports (field-sizing: content) { a { color: red } } b{}",
+			"b{}"
+		);
+		assert_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			options(&["chrome 120", "firefox 120"]),
+			CssAtomSet,
+			StyleSheet,
+			"@supports not (display: flex) { a { float: left } } b{}",
+			"b{}"
+		);
+		assert_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			options(&["chrome 100"]),
+			CssAtomSet,
+			StyleSheet,
+			"@supports selector(:has(a)) { a { color: red } } b{}",
+			"b{}"
+		);
+	}
+
+	#[test]
+	fn keeps_undecidable_conditions() {
+		assert_no_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			options(&["chrome 123", "firefox 120"]),
+			CssAtomSet,
+			StyleSheet,
+			"@supports (field-sizing: content) { a { color: red } }"
+		);
+		assert_no_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			CssAtomSet,
+			StyleSheet,
+			"@supports (field-sizing: content) { a { color: red } }"
+		);
+		assert_no_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			options(&["samsung 20"]),
+			CssAtomSet,
+			StyleSheet,
+			"@supports (field-sizing: content) { a { color: red } }"
+		);
+		assert_no_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			options(&["chrome 120"]),
+			CssAtomSet,
+			StyleSheet,
+			"@supports (-webkit-foo: bar) { a { color: red } }"
+		);
+		assert_no_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			options(&["chrome 50"]),
+			CssAtomSet,
+			StyleSheet,
+			"@supports (width: calc(1px + 1cqw)) { a { color: red } }"
+		);
+		assert_no_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			options(&["chrome 50"]),
+			CssAtomSet,
+			StyleSheet,
+			"@supports (width: 1cqw) { a { color: red } }"
+		);
+	}
+
+	#[test]
+	fn evaluates_conditions_per_target() {
+		let bump = Bump::default();
+		let source_text = "(display: grid) or (field-sizing: content)";
+		let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+		let condition = Parser::new(&bump, source_text, lexer).parse_entirely::<SupportsCondition>().output.unwrap();
+		let chrome = NamedBrowserVersion::Chrome(BrowserVersion(120, 0));
+		let ie = NamedBrowserVersion::InternetExplorer(BrowserVersion(11, 0));
+		assert_eq!(supports_condition(&condition, source_text, chrome), Some(true));
+		assert_eq!(supports_condition(&condition, source_text, ie), None);
+	}
+
+	#[test]
+	fn removes_media_queries_which_never_match() {
+		assert_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			CssAtomSet,
+			StyleSheet,
+			"@media (min-width: 600px) and (max-width: 400px) { a { color: red } } b{}",
+			"b{}"
+		);
+		assert_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			CssAtomSet,
+			StyleSheet,
+			"@media (width > 600px) and (width <= 600px) { a { color: red } } b{}",
+			"b{}"
+		);
+		assert_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			CssAtomSet,
+			StyleSheet,
+			"@media screen and (400px < height < 300px), (min-width: 2px) and (width < 1px) { a{} } b{}",
+			"b{}"
+		);
+	}
+
+	#[test]
+	fn keeps_media_queries_which_may_match() {
+		assert_no_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			CssAtomSet,
+			StyleSheet,
+			"@media (min-width: 400px) and (max-width: 400px) { a { color: red } }"
+		);
+		assert_no_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			CssAtomSet,
+			StyleSheet,
+			"@media (min-width: 600px) and (max-width: 40em) { a { color: red } }"
+		);
+		assert_no_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			CssAtomSet,
+			StyleSheet,
+			"@media not all and (min-width: 600px) and (max-width: 400px) { a { color: red } }"
+		);
+		assert_no_transform!(
+			CssMinifierFeature::PruneConditionalRules,
+			CssAtomSet,
+			StyleSheet,
+			"@media (min-width: 600px) and (max-width: 400px), print { a { color: red } }"
+		);
+	}
+}
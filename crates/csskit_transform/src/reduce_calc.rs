@@ -0,0 +1,158 @@
+use crate::prelude::*;
+use css_ast::{DeclarationValue, MathFunction, QueryableNode, Visitable};
+use css_parse::{ComponentValues, CursorCompactWriteSink, Declaration, ToCursors};
+use std::cell::Cell;
+
+pub struct ReduceCalc<'a, 'ctx, N: Visitable + NodeWithMetadata<CssMetadata>> {
+	pub transformer: &'ctx Transformer<'a, CssMetadata, N, CssMinifierFeature>,
+	/// How many math functions the visitor is within. Only the outermost function is simplified, which covers any
+	/// functions nested within it.
+	depth: Cell<usize>,
+	/// Whether the visitor is within a custom property, whose value is kept as written.
+	in_custom_property: Cell<bool>,
+}
+
+impl<'a, 'ctx, N> Transform<'a, 'ctx, CssMetadata, N, CssMinifierFeature> for ReduceCalc<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn may_change(features: CssMinifierFeature, _node: &N) -> bool {
+		features.contains(CssMinifierFeature::ReduceCalc)
+	}
+
+	fn new(transformer: &'ctx Transformer<'a, CssMetadata, N, CssMinifierFeature>) -> Self {
+		Self { transformer, depth: Cell::new(0), in_custom_property: Cell::new(false) }
+	}
+}
+
+impl<'a, 'ctx, N> Visit for ReduceCalc<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn visit_declaration<'b, T: DeclarationValue<'b, CssMetadata> + QueryableNode>(
+		&mut self,
+		decl: &Declaration<'b, T, CssMetadata>,
+	) {
+		self.in_custom_property.set(decl.value.is_custom());
+	}
+
+	fn exit_declaration<'b, T: DeclarationValue<'b, CssMetadata> + QueryableNode>(
+		&mut self,
+		_decl: &Declaration<'b, T, CssMetadata>,
+	) {
+		self.in_custom_property.set(false);
+	}
+
+	fn visit_math_function(&mut self, function: &MathFunction) {
+		let depth = self.depth.get();
+		self.depth.set(depth + 1);
+		if depth > 0 || self.in_custom_property.get() {
+			return;
+		}
+		let source_text = self.transformer.source_text;
+		let Some(simplified) = function.simplify(source_text) else {
+			return;
+		};
+		let mut original = String::new();
+		function.to_cursors(&mut CursorCompactWriteSink::new(source_text, &mut original));
+		if simplified.len() < original.len() {
+			self.transformer.replace_parsed::<ComponentValues>(function, &simplified);
+		}
+	}
+
+	fn exit_math_function(&mut self, _function: &MathFunction) {
+		self.depth.set(self.depth.get() - 1);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::test_helpers::{assert_no_transform, assert_transform};
+	use css_ast::{CssAtomSet, StyleSheet};
+
+	#[test]
+	fn folds_literal_sums() {
+		assert_transform!(
+			CssMinifierFeature::ReduceCalc,
+			CssAtomSet,
+			StyleSheet,
+			"div { width: calc(10px + 5px); }",
+			"div { width: 15px; }"
+		);
+	}
+
+	#[test]
+	fn combines_terms_around_unknown_values() {
+		assert_transform!(
+			CssMinifierFeature::ReduceCalc,
+			CssAtomSet,
+			StyleSheet,
+			"div { width: calc(100% - 10px - 10px); margin: calc(1px + var(--gap) + 1px) auto; }",
+			"div { width: calc(100% - 20px); margin: calc(2px + var(--gap)) auto; }"
+		);
+	}
+
+	#[test]
+	fn resolves_literal_functions() {
+		assert_transform!(
+			CssMinifierFeature::ReduceCalc,
+			CssAtomSet,
+			StyleSheet,
+			"div { width: clamp(10px, 5px, 20px); height: max(1in, 90px); }",
+			"div { width: 10px; height: 96px; }"
+		);
+	}
+
+	#[test]
+	fn keeps_calc_for_negative_results() {
+		assert_transform!(
+			CssMinifierFeature::ReduceCalc,
+			CssAtomSet,
+			StyleSheet,
+			"div { width: calc(10px - 15px); }",
+			"div { width: calc(-5px); }"
+		);
+	}
+
+	#[test]
+	fn keeps_unresolvable_functions() {
+		assert_no_transform!(
+			CssMinifierFeature::ReduceCalc,
+			CssAtomSet,
+			StyleSheet,
+			"div { width: calc(100% - 10px); height: clamp(1px, 5vw, 20px); }"
+		);
+	}
+
+	#[test]
+	fn simplifies_outermost_function() {
+		assert_transform!(
+			CssMinifierFeature::ReduceCalc,
+			CssAtomSet,
+			StyleSheet,
+			"div { width: calc(100% - min(10px, 20px) - max(1px, 2px)); }",
+			"div { width: calc(100% - 12px); }"
+		);
+	}
+
+	#[test]
+	fn simplifies_functions_in_fallbacks() {
+		assert_transform!(
+			CssMinifierFeature::ReduceCalc,
+			CssAtomSet,
+			StyleSheet,
+			"div { width: var(--w, calc(10px + 5px)); }",
+			"div { width: var(--w, 15px); }"
+		);
+	}
+
+	#[test]
+	fn keeps_custom_properties() {
+		assert_no_transform!(CssMinifierFeature::ReduceCalc, CssAtomSet, StyleSheet, "div { --w: calc(10px + 5px); }");
+	}
+
+	#[test]
+	fn keeps_invalid_calc() {
+		assert_no_transform!(CssMinifierFeature::ReduceCalc, CssAtomSet, StyleSheet, "div { width: calc(1px + 1s); }");
+	}
+}
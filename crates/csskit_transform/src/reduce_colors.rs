@@ -85,6 +85,27 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn reduces_font_palette_override_colors() {
+		assert_transform!(
+			CssMinifierFeature::ReduceColors,
+			CssAtomSet,
+			StyleSheet,
+			"@font-palette-values --cooler { override-colors: 0 #ff0000, 1 rgb(0, 0, 255); }",
+			"@font-palette-values --cooler { override-colors: 0 red, 1 #00f; }"
+		);
+	}
+
+	#[test]
+	fn no_transform_custom_color_profile() {
+		assert_no_transform!(
+			CssMinifierFeature::ReduceColors,
+			CssAtomSet,
+			StyleSheet,
+			"@color-profile --swop5c { src: url(swop.icc); } .a { color: color(--swop5c 0% 70% 20% 0%); }"
+		);
+	}
+
 	#[test]
 	fn shortens_alpha_hex() {
 		assert_transform!(
@@ -0,0 +1,110 @@
+use crate::prelude::*;
+use css_ast::{FilterValueList, NumberPercentage, Visitable};
+
+pub struct ReduceFilters<'a, 'ctx, N: Visitable + NodeWithMetadata<CssMetadata>> {
+	pub transformer: &'ctx Transformer<'a, CssMetadata, N, CssMinifierFeature>,
+}
+
+impl<'a, 'ctx, N> Transform<'a, 'ctx, CssMetadata, N, CssMinifierFeature> for ReduceFilters<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn may_change(features: CssMinifierFeature, _node: &N) -> bool {
+		features.contains(CssMinifierFeature::ReduceFilters)
+	}
+
+	fn new(transformer: &'ctx Transformer<'a, CssMetadata, N, CssMinifierFeature>) -> Self {
+		Self { transformer }
+	}
+}
+
+impl<'a, 'ctx, N> Visit for ReduceFilters<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn visit_filter_value_list(&mut self, list: &FilterValueList) {
+		// Filter lists are interpolated function by function, with the shorter list padded by trailing identity
+		// functions, so only the identity functions after the last non-identity function can be dropped. A filter other
+		// than `none` also creates a containing block and stacking context, so a list made up entirely of identity
+		// functions keeps its first function rather than being emptied.
+		let keep = list.0.iter().rposition(|function| !function.is_identity()).unwrap_or(0);
+		for (i, function) in list.0.iter().enumerate() {
+			if i > keep {
+				self.transformer.delete(function.to_span());
+				continue;
+			}
+			if let Some(NumberPercentage::Percentage(percentage)) = function.amount() {
+				let number = percentage.value() / 100.0;
+				let value = if number.fract() == 0.0 { format!("{}", number as i64) } else { format!("{number}") };
+				let number_len = value.len() - value.starts_with("0.") as usize;
+				if number_len < percentage.to_span().len() as usize {
+					self.transformer.replace_parsed::<NumberPercentage>(percentage.to_span(), &value);
+				}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::test_helpers::{assert_no_transform, assert_transform};
+	use css_ast::{CssAtomSet, StyleSheet};
+
+	#[test]
+	fn converts_percentages_to_numbers() {
+		assert_transform!(
+			CssMinifierFeature::ReduceFilters,
+			CssAtomSet,
+			StyleSheet,
+			"div { filter: opacity(50%) brightness(150%); }",
+			"div { filter: opacity(0.5) brightness(1.5); }"
+		);
+	}
+
+	#[test]
+	fn keeps_shorter_percentages() {
+		assert_no_transform!(CssMinifierFeature::ReduceFilters, CssAtomSet, StyleSheet, "div { filter: sepia(5%); }");
+	}
+
+	#[test]
+	fn removes_trailing_identity_functions() {
+		assert_transform!(
+			CssMinifierFeature::ReduceFilters,
+			CssAtomSet,
+			StyleSheet,
+			"div { filter: blur(0) contrast(200%) opacity(100%) grayscale(0); }",
+			"div { filter: blur(0) contrast(2); }"
+		);
+	}
+
+	#[test]
+	fn keeps_leading_identity_functions() {
+		assert_no_transform!(
+			CssMinifierFeature::ReduceFilters,
+			CssAtomSet,
+			StyleSheet,
+			"a { filter: blur(0) brightness(1.2); } a:hover { filter: blur(4px) brightness(1.2); }"
+		);
+	}
+
+	#[test]
+	fn keeps_one_function_of_an_identity_list() {
+		assert_transform!(
+			CssMinifierFeature::ReduceFilters,
+			CssAtomSet,
+			StyleSheet,
+			"div { backdrop-filter: opacity(100%) hue-rotate(0deg); }",
+			"div { backdrop-filter: opacity(1); }"
+		);
+	}
+
+	#[test]
+	fn keeps_functions_without_an_identity_default() {
+		assert_no_transform!(
+			CssMinifierFeature::ReduceFilters,
+			CssAtomSet,
+			StyleSheet,
+			"div { filter: invert() drop-shadow(0 0 red) url(#f); }"
+		);
+	}
+}
@@ -26,8 +26,9 @@ where
 		let original_len = time.to_span().len() as usize;
 		let seconds = time.as_seconds();
 
-		if seconds == 0.0 && original_len > 1 {
-			self.transformer.replace_parsed::<Time>(time.to_span(), "0");
+		// A zero time must keep its unit; unitless zero is not a valid `<time>`.
+		if seconds == 0.0 && original_len > 2 {
+			self.transformer.replace_parsed::<Time>(time.to_span(), "0s");
 			return;
 		}
 
@@ -79,8 +80,18 @@ mod tests {
 			CssMinifierFeature::ReduceTimeUnits,
 			CssAtomSet,
 			StyleSheet,
-			"div { transition-delay: 0ms; animation-duration: 0s; }",
-			"div { transition-delay: 0; animation-duration: 0; }"
+			"div { transition-delay: 0ms; animation-duration: 0.0s; }",
+			"div { transition-delay: 0s; animation-duration: 0s; }"
+		);
+	}
+
+	#[test]
+	fn keeps_zero_seconds() {
+		assert_no_transform!(
+			CssMinifierFeature::ReduceTimeUnits,
+			CssAtomSet,
+			StyleSheet,
+			"div { transition-delay: 0s; }"
 		);
 	}
 
@@ -0,0 +1,352 @@
+use crate::{convert_length_units::property_matches, prelude::*};
+use css_ast::{CssAtomSet, DeclarationValue, PropertyGroup, StyleRule, StyleValue, Visitable};
+use css_lexer::{AtomSet, Cursor, Lexer, SourceCursor};
+use css_parse::Span;
+use std::cmp::Ordering;
+
+/// The order [PropertyGroup]s are sorted into by [DeclarationOrder::Grouped]: position and layout first, then the box
+/// model, typography, visuals and finally motion. Groups which are not listed are sorted after these, in the order they
+/// are declared.
+const GROUP_ORDER: &[PropertyGroup] = &[
+	PropertyGroup::Position,
+	PropertyGroup::AnchorPosition,
+	PropertyGroup::Display,
+	PropertyGroup::Flexbox,
+	PropertyGroup::Grid,
+	PropertyGroup::Align,
+	PropertyGroup::Gaps,
+	PropertyGroup::Multicol,
+	PropertyGroup::Tables,
+	PropertyGroup::Lists,
+	PropertyGroup::Box,
+	PropertyGroup::Sizing,
+	PropertyGroup::Logical,
+	PropertyGroup::Overflow,
+	PropertyGroup::Fonts,
+	PropertyGroup::Inline,
+	PropertyGroup::Text,
+	PropertyGroup::TextDecor,
+	PropertyGroup::WritingModes,
+	PropertyGroup::Content,
+	PropertyGroup::Color,
+	PropertyGroup::Backgrounds,
+	PropertyGroup::Borders,
+	PropertyGroup::Images,
+	PropertyGroup::FillStroke,
+	PropertyGroup::Masking,
+	PropertyGroup::Shapes,
+	PropertyGroup::FilterEffects,
+	PropertyGroup::Compositing,
+	PropertyGroup::Transforms,
+	PropertyGroup::Transitions,
+	PropertyGroup::Animations,
+];
+
+/// How [SortDeclarations] orders the declarations within each style rule.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum DeclarationOrder {
+	/// Sorts declarations by property name.
+	#[default]
+	Alphabetical,
+	/// Sorts declarations by the [PropertyGroup] (or specification) each property belongs to, such as positioning,
+	/// then the box model, then typography. Properties within a group are sorted by name.
+	Grouped,
+	/// Sorts declarations into the order of the given property names, each of which may start or end with `*` to match
+	/// any prefix or suffix (for example `margin*`). Properties which are not listed come last, sorted by name.
+	Custom(Vec<String>),
+}
+
+impl DeclarationOrder {
+	/// Returns where the given (unprefixed) property sorts, relative to other properties. Properties of equal rank are
+	/// then sorted by name.
+	pub fn rank(&self, property: &str) -> usize {
+		match self {
+			Self::Alphabetical => 0,
+			Self::Grouped => {
+				let Some(group) = StyleValue::property_group_by_name(CssAtomSet::from_str(property)) else {
+					return usize::MAX;
+				};
+				GROUP_ORDER
+					.iter()
+					.position(|ordered| group.contains(*ordered))
+					.unwrap_or_else(|| GROUP_ORDER.len() + group.bits().trailing_zeros() as usize)
+			}
+			Self::Custom(order) => {
+				order.iter().position(|pattern| property_matches(pattern, property)).unwrap_or(order.len())
+			}
+		}
+	}
+}
+
+/// Returns the property name without any vendor prefix, so that `-webkit-transition` sorts alongside `transition`.
+fn unprefixed(property: &str) -> &str {
+	["-webkit-", "-moz-", "-ms-", "-o-"].iter().find_map(|prefix| property.strip_prefix(prefix)).unwrap_or(property)
+}
+
+/// Returns true if the property is flow-relative, such as `margin-inline-start` or `inline-size`, and so sets the same
+/// computed value as one of its physical counterparts depending on the writing mode.
+fn is_flow_relative(property: &str) -> bool {
+	property.split('-').any(|part| matches!(part, "block" | "inline" | "start" | "end"))
+}
+
+/// Returns true if the relative order of two properties changes what they mean, because they can set the same computed
+/// longhand. This is the case for the same property (such as a fallback followed by its replacement, or a prefixed
+/// property followed by the standard one), a shorthand and a longhand it sets, a physical property and its logical
+/// counterpart (such as `left` and `inset-inline-start`), and `all` with any other property.
+fn must_keep_order(a: &str, b: &str) -> bool {
+	if a == b || a == "all" || b == "all" {
+		return true;
+	}
+	let longhands = |property: &str| {
+		let atom = CssAtomSet::from_str(property);
+		StyleValue::longhands_by_name(atom).map(|longhands| longhands.to_vec()).unwrap_or_else(|| vec![atom])
+	};
+	let overlaps = |a: CssAtomSet, b: CssAtomSet| {
+		a == b
+			|| StyleValue::logical_property_group_by_name(a).is_some_and(|group| {
+				StyleValue::logical_property_group_by_name(b) == Some(group)
+					&& is_flow_relative(a.to_str()) != is_flow_relative(b.to_str())
+			})
+	};
+	let sets =
+		|shorthand: &str, longhand: &str| longhand.strip_prefix(shorthand).is_some_and(|rest| rest.starts_with('-'));
+	let (a_longhands, b_longhands) = (longhands(a), longhands(b));
+	sets(a, b) || sets(b, a) || a_longhands.iter().any(|a| b_longhands.iter().any(|b| overlaps(*a, *b)))
+}
+
+/// Sorts the declarations within each style rule, as configured by [DeclarationOrder].
+///
+/// Custom properties are moved to the start of the rule, keeping the order they were written in. Declarations whose
+/// relative order matters (see [must_keep_order]) are never swapped with one another, so sorting does not change what
+/// the stylesheet means. Rules which have nested rules between their declarations are left as written.
+pub struct SortDeclarations<'a, 'ctx, N: Visitable + NodeWithMetadata<CssMetadata>> {
+	pub transformer: &'ctx Transformer<'a, CssMetadata, N, CssBuildFeature>,
+}
+
+impl<'a, 'ctx, N> Transform<'a, 'ctx, CssMetadata, N, CssBuildFeature> for SortDeclarations<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn may_change(features: CssBuildFeature, _node: &N) -> bool {
+		features.contains(CssBuildFeature::SortDeclarations)
+	}
+
+	fn new(transformer: &'ctx Transformer<'a, CssMetadata, N, CssBuildFeature>) -> Self {
+		Self { transformer }
+	}
+}
+
+struct SortableDeclaration<'s> {
+	/// The lowercased property name, without any vendor prefix.
+	property: String,
+	custom: bool,
+	rank: usize,
+	/// The source text of the declaration, without its semicolon.
+	text: &'s str,
+}
+
+impl SortableDeclaration<'_> {
+	fn cmp(&self, other: &Self) -> Ordering {
+		other
+			.custom
+			.cmp(&self.custom)
+			.then_with(|| if self.custom { Ordering::Equal } else { self.rank.cmp(&other.rank) })
+			.then_with(|| if self.custom { Ordering::Equal } else { self.property.cmp(&other.property) })
+	}
+}
+
+impl<'a, 'ctx, N> Visit for SortDeclarations<'a, 'ctx, N>
+where
+	N: Visitable + NodeWithMetadata<CssMetadata>,
+{
+	fn visit_style_rule(&mut self, rule: &StyleRule) {
+		let block = &rule.rule.block;
+		let (Some(first), Some(last)) = (block.declarations.first(), block.declarations.last()) else {
+			return;
+		};
+		let span = Span::new(first.to_span().start(), last.to_span().end());
+		if block.rules.iter().any(|rule| rule.to_span().start() < span.end()) {
+			return;
+		}
+		let source_text = self.transformer.source_text;
+		let order = &self.transformer.options().declaration_order;
+		let declarations = block
+			.declarations
+			.iter()
+			.map(|decl| {
+				let property = Cursor::from(decl.name).str_slice(source_text).to_ascii_lowercase();
+				let property = unprefixed(&property).to_string();
+				let end = decl.semicolon.map(|semicolon| semicolon.to_span().start()).unwrap_or(decl.to_span().end());
+				let text = source_text[usize::from(decl.to_span().start())..usize::from(end)].trim_end();
+				let custom = decl.value.is_custom();
+				let rank = if custom { 0 } else { order.rank(&property) };
+				SortableDeclaration { property, custom, rank, text }
+			})
+			.collect::<Vec<_>>();
+
+		// Repeatedly take the first declaration (in sorted order) which has no unplaced declaration before it that it
+		// must stay after.
+		let mut remaining = (0..declarations.len()).collect::<Vec<_>>();
+		let mut sorted = Vec::with_capacity(declarations.len());
+		while !remaining.is_empty() {
+			let (position, &index) = remaining
+				.iter()
+				.enumerate()
+				.filter(|(position, index)| {
+					let decl = &declarations[**index];
+					remaining[..*position].iter().all(|earlier| {
+						let earlier = &declarations[*earlier];
+						decl.custom || earlier.custom || !must_keep_order(&earlier.property, &decl.property)
+					})
+				})
+				.min_by(|(_, a), (_, b)| declarations[**a].cmp(&declarations[**b]).then(a.cmp(b)))
+				.expect("the first remaining declaration can always be placed");
+			remaining.remove(position);
+			sorted.push(index);
+		}
+		if sorted.iter().enumerate().all(|(position, index)| position == *index) {
+			return;
+		}
+
+		// Each declaration is lexed from its source text rather than parsed, so the whitespace and comments within it are
+		// kept as written.
+		let mut cursors = bumpalo::collections::Vec::new_in(self.transformer.bump());
+		for (position, index) in sorted.iter().enumerate() {
+			if position > 0 {
+				cursors.push(SourceCursor::SEMICOLON);
+			}
+			let text = declarations[*index].text;
+			let lexer = Lexer::new(&CssAtomSet::ATOMS, text);
+			cursors.extend(lexer.map(|cursor| SourceCursor::from(cursor, cursor.str_slice(text))));
+		}
+		if last.semicolon.is_some() {
+			cursors.push(SourceCursor::SEMICOLON);
+		}
+		self.transformer.replace(span, cursors);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CssBuildOptions;
+	use crate::test_helpers::{assert_no_transform, assert_transform};
+	use css_ast::StyleSheet;
+
+	#[test]
+	fn sorts_alphabetically() {
+		assert_transform!(
+			CssBuildFeature::SortDeclarations,
+			CssBuildOptions { declaration_order: DeclarationOrder::Alphabetical, ..Default::default() },
+			CssAtomSet,
+			StyleSheet,
+			"a{z-index:1;color:red;display:block}",
+			"a{color:red;display:block;z-index:1}"
+		);
+	}
+
+	#[test]
+	fn sorts_by_group() {
+		assert_transform!(
+			CssBuildFeature::SortDeclarations,
+			CssBuildOptions { declaration_order: DeclarationOrder::Grouped, ..Default::default() },
+			CssAtomSet,
+			StyleSheet,
+			"a{color:red;font-size:12px;width:10px;display:flex;position:relative}",
+			"a{position:relative;display:flex;width:10px;font-size:12px;color:red}"
+		);
+	}
+
+	#[test]
+	fn sorts_by_custom_order() {
+		let order = vec!["width".into(), "margin*".into()];
+		assert_transform!(
+			CssBuildFeature::SortDeclarations,
+			CssBuildOptions { declaration_order: DeclarationOrder::Custom(order), ..Default::default() },
+			CssAtomSet,
+			StyleSheet,
+			"a{color:red;margin-top:0;width:10px}",
+			"a{width:10px;margin-top:0;color:red}"
+		);
+	}
+
+	#[test]
+	fn keeps_custom_properties_first_and_in_order() {
+		assert_transform!(
+			CssBuildFeature::SortDeclarations,
+			CssBuildOptions { declaration_order: DeclarationOrder::Alphabetical, ..Default::default() },
+			CssAtomSet,
+			StyleSheet,
+			"a{color:red;--z:1;--a:2}",
+			"a{--z:1;--a:2;color:red}"
+		);
+	}
+
+	#[test]
+	fn keeps_fallbacks_and_prefixes_in_order() {
+		assert_transform!(
+			CssBuildFeature::SortDeclarations,
+			CssBuildOptions { declaration_order: DeclarationOrder::Alphabetical, ..Default::default() },
+			CssAtomSet,
+			StyleSheet,
+			"a{display:grid;-webkit-transition:none;color:red;transition:none;display:-ms-grid}",
+			"a{color:red;display:grid;display:-ms-grid;-webkit-transition:none;transition:none}"
+		);
+	}
+
+	#[test]
+	fn keeps_longhands_before_overriding_shorthands() {
+		assert_transform!(
+			CssBuildFeature::SortDeclarations,
+			CssBuildOptions {
+				declaration_order: DeclarationOrder::Custom(vec!["margin".into()]),
+				..Default::default()
+			},
+			CssAtomSet,
+			StyleSheet,
+			"a{margin-top:4px;color:red;margin:0}",
+			"a{color:red;margin-top:4px;margin:0}"
+		);
+	}
+
+	#[test]
+	fn keeps_physical_and_logical_properties_in_order() {
+		assert_transform!(
+			CssBuildFeature::SortDeclarations,
+			CssBuildOptions { declaration_order: DeclarationOrder::Alphabetical, ..Default::default() },
+			CssAtomSet,
+			StyleSheet,
+			"a{left:0;inset-inline-start:1px;margin-left:0;margin-inline-start:auto;color:red}",
+			"a{color:red;left:0;inset-inline-start:1px;margin-left:0;margin-inline-start:auto}"
+		);
+		assert_no_transform!(
+			CssBuildFeature::SortDeclarations,
+			CssBuildOptions { declaration_order: DeclarationOrder::Alphabetical, ..Default::default() },
+			CssAtomSet,
+			StyleSheet,
+			"a{margin-left:auto;margin-inline:0}"
+		);
+	}
+
+	#[test]
+	fn keeps_all_in_order() {
+		assert_no_transform!(
+			CssBuildFeature::SortDeclarations,
+			CssBuildOptions { declaration_order: DeclarationOrder::Alphabetical, ..Default::default() },
+			CssAtomSet,
+			StyleSheet,
+			"a{color:red;all:unset}"
+		);
+	}
+
+	#[test]
+	fn leaves_sorted_rules_alone() {
+		assert_no_transform!(
+			CssBuildFeature::SortDeclarations,
+			CssBuildOptions { declaration_order: DeclarationOrder::Alphabetical, ..Default::default() },
+			CssAtomSet,
+			StyleSheet,
+			"a{color:red;width:1px}"
+		);
+	}
+}
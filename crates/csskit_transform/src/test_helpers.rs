@@ -1,6 +1,31 @@
 #[cfg(test)]
 macro_rules! assert_transform {
-	($features: ident :: $transform: ident, $atoms: ident, $node: ident, $str: literal, $expected: literal) => {{
+	($features: ident :: $transform: ident $(($($args: tt)*))?, retain $retention: expr, $atoms: ident, $node: ident, $str: literal, $expected: literal) => {
+		$crate::test_helpers::assert_transform!(
+			@transform $crate::$features::$transform$(($($args)*))?, Default::default(), [], [$retention], $atoms, $node, $str, $expected
+		)
+	};
+	($features: ident :: $transform: ident $(($($args: tt)*))?, $atoms: ident, $node: ident, $str: literal, $expected: literal) => {
+		$crate::test_helpers::assert_transform!(
+			@transform $crate::$features::$transform$(($($args)*))?, Default::default(), [], [], $atoms, $node, $str, $expected
+		)
+	};
+	($features: ident :: $transform: ident $(($($args: tt)*))?, plugins [$($plugin: expr),+], $atoms: ident, $node: ident, $str: literal, $expected: literal) => {
+		$crate::test_helpers::assert_transform!(
+			@transform $crate::$features::$transform$(($($args)*))?, Default::default(), [$($plugin),+], [], $atoms, $node, $str, $expected
+		)
+	};
+	($features: ident :: $transform: ident $(($($args: tt)*))?, $options: expr, $atoms: ident, $node: ident, $str: literal, $expected: literal) => {
+		$crate::test_helpers::assert_transform!(
+			@transform $crate::$features::$transform$(($($args)*))?, $options, [], [], $atoms, $node, $str, $expected
+		)
+	};
+	(plugin $plugin: expr, $atoms: ident, $node: ident, $str: literal, $expected: literal) => {
+		$crate::test_helpers::assert_transform!(
+			@transform $crate::CssBuildFeature::none(), Default::default(), [$plugin], [], $atoms, $node, $str, $expected
+		)
+	};
+	(@transform $features: expr, $options: expr, [$($plugin: expr),*], [$($retention: expr)?], $atoms: ident, $node: ident, $str: literal, $expected: literal) => {{
 		use bumpalo::Bump;
 		use css_lexer::{Lexer, QuoteStyle};
 		use css_parse::{CursorOverlaySink, CursorPrettyWriteSink, Parser, ToCursors};
@@ -10,27 +35,30 @@ macro_rules! assert_transform {
 		let allocator = Bump::default();
 		let lexer = Lexer::new(&$atoms::ATOMS, source_text);
 		let mut parser = Parser::new(&allocator, source_text, lexer);
-		let mut result = parser.parse_entirely::<$node>();
+		let result = parser.parse_entirely::<$node>();
+		$(let result = result.retain_trivia(|c| $retention.retains(c, source_text));)?
+		let mut result = result;
 		assert!(result.errors.is_empty(), "({:?}) saw error {:?}", source_text, result.errors[0]);
 
 		let expected = $expected;
 		let elexer = Lexer::new(&$atoms::ATOMS, expected);
 		let mut eparser = Parser::new(&allocator, expected, elexer);
 		let eresult = eparser.parse_entirely::<$node>();
+		$(let eresult = eresult.retain_trivia(|c| $retention.retains(c, expected));)?
 		assert!(eresult.errors.is_empty(), "({:?}) saw error {:?}", expected, result.errors[0]);
 
-		let mut transformer: $crate::Transformer<_, $node, $crate::$features> =
-			$crate::Transformer::new_in(&allocator, $crate::$features::$transform, &$atoms::ATOMS, source_text);
+		let mut transformer: $crate::Transformer<_, $node, _> =
+			$crate::Transformer::new_in(&allocator, $features, &$atoms::ATOMS, source_text).with_options($options)$(.with_plugin($plugin))*;
 		let mut actual = String::new();
 		if let Some(ref mut output) = result.output {
-			transformer.transform(output);
+			transformer.transform(output).unwrap();
 			let overlays = transformer.overlays();
 			let mut overlay_stream = CursorOverlaySink::new(
 				source_text,
 				&*overlays,
 				CursorPrettyWriteSink::new(source_text, &mut actual, None, QuoteStyle::Double),
 			);
-			result.output.to_cursors(&mut overlay_stream);
+			result.to_cursors(&mut overlay_stream);
 
 			assert!(transformer.has_changed(), "Transformer did not transform {}", source_text);
 		} else {
@@ -44,7 +72,7 @@ macro_rules! assert_transform {
 
 		let mut expected_pretty = String::new();
 		let mut expected_sink = CursorPrettyWriteSink::new(&expected, &mut expected_pretty, None, QuoteStyle::Double);
-		eresult.output.to_cursors(&mut expected_sink);
+		eresult.to_cursors(&mut expected_sink);
 
 		assert!(
 			actual == expected_pretty,
@@ -61,7 +89,32 @@ pub(crate) use assert_transform;
 
 #[cfg(test)]
 macro_rules! assert_no_transform {
-	($features: ident :: $transform: ident, $atoms: ident, $node: ident, $str: literal) => {{
+	($features: ident :: $transform: ident $(($($args: tt)*))?, retain $retention: expr, $atoms: ident, $node: ident, $str: literal) => {
+		$crate::test_helpers::assert_no_transform!(
+			@transform $crate::$features::$transform$(($($args)*))?, Default::default(), [], [$retention], $atoms, $node, $str
+		)
+	};
+	($features: ident :: $transform: ident $(($($args: tt)*))?, $atoms: ident, $node: ident, $str: literal) => {
+		$crate::test_helpers::assert_no_transform!(
+			@transform $crate::$features::$transform$(($($args)*))?, Default::default(), [], [], $atoms, $node, $str
+		)
+	};
+	($features: ident :: $transform: ident $(($($args: tt)*))?, plugins [$($plugin: expr),+], $atoms: ident, $node: ident, $str: literal) => {
+		$crate::test_helpers::assert_no_transform!(
+			@transform $crate::$features::$transform$(($($args)*))?, Default::default(), [$($plugin),+], [], $atoms, $node, $str
+		)
+	};
+	($features: ident :: $transform: ident $(($($args: tt)*))?, $options: expr, $atoms: ident, $node: ident, $str: literal) => {
+		$crate::test_helpers::assert_no_transform!(
+			@transform $crate::$features::$transform$(($($args)*))?, $options, [], [], $atoms, $node, $str
+		)
+	};
+	(plugin $plugin: expr, $atoms: ident, $node: ident, $str: literal) => {
+		$crate::test_helpers::assert_no_transform!(
+			@transform $crate::CssBuildFeature::none(), Default::default(), [$plugin], [], $atoms, $node, $str
+		)
+	};
+	(@transform $features: expr, $options: expr, [$($plugin: expr),*], [$($retention: expr)?], $atoms: ident, $node: ident, $str: literal) => {{
 		use bumpalo::Bump;
 		use css_lexer::{Lexer, QuoteStyle};
 		use css_parse::{CursorOverlaySink, CursorPrettyWriteSink, Parser, ToCursors};
@@ -71,21 +124,23 @@ macro_rules! assert_no_transform {
 		let allocator = Bump::default();
 		let lexer = Lexer::new(&$atoms::ATOMS, source_text);
 		let mut parser = Parser::new(&allocator, source_text, lexer);
-		let mut result = parser.parse_entirely::<$node>();
+		let result = parser.parse_entirely::<$node>();
+		$(let result = result.retain_trivia(|c| $retention.retains(c, source_text));)?
+		let mut result = result;
 		assert!(result.errors.is_empty(), "({:?}) saw error {:?}", source_text, result.errors[0]);
 
-		let mut transformer: $crate::Transformer<_, $node, $crate::$features> =
-			$crate::Transformer::new_in(&allocator, $crate::$features::$transform, &$atoms::ATOMS, source_text);
+		let mut transformer: $crate::Transformer<_, $node, _> =
+			$crate::Transformer::new_in(&allocator, $features, &$atoms::ATOMS, source_text).with_options($options)$(.with_plugin($plugin))*;
 		let mut actual = String::new();
 		if let Some(ref mut output) = result.output {
-			transformer.transform(output);
+			transformer.transform(output).unwrap();
 			let overlays = transformer.overlays();
 			let mut overlay_stream = CursorOverlaySink::new(
 				source_text,
 				&*overlays,
 				CursorPrettyWriteSink::new(source_text, &mut actual, None, QuoteStyle::Double),
 			);
-			result.output.to_cursors(&mut overlay_stream);
+			result.to_cursors(&mut overlay_stream);
 
 			assert!(!transformer.has_changed(), "Transformer claims to have changed! {} \"{}\"", source_text, actual);
 		} else {
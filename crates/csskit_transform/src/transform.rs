@@ -6,3 +6,23 @@ pub trait Transform<'a, 'ctx, M: NodeMetadata, N: NodeWithMetadata<M>, F: Transf
 
 	fn new(transformer: &'ctx Transformer<'a, M, N, F>) -> Self;
 }
+
+/// A transform defined outside of a [transformer!][crate::transformer] feature set, such as one specific to a single
+/// project. Plugins are registered with [Transformer::with_plugin()], and run after the enabled features in each pass,
+/// in the order they were registered.
+///
+/// Plugins edit the node through the [Transformer] they are given, just as the built-in transforms do, so their edits
+/// are committed alongside every other edit in the pass: an edit which overlaps another edit earlier in the source is
+/// deferred to the next pass of [Transformer::transform_until_stable()].
+pub trait TransformPlugin<M: NodeMetadata, N: NodeWithMetadata<M>, F: TransformerFeatures<M, N>> {
+	/// A short name for the plugin, which labels its [Transformer::plugin_stats()].
+	fn name(&self) -> &str;
+
+	/// Returns false to skip running the plugin, given the enabled features and the node to be transformed.
+	fn may_change(&self, _features: F, _node: &N) -> bool {
+		true
+	}
+
+	/// Visits the node, making edits through the given [Transformer].
+	fn transform<'a>(&mut self, transformer: &Transformer<'a, M, N, F>, node: &N);
+}
@@ -1,10 +1,16 @@
+use crate::TransformPlugin;
+use bumpalo::collections::CollectIn;
 use bumpalo::{Bump, collections::Vec};
-use css_lexer::{AtomSet, Cursor, DynAtomSet, Lexer, ToSpan};
+use css_lexer::{AtomSet, Cursor, DynAtomSet, Kind, KindSet, Lexer, ToSpan};
 use css_parse::{
-	CursorOverlaySet, CursorToSourceCursorSink, NodeMetadata, NodeWithMetadata, OverlayKind, OverlaySegment, Parse,
-	Parser, SourceCursor, SourceOffset, Span, ToCursors,
+	CursorCompactWriteSink, CursorOverlaySet, CursorOverlaySink, CursorToSourceCursorSink, NodeMetadata,
+	NodeWithMetadata, OverlayKind, OverlaySegment, Parse, Parser, ParserReturn, SourceCursor, SourceCursorSink,
+	SourceOffset, Span, ToCursors,
+};
+use std::{
+	cell::{Cell, RefCell},
+	marker::PhantomData,
 };
-use std::{cell::RefCell, marker::PhantomData};
 
 #[derive(Debug)]
 pub enum TransformEdit<'a> {
@@ -20,6 +26,24 @@ pub enum CommitError {
 	InvalidEdit { span: Span },
 }
 
+/// The edits made by a single transform, see [Transformer::stats()].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TransformStats {
+	/// How many edits the transform made.
+	pub edits: usize,
+	/// How many bytes of source text the edits removed, less the bytes they inserted. Both sides are measured in their
+	/// compact form, so whitespace and comments within a replaced span are not counted. This may be negative if a
+	/// transform makes the output larger.
+	pub bytes_saved: isize,
+}
+
+/// What made an edit, so that it can be counted in the right [TransformStats].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EditOrigin<F> {
+	Feature(F),
+	Plugin(usize),
+}
+
 struct PendingSegment<'a> {
 	span: Span,
 	intent: OverlayKind,
@@ -27,7 +51,10 @@ struct PendingSegment<'a> {
 	cursors: Vec<'a, SourceCursor<'a>>,
 }
 
-pub trait TransformerFeatures<M, N>: Sized + Default + Copy {
+pub trait TransformerFeatures<M, N>: Sized + Default + Copy + PartialEq {
+	/// Configuration for the transforms, see [Transformer::with_options()].
+	type Options: Default;
+
 	fn transforms<'a, 'ctx>(self, transformer: &'ctx Transformer<'a, M, N, Self>, node: &N)
 	where
 		M: NodeMetadata,
@@ -38,9 +65,14 @@ pub struct Transformer<'a, M: NodeMetadata, N: NodeWithMetadata<M>, F: Transform
 	bump: &'a Bump,
 	atoms: &'static dyn DynAtomSet,
 	pub(crate) features: F,
+	options: F::Options,
 	changed: RefCell<bool>,
 	overlays: RefCell<CursorOverlaySet<'a>>,
-	edits: RefCell<Vec<'a, TransformEdit<'a>>>,
+	edits: RefCell<Vec<'a, (EditOrigin<F>, TransformEdit<'a>)>>,
+	current_origin: Cell<EditOrigin<F>>,
+	stats: RefCell<Vec<'a, (F, TransformStats)>>,
+	plugins: std::vec::Vec<Box<dyn TransformPlugin<M, N, F> + 'a>>,
+	plugin_stats: RefCell<Vec<'a, (usize, TransformStats)>>,
 	pub(crate) source_text: &'a str,
 	_phantom: PhantomData<(M, N)>,
 }
@@ -50,21 +82,121 @@ impl<'a, M: NodeMetadata, N: NodeWithMetadata<M>, F: TransformerFeatures<M, N>>
 		Self {
 			bump,
 			features,
+			options: F::Options::default(),
 			atoms,
 			changed: RefCell::new(false),
 			overlays: RefCell::new(CursorOverlaySet::new(bump)),
 			edits: RefCell::new(Vec::new_in(bump)),
+			current_origin: Cell::new(EditOrigin::Feature(F::default())),
+			stats: RefCell::new(Vec::new_in(bump)),
+			plugins: std::vec::Vec::new(),
+			plugin_stats: RefCell::new(Vec::new_in(bump)),
 			source_text,
 			_phantom: PhantomData,
 		}
 	}
 
-	pub fn transform(&mut self, node: &mut N) {
+	/// Configures the transforms, which otherwise use the [Default] options.
+	pub fn with_options(mut self, options: F::Options) -> Self {
+		self.options = options;
+		self
+	}
+
+	/// Registers a [TransformPlugin] to run after the enabled features.
+	pub fn with_plugin(mut self, plugin: impl TransformPlugin<M, N, F> + 'a) -> Self {
+		self.plugins.push(Box::new(plugin));
+		self
+	}
+
+	pub fn options(&self) -> &F::Options {
+		&self.options
+	}
+
+	pub fn features(&self) -> F {
+		self.features
+	}
+
+	/// The source text of the node being transformed, which the spans of its cursors point into.
+	pub fn source_text(&self) -> &'a str {
+		self.source_text
+	}
+
+	/// Runs each enabled transform once over the node, committing the edits into [Transformer::overlays()]. Edits which
+	/// overlap an earlier edit are dropped, and their number is returned; use [Transformer::transform_until_stable()]
+	/// to have them retried.
+	pub fn transform(&mut self, node: &mut N) -> Result<usize, CommitError> {
+		self.reset();
+		self.run_pass(node)
+	}
+
+	/// Runs the transforms over the parsed result, then writes out the overlaid output and re-parses it so that the
+	/// transforms can run again, building on the edits of the previous pass. This repeats until a pass makes no edits,
+	/// or `max_passes` is reached.
+	///
+	/// The returned [ParserReturn] is for the output of the last pass (so its `source_text` may differ from the one
+	/// given), and [Transformer::overlays()] holds the edits to apply to it. [Transformer::stats()] covers every pass.
+	///
+	/// Intermediate passes are written compactly, keeping only comments if the result interleaves trivia. If the
+	/// output of a pass fails to parse, the result of that pass is returned as-is.
+	pub fn transform_until_stable(
+		&mut self,
+		mut result: ParserReturn<'a, N>,
+		max_passes: usize,
+	) -> Result<ParserReturn<'a, N>, CommitError>
+	where
+		N: Parse<'a> + ToCursors,
+	{
 		self.reset();
+		let mut changed = false;
+		for pass in 1..=max_passes {
+			let Some(node) = result.output.as_mut() else { break };
+			let deferred = self.run_pass(node)?;
+			changed |= self.has_changed();
+			if pass == max_passes || (!self.has_changed() && deferred == 0) {
+				break;
+			}
+			let mut output = String::new();
+			{
+				let overlays = self.overlays();
+				let mut sink = CursorOverlaySink::new(
+					result.source_text,
+					&overlays,
+					CursorCompactWriteSink::new(result.source_text, &mut output),
+				);
+				result.to_cursors(&mut sink);
+			}
+			let source_text: &'a str = self.bump.alloc_str(&output);
+			let lexer = Lexer::new(self.atoms, source_text);
+			let mut parser = Parser::new(self.bump, source_text, lexer);
+			let mut next = parser.parse_entirely::<N>();
+			if next.output.is_none() || !next.errors.is_empty() {
+				break;
+			}
+			if result.keeps_trivia() {
+				next = next.retain_trivia(|c| c == Kind::Comment);
+			}
+			self.source_text = source_text;
+			self.overlays.borrow_mut().clear();
+			result = next;
+		}
+		*self.changed.borrow_mut() = changed;
+		Ok(result)
+	}
+
+	fn run_pass(&mut self, node: &mut N) -> Result<usize, CommitError> {
+		*self.changed.borrow_mut() = false;
+		self.overlays.borrow_mut().clear();
+		self.edits.borrow_mut().clear();
 		self.features.transforms(self, node);
-		if let Err(err) = self.commit_overlays() {
-			panic!("Transform overlays commit failed: {:?}", err);
+		let mut plugins = std::mem::take(&mut self.plugins);
+		for (index, plugin) in plugins.iter_mut().enumerate() {
+			if plugin.may_change(self.features, node) {
+				self.current_origin.set(EditOrigin::Plugin(index));
+				plugin.transform(self, node);
+			}
 		}
+		self.plugins = plugins;
+		self.commit(false)
 	}
 
 	pub fn has_changed(&self) -> bool {
@@ -99,6 +231,29 @@ impl<'a, M: NodeMetadata, N: NodeWithMetadata<M>, F: TransformerFeatures<M, N>>
 		self.overlays.borrow()
 	}
 
+	/// Attributes any subsequent edits to the given feature. This is called by [transformer!] before running each
+	/// transform.
+	pub fn set_current_feature(&self, feature: F) {
+		self.current_origin.set(EditOrigin::Feature(feature));
+	}
+
+	/// The [TransformStats] for each feature which made edits during the last [Transformer::transform()], in the order
+	/// the features first made an edit.
+	pub fn stats(&self) -> std::cell::Ref<'_, Vec<'a, (F, TransformStats)>> {
+		self.stats.borrow()
+	}
+
+	/// The [TransformStats] for each [TransformPlugin] which made edits, named by [TransformPlugin::name()], like
+	/// [Transformer::stats()]. Plugins are detached from the transformer while they run, so this is empty when called
+	/// from within a [TransformPlugin].
+	pub fn plugin_stats(&self) -> std::vec::Vec<(&str, TransformStats)> {
+		self.plugin_stats
+			.borrow()
+			.iter()
+			.filter_map(|(index, stats)| Some((self.plugins.get(*index)?.name(), *stats)))
+			.collect()
+	}
+
 	pub fn parse_value<T>(&self, source: &'a str) -> Vec<'a, SourceCursor<'a>>
 	where
 		T: Parse<'a> + ToCursors,
@@ -122,6 +277,14 @@ impl<'a, M: NodeMetadata, N: NodeWithMetadata<M>, F: TransformerFeatures<M, N>>
 		*self.changed.borrow_mut() = false;
 		self.overlays.borrow_mut().clear();
 		self.edits.borrow_mut().clear();
+		self.stats.borrow_mut().clear();
+		self.plugin_stats.borrow_mut().clear();
+		self.current_origin.set(EditOrigin::Feature(F::default()));
+	}
+
+	fn push_edit(&self, edit: TransformEdit<'a>) {
+		*self.changed.borrow_mut() = true;
+		self.edits.borrow_mut().push((self.current_origin.get(), edit));
 	}
 
 	pub fn has_replacement(&self, span: impl ToSpan) -> bool {
@@ -131,7 +294,7 @@ impl<'a, M: NodeMetadata, N: NodeWithMetadata<M>, F: TransformerFeatures<M, N>>
 	pub fn clear_pending_edits(&self, span: Span) -> bool {
 		let mut edits = self.edits.borrow_mut();
 		let len_before = edits.len();
-		edits.retain(|edit| match edit {
+		edits.retain(|(_, edit)| match edit {
 			TransformEdit::Replace { target, .. } | TransformEdit::Delete { target } => target != &span,
 			_ => true,
 		});
@@ -141,25 +304,21 @@ impl<'a, M: NodeMetadata, N: NodeWithMetadata<M>, F: TransformerFeatures<M, N>>
 	pub fn replace(&self, span: impl ToSpan, cursors: Vec<'a, SourceCursor<'a>>) {
 		let span = span.to_span();
 		debug_assert!(span.start() <= span.end(), "Transformer::replace received invalid span: {:?}", span);
-		*self.changed.borrow_mut() = true;
-		self.edits.borrow_mut().push(TransformEdit::Replace { target: span, cursors });
+		self.push_edit(TransformEdit::Replace { target: span, cursors });
 	}
 
 	pub fn delete(&self, span: impl ToSpan) {
 		let span = span.to_span();
 		debug_assert!(span.start() <= span.end(), "Transformer::delete received invalid span: {:?}", span);
-		*self.changed.borrow_mut() = true;
-		self.edits.borrow_mut().push(TransformEdit::Delete { target: span });
+		self.push_edit(TransformEdit::Delete { target: span });
 	}
 
 	pub fn insert_before(&self, anchor: SourceOffset, cursors: Vec<'a, SourceCursor<'a>>) {
-		*self.changed.borrow_mut() = true;
-		self.edits.borrow_mut().push(TransformEdit::InsertBefore { anchor, cursors });
+		self.push_edit(TransformEdit::InsertBefore { anchor, cursors });
 	}
 
 	pub fn insert_after(&self, anchor: SourceOffset, cursors: Vec<'a, SourceCursor<'a>>) {
-		*self.changed.borrow_mut() = true;
-		self.edits.borrow_mut().push(TransformEdit::InsertAfter { anchor, cursors });
+		self.push_edit(TransformEdit::InsertAfter { anchor, cursors });
 	}
 
 	pub fn replace_parsed<T>(&self, span: impl ToSpan, css: &str)
@@ -170,47 +329,48 @@ impl<'a, M: NodeMetadata, N: NodeWithMetadata<M>, F: TransformerFeatures<M, N>>
 		self.replace(span, self.parse_value::<T>(owned));
 	}
 
+	/// Commits the pending edits into [Transformer::overlays()], returning an error if any edits overlap.
 	pub fn commit_overlays(&self) -> Result<(), CommitError> {
+		self.commit(true).map(|_| ())
+	}
+
+	/// Commits the pending edits into [Transformer::overlays()]. When `strict` is false, an edit which overlaps an
+	/// earlier edit is deferred (dropped from this pass) rather than returning an error. Returns the number of edits
+	/// which were deferred.
+	fn commit(&self, strict: bool) -> Result<usize, CommitError> {
 		let mut edits = self.edits.borrow_mut();
 		if edits.is_empty() {
-			return Ok(());
+			return Ok(0);
 		}
 
-		let mut pending_segments: Vec<'a, PendingSegment<'a>> = Vec::with_capacity_in(edits.len(), self.bump);
+		let mut pending_segments: Vec<'a, (PendingSegment<'a>, EditOrigin<F>)> =
+			Vec::with_capacity_in(edits.len(), self.bump);
 
-		for (order, edit) in edits.drain(..).enumerate() {
-			match edit {
+		for (order, (origin, edit)) in edits.drain(..).enumerate() {
+			let segment = match edit {
 				TransformEdit::Replace { target, cursors } => {
 					if target.start() > target.end() {
 						return Err(CommitError::InvalidEdit { span: target });
 					}
-					pending_segments.push(PendingSegment {
-						span: target,
-						intent: OverlayKind::Replace,
-						order,
-						cursors,
-					});
+					PendingSegment { span: target, intent: OverlayKind::Replace, order, cursors }
 				}
 				TransformEdit::InsertBefore { anchor, cursors } => {
 					let span = Span::new(anchor, anchor);
-					pending_segments.push(PendingSegment { span, intent: OverlayKind::InsertBefore, order, cursors });
+					PendingSegment { span, intent: OverlayKind::InsertBefore, order, cursors }
 				}
 				TransformEdit::InsertAfter { anchor, cursors } => {
 					let span = Span::new(anchor, anchor);
-					pending_segments.push(PendingSegment { span, intent: OverlayKind::InsertAfter, order, cursors });
+					PendingSegment { span, intent: OverlayKind::InsertAfter, order, cursors }
 				}
 				TransformEdit::Delete { target } => {
-					pending_segments.push(PendingSegment {
-						span: target,
-						intent: OverlayKind::Replace,
-						order,
-						cursors: Vec::with_capacity_in(0, self.bump()),
-					});
+					let cursors = Vec::with_capacity_in(0, self.bump());
+					PendingSegment { span: target, intent: OverlayKind::Replace, order, cursors }
 				}
-			}
+			};
+			pending_segments.push((segment, origin));
 		}
 
-		pending_segments.sort_by(|a, b| {
+		pending_segments.sort_by(|(a, ..), (b, ..)| {
 			a.span
 				.start()
 				.cmp(&b.span.start())
@@ -219,40 +379,105 @@ impl<'a, M: NodeMetadata, N: NodeWithMetadata<M>, F: TransformerFeatures<M, N>>
 				.then_with(|| a.order.cmp(&b.order))
 		});
 
+		let mut deferred = 0;
 		let mut last_non_zero: Option<Span> = None;
-		for segment in &pending_segments {
+		let mut committed: Vec<'a, (PendingSegment<'a>, EditOrigin<F>)> =
+			Vec::with_capacity_in(pending_segments.len(), self.bump);
+		for pending in pending_segments {
+			let segment = &pending.0;
 			if segment.span.start() > segment.span.end() {
 				return Err(CommitError::InvalidEdit { span: segment.span });
 			}
-			if segment.span.start() == segment.span.end() {
-				continue;
-			}
-			if let Some(prev) = last_non_zero
-				&& segment.span.start() < prev.end()
-			{
-				return Err(CommitError::OverlappingEdit { previous: prev, new: segment.span });
+			if segment.span.start() != segment.span.end() {
+				if let Some(prev) = last_non_zero
+					&& segment.span.start() < prev.end()
+				{
+					if strict {
+						return Err(CommitError::OverlappingEdit { previous: prev, new: segment.span });
+					}
+					deferred += 1;
+					continue;
+				}
+				last_non_zero = Some(segment.span);
 			}
-			last_non_zero = Some(segment.span);
+			committed.push(pending);
+		}
+
+		let mut stats = self.stats.borrow_mut();
+		let mut plugin_stats = self.plugin_stats.borrow_mut();
+		let mut by_order = committed
+			.iter()
+			.map(|(segment, origin)| (segment.order, *origin, self.bytes_saved(segment)))
+			.collect_in::<Vec<'a, _>>(self.bump);
+		by_order.sort_by_key(|(order, ..)| *order);
+		for (_, origin, bytes_saved) in by_order {
+			let entry = match origin {
+				EditOrigin::Feature(feature) => Self::stats_entry(&mut stats, feature),
+				EditOrigin::Plugin(index) => Self::stats_entry(&mut plugin_stats, index),
+			};
+			entry.edits += 1;
+			entry.bytes_saved += bytes_saved;
 		}
 
 		let mut overlays = self.overlays.borrow_mut();
 		overlays.clear();
-		for segment in pending_segments {
+		for (segment, ..) in committed {
 			let overlay_segment = OverlaySegment::new(segment.span, segment.cursors, segment.intent);
 			overlays.push_segment(overlay_segment);
 		}
 
-		Ok(())
+		Ok(deferred)
+	}
+
+	fn stats_entry<'s, K: PartialEq>(stats: &'s mut Vec<'a, (K, TransformStats)>, key: K) -> &'s mut TransformStats {
+		let index = match stats.iter().position(|(k, _)| *k == key) {
+			Some(index) => index,
+			None => {
+				stats.push((key, TransformStats::default()));
+				stats.len() - 1
+			}
+		};
+		&mut stats[index].1
+	}
+
+	/// How many bytes the segment removes once both the replaced source text and its cursors are compacted.
+	fn bytes_saved(&self, segment: &PendingSegment<'a>) -> isize {
+		let removed = if segment.intent == OverlayKind::Replace { self.span_compact_len(segment.span) } else { 0 };
+		removed - Self::compact_len("", segment.cursors.iter().copied())
+	}
+
+	/// The length of the source text within the span once compacted, as if it were parsed and written by a minifier.
+	fn span_compact_len(&self, span: Span) -> isize {
+		let source_text = &self.source_text[usize::from(span.start())..usize::from(span.end())];
+		let cursors = Lexer::new(self.atoms, source_text)
+			.take_while(|c| *c != Kind::Eof)
+			.map(|c| SourceCursor::from(c, c.str_slice(source_text)));
+		Self::compact_len(source_text, cursors)
+	}
+
+	fn compact_len<'s>(source_text: &'s str, cursors: impl Iterator<Item = SourceCursor<'s>>) -> isize {
+		let mut output = String::new();
+		let mut sink = CursorCompactWriteSink::new(source_text, &mut output);
+		for c in cursors.filter(|c| *c != KindSet::TRIVIA) {
+			SourceCursorSink::append(&mut sink, c);
+		}
+		output.len() as isize
 	}
 }
 
 #[macro_export]
 macro_rules! transformer {
 	($(#[$meta:meta])* $vis:vis enum $feature: ident [ $metadata: ident, $($node:tt)+ ] { $( $(#[$varmeta:meta])* $variant: ident$(,)?)+ } ) => {
+		$crate::transformer!(
+			$(#[$meta])* $vis enum $feature [ $metadata, $($node)+ ] with () { $( $(#[$varmeta])* $variant, )+ }
+		);
+	};
+	($(#[$meta:meta])* $vis:vis enum $feature: ident [ $metadata: ident, $($node:tt)+ ] with $options: ty { $( $(#[$varmeta:meta])* $variant: ident$(,)?)+ } ) => {
 			use $crate::Transform;
 
 			$(#[$meta])*
 			#[bitmask(u16)]
+			#[bitmask_config(flags_iter)]
 			pub enum $feature {
 				$(
 					$(#[$varmeta])*
@@ -264,9 +489,12 @@ macro_rules! transformer {
 			where
 				N: $($node)+ + ::css_parse::NodeWithMetadata<$metadata>
 			{
+				type Options = $options;
+
 				fn transforms<'a, 'ctx>(self, transformer: &'ctx $crate::Transformer<'a, $metadata, N, Self>, node: &N) {
 					$(
 						if $variant::may_change(transformer.features, node) {
+							transformer.set_current_feature(Self::$variant);
 							let mut transform = $variant::new(transformer);
 							node.accept(&mut transform);
 						}
@@ -328,4 +556,317 @@ mod tests {
 		assert_eq!(segments[2].cursors()[0].source(), "C");
 		assert_eq!(segments[3].cursors()[0].source(), "D");
 	}
+
+	mod multi_pass {
+		use crate::Transformer;
+		use bitmask_enum::bitmask;
+		use bumpalo::Bump;
+		use css_ast::{CssAtomSet, CssMetadata, Length, StyleRule, StyleSheet, Visit, Visitable};
+		use css_lexer::{Lexer, ToSpan};
+		use css_parse::{CursorCompactWriteSink, CursorOverlaySink, NodeWithMetadata, Parser, ToCursors};
+
+		transformer!(
+			/// Features for testing how transforms interact with each other.
+			pub enum TestFeature[CssMetadata, Visitable] {
+				/// Renames `a` style rules to `b`, replacing the whole rule.
+				RenameRule,
+				/// Replaces zero lengths with `0`.
+				ZeroLengths,
+			}
+		);
+
+		impl Default for TestFeature {
+			fn default() -> Self {
+				Self::none()
+			}
+		}
+
+		pub struct RenameRule<'a, 'ctx, N: Visitable + NodeWithMetadata<CssMetadata>> {
+			transformer: &'ctx Transformer<'a, CssMetadata, N, TestFeature>,
+		}
+
+		impl<'a, 'ctx, N: Visitable + NodeWithMetadata<CssMetadata>> Transform<'a, 'ctx, CssMetadata, N, TestFeature>
+			for RenameRule<'a, 'ctx, N>
+		{
+			fn may_change(features: TestFeature, _node: &N) -> bool {
+				features.contains(TestFeature::RenameRule)
+			}
+
+			fn new(transformer: &'ctx Transformer<'a, CssMetadata, N, TestFeature>) -> Self {
+				Self { transformer }
+			}
+		}
+
+		impl<'a, 'ctx, N: Visitable + NodeWithMetadata<CssMetadata>> Visit for RenameRule<'a, 'ctx, N> {
+			fn visit_style_rule(&mut self, rule: &StyleRule) {
+				let source_text = self.transformer.source_text;
+				let span = rule.to_span();
+				let rule = &source_text[usize::from(span.start())..usize::from(span.end())];
+				if let Some(rest) = rule.strip_prefix("a") {
+					self.transformer.replace_parsed::<StyleRule>(span, &format!("b{rest}"));
+				}
+			}
+		}
+
+		pub struct ZeroLengths<'a, 'ctx, N: Visitable + NodeWithMetadata<CssMetadata>> {
+			transformer: &'ctx Transformer<'a, CssMetadata, N, TestFeature>,
+		}
+
+		impl<'a, 'ctx, N: Visitable + NodeWithMetadata<CssMetadata>> Transform<'a, 'ctx, CssMetadata, N, TestFeature>
+			for ZeroLengths<'a, 'ctx, N>
+		{
+			fn may_change(features: TestFeature, _node: &N) -> bool {
+				features.contains(TestFeature::ZeroLengths)
+			}
+
+			fn new(transformer: &'ctx Transformer<'a, CssMetadata, N, TestFeature>) -> Self {
+				Self { transformer }
+			}
+		}
+
+		impl<'a, 'ctx, N: Visitable + NodeWithMetadata<CssMetadata>> Visit for ZeroLengths<'a, 'ctx, N> {
+			fn visit_length(&mut self, length: &Length) {
+				if !matches!(length, Length::Zero(_)) && Into::<f32>::into(*length) == 0.0 {
+					self.transformer.replace_parsed::<Length>(length.to_span(), "0");
+				}
+			}
+		}
+
+		fn transform(source_text: &str, max_passes: usize) -> (String, Vec<(TestFeature, usize)>) {
+			let bump = Bump::default();
+			let mut transformer = Transformer::new_in(&bump, TestFeature::all_bits(), &CssAtomSet::ATOMS, source_text);
+			let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+			let mut parser = Parser::new(&bump, source_text, lexer);
+			let result = parser.parse_entirely::<StyleSheet>();
+			let result = transformer.transform_until_stable(result, max_passes).unwrap();
+			let overlays = transformer.overlays();
+			let mut output = String::new();
+			let mut stream = CursorOverlaySink::new(
+				result.source_text,
+				&overlays,
+				CursorCompactWriteSink::new(result.source_text, &mut output),
+			);
+			result.to_cursors(&mut stream);
+			let stats = transformer.stats().iter().map(|(feature, stats)| (*feature, stats.edits)).collect();
+			(output, stats)
+		}
+
+		#[test]
+		fn single_pass_drops_overlapping_edits() {
+			let source_text = "a { width: 0px }";
+			let bump = Bump::default();
+			let mut transformer = Transformer::new_in(&bump, TestFeature::all_bits(), &CssAtomSet::ATOMS, source_text);
+			let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+			let mut parser = Parser::new(&bump, source_text, lexer);
+			let mut result = parser.parse_entirely::<StyleSheet>();
+			assert_eq!(transformer.transform(result.output.as_mut().unwrap()).unwrap(), 1);
+			assert!(transformer.has_changed());
+			assert_eq!(transformer.overlays().segments().len(), 1);
+			assert_eq!(
+				transformer.stats().as_slice(),
+				&[(TestFeature::RenameRule, crate::TransformStats { edits: 1, bytes_saved: 0 })]
+			);
+		}
+
+		#[test]
+		fn later_passes_build_on_earlier_edits() {
+			assert_eq!(
+				transform("a { width: 0px }", 8),
+				("b{width:0}".into(), vec![(TestFeature::RenameRule, 1), (TestFeature::ZeroLengths, 1)])
+			);
+		}
+
+		#[test]
+		fn stops_at_max_passes() {
+			assert_eq!(transform("a { width: 0px }", 1), ("b{width:0px}".into(), vec![(TestFeature::RenameRule, 1)]));
+		}
+
+		#[test]
+		fn stops_when_nothing_changes() {
+			assert_eq!(transform("b { width: 0px }", 8), ("b{width:0}".into(), vec![(TestFeature::ZeroLengths, 1)]));
+			assert_eq!(transform("b { width: 1px }", 8), ("b{width:1px}".into(), vec![]));
+		}
+	}
+
+	mod plugins {
+		use crate::test_helpers::{assert_no_transform, assert_transform};
+		use crate::{CssMinifierFeature, TransformPlugin, TransformStats, Transformer};
+		use bumpalo::Bump;
+		use css_ast::{Class, CssAtomSet, CssMetadata, StyleRule, StyleSheet, Visit, Visitable};
+		use css_lexer::{Cursor, Lexer, ToSpan};
+		use css_parse::{CursorCompactWriteSink, CursorOverlaySink, NodeWithMetadata, Parser, ToCursors};
+
+		/// Renames a class, as a project might to migrate away from a legacy class.
+		struct RenameClass {
+			from: &'static str,
+			to: &'static str,
+		}
+
+		struct RenameClassVisitor<'t, 'a, N: Visitable + NodeWithMetadata<CssMetadata>> {
+			transformer: &'t Transformer<'a, CssMetadata, N, CssMinifierFeature>,
+			from: &'static str,
+			to: &'static str,
+		}
+
+		impl<N: Visitable + NodeWithMetadata<CssMetadata>> Visit for RenameClassVisitor<'_, '_, N> {
+			fn visit_class(&mut self, class: &Class) {
+				if Cursor::from(class.name).str_slice(self.transformer.source_text()) == self.from {
+					self.transformer.replace_parsed::<Class>(class.to_span(), &format!(".{}", self.to));
+				}
+			}
+		}
+
+		impl<N: Visitable + NodeWithMetadata<CssMetadata>> TransformPlugin<CssMetadata, N, CssMinifierFeature> for RenameClass {
+			fn name(&self) -> &str {
+				"rename-class"
+			}
+
+			fn transform<'a>(&mut self, transformer: &Transformer<'a, CssMetadata, N, CssMinifierFeature>, node: &N) {
+				node.accept(&mut RenameClassVisitor { transformer, from: self.from, to: self.to });
+			}
+		}
+
+		/// Rewrites `.old` style rules as `.new`, replacing the whole rule.
+		struct RenameRule;
+
+		struct RenameRuleVisitor<'t, 'a, N: Visitable + NodeWithMetadata<CssMetadata>> {
+			transformer: &'t Transformer<'a, CssMetadata, N, CssMinifierFeature>,
+		}
+
+		impl<N: Visitable + NodeWithMetadata<CssMetadata>> Visit for RenameRuleVisitor<'_, '_, N> {
+			fn visit_style_rule(&mut self, rule: &StyleRule) {
+				let span = rule.to_span();
+				let rule = &self.transformer.source_text()[usize::from(span.start())..usize::from(span.end())];
+				if let Some(rest) = rule.strip_prefix(".old") {
+					self.transformer.replace_parsed::<StyleRule>(span, &format!(".new{rest}"));
+				}
+			}
+		}
+
+		impl<N: Visitable + NodeWithMetadata<CssMetadata>> TransformPlugin<CssMetadata, N, CssMinifierFeature> for RenameRule {
+			fn name(&self) -> &str {
+				"rename-rule"
+			}
+
+			fn transform<'a>(&mut self, transformer: &Transformer<'a, CssMetadata, N, CssMinifierFeature>, node: &N) {
+				node.accept(&mut RenameRuleVisitor { transformer });
+			}
+		}
+
+		fn transform(source_text: &str, features: CssMinifierFeature, max_passes: usize) -> String {
+			let bump = Bump::default();
+			let mut transformer = Transformer::new_in(&bump, features, &CssAtomSet::ATOMS, source_text)
+				.with_plugin(RenameClass { from: "legacy", to: "button" })
+				.with_plugin(RenameRule);
+			let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+			let mut parser = Parser::new(&bump, source_text, lexer);
+			let result = transformer.transform_until_stable(parser.parse_entirely::<StyleSheet>(), max_passes).unwrap();
+			let overlays = transformer.overlays();
+			let mut output = String::new();
+			let mut stream = CursorOverlaySink::new(
+				result.source_text,
+				&overlays,
+				CursorCompactWriteSink::new(result.source_text, &mut output),
+			);
+			result.to_cursors(&mut stream);
+			output
+		}
+
+		#[test]
+		fn runs_alongside_features() {
+			let source_text = ".legacy { width: 0px; color: #ffffff }";
+			let bump = Bump::default();
+			let mut transformer =
+				Transformer::new_in(&bump, CssMinifierFeature::safe(), &CssAtomSet::ATOMS, source_text)
+					.with_plugin(RenameClass { from: "legacy", to: "button" });
+			let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+			let mut parser = Parser::new(&bump, source_text, lexer);
+			let mut result = parser.parse_entirely::<StyleSheet>();
+			transformer.transform(result.output.as_mut().unwrap()).unwrap();
+			assert_eq!(
+				transformer.stats().as_slice(),
+				&[
+					(CssMinifierFeature::ReduceColors, TransformStats { edits: 1, bytes_saved: 3 }),
+					(CssMinifierFeature::ReduceLengths, TransformStats { edits: 1, bytes_saved: 2 }),
+				]
+			);
+			assert_eq!(transformer.plugin_stats(), vec![("rename-class", TransformStats { edits: 1, bytes_saved: 0 })]);
+			let overlays = transformer.overlays();
+			let mut output = String::new();
+			let mut stream =
+				CursorOverlaySink::new(source_text, &overlays, CursorCompactWriteSink::new(source_text, &mut output));
+			result.to_cursors(&mut stream);
+			assert_eq!(output, ".button{width:0;color:#fff}");
+		}
+
+		/// Reads the plugin stats from within a plugin, while the plugins are detached from the transformer.
+		struct ReadStats;
+
+		impl<N: Visitable + NodeWithMetadata<CssMetadata>> TransformPlugin<CssMetadata, N, CssMinifierFeature> for ReadStats {
+			fn name(&self) -> &str {
+				"read-stats"
+			}
+
+			fn transform<'a>(&mut self, transformer: &Transformer<'a, CssMetadata, N, CssMinifierFeature>, _node: &N) {
+				assert_eq!(transformer.plugin_stats(), vec![]);
+			}
+		}
+
+		#[test]
+		fn bytes_saved_ignores_whitespace_and_comments() {
+			let source_text = ".old {\n\twidth: 0px; /* legacy */\n}";
+			let bump = Bump::default();
+			let mut transformer =
+				Transformer::new_in(&bump, CssMinifierFeature::none(), &CssAtomSet::ATOMS, source_text)
+					.with_plugin(RenameRule);
+			let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+			let mut parser = Parser::new(&bump, source_text, lexer);
+			let mut result = parser.parse_entirely::<StyleSheet>();
+			transformer.transform(result.output.as_mut().unwrap()).unwrap();
+			assert_eq!(transformer.plugin_stats(), vec![("rename-rule", TransformStats { edits: 1, bytes_saved: 0 })]);
+		}
+
+		#[test]
+		fn reads_plugin_stats_from_plugins() {
+			let source_text = ".legacy {}";
+			let bump = Bump::default();
+			let mut transformer =
+				Transformer::new_in(&bump, CssMinifierFeature::none(), &CssAtomSet::ATOMS, source_text)
+					.with_plugin(RenameClass { from: "legacy", to: "button" })
+					.with_plugin(ReadStats);
+			let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text);
+			let mut parser = Parser::new(&bump, source_text, lexer);
+			// The edit from the first pass is counted while the plugins run in the second.
+			transformer.transform_until_stable(parser.parse_entirely::<StyleSheet>(), 8).unwrap();
+			assert_eq!(transformer.plugin_stats(), vec![("rename-class", TransformStats { edits: 1, bytes_saved: 0 })]);
+		}
+
+		#[test]
+		fn runs_without_features() {
+			assert_transform!(
+				CssMinifierFeature::none(),
+				plugins [RenameClass { from: "legacy", to: "button" }, RenameRule],
+				CssAtomSet,
+				StyleSheet,
+				".a, .legacy:hover {}",
+				".a, .button:hover {}"
+			);
+			assert_no_transform!(
+				CssMinifierFeature::none(),
+				plugins [RenameClass { from: "legacy", to: "button" }, RenameRule],
+				CssAtomSet,
+				StyleSheet,
+				".a {}"
+			);
+		}
+
+		#[test]
+		fn overlapping_edits_are_deferred_to_later_passes() {
+			// The plugin's edit to the whole rule overlaps the edit ReduceLengths makes to `0px`, which waits for the
+			// next pass, just as it would for an overlapping edit from another feature.
+			let source_text = ".old { width: 0px }";
+			assert_eq!(transform(source_text, CssMinifierFeature::ReduceLengths, 1), ".new{width:0px}");
+			assert_eq!(transform(source_text, CssMinifierFeature::ReduceLengths, 8), ".new{width:0}");
+		}
+	}
 }
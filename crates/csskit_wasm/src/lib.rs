@@ -14,6 +14,9 @@ use miette::{GraphicalReportHandler, GraphicalTheme};
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
+/// The most times [minify] will re-run its transforms over their own output.
+const MAX_PASSES: usize = 8;
+
 #[wasm_bindgen(start)]
 pub fn main() {
 	#[cfg(feature = "console_error_panic_hook")]
@@ -68,20 +71,22 @@ pub fn parse(source_text: String) -> Result<SerializableParserResult, serde_wasm
 pub fn minify(source_text: String) -> Result<String, serde_wasm_bindgen::Error> {
 	let allocator = Bump::default();
 	let lexer = Lexer::new(&CssAtomSet::ATOMS, source_text.as_str());
-	let mut result = Parser::new(&allocator, source_text.as_str(), lexer).parse_entirely::<StyleSheet>();
+	let result = Parser::new(&allocator, source_text.as_str(), lexer).parse_entirely::<StyleSheet>();
 	if !result.errors.is_empty() {
 		return Err(serde_wasm_bindgen::Error::new("Parse error"));
 	}
 	let mut output_string = String::new();
-	if let Some(ref mut stylesheet) = result.output {
+	if result.output.is_some() {
 		let mut transformer =
-			Transformer::new_in(&allocator, CssMinifierFeature::all_bits(), &CssAtomSet::ATOMS, &source_text);
-		transformer.transform(stylesheet);
+			Transformer::new_in(&allocator, CssMinifierFeature::safe(), &CssAtomSet::ATOMS, &source_text);
+		let result = transformer
+			.transform_until_stable(result, MAX_PASSES)
+			.map_err(|err| serde_wasm_bindgen::Error::new(format!("{err:?}")))?;
 		let overlays = transformer.overlays();
 		let mut stream = CursorOverlaySink::new(
-			&source_text,
+			result.source_text,
 			&overlays,
-			CursorCompactWriteSink::new(&source_text, &mut output_string),
+			CursorCompactWriteSink::new(result.source_text, &mut output_string),
 		);
 		result.to_cursors(&mut stream);
 	}